@@ -17,13 +17,13 @@
 )]
 
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use syn::parse::{Error, Parse, ParseStream, Result};
 use syn::punctuated::Punctuated;
-use syn::Expr;
 use syn::{parse_macro_input, Token};
+use syn::{Expr, ExprLit, Lit};
 
-use proc_macro2::{Delimiter, Spacing, TokenStream as TokenStream2, TokenTree};
+use proc_macro2::{Delimiter, Spacing, Span, TokenStream as TokenStream2, TokenTree};
 use proc_macro2::{Group, Punct};
 
 struct Matrix {
@@ -322,3 +322,214 @@ pub fn point(stream: TokenStream) -> TokenStream {
     };
     proc_macro::TokenStream::from(output)
 }
+
+/// Returns `true` if the given block of a `stack!` invocation is the literal `0`.
+fn is_zero_block(block: &Expr) -> bool {
+    matches!(block, Expr::Lit(ExprLit { lit: Lit::Int(lit), .. }) if lit.base10_digits() == "0")
+}
+
+/// The identifier bound to the block `(i, j)` of a `stack!` invocation.
+fn block_ident(i: usize, j: usize) -> proc_macro2::Ident {
+    format_ident!("__stack_block_{}_{}", i, j)
+}
+
+/// A block row or a block column of a `stack!` invocation.
+#[derive(Clone, Copy)]
+enum Axis {
+    Row(usize),
+    Column(usize),
+}
+
+/// Produces the expression computing the number of rows of a block row, or the number of columns
+/// of a block column, checking that all its non-zero blocks agree.
+fn stack_block_dim(blocks: &Matrix, axis: Axis) -> Result<TokenStream2> {
+    let (indices, first, check, error_msg): (Vec<_>, _, _, _) = match axis {
+        Axis::Row(i) => (
+            (0..blocks.ncols()).map(|j| (i, j)).collect(),
+            quote! { 0 },
+            quote! { nalgebra::base::helper::stack_same_nrows },
+            format!(
+                "stack!: the number of rows of the block row {i} cannot be inferred \
+                 because all its blocks are zero."
+            ),
+        ),
+        Axis::Column(j) => (
+            (0..blocks.nrows()).map(|i| (i, j)).collect(),
+            quote! { 1 },
+            quote! { nalgebra::base::helper::stack_same_ncols },
+            format!(
+                "stack!: the number of columns of the block column {j} cannot be inferred \
+                 because all its blocks are zero."
+            ),
+        ),
+    };
+
+    let mut dim = None;
+    for &(i, j) in indices
+        .iter()
+        .filter(|(i, j)| !is_zero_block(&blocks.rows[*i][*j]))
+    {
+        let block = block_ident(i, j);
+        dim = Some(match dim {
+            None => quote! { #block.shape_generic().#first },
+            Some(dim) => quote! { #check(#dim, #block.shape_generic().#first) },
+        });
+    }
+
+    let (i, j) = indices[0];
+    dim.ok_or_else(|| Error::new_spanned(&blocks.rows[i][j], error_msg))
+}
+
+/// Produces the expression computing the block matrix represented by `blocks`.
+fn stack_impl(blocks: &Matrix) -> Result<TokenStream2> {
+    if blocks.nrows() == 0 || blocks.ncols() == 0 {
+        return Err(Error::new(
+            Span::call_site(),
+            "stack! requires at least one block.",
+        ));
+    }
+
+    let row_dim_ident = |i: usize| format_ident!("__stack_row_dim_{}", i);
+    let col_dim_ident = |j: usize| format_ident!("__stack_col_dim_{}", j);
+    let is_zero = |i: usize, j: usize| is_zero_block(&blocks.rows[i][j]);
+
+    let mut output = TokenStream2::new();
+
+    // Evaluate each block exactly once.
+    for (i, row) in blocks.rows.iter().enumerate() {
+        for (j, block) in row.iter().enumerate() {
+            if !is_zero(i, j) {
+                let ident = block_ident(i, j);
+                output.extend(quote! { let #ident = &(#block); });
+            }
+        }
+    }
+
+    // The number of rows of each block row, and the number of columns of each block column, are
+    // given by their non-zero blocks, which must all agree.
+    for i in 0..blocks.nrows() {
+        let ident = row_dim_ident(i);
+        let dim = stack_block_dim(blocks, Axis::Row(i))?;
+        output.extend(quote! { let #ident = #dim; });
+    }
+
+    for j in 0..blocks.ncols() {
+        let ident = col_dim_ident(j);
+        let dim = stack_block_dim(blocks, Axis::Column(j))?;
+        output.extend(quote! { let #ident = #dim; });
+    }
+
+    // The dimensions of the result are the sums of the dimensions of the block rows and columns.
+    let sum_dims = |idents: Vec<proc_macro2::Ident>| {
+        let mut idents = idents.into_iter();
+        let first = idents.next().unwrap();
+        idents.fold(quote! { #first }, |sum, ident| {
+            quote! { nalgebra::DimAdd::add(#sum, #ident) }
+        })
+    };
+    let nrows = sum_dims((0..blocks.nrows()).map(row_dim_ident).collect());
+    let ncols = sum_dims((0..blocks.ncols()).map(col_dim_ident).collect());
+
+    output.extend(quote! {
+        let mut __stack_result = nalgebra::OMatrix::<_, _, _>::zeros_generic(#nrows, #ncols);
+    });
+
+    let mut row_offset = quote! { 0 };
+    for i in 0..blocks.nrows() {
+        let row_dim = row_dim_ident(i);
+        let mut col_offset = quote! { 0 };
+
+        for j in 0..blocks.ncols() {
+            let col_dim = col_dim_ident(j);
+            if !is_zero(i, j) {
+                let block = block_ident(i, j);
+                output.extend(quote! {
+                    __stack_result
+                        .generic_slice_mut((#row_offset, #col_offset), (#row_dim, #col_dim))
+                        .copy_from(#block);
+                });
+            }
+            col_offset = quote! { #col_offset + nalgebra::Dim::value(&#col_dim) };
+        }
+
+        row_offset = quote! { #row_offset + nalgebra::Dim::value(&#row_dim) };
+    }
+
+    Ok(quote! {
+        {
+            #output
+            __stack_result
+        }
+    })
+}
+
+/// Construct a new matrix by stacking matrices in a block matrix.
+///
+/// **Note: Requires the `macros` feature to be enabled (enabled by default)**.
+///
+/// This macro facilitates the construction of block matrices by stacking blocks (matrices) with
+/// the same syntax as [`matrix!`] and [`dmatrix!`]: the blocks of a block row are separated by a
+/// comma (`,`), and a semi-colon (`;`) designates that a new block row begins. All the block rows
+/// must contain the same number of blocks. The blocks can be
+/// arbitrary expressions of any matrix type, including matrix slices, and each of them is
+/// evaluated exactly once. The literal `0` designates a zero block, whose dimensions are
+/// inferred from the other blocks of its block row and block column.
+///
+/// All the blocks of a block row must have the same number of rows, and all the blocks of a
+/// block column must have the same number of columns. These dimensions are checked at
+/// compile-time whenever they are statically known, and at runtime otherwise. The dimensions of
+/// the resulting matrix are statically known exactly when the number of rows of every block row
+/// and the number of columns of every block column are statically known, for instance when
+/// stacking fixed-size matrices.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the blocks are not consistent and can only be checked at
+/// runtime.
+///
+/// # Examples
+///
+/// ```
+/// use nalgebra::{matrix, stack, Matrix3x4, Vector2};
+///
+/// let a = matrix![1, 2;
+///                 3, 4];
+/// let b = matrix![5;
+///                 6];
+/// let c = matrix![7, 8];
+/// let d = matrix![9];
+///
+/// // Produces a Matrix3<_> == SMatrix<_, 3, 3>
+/// let m = stack![a, b;
+///                c, d];
+/// assert_eq!(m, matrix![1, 2, 5;
+///                       3, 4, 6;
+///                       7, 8, 9]);
+///
+/// // Blocks can be arbitrary matrix expressions, and `0` designates a zero block.
+/// let block_diagonal: Matrix3x4<_> = stack![a * 2, 0;
+///                                           0,     Vector2::new(1, 2).transpose()];
+/// assert_eq!(block_diagonal, matrix![2, 4, 0, 0;
+///                                    6, 8, 0, 0;
+///                                    0, 0, 1, 2]);
+/// ```
+///
+/// Stacking dynamically-sized matrices produces dynamically-sized results:
+///
+/// ```
+/// use nalgebra::{stack, DMatrix, OMatrix, RowVector3, U1, Dynamic};
+///
+/// let a = DMatrix::from_fn(2, 3, |i, j| i + j);
+/// let b = RowVector3::new(7, 8, 9);
+///
+/// // Produces a matrix with a dynamic number of rows, and 3 columns.
+/// let m = stack![a; b; a.rows(0, 1)];
+/// assert_eq!(m.shape(), (4, 3));
+/// assert_eq!(m.row(2), b);
+/// ```
+#[proc_macro]
+pub fn stack(stream: TokenStream) -> TokenStream {
+    let blocks = parse_macro_input!(stream as Matrix);
+    let output = stack_impl(&blocks).unwrap_or_else(|err| err.to_compile_error());
+    proc_macro::TokenStream::from(output)
+}
@@ -1,10 +1,10 @@
 use nalgebra::{
-    DMatrix, DVector, Matrix1x2, Matrix1x3, Matrix1x4, Matrix2, Matrix2x1, Matrix2x3, Matrix2x4,
-    Matrix3, Matrix3x1, Matrix3x2, Matrix3x4, Matrix4, Matrix4x1, Matrix4x2, Matrix4x3, Point,
-    Point1, Point2, Point3, Point4, Point5, Point6, SMatrix, SVector, Vector1, Vector2, Vector3,
-    Vector4, Vector5, Vector6,
+    DMatrix, DVector, Dynamic, Matrix1x2, Matrix1x3, Matrix1x4, Matrix2, Matrix2x1, Matrix2x3,
+    Matrix2x4, Matrix3, Matrix3x1, Matrix3x2, Matrix3x4, Matrix4, Matrix4x1, Matrix4x2, Matrix4x3,
+    OMatrix, Point, Point1, Point2, Point3, Point4, Point5, Point6, SMatrix, SVector, Vector1,
+    Vector2, Vector3, Vector4, Vector5, Vector6, U2, U3,
 };
-use nalgebra_macros::{dmatrix, dvector, matrix, point, vector};
+use nalgebra_macros::{dmatrix, dvector, matrix, point, stack, vector};
 
 fn check_statically_same_type<T>(_: &T, _: &T) {}
 
@@ -167,6 +167,15 @@ fn dmatrix_trybuild_tests() {
     t.compile_fail("tests/trybuild/dmatrix_mismatched_dimensions.rs");
 }
 
+#[test]
+fn stack_trybuild_tests() {
+    let t = trybuild::TestCases::new();
+
+    // Verify error messages when the dimensions of block rows or columns cannot be inferred
+    t.compile_fail("tests/trybuild/stack_empty.rs");
+    t.compile_fail("tests/trybuild/stack_zero_block_row.rs");
+}
+
 #[test]
 fn matrix_builtin_types() {
     // Check that matrix! compiles for all built-in types
@@ -305,3 +314,68 @@ fn dvector_arbitrary_expressions() {
     let a_expected = DVector::from_column_slice(&[1 + 2, 2 * 3, 4 * f(5 + 6), 7 - 8 * 9]);
     assert_eq_and_type!(a, a_expected);
 }
+
+#[rustfmt::skip]
+#[test]
+fn stack_fixed_size() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5; 6];
+    let c = matrix![7, 8, 9];
+
+    assert_eq_and_type!(stack![a], a);
+    assert_eq_and_type!(stack![a, b], Matrix2x3::new(1, 2, 5, 3, 4, 6));
+    assert_eq_and_type!(stack![a; c.fixed_columns::<2>(0)], Matrix3x2::new(1, 2, 3, 4, 7, 8));
+    assert_eq_and_type!(stack![a, b; c.fixed_columns::<2>(0), c.column(2)],
+                        Matrix3::new(1, 2, 5,
+                                     3, 4, 6,
+                                     7, 8, 9));
+    assert_eq_and_type!(stack![b, a; 0, c.fixed_columns::<2>(1); c.transpose(), 0],
+                        SMatrix::<i32, 6, 3>::from_row_slice(&[5, 1, 2,
+                                                               6, 3, 4,
+                                                               0, 8, 9,
+                                                               7, 0, 0,
+                                                               8, 0, 0,
+                                                               9, 0, 0]));
+}
+
+#[rustfmt::skip]
+#[test]
+fn stack_dynamic_size() {
+    let a = DMatrix::from_row_slice(2, 2, &[1, 2, 3, 4]);
+    let b = matrix![5; 6];
+    let c = DMatrix::from_row_slice(1, 3, &[7, 8, 9]);
+
+    // The number of rows is statically known, but not the number of columns.
+    let m: OMatrix<i32, U2, Dynamic> = stack![a, b];
+    assert_eq!(m, Matrix2x3::new(1, 2, 5, 3, 4, 6));
+
+    // The number of columns is statically known, but not the number of rows.
+    let m: OMatrix<i32, Dynamic, U3> = stack![a, b; c.fixed_columns::<2>(0), c.columns(2, 1)];
+    assert_eq!(m, Matrix3::new(1, 2, 5, 3, 4, 6, 7, 8, 9));
+
+    let m: DMatrix<i32> = stack![a, 0; 0, c];
+    assert_eq!(m, DMatrix::from_row_slice(3, 5, &[1, 2, 0, 0, 0,
+                                                  3, 4, 0, 0, 0,
+                                                  0, 0, 7, 8, 9]));
+}
+
+#[test]
+fn stack_evaluates_blocks_once() {
+    let mut count = 0;
+    let mut block = || {
+        count += 1;
+        matrix![count]
+    };
+
+    let m = stack![block(), block(); block(), 0];
+    assert_eq_and_type!(m, Matrix2::new(1, 2, 3, 0));
+    assert_eq!(count, 3);
+}
+
+#[test]
+#[should_panic]
+fn stack_mismatched_dynamic_dimensions() {
+    let a = DMatrix::<i32>::zeros(2, 2);
+    let b = DMatrix::<i32>::zeros(3, 2);
+    let _ = stack![a, b];
+}
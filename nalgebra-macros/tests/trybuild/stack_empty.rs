@@ -0,0 +1,5 @@
+use nalgebra_macros::stack;
+
+fn main() {
+    let _ = stack![];
+}
@@ -0,0 +1,7 @@
+use nalgebra_macros::{matrix, stack};
+
+fn main() {
+    let a = matrix![1, 2; 3, 4];
+    let _ = stack![a, 0;
+                   0, 0];
+}
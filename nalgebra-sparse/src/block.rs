@@ -0,0 +1,88 @@
+//! Support for the [`block!`](crate::block!) macro.
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+
+/// A sparse matrix format that can be assembled from blocks with the [`block!`](crate::block!)
+/// macro.
+pub trait FromBlocks: Sized {
+    /// Assembles a matrix from a grid of blocks, given as a slice of block rows.
+    fn from_blocks(blocks: &[&[Option<&Self>]]) -> Self;
+}
+
+impl<T: Clone> FromBlocks for CsrMatrix<T> {
+    fn from_blocks(blocks: &[&[Option<&Self>]]) -> Self {
+        CsrMatrix::from_blocks(blocks)
+    }
+}
+
+impl<T: Clone> FromBlocks for CscMatrix<T> {
+    fn from_blocks(blocks: &[&[Option<&Self>]]) -> Self {
+        CscMatrix::from_blocks(blocks)
+    }
+}
+
+/// Assembles a sparse matrix from a grid of sparse blocks.
+///
+/// The blocks in a block row are separated by `,`, and the block rows are separated by `;`.
+/// Each block is an expression of type [`CsrMatrix`](crate::csr::CsrMatrix) or
+/// [`CscMatrix`](crate::csc::CscMatrix), which is taken by reference, and all blocks must have the
+/// same type. A zero block is written `_`, and its dimensions are determined by the other blocks
+/// in the same block row and block column.
+///
+/// This is a shorthand for [`CsrMatrix::from_blocks`](crate::csr::CsrMatrix::from_blocks) and
+/// [`CscMatrix::from_blocks`](crate::csc::CscMatrix::from_blocks), and panics under the same
+/// conditions.
+///
+/// # Example
+///
+/// ```
+/// use nalgebra_sparse::{block, CsrMatrix};
+/// use nalgebra::DMatrix;
+///
+/// let a = CsrMatrix::<f64>::identity(2);
+/// let b = CsrMatrix::from(&DMatrix::from_row_slice(1, 2, &[1.0, 2.0]));
+///
+/// // The saddle point matrix [A Bᵀ; B 0]
+/// let saddle_point = block![a, b.transpose();
+///                           b, _];
+/// assert_eq!(DMatrix::from(&saddle_point), DMatrix::from_row_slice(3, 3, &[1.0, 0.0, 1.0,
+///                                                                          0.0, 1.0, 2.0,
+///                                                                          1.0, 2.0, 0.0]));
+/// ```
+#[macro_export]
+macro_rules! block {
+    ($($tokens:tt)+) => {
+        $crate::__block!(@parse [] [] [] $($tokens)+)
+    };
+}
+
+/// Implementation detail of the [`block!`](crate::block!) macro, which splits the tokens into
+/// blocks and block rows.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __block {
+    // End of a block
+    (@parse [$($rows:tt)*] [$($row:tt)*] [$($block:tt)+] , $($rest:tt)*) => {
+        $crate::__block!(@parse [$($rows)*] [$($row)* $crate::__block!(@block $($block)+),] [] $($rest)*)
+    };
+    // End of a block row
+    (@parse [$($rows:tt)*] [$($row:tt)*] [$($block:tt)+] ; $($rest:tt)*) => {
+        $crate::__block!(@parse [$($rows)* &[$($row)* $crate::__block!(@block $($block)+)],] [] [] $($rest)*)
+    };
+    // End of the input, without and with a trailing `;`
+    (@parse [$($rows:tt)*] [$($row:tt)*] [$($block:tt)+]) => {
+        $crate::__block!(@parse [$($rows)* &[$($row)* $crate::__block!(@block $($block)+)],] [] [])
+    };
+    (@parse [$($rows:tt)*] [] []) => {
+        $crate::block::FromBlocks::from_blocks(&[$($rows)*])
+    };
+    (@parse [$($rows:tt)*] [$($row:tt)*] [$($block:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__block!(@parse [$($rows)*] [$($row)*] [$($block)* $next] $($rest)*)
+    };
+    (@block _) => {
+        ::core::option::Option::None
+    };
+    (@block $($block:tt)+) => {
+        ::core::option::Option::Some(&($($block)+))
+    };
+}
@@ -1,10 +1,11 @@
 use std::mem::replace;
-use std::ops::Range;
+use std::ops::{Bound, Range, RangeBounds};
 
 use num_traits::One;
 
 use nalgebra::Scalar;
 
+use crate::ordering::Permutation;
 use crate::pattern::SparsityPattern;
 use crate::{SparseEntry, SparseEntryMut};
 
@@ -223,6 +224,198 @@ impl<T> CsMatrix<T> {
         // TODO: This might be faster with a binary search for each diagonal entry
         self.filter(|i, j, _| i == j)
     }
+
+    /// Computes `P A Pᵀ` for the given permutation.
+    #[must_use]
+    pub fn permute_symmetric(&self, permutation: &Permutation) -> Self
+    where
+        T: Clone,
+    {
+        let (pattern, value_map) = permute_pattern_symmetric(self.pattern(), permutation);
+        let values = value_map.iter().map(|&k| self.values[k].clone()).collect();
+        Self::from_pattern_and_values(pattern, values)
+    }
+
+    /// Returns the matrix made of the given lanes, in the given order.
+    ///
+    /// The same lane may be selected several times. The lane indices must be in bounds.
+    #[must_use]
+    pub fn select_lanes(&self, lanes: &[usize]) -> Self
+    where
+        T: Clone,
+    {
+        let pattern = self.pattern();
+        let mut offsets = Vec::with_capacity(lanes.len() + 1);
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+
+        offsets.push(0);
+        for &lane in lanes {
+            let range = pattern.major_offsets()[lane]..pattern.major_offsets()[lane + 1];
+            indices.extend_from_slice(&pattern.minor_indices()[range.clone()]);
+            values.extend_from_slice(&self.values[range]);
+            offsets.push(indices.len());
+        }
+
+        // TODO: Avoid checks here
+        let pattern = SparsityPattern::try_from_offsets_and_indices(
+            lanes.len(),
+            pattern.minor_dim(),
+            offsets,
+            indices,
+        )
+        .expect("Internal error: Sparsity pattern must always be valid.");
+        Self::from_pattern_and_values(pattern, values)
+    }
+
+    /// Returns the matrix made of the given minor indices, in the given order.
+    ///
+    /// The same minor index may be selected several times. The minor indices must be in bounds.
+    #[must_use]
+    pub fn select_minor(&self, minor_indices: &[usize]) -> Self
+    where
+        T: Clone,
+    {
+        let pattern = self.pattern();
+        let minor_dim = pattern.minor_dim();
+
+        // For each old minor index, the increasing list of new minor indices it is selected as,
+        // stored in compressed form
+        let mut selection_offsets = vec![0; minor_dim + 1];
+        for &old_minor in minor_indices {
+            selection_offsets[old_minor] += 1;
+        }
+        convert_counts_to_offsets(&mut selection_offsets);
+        let mut selections = vec![0; minor_indices.len()];
+        let mut next = selection_offsets[..minor_dim].to_vec();
+        for (new_minor, &old_minor) in minor_indices.iter().enumerate() {
+            selections[next[old_minor]] = new_minor;
+            next[old_minor] += 1;
+        }
+
+        let mut offsets = Vec::with_capacity(pattern.major_dim() + 1);
+        let mut indices = Vec::new();
+        let mut value_map = Vec::new();
+        let mut lane = Vec::new();
+
+        offsets.push(0);
+        for major in 0..pattern.major_dim() {
+            let begin = pattern.major_offsets()[major];
+            lane.clear();
+            for (k, &old_minor) in pattern.lane(major).iter().enumerate() {
+                let range = selection_offsets[old_minor]..selection_offsets[old_minor + 1];
+                lane.extend(
+                    selections[range]
+                        .iter()
+                        .map(|&new_minor| (new_minor, begin + k)),
+                );
+            }
+            lane.sort_unstable();
+
+            for &(minor, k) in &lane {
+                indices.push(minor);
+                value_map.push(k);
+            }
+            offsets.push(indices.len());
+        }
+
+        // TODO: Avoid checks here
+        let new_pattern = SparsityPattern::try_from_offsets_and_indices(
+            pattern.major_dim(),
+            minor_indices.len(),
+            offsets,
+            indices,
+        )
+        .expect("Internal error: Sparsity pattern must always be valid.");
+        let values = value_map.iter().map(|&k| self.values[k].clone()).collect();
+        Self::from_pattern_and_values(new_pattern, values)
+    }
+
+    /// Returns the contiguous block of the matrix given by the major and minor index ranges.
+    ///
+    /// The ranges must be in bounds.
+    #[must_use]
+    pub fn slice(&self, major_range: Range<usize>, minor_range: Range<usize>) -> Self
+    where
+        T: Clone,
+    {
+        let pattern = self.pattern();
+        let mut offsets = Vec::with_capacity(major_range.len() + 1);
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+
+        offsets.push(0);
+        for major in major_range.clone() {
+            let begin = pattern.major_offsets()[major];
+            let lane = pattern.lane(major);
+            // The minor indices are sorted, so the entries in the range are contiguous
+            let first = lane.partition_point(|&j| j < minor_range.start);
+            let last = lane.partition_point(|&j| j < minor_range.end);
+            indices.extend(lane[first..last].iter().map(|j| j - minor_range.start));
+            values.extend_from_slice(&self.values[begin + first..begin + last]);
+            offsets.push(indices.len());
+        }
+
+        // TODO: Avoid checks here
+        let pattern = SparsityPattern::try_from_offsets_and_indices(
+            major_range.len(),
+            minor_range.len(),
+            offsets,
+            indices,
+        )
+        .expect("Internal error: Sparsity pattern must always be valid.");
+        Self::from_pattern_and_values(pattern, values)
+    }
+
+    /// Assembles a matrix from a grid of blocks.
+    ///
+    /// `block(I, J)` is the block at the `I`-th position along the major dimension and the
+    /// `J`-th position along the minor dimension, or `None` for a zero block. The dimensions
+    /// of the blocks must be consistent with `major_dims` and `minor_dims`.
+    pub fn from_blocks<'a, F>(major_dims: &[usize], minor_dims: &[usize], block: F) -> Self
+    where
+        T: 'a + Clone,
+        F: Fn(usize, usize) -> Option<&'a CsMatrix<T>>,
+    {
+        let mut minor_offsets = Vec::with_capacity(minor_dims.len());
+        let mut minor_dim = 0;
+        for &dim in minor_dims {
+            minor_offsets.push(minor_dim);
+            minor_dim += dim;
+        }
+
+        let major_dim = major_dims.iter().sum();
+        let mut offsets = Vec::with_capacity(major_dim + 1);
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+
+        offsets.push(0);
+        for (block_major, &block_major_dim) in major_dims.iter().enumerate() {
+            let blocks: Vec<_> = minor_offsets
+                .iter()
+                .enumerate()
+                .filter_map(|(block_minor, &offset)| {
+                    block(block_major, block_minor).map(|block| (offset, block))
+                })
+                .collect();
+            for major in 0..block_major_dim {
+                for &(offset, block) in &blocks {
+                    let lane = block
+                        .get_lane(major)
+                        .expect("Internal error: Block dimensions must be consistent.");
+                    indices.extend(lane.minor_indices().iter().map(|j| j + offset));
+                    values.extend_from_slice(lane.values());
+                }
+                offsets.push(indices.len());
+            }
+        }
+
+        // TODO: Avoid checks here
+        let pattern =
+            SparsityPattern::try_from_offsets_and_indices(major_dim, minor_dim, offsets, indices)
+                .expect("Internal error: Sparsity pattern must always be valid.");
+        Self::from_pattern_and_values(pattern, values)
+    }
 }
 
 impl<T: Scalar + One> CsMatrix<T> {
@@ -534,6 +727,125 @@ where
     (target_offsets, target_indices, target_values)
 }
 
+/// Computes the sparsity pattern of `P A Pᵀ` for the given permutation.
+///
+/// Also returns, for each entry of the permuted pattern, the index of the corresponding
+/// entry in the original pattern.
+pub fn permute_pattern_symmetric(
+    pattern: &SparsityPattern,
+    permutation: &Permutation,
+) -> (SparsityPattern, Vec<usize>) {
+    let n = pattern.major_dim();
+    assert_eq!(
+        n,
+        pattern.minor_dim(),
+        "Major and minor dimensions must be the same (square matrix)."
+    );
+    assert_eq!(
+        permutation.len(),
+        n,
+        "The permutation must have the same dimension as the matrix."
+    );
+
+    let inverse = permutation.inverse_indices();
+    let mut offsets = Vec::with_capacity(n + 1);
+    let mut indices = Vec::with_capacity(pattern.nnz());
+    let mut value_map = Vec::with_capacity(pattern.nnz());
+    let mut lane = Vec::new();
+
+    offsets.push(0);
+    for &old_major in permutation.indices() {
+        let begin = pattern.major_offsets()[old_major];
+        lane.clear();
+        lane.extend(
+            pattern
+                .lane(old_major)
+                .iter()
+                .enumerate()
+                .map(|(k, &old_minor)| (inverse[old_minor], begin + k)),
+        );
+        lane.sort_unstable();
+
+        for &(minor, k) in &lane {
+            indices.push(minor);
+            value_map.push(k);
+        }
+        offsets.push(indices.len());
+    }
+
+    // TODO: Avoid checks here
+    let pattern = SparsityPattern::try_from_offsets_and_indices(n, n, offsets, indices)
+        .expect("Internal error: Permuted pattern must always be valid.");
+    (pattern, value_map)
+}
+
+/// Converts a range of indices into a `Range`, or returns `None` if it is out of bounds for the
+/// given dimension.
+pub fn resolve_range(range: impl RangeBounds<usize>, dim: usize) -> Option<Range<usize>> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => dim,
+    };
+    (start <= end && end <= dim).then_some(start..end)
+}
+
+/// Computes the number of rows of each block row and the number of columns of each block column
+/// of a grid of blocks, in which `None` denotes a zero block.
+///
+/// Panics if the grid is empty or not rectangular, if a block row or block column only contains
+/// zero blocks, or if the dimensions of the blocks are inconsistent.
+pub fn block_grid_dims<M>(
+    blocks: &[&[Option<&M>]],
+    block_dims: impl Fn(&M) -> (usize, usize),
+) -> (Vec<usize>, Vec<usize>) {
+    let nblock_rows = blocks.len();
+    let nblock_cols = blocks.first().map(|row| row.len()).unwrap_or(0);
+    assert!(
+        nblock_rows > 0 && nblock_cols > 0,
+        "The grid of blocks must not be empty."
+    );
+    assert!(
+        blocks.iter().all(|row| row.len() == nblock_cols),
+        "All block rows must contain the same number of blocks."
+    );
+
+    let mut row_dims = vec![None; nblock_rows];
+    let mut col_dims = vec![None; nblock_cols];
+    for (i, row) in blocks.iter().enumerate() {
+        for (j, block) in row.iter().enumerate() {
+            if let Some(block) = block {
+                let (nrows, ncols) = block_dims(block);
+                assert_eq!(
+                    *row_dims[i].get_or_insert(nrows),
+                    nrows,
+                    "All blocks in a block row must have the same number of rows."
+                );
+                assert_eq!(
+                    *col_dims[j].get_or_insert(ncols),
+                    ncols,
+                    "All blocks in a block column must have the same number of columns."
+                );
+            }
+        }
+    }
+
+    let row_dims = row_dims
+        .into_iter()
+        .map(|dim| dim.expect("Each block row must contain at least one non-zero block."))
+        .collect();
+    let col_dims = col_dims
+        .into_iter()
+        .map(|dim| dim.expect("Each block column must contain at least one non-zero block."))
+        .collect();
+    (row_dims, col_dims)
+}
+
 pub fn convert_counts_to_offsets(counts: &mut [usize]) {
     // Convert the counts to an offset
     let mut offset = 0;
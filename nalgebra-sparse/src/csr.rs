@@ -2,8 +2,11 @@
 //!
 //! This is the module-level documentation. See [`CsrMatrix`] for the main documentation of the
 //! CSC implementation.
-use crate::cs::{CsLane, CsLaneIter, CsLaneIterMut, CsLaneMut, CsMatrix};
+use crate::cs::{
+    block_grid_dims, resolve_range, CsLane, CsLaneIter, CsLaneIterMut, CsLaneMut, CsMatrix,
+};
 use crate::csc::CscMatrix;
+use crate::ordering::Permutation;
 use crate::pattern::{SparsityPattern, SparsityPatternFormatError, SparsityPatternIter};
 use crate::{SparseEntry, SparseEntryMut, SparseFormatError, SparseFormatErrorKind};
 
@@ -11,6 +14,7 @@ use nalgebra::Scalar;
 use num_traits::One;
 
 use std::iter::FromIterator;
+use std::ops::RangeBounds;
 use std::slice::{Iter, IterMut};
 
 /// A CSR representation of a sparse matrix.
@@ -586,6 +590,176 @@ impl<T> CsrMatrix<T> {
         }
     }
 
+    /// Computes the symmetric permutation `P A Pᵀ` of this square matrix.
+    ///
+    /// The entry `(i, j)` of the result is the entry `(p[i], p[j])` of this matrix, where
+    /// `p = permutation.indices()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, or if the dimension of the permutation does not
+    /// match the dimension of the matrix.
+    #[must_use]
+    pub fn permute_symmetric(&self, permutation: &Permutation) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.permute_symmetric(permutation),
+        }
+    }
+
+    /// Returns a new matrix containing the given rows of this matrix, in the given order.
+    ///
+    /// The same row may be selected several times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the row indices is out of bounds.
+    #[must_use]
+    pub fn select_rows<'a, I>(&self, irows: I) -> Self
+    where
+        T: Clone,
+        I: IntoIterator<Item = &'a usize>,
+    {
+        let irows: Vec<usize> = irows.into_iter().copied().collect();
+        assert!(
+            irows.iter().all(|&i| i < self.nrows()),
+            "Row index out of bounds."
+        );
+        Self {
+            cs: self.cs.select_lanes(&irows),
+        }
+    }
+
+    /// Returns a new matrix containing the given columns of this matrix, in the given order.
+    ///
+    /// The same column may be selected several times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the column indices is out of bounds.
+    #[must_use]
+    pub fn select_columns<'a, I>(&self, icols: I) -> Self
+    where
+        T: Clone,
+        I: IntoIterator<Item = &'a usize>,
+    {
+        let icols: Vec<usize> = icols.into_iter().copied().collect();
+        assert!(
+            icols.iter().all(|&j| j < self.ncols()),
+            "Column index out of bounds."
+        );
+        Self {
+            cs: self.cs.select_minor(&icols),
+        }
+    }
+
+    /// Returns a new matrix containing the contiguous block of this matrix given by the row and
+    /// column ranges.
+    ///
+    /// Any kind of range can be used, such as `1..3`, `2..` or `..`. To extract the submatrix
+    /// given by lists of row and column indices, use [`select_rows`](Self::select_rows) and
+    /// [`select_columns`](Self::select_columns).
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the ranges is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra_sparse::csr::CsrMatrix;
+    /// # use nalgebra::DMatrix;
+    /// let dense = DMatrix::from_row_slice(3, 3, &[1, 0, 2,
+    ///                                             0, 3, 0,
+    ///                                             4, 0, 5]);
+    /// let matrix = CsrMatrix::from(&dense);
+    /// let block = matrix.submatrix(1.., ..2);
+    /// assert_eq!(DMatrix::from(&block), DMatrix::from_row_slice(2, 2, &[0, 3,
+    ///                                                                  4, 0]));
+    /// ```
+    #[must_use]
+    pub fn submatrix(&self, rows: impl RangeBounds<usize>, cols: impl RangeBounds<usize>) -> Self
+    where
+        T: Clone,
+    {
+        let rows = resolve_range(rows, self.nrows()).expect("Row range out of bounds.");
+        let cols = resolve_range(cols, self.ncols()).expect("Column range out of bounds.");
+        Self {
+            cs: self.cs.slice(rows, cols),
+        }
+    }
+
+    /// Assembles a matrix from a grid of blocks, given as a slice of block rows.
+    ///
+    /// A `None` block is a zero block, whose dimensions are determined by the other blocks in
+    /// the same block row and block column. See also the [`block!`](crate::block!) macro.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty or if the block rows do not all contain the same number of
+    /// blocks. Panics if the blocks in a block row do not have the same number of rows, if the
+    /// blocks in a block column do not have the same number of columns, or if a block row or
+    /// block column only contains zero blocks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra_sparse::csr::CsrMatrix;
+    /// # use nalgebra::DMatrix;
+    /// // The saddle point matrix [A Bᵀ; B 0]
+    /// let a = CsrMatrix::identity(2);
+    /// let b = CsrMatrix::from(&DMatrix::from_row_slice(1, 2, &[1, 2]));
+    /// let b_t = b.transpose();
+    /// let matrix = CsrMatrix::from_blocks(&[&[Some(&a), Some(&b_t)], &[Some(&b), None]]);
+    /// assert_eq!(DMatrix::from(&matrix), DMatrix::from_row_slice(3, 3, &[1, 0, 1,
+    ///                                                                   0, 1, 2,
+    ///                                                                   1, 2, 0]));
+    /// ```
+    #[must_use]
+    pub fn from_blocks(blocks: &[&[Option<&CsrMatrix<T>>]]) -> Self
+    where
+        T: Clone,
+    {
+        let (row_dims, col_dims) = block_grid_dims(blocks, |block| (block.nrows(), block.ncols()));
+        Self {
+            cs: CsMatrix::from_blocks(&row_dims, &col_dims, |i, j| {
+                blocks[i][j].map(|block| &block.cs)
+            }),
+        }
+    }
+
+    /// Stacks the matrices horizontally, i.e. concatenates their columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no matrix is given, or if the matrices do not all have the same number of rows.
+    #[must_use]
+    pub fn hstack(blocks: &[&CsrMatrix<T>]) -> Self
+    where
+        T: Clone,
+    {
+        let row: Vec<_> = blocks.iter().copied().map(Some).collect();
+        Self::from_blocks(&[&row])
+    }
+
+    /// Stacks the matrices vertically, i.e. concatenates their rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no matrix is given, or if the matrices do not all have the same number of
+    /// columns.
+    #[must_use]
+    pub fn vstack(blocks: &[&CsrMatrix<T>]) -> Self
+    where
+        T: Clone,
+    {
+        let rows: Vec<_> = blocks.iter().map(|&block| [Some(block)]).collect();
+        let rows: Vec<&[_]> = rows.iter().map(|row| &row[..]).collect();
+        Self::from_blocks(&rows)
+    }
+
     /// Compute the transpose of the matrix.
     #[must_use]
     pub fn transpose(&self) -> CsrMatrix<T>
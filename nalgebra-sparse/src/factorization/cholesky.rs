@@ -1,6 +1,8 @@
+use crate::cs::permute_pattern_symmetric;
 use crate::csc::CscMatrix;
 use crate::ops::serial::spsolve_csc_lower_triangular;
 use crate::ops::Op;
+use crate::ordering::Permutation;
 use crate::pattern::SparsityPattern;
 use core::{iter, mem};
 use nalgebra::{DMatrix, DMatrixSlice, DMatrixSliceMut, RealField};
@@ -11,11 +13,20 @@ use std::fmt::{Display, Formatter};
 /// The symbolic factorization computes the sparsity pattern of `L`, the Cholesky factor.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CscSymbolicCholesky {
-    // Pattern of the original matrix that was decomposed
+    // Pattern of the (permuted) matrix that was decomposed
     m_pattern: SparsityPattern,
     l_pattern: SparsityPattern,
     // u in this context is L^T, so that M = L L^T
     u_pattern: SparsityPattern,
+    permutation: Option<SymmetricPermutation>,
+}
+
+/// A symmetric permutation applied to the matrix before factorization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SymmetricPermutation {
+    permutation: Permutation,
+    // The index of the value in the original matrix for each entry of the permuted matrix
+    value_map: Vec<usize>,
 }
 
 impl CscSymbolicCholesky {
@@ -38,6 +49,35 @@ impl CscSymbolicCholesky {
             m_pattern: pattern,
             l_pattern,
             u_pattern,
+            permutation: None,
+        }
+    }
+
+    /// Compute the symbolic factorization of `P A Pᵀ` for a sparsity pattern belonging to a
+    /// CSC matrix `A` and the given permutation.
+    ///
+    /// A fill-reducing permutation, such as the one computed by [`amd`](crate::ordering::amd),
+    /// can substantially reduce the number of non-zeros in the Cholesky factor. The numerical
+    /// factorization still takes the values of the original, unpermuted matrix.
+    ///
+    /// The sparsity pattern must be symmetric. However, this is not enforced, and it is the
+    /// responsibility of the user to ensure that this property holds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sparsity pattern is not square, or if the dimension of the permutation
+    /// does not match the dimension of the pattern.
+    pub fn factor_with_permutation(pattern: SparsityPattern, permutation: Permutation) -> Self {
+        let (permuted_pattern, value_map) = permute_pattern_symmetric(&pattern, &permutation);
+        let (l_pattern, u_pattern) = nonzero_pattern(&permuted_pattern);
+        Self {
+            m_pattern: permuted_pattern,
+            l_pattern,
+            u_pattern,
+            permutation: Some(SymmetricPermutation {
+                permutation,
+                value_map,
+            }),
         }
     }
 
@@ -46,6 +86,12 @@ impl CscSymbolicCholesky {
     pub fn l_pattern(&self) -> &SparsityPattern {
         &self.l_pattern
     }
+
+    /// The permutation applied to the matrix before factorization, if any.
+    #[must_use]
+    pub fn permutation(&self) -> Option<&Permutation> {
+        self.permutation.as_ref().map(|p| &p.permutation)
+    }
 }
 
 /// A sparse Cholesky factorization `A = L L^T` of a [`CscMatrix`].
@@ -54,9 +100,10 @@ impl CscSymbolicCholesky {
 /// more information.
 ///
 /// The implementation is a port of the `CsCholesky` implementation in `nalgebra`. It is similar
-/// to Tim Davis' [`CSparse`]. The current implementation performs no fill-in reduction, and can
-/// therefore be expected to produce much too dense Cholesky factors for many matrices.
-/// It is therefore not currently recommended to use this implementation for serious projects.
+/// to Tim Davis' [`CSparse`]. No fill-in reduction is performed by default, which can produce
+/// much too dense Cholesky factors for many matrices. A fill-reducing [ordering](crate::ordering)
+/// can be provided with [`CscCholesky::factor_with_permutation`], in which case the factorization
+/// `P A Pᵀ = L Lᵀ` is computed instead.
 ///
 /// [`CSparse`]: https://epubs.siam.org/doi/book/10.1137/1.9780898718881
 /// [Wikipedia]: https://en.wikipedia.org/wiki/Cholesky_decomposition
@@ -68,8 +115,11 @@ pub struct CscCholesky<T> {
     m_pattern: SparsityPattern,
     l_factor: CscMatrix<T>,
     u_pattern: SparsityPattern,
+    permutation: Option<SymmetricPermutation>,
     work_x: Vec<T>,
     work_c: Vec<usize>,
+    // Values of the permuted matrix, only used when a permutation is present
+    work_values: Vec<T>,
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -125,10 +175,12 @@ impl<T: RealField> CscCholesky<T> {
             m_pattern: symbolic.m_pattern,
             l_factor,
             u_pattern: symbolic.u_pattern,
+            permutation: symbolic.permutation,
             work_x: vec![T::zero(); nrows],
             // Fill with MAX so that things hopefully totally fail if values are not
             // overwritten. Might be easier to debug this way
             work_c: vec![usize::MAX, ncols],
+            work_values: Vec::new(),
         };
 
         factorization.refactor(values)?;
@@ -153,6 +205,30 @@ impl<T: RealField> CscCholesky<T> {
         Self::factor_numerical(symbolic, matrix.values())
     }
 
+    /// Computes the Cholesky factorization `P A Pᵀ = L Lᵀ` of the provided matrix `A`
+    /// for the given permutation.
+    ///
+    /// The matrix must be symmetric positive definite. Symmetry is not checked, and it is up
+    /// to the user to enforce this property.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the numerical factorization fails. This can occur if the matrix is not
+    /// symmetric positive definite.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, or if the dimension of the permutation does not match
+    /// the dimension of the matrix.
+    pub fn factor_with_permutation(
+        matrix: &CscMatrix<T>,
+        permutation: Permutation,
+    ) -> Result<Self, CholeskyError> {
+        let symbolic =
+            CscSymbolicCholesky::factor_with_permutation(matrix.pattern().clone(), permutation);
+        Self::factor_numerical(symbolic, matrix.values())
+    }
+
     /// Re-computes the factorization for a new set of non-zero values.
     ///
     /// This is useful when the values of a matrix changes, but the sparsity pattern remains
@@ -168,15 +244,39 @@ impl<T: RealField> CscCholesky<T> {
     /// Panics if the number of values does not match the number of non-zeros in the sparsity
     /// pattern.
     pub fn refactor(&mut self, values: &[T]) -> Result<(), CholeskyError> {
-        self.decompose_left_looking(values)
+        match &self.permutation {
+            Some(permutation) => {
+                assert_eq!(
+                    values.len(),
+                    self.m_pattern.nnz(),
+                    "The number of values must match the number of non-zeros of the sparsity pattern."
+                );
+                let mut permuted_values = mem::take(&mut self.work_values);
+                permuted_values.clear();
+                permuted_values.extend(permutation.value_map.iter().map(|&k| values[k].clone()));
+                let result = self.decompose_left_looking(&permuted_values);
+                self.work_values = permuted_values;
+                result
+            }
+            None => self.decompose_left_looking(values),
+        }
     }
 
     /// Returns a reference to the Cholesky factor `L`.
+    ///
+    /// If the factorization was computed with a permutation `P`, this is the Cholesky factor
+    /// of `P A Pᵀ`.
     #[must_use]
     pub fn l(&self) -> &CscMatrix<T> {
         &self.l_factor
     }
 
+    /// The permutation applied to the matrix before factorization, if any.
+    #[must_use]
+    pub fn permutation(&self) -> Option<&Permutation> {
+        self.permutation.as_ref().map(|p| &p.permutation)
+    }
+
     /// Returns the Cholesky factor `L`.
     pub fn take_l(self) -> CscMatrix<T> {
         self.l_factor
@@ -185,10 +285,10 @@ impl<T: RealField> CscCholesky<T> {
     /// Perform a numerical left-looking cholesky decomposition of a matrix with the same structure as the
     /// one used to initialize `self`, but with different non-zero values provided by `values`.
     fn decompose_left_looking(&mut self, values: &[T]) -> Result<(), CholeskyError> {
-        assert!(
-            values.len() >= self.m_pattern.nnz(),
-            // TODO: Improve error message
-            "The set of values is too small."
+        assert_eq!(
+            values.len(),
+            self.m_pattern.nnz(),
+            "The number of values must match the number of non-zeros of the sparsity pattern."
         );
 
         let n = self.l_factor.nrows();
@@ -281,13 +381,30 @@ impl<T: RealField> CscCholesky<T> {
     pub fn solve_mut<'a>(&'a self, b: impl Into<DMatrixSliceMut<'a, T>>) {
         let expect_msg = "If the Cholesky factorization succeeded,\
             then the triangular solve should never fail";
-        // Solve LY = B
-        let mut y = b.into();
-        spsolve_csc_lower_triangular(Op::NoOp(self.l()), &mut y).expect(expect_msg);
+        let mut b = b.into();
+
+        match &self.permutation {
+            Some(permutation) => {
+                // Solve (P A P^T) (P X) = P B
+                let p = permutation.permutation.indices();
+                let mut y = DMatrix::from_fn(b.nrows(), b.ncols(), |i, j| b[(p[i], j)].clone());
+                spsolve_csc_lower_triangular(Op::NoOp(self.l()), &mut y).expect(expect_msg);
+                spsolve_csc_lower_triangular(Op::Transpose(self.l()), &mut y).expect(expect_msg);
+
+                for (i, &pi) in p.iter().enumerate() {
+                    for j in 0..b.ncols() {
+                        b[(pi, j)] = y[(i, j)].clone();
+                    }
+                }
+            }
+            None => {
+                // Solve LY = B
+                spsolve_csc_lower_triangular(Op::NoOp(self.l()), &mut b).expect(expect_msg);
 
-        // Solve L^T X = Y
-        let mut x = y;
-        spsolve_csc_lower_triangular(Op::Transpose(self.l()), &mut x).expect(expect_msg);
+                // Solve L^T X = Y
+                spsolve_csc_lower_triangular(Op::Transpose(self.l()), &mut b).expect(expect_msg);
+            }
+        }
     }
 }
 
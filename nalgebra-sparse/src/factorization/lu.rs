@@ -0,0 +1,489 @@
+use crate::csc::CscMatrix;
+use crate::ops::serial::{spsolve_csc_lower_triangular, spsolve_csc_upper_triangular};
+use crate::ops::Op;
+use crate::ordering::Permutation;
+use crate::pattern::SparsityPattern;
+use nalgebra::{DMatrix, DMatrixSlice, DMatrixSliceMut, RealField};
+use std::fmt::{Display, Formatter};
+
+/// Sentinel used to mark rows that have not (yet) been chosen as pivots.
+const UNPIVOTED: usize = usize::MAX;
+
+/// A symbolic sparse LU factorization of a CSC matrix.
+///
+/// The symbolic factorization stores the sparsity pattern of the matrix together with the
+/// column ordering used by the numerical factorization. Since the numerical factorization
+/// chooses its row pivots dynamically, the patterns of the factors `L` and `U` are only known
+/// once the numerical factorization has been performed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CscSymbolicLu {
+    // Pattern of the original matrix that was decomposed
+    m_pattern: SparsityPattern,
+    // Column `k` of `A Q` is column `col_perm.indices()[k]` of `A`
+    col_perm: Permutation,
+}
+
+impl CscSymbolicLu {
+    /// Compute the symbolic factorization for a sparsity pattern belonging to a CSC matrix.
+    ///
+    /// The columns are eliminated in their natural order. Use
+    /// [`factor_with_column_permutation`](Self::factor_with_column_permutation) to provide a
+    /// fill-reducing column ordering.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sparsity pattern is not square.
+    pub fn factor(pattern: SparsityPattern) -> Self {
+        let n = pattern.major_dim();
+        Self::factor_with_column_permutation(pattern, Permutation::identity(n))
+    }
+
+    /// Compute the symbolic factorization for a sparsity pattern belonging to a CSC matrix,
+    /// eliminating the columns in the order given by `col_perm`.
+    ///
+    /// The `k`-th column eliminated is the column `col_perm.indices()[k]` of the matrix. A good
+    /// column ordering can drastically reduce the fill-in of the factors. Such an ordering can
+    /// for instance be obtained by applying one of the [orderings](crate::ordering) to the
+    /// pattern of `AᵀA`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sparsity pattern is not square, or if the dimension of `col_perm` does not
+    /// match the number of columns.
+    pub fn factor_with_column_permutation(pattern: SparsityPattern, col_perm: Permutation) -> Self {
+        assert_eq!(
+            pattern.major_dim(),
+            pattern.minor_dim(),
+            "Major and minor dimensions must be the same (square matrix)."
+        );
+        assert_eq!(
+            col_perm.len(),
+            pattern.major_dim(),
+            "The column permutation must have one entry per column."
+        );
+
+        Self {
+            m_pattern: pattern,
+            col_perm,
+        }
+    }
+
+    /// The column permutation used by the factorization.
+    ///
+    /// The `k`-th column eliminated is the column `column_permutation().indices()[k]` of the
+    /// matrix.
+    #[must_use]
+    pub fn column_permutation(&self) -> &Permutation {
+        &self.col_perm
+    }
+}
+
+/// A sparse LU factorization `P A Q = L U` of a [`CscMatrix`] with partial (row) pivoting.
+///
+/// `L` is a sparse lower-triangular matrix with a unit diagonal and `U` is a sparse
+/// upper-triangular matrix. `P` and `Q` are permutation matrices: row `k` of `P A` is the row
+/// [`row_permutation().indices()[k]`](Self::row_permutation) of `A` and column `k` of `A Q` is
+/// the column [`column_permutation().indices()[k]`](Self::column_permutation) of `A`.
+///
+/// The numerical factorization is a left-looking Gilbert-Peierls algorithm, similar to the
+/// `cs_lu` routine of Tim Davis' [`CSparse`]. The column ordering is fixed by the
+/// symbolic factorization, while the row pivots are chosen during the numerical factorization.
+///
+/// [`CSparse`]: https://epubs.siam.org/doi/book/10.1137/1.9780898718881
+#[derive(Debug, Clone)]
+pub struct CscLu<T> {
+    // Pattern of the original matrix
+    m_pattern: SparsityPattern,
+    col_perm: Permutation,
+    // Row `k` of `P A` is row `row_perm.indices()[k]` of `A`
+    row_perm: Permutation,
+    l_factor: CscMatrix<T>,
+    u_factor: CscMatrix<T>,
+    work_x: Vec<T>,
+    // Inverse row permutation, containing `UNPIVOTED` for rows that are not pivotal yet
+    work_pinv: Vec<usize>,
+    work_marks: Vec<bool>,
+    work_reach: Vec<usize>,
+    work_stack: Vec<(usize, usize, usize)>,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[non_exhaustive]
+/// Possible errors produced by the LU factorization.
+pub enum LuError {
+    /// The matrix is singular.
+    Singular,
+}
+
+impl Display for LuError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Matrix is singular")
+    }
+}
+
+impl std::error::Error for LuError {}
+
+impl<T: RealField> CscLu<T> {
+    /// Computes the numerical LU factorization associated with the given
+    /// symbolic factorization and the provided values.
+    ///
+    /// The values correspond to the non-zero values of the CSC matrix for which the
+    /// symbolic factorization was computed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the numerical factorization fails. This occurs if the matrix is
+    /// singular.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values differ from the number of non-zeros of the sparsity pattern
+    /// of the matrix that was symbolically factored.
+    pub fn factor_numerical(symbolic: CscSymbolicLu, values: &[T]) -> Result<Self, LuError> {
+        let n = symbolic.m_pattern.major_dim();
+
+        let mut factorization = CscLu {
+            m_pattern: symbolic.m_pattern,
+            col_perm: symbolic.col_perm,
+            row_perm: Permutation::identity(n),
+            l_factor: CscMatrix::zeros(n, n),
+            u_factor: CscMatrix::zeros(n, n),
+            work_x: vec![T::zero(); n],
+            work_pinv: vec![UNPIVOTED; n],
+            work_marks: vec![false; n],
+            work_reach: Vec::with_capacity(n),
+            work_stack: Vec::with_capacity(n),
+        };
+
+        factorization.refactor(values)?;
+        Ok(factorization)
+    }
+
+    /// Computes the LU factorization of the provided matrix.
+    ///
+    /// The columns are eliminated in their natural order. Use [`CscSymbolicLu`] and
+    /// [`factor_numerical`](Self::factor_numerical) to provide a column ordering.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the numerical factorization fails. This occurs if the matrix is
+    /// singular.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn factor(matrix: &CscMatrix<T>) -> Result<Self, LuError> {
+        let symbolic = CscSymbolicLu::factor(matrix.pattern().clone());
+        Self::factor_numerical(symbolic, matrix.values())
+    }
+
+    /// Re-computes the factorization for a new set of non-zero values.
+    ///
+    /// This is useful when the values of a matrix changes, but the sparsity pattern remains
+    /// constant. The column ordering is reused, while the row pivots are chosen anew.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the numerical factorization fails. This occurs if the matrix is
+    /// singular. In that case, `self` is left unchanged and still holds the factorization of the
+    /// previous values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values does not match the number of non-zeros in the sparsity
+    /// pattern.
+    pub fn refactor(&mut self, values: &[T]) -> Result<(), LuError> {
+        self.decompose_left_looking(values)
+    }
+
+    /// Returns a reference to the factor `L`.
+    #[must_use]
+    pub fn l(&self) -> &CscMatrix<T> {
+        &self.l_factor
+    }
+
+    /// Returns a reference to the factor `U`.
+    #[must_use]
+    pub fn u(&self) -> &CscMatrix<T> {
+        &self.u_factor
+    }
+
+    /// Returns the factors `L` and `U`.
+    pub fn take_l_u(self) -> (CscMatrix<T>, CscMatrix<T>) {
+        (self.l_factor, self.u_factor)
+    }
+
+    /// The row permutation chosen by the partial pivoting.
+    ///
+    /// Row `k` of `P A` is the row `row_permutation().indices()[k]` of `A`.
+    #[must_use]
+    pub fn row_permutation(&self) -> &Permutation {
+        &self.row_perm
+    }
+
+    /// The column permutation used by the factorization.
+    ///
+    /// Column `k` of `A Q` is the column `column_permutation().indices()[k]` of `A`.
+    #[must_use]
+    pub fn column_permutation(&self) -> &Permutation {
+        &self.col_perm
+    }
+
+    /// Perform a numerical left-looking LU decomposition of a matrix with the same structure as
+    /// the one used to initialize `self`, but with different non-zero values provided by `values`.
+    fn decompose_left_looking(&mut self, values: &[T]) -> Result<(), LuError> {
+        assert_eq!(
+            values.len(),
+            self.m_pattern.nnz(),
+            "The number of values must match the number of non-zeros of the sparsity pattern."
+        );
+
+        let n = self.m_pattern.major_dim();
+
+        // Factor into new buffers, so that the previous factors are kept intact if the matrix
+        // turns out to be singular.
+        let mut l_offsets = Vec::with_capacity(n + 1);
+        let mut l_indices = Vec::with_capacity(self.l_factor.nnz());
+        let mut l_values: Vec<T> = Vec::with_capacity(self.l_factor.nnz());
+        let mut u_offsets = Vec::with_capacity(n + 1);
+        let mut u_indices = Vec::with_capacity(self.u_factor.nnz());
+        let mut u_values = Vec::with_capacity(self.u_factor.nnz());
+        l_offsets.push(0);
+        u_offsets.push(0);
+
+        let mut row_perm = vec![0; n];
+
+        self.work_pinv.iter_mut().for_each(|p| *p = UNPIVOTED);
+        let pinv = &mut self.work_pinv;
+        let x = &mut self.work_x;
+
+        for (k, &col) in self.col_perm.indices().iter().enumerate() {
+            let range =
+                self.m_pattern.major_offsets()[col]..self.m_pattern.major_offsets()[col + 1];
+            let a_rows = &self.m_pattern.minor_indices()[range.clone()];
+            let a_values = &values[range];
+
+            // Compute the nodes reachable in the graph of L from the non-zeros of A(:, col).
+            // They form the non-zero pattern of the solution of the triangular system below.
+            reach(
+                &l_offsets,
+                &l_indices,
+                pinv,
+                a_rows,
+                &mut self.work_marks,
+                &mut self.work_stack,
+                &mut self.work_reach,
+            );
+
+            // Solve L x = A(:, col), processing the nodes in topological order.
+            for &i in &self.work_reach {
+                x[i] = T::zero();
+            }
+            for (&i, v) in a_rows.iter().zip(a_values) {
+                x[i] = v.clone();
+            }
+
+            for &j in self.work_reach.iter().rev() {
+                let jj = pinv[j];
+                if jj != UNPIVOTED {
+                    let x_j = x[j].clone();
+                    // Note: the first entry of each column of L is its unit diagonal.
+                    for p in l_offsets[jj] + 1..l_offsets[jj + 1] {
+                        x[l_indices[p]] -= l_values[p].clone() * x_j.clone();
+                    }
+                }
+            }
+
+            // Select the pivot among the rows that are not pivotal yet, and scatter the
+            // entries belonging to the pivotal rows into U.
+            let mut pivot_row = UNPIVOTED;
+            let mut pivot_abs = T::zero();
+            for &i in self.work_reach.iter() {
+                if pinv[i] == UNPIVOTED {
+                    let abs = x[i].clone().abs();
+                    if pivot_row == UNPIVOTED || abs > pivot_abs {
+                        pivot_row = i;
+                        pivot_abs = abs;
+                    }
+                } else {
+                    u_indices.push(pinv[i]);
+                    u_values.push(x[i].clone());
+                }
+            }
+
+            // Prefer the diagonal entry in case of a tie, which avoids needless row exchanges.
+            if pinv[col] == UNPIVOTED && self.work_marks[col] && x[col].clone().abs() >= pivot_abs {
+                pivot_row = col;
+                pivot_abs = x[col].clone().abs();
+            }
+
+            for &i in &self.work_reach {
+                self.work_marks[i] = false;
+            }
+
+            if pivot_row == UNPIVOTED || pivot_abs.is_zero() {
+                return Err(LuError::Singular);
+            }
+
+            let pivot = x[pivot_row].clone();
+            u_indices.push(k);
+            u_values.push(pivot.clone());
+            u_offsets.push(u_indices.len());
+
+            pinv[pivot_row] = k;
+            row_perm[k] = pivot_row;
+            l_indices.push(pivot_row);
+            l_values.push(T::one());
+            for &i in &self.work_reach {
+                if pinv[i] == UNPIVOTED {
+                    l_indices.push(i);
+                    l_values.push(x[i].clone() / pivot.clone());
+                }
+            }
+            l_offsets.push(l_indices.len());
+        }
+
+        // Express the row indices of L in terms of the pivoted rows, and sort the row indices of
+        // both factors.
+        for i in l_indices.iter_mut() {
+            *i = pinv[*i];
+        }
+        sort_lanes(&l_offsets, &mut l_indices, &mut l_values);
+        sort_lanes(&u_offsets, &mut u_indices, &mut u_values);
+
+        self.row_perm = Permutation::from_indices_and_inverse_unchecked(row_perm, pinv.clone());
+
+        self.l_factor = CscMatrix::try_from_csc_data(n, n, l_offsets, l_indices, l_values)
+            .expect("The factor L has a valid CSC structure by construction.");
+        self.u_factor = CscMatrix::try_from_csc_data(n, n, u_offsets, u_indices, u_values)
+            .expect("The factor U has a valid CSC structure by construction.");
+
+        Ok(())
+    }
+
+    /// Solves the system `A X = B`, where `X` and `B` are dense matrices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `B` does not have the same number of rows as `A`.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<'a>(&'a self, b: impl Into<DMatrixSlice<'a, T>>) -> DMatrix<T> {
+        let b = b.into();
+        let mut output = b.clone_owned();
+        self.solve_mut(&mut output);
+        output
+    }
+
+    /// Solves the system `A X = B`, where `X` and `B` are dense matrices.
+    ///
+    /// The result is stored in-place in `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have the same number of rows as `A`.
+    pub fn solve_mut<'a>(&'a self, b: impl Into<DMatrixSliceMut<'a, T>>) {
+        let mut b = b.into();
+        let n = self.l_factor.nrows();
+        assert_eq!(
+            b.nrows(),
+            n,
+            "The right-hand side must have as many rows as A."
+        );
+
+        let expect_msg = "If the LU factorization succeeded,\
+            then the triangular solve should never fail";
+
+        // Solve L Y = P B
+        let mut y = DMatrix::from_fn(n, b.ncols(), |i, j| {
+            b[(self.row_perm.indices()[i], j)].clone()
+        });
+        spsolve_csc_lower_triangular(Op::NoOp(self.l()), &mut y).expect(expect_msg);
+
+        // Solve U Z = Y
+        spsolve_csc_upper_triangular(Op::NoOp(self.u()), &mut y).expect(expect_msg);
+
+        // X = Q Z
+        for j in 0..b.ncols() {
+            for (k, &i) in self.col_perm.indices().iter().enumerate() {
+                b[(i, j)] = y[(k, j)].clone();
+            }
+        }
+    }
+}
+
+/// Computes the set of nodes reachable from `roots` in the graph of the partially
+/// computed factor `L`, in reverse topological order.
+///
+/// The nodes are original row indices. A node `j` only has outgoing edges if the row `j` is
+/// pivotal, in which case its neighbors are the off-diagonal entries of the column `pinv[j]`
+/// of `L`.
+fn reach(
+    l_offsets: &[usize],
+    l_indices: &[usize],
+    pinv: &[usize],
+    roots: &[usize],
+    marks: &mut [bool],
+    stack: &mut Vec<(usize, usize, usize)>,
+    out: &mut Vec<usize>,
+) {
+    // Range of `l_indices` containing the neighbors of the node `j`.
+    let neighbors = |j: usize| {
+        let jj = pinv[j];
+        if jj == UNPIVOTED {
+            (0, 0)
+        } else {
+            // Skip the unit diagonal, which is the first entry of the column.
+            (l_offsets[jj] + 1, l_offsets[jj + 1])
+        }
+    };
+
+    out.clear();
+
+    for &root in roots {
+        if marks[root] {
+            continue;
+        }
+
+        marks[root] = true;
+        let (begin, end) = neighbors(root);
+        stack.push((root, begin, end));
+
+        while let Some((j, next, end)) = stack.last_mut() {
+            while *next < *end && marks[l_indices[*next]] {
+                *next += 1;
+            }
+
+            if *next < *end {
+                let i = l_indices[*next];
+                *next += 1;
+                marks[i] = true;
+                let (begin, end) = neighbors(i);
+                stack.push((i, begin, end));
+            } else {
+                out.push(*j);
+                let _ = stack.pop();
+            }
+        }
+    }
+}
+
+/// Sorts the indices (and associated values) of each lane of a compressed matrix.
+fn sort_lanes<T: Clone>(offsets: &[usize], indices: &mut [usize], values: &mut [T]) {
+    let mut lane = Vec::new();
+    for w in offsets.windows(2) {
+        let (begin, end) = (w[0], w[1]);
+        lane.clear();
+        lane.extend(
+            indices[begin..end]
+                .iter()
+                .cloned()
+                .zip(values[begin..end].iter().cloned()),
+        );
+        lane.sort_unstable_by_key(|(i, _)| *i);
+
+        for (p, (i, v)) in lane.drain(..).enumerate() {
+            indices[begin + p] = i;
+            values[begin + p] = v;
+        }
+    }
+}
@@ -1,6 +1,10 @@
 //! Matrix factorization for sparse matrices.
 //!
-//! Currently, the only factorization provided here is the [`CscCholesky`] factorization.
+//! Currently, the factorizations provided here are the [`CscCholesky`] factorization for
+//! symmetric positive definite matrices and the [`CscLu`] factorization for general square
+//! matrices.
 mod cholesky;
+mod lu;
 
 pub use cholesky::*;
+pub use lu::*;
@@ -2,9 +2,12 @@
 //!
 //! See the [website](https://math.nist.gov/MatrixMarket/formats.html) or the [paper](https://www.researchgate.net/publication/2630533_The_Matrix_Market_Exchange_Formats_Initial_Design) for more details about matrix market.
 use crate::coo::CooMatrix;
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
 use crate::SparseFormatError;
 use crate::SparseFormatErrorKind;
-use nalgebra::Complex;
+use nalgebra::{ClosedAdd, Complex, DMatrix};
+use num_traits::Zero;
 use pest::iterators::Pairs;
 use pest::Parser;
 use std::cmp::PartialEq;
@@ -12,6 +15,7 @@ use std::convert::Infallible;
 use std::convert::TryFrom;
 use std::fmt;
 use std::fmt::Formatter;
+use std::fmt::Write;
 use std::fs;
 use std::num::ParseIntError;
 use std::num::TryFromIntError;
@@ -233,6 +237,23 @@ pub enum MatrixMarketErrorKind {
     /// assert_eq!(matrix_result.unwrap_err().kind(),MatrixMarketErrorKind::NonSquare);
     /// ```
     NonSquare,
+
+    /// Indicates that a matrix exported with a (skew-)symmetric or hermitian storage scheme does not
+    /// have the corresponding symmetry.
+    ///
+    /// Examples
+    /// --------
+    /// ```rust
+    /// # use nalgebra_sparse::coo::CooMatrix;
+    /// # use nalgebra_sparse::io::{save_to_matrix_market_str, MatrixMarketErrorKind, StorageScheme};
+    /// let mut coo = CooMatrix::new(2, 2);
+    /// coo.push(1, 0, 1);
+    /// coo.push(0, 1, 2);
+    /// let result = save_to_matrix_market_str(&coo, StorageScheme::Symmetric);
+    /// assert_eq!(result.is_err(), true);
+    /// assert_eq!(result.unwrap_err().kind(), MatrixMarketErrorKind::NotSymmetric);
+    /// ```
+    NotSymmetric,
 }
 
 impl MatrixMarketError {
@@ -290,6 +311,9 @@ impl fmt::Display for MatrixMarketError {
             MatrixMarketErrorKind::NonSquare => {
                 write!(f, "NotSquareMatrix,")?;
             }
+            MatrixMarketErrorKind::NotSymmetric => {
+                write!(f, "NotSymmetric,")?;
+            }
         }
         write!(f, " message: {}", self.message)
     }
@@ -364,18 +388,27 @@ enum Sparsity {
     Sparse,
     Dense,
 }
-#[derive(Debug, PartialEq)]
-enum DataType {
+// Public, but not exported, since it appears in the sealed `SupportedMatrixMarketScalar` trait.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DataType {
     Real,
     Complex,
     Pattern,
     Integer,
 }
-#[derive(Debug, PartialEq)]
-enum StorageScheme {
+/// The storage scheme (symmetry structure) of a matrix in the matrix market format.
+///
+/// Except for [`General`](Self::General), only the entries in the lower triangular part of the
+/// matrix are stored, and the remaining entries are deduced from the symmetry of the matrix.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StorageScheme {
+    /// The matrix is symmetric: `A = Aᵀ`.
     Symmetric,
+    /// All the entries of the matrix are stored.
     General,
+    /// The matrix is skew-symmetric: `A = -Aᵀ`. Its diagonal is zero and is not stored.
     Skew,
+    /// The matrix is hermitian: `A = Aᴴ`. Only complex matrices can be hermitian.
     Hermitian,
 }
 #[derive(Debug, PartialEq)]
@@ -417,6 +450,17 @@ impl FromStr for DataType {
     }
 }
 
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataType::Real => write!(f, "real"),
+            DataType::Complex => write!(f, "complex"),
+            DataType::Integer => write!(f, "integer"),
+            DataType::Pattern => write!(f, "pattern"),
+        }
+    }
+}
+
 impl FromStr for StorageScheme {
     type Err = MatrixMarketError;
     /// Assumes that `word` is already lower case.
@@ -490,6 +534,7 @@ fn typecode_precheck(tc: &Typecode) -> Result<(), MatrixMarketError> {
 
 /// Scalar types supported by the matrix market parser.
 mod internal {
+    use super::DataType;
     use crate::io::MatrixMarketError;
     use na::{Complex, Scalar};
 
@@ -506,6 +551,27 @@ mod internal {
         fn negative(self) -> Result<Self, MatrixMarketError>;
         /// When matrix is a Hermitian matrix, it will convert itself to its conjugate.
         fn conjugate(self) -> Result<Self, MatrixMarketError>;
+        /// Whether the value is zero. Pattern values are never zero.
+        fn is_zero(&self) -> bool;
+        /// The sum of two values, used for duplicate entries. The sum of pattern values is a
+        /// pattern value.
+        fn add(self, other: Self) -> Self;
+        /// The data type of a matrix market file holding values of this type.
+        fn data_type() -> DataType;
+        /// Writes the value as it appears in a matrix market file.
+        fn write_matrix_market<W: std::fmt::Write>(&self, w: W) -> std::fmt::Result;
+    }
+
+    pub trait SupportedMatrixMarketExport<T> {
+        /// The number of rows of the matrix.
+        fn nrows(&self) -> usize;
+        /// The number of columns of the matrix.
+        fn ncols(&self) -> usize;
+        /// Whether the matrix is written in the dense array format.
+        fn is_dense(&self) -> bool;
+        /// The stored entries of the matrix. Dense matrices yield all their entries in
+        /// column-major order.
+        fn triplet_iter(&self) -> Box<dyn Iterator<Item = (usize, usize, &T)> + '_>;
     }
 }
 
@@ -557,6 +623,22 @@ macro_rules! mm_int_impl {
             fn negative(self) -> Result<Self, MatrixMarketError> {
                 Ok(-self)
             }
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+            #[inline]
+            fn data_type() -> DataType {
+                DataType::Integer
+            }
+            #[inline]
+            fn write_matrix_market<W: std::fmt::Write>(&self, mut w: W) -> std::fmt::Result {
+                write!(w, "{}", self)
+            }
         }
     };
 }
@@ -602,6 +684,22 @@ macro_rules! mm_real_impl {
             fn negative(self) -> Result<Self, MatrixMarketError> {
                 Ok(-self)
             }
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == 0.0
+            }
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+            #[inline]
+            fn data_type() -> DataType {
+                DataType::Real
+            }
+            #[inline]
+            fn write_matrix_market<W: std::fmt::Write>(&self, mut w: W) -> std::fmt::Result {
+                write!(w, "{:e}", self)
+            }
         }
     };
 }
@@ -648,6 +746,22 @@ macro_rules! mm_complex_impl {
             fn negative(self) -> Result<Self, MatrixMarketError> {
                 Ok(-self)
             }
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.re == 0.0 && self.im == 0.0
+            }
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+            #[inline]
+            fn data_type() -> DataType {
+                DataType::Complex
+            }
+            #[inline]
+            fn write_matrix_market<W: std::fmt::Write>(&self, mut w: W) -> std::fmt::Result {
+                write!(w, "{:e} {:e}", self.re, self.im)
+            }
         }
     };
 }
@@ -697,6 +811,22 @@ macro_rules! mm_pattern_impl {
                     format!("Pattern type has no negative"),
                 ))
             }
+            #[inline]
+            fn is_zero(&self) -> bool {
+                false
+            }
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                other
+            }
+            #[inline]
+            fn data_type() -> DataType {
+                DataType::Pattern
+            }
+            #[inline]
+            fn write_matrix_market<W: std::fmt::Write>(&self, mut _w: W) -> std::fmt::Result {
+                Ok(())
+            }
         }
     };
 }
@@ -719,6 +849,29 @@ mm_pattern_impl!(());
 #[grammar = "io/matrix_market.pest"]
 struct MatrixMarketParser;
 
+/// Options for loading matrices stored in the matrix market format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MatrixMarketLoadOptions {
+    /// Whether only the lower triangular part of (skew-)symmetric and hermitian matrices is loaded.
+    ///
+    /// These matrices are stored as their lower triangular part in the matrix market format. By
+    /// default, the upper triangular part is deduced from the stored entries, and the loaded matrix is
+    /// the full matrix. When this is `true`, the loaded matrix only contains the stored entries,
+    /// which roughly halves its memory usage. Matrices with the `general` storage scheme are not
+    /// affected by this option.
+    pub lower_triangle_only: bool,
+}
+
+impl MatrixMarketLoadOptions {
+    /// Sets whether only the lower triangular part of (skew-)symmetric and hermitian matrices is loaded.
+    #[must_use]
+    pub fn with_lower_triangle_only(self, lower_triangle_only: bool) -> Self {
+        Self {
+            lower_triangle_only,
+        }
+    }
+}
+
 /// Parses a Matrix Market file at the given path as a `CooMatrix`.
 ///
 /// The matrix market format specification does not clarify whether duplicate entries are allowed. Our importer
@@ -775,6 +928,146 @@ where
 /// let matrix = load_coo_from_matrix_market_str::<i32>(str).unwrap();
 /// ```
 pub fn load_coo_from_matrix_market_str<T>(data: &str) -> Result<CooMatrix<T>, MatrixMarketError>
+where
+    T: MatrixMarketScalar,
+{
+    load_coo_from_matrix_market_str_with_options(data, &MatrixMarketLoadOptions::default())
+}
+
+/// Parses a Matrix Market file at the given path as a `CooMatrix`, with the given options.
+///
+/// See [load_coo_from_matrix_market_file] for more information.
+///
+/// Errors
+/// --------
+///
+/// See [MatrixMarketErrorKind] for a list of possible error conditions.
+///
+/// Examples
+/// --------
+/// ```no_run
+/// use nalgebra_sparse::io::{load_coo_from_matrix_market_file_with_options, MatrixMarketLoadOptions};
+/// let options = MatrixMarketLoadOptions::default().with_lower_triangle_only(true);
+/// let lower = load_coo_from_matrix_market_file_with_options::<f64, _>("path/to/spd.mtx", &options).unwrap();
+/// ```
+pub fn load_coo_from_matrix_market_file_with_options<T, P: AsRef<Path>>(
+    path: P,
+    options: &MatrixMarketLoadOptions,
+) -> Result<CooMatrix<T>, MatrixMarketError>
+where
+    T: MatrixMarketScalar,
+{
+    let file = fs::read_to_string(path)?;
+    load_coo_from_matrix_market_str_with_options(&file, options)
+}
+
+/// Parses a Matrix Market file described by the given string as a `CooMatrix`, with the given options.
+///
+/// See [load_coo_from_matrix_market_file] for more information.
+///
+/// Errors
+/// --------
+///
+/// See [MatrixMarketErrorKind] for a list of possible error conditions.
+///
+/// Examples
+/// --------
+/// ```
+/// use nalgebra_sparse::io::{load_coo_from_matrix_market_str_with_options, MatrixMarketLoadOptions};
+/// let str = r#"
+/// %%matrixmarket matrix coordinate real symmetric
+/// 3 3 3
+/// 1 1 4.0
+/// 2 1 -1.0
+/// 3 3 4.0
+/// "#;
+/// let options = MatrixMarketLoadOptions::default().with_lower_triangle_only(true);
+/// let lower = load_coo_from_matrix_market_str_with_options::<f64>(str, &options).unwrap();
+/// // Only the stored entries are loaded, the entry (0, 1) is not
+/// assert_eq!(lower.nnz(), 3);
+/// ```
+pub fn load_coo_from_matrix_market_str_with_options<T>(
+    data: &str,
+    options: &MatrixMarketLoadOptions,
+) -> Result<CooMatrix<T>, MatrixMarketError>
+where
+    T: MatrixMarketScalar,
+{
+    parse_matrix_market(data, !options.lower_triangle_only)
+}
+
+/// Parses a Matrix Market file at the given path as a dense `DMatrix`.
+///
+/// Files in the `array` format, which is the matrix market format for dense matrices, are loaded
+/// with all their storage schemes. The entries that are not stored for `symmetric`,
+/// `skew-symmetric` and `hermitian` matrices are deduced from the stored entries. Files in the
+/// `coordinate` format are also accepted, in which case duplicate entries are summed.
+///
+/// Since a dense matrix can not represent a sparsity pattern, `pattern` matrices can not be loaded.
+///
+/// Errors
+/// --------
+///
+/// See [MatrixMarketErrorKind] for a list of possible error conditions.
+///
+/// Examples
+/// --------
+/// ```no_run
+/// use nalgebra_sparse::io::load_dmatrix_from_matrix_market_file;
+/// let matrix = load_dmatrix_from_matrix_market_file::<f64, _>("path/to/matrix.mtx").unwrap();
+/// ```
+pub fn load_dmatrix_from_matrix_market_file<T, P: AsRef<Path>>(
+    path: P,
+) -> Result<DMatrix<T>, MatrixMarketError>
+where
+    T: MatrixMarketScalar + Zero + ClosedAdd,
+{
+    let file = fs::read_to_string(path)?;
+    load_dmatrix_from_matrix_market_str(&file)
+}
+
+/// Parses a Matrix Market file described by the given string as a dense `DMatrix`.
+///
+/// See [load_dmatrix_from_matrix_market_file] for more information.
+///
+/// Errors
+/// --------
+///
+/// See [MatrixMarketErrorKind] for a list of possible error conditions.
+///
+/// Examples
+/// --------
+/// ```
+/// use nalgebra::dmatrix;
+/// use nalgebra_sparse::io::load_dmatrix_from_matrix_market_str;
+/// let str = r#"
+/// %%matrixmarket matrix array integer skew-symmetric
+/// 3 3
+/// 1
+/// 2
+/// 3
+/// "#;
+/// let matrix = load_dmatrix_from_matrix_market_str::<i32>(str).unwrap();
+/// assert_eq!(matrix, dmatrix![0, -1, -2;
+///                             1,  0, -3;
+///                             2,  3,  0]);
+/// ```
+pub fn load_dmatrix_from_matrix_market_str<T>(data: &str) -> Result<DMatrix<T>, MatrixMarketError>
+where
+    T: MatrixMarketScalar + Zero + ClosedAdd,
+{
+    let coo = parse_matrix_market(data, true)?;
+    Ok(DMatrix::from(&coo))
+}
+
+/// Parses matrix market data as a `CooMatrix`.
+///
+/// If `expand_symmetric` is `false`, only the stored lower triangular part of (skew-)symmetric and
+/// hermitian matrices is loaded.
+fn parse_matrix_market<T>(
+    data: &str,
+    expand_symmetric: bool,
+) -> Result<CooMatrix<T>, MatrixMarketError>
 where
     T: MatrixMarketScalar,
 {
@@ -924,7 +1217,7 @@ where
                 cols.push(c);
                 data.push(d.clone());
                 // don't need to add twice if the element in on diagonal
-                if r != c {
+                if expand_symmetric && r != c {
                     rows.push(c);
                     cols.push(r);
                     data.push(d);
@@ -945,9 +1238,11 @@ where
                         ),
                     ));
                 }
-                rows.push(c);
-                cols.push(r);
-                data.push(d.negative()?);
+                if expand_symmetric {
+                    rows.push(c);
+                    cols.push(r);
+                    data.push(d.negative()?);
+                }
             }
             StorageScheme::Hermitian => {
                 check_lower_triangle(r, c)?;
@@ -965,7 +1260,7 @@ where
                     ));
                 }
                 // don't need to add twice if the element in on diagonal
-                if r != c {
+                if expand_symmetric && r != c {
                     rows.push(c);
                     cols.push(r);
                     data.push(d.conjugate()?);
@@ -978,6 +1273,309 @@ where
     )?)
 }
 
+/// Matrix types that can be exported to the matrix market format.
+///
+/// Sparse matrices are written in the `coordinate` format, and dense matrices in the `array` format.
+///
+/// This is a sealed trait; it cannot be implemented by external crates.
+pub trait MatrixMarketExport<T: MatrixMarketScalar>:
+    internal::SupportedMatrixMarketExport<T>
+{
+}
+
+macro_rules! mm_sparse_export_impl {
+    ($Matrix:ident) => {
+        impl<T: MatrixMarketScalar> MatrixMarketExport<T> for $Matrix<T> {}
+
+        impl<T: MatrixMarketScalar> internal::SupportedMatrixMarketExport<T> for $Matrix<T> {
+            #[inline]
+            fn nrows(&self) -> usize {
+                self.nrows()
+            }
+            #[inline]
+            fn ncols(&self) -> usize {
+                self.ncols()
+            }
+            #[inline]
+            fn is_dense(&self) -> bool {
+                false
+            }
+            fn triplet_iter(&self) -> Box<dyn Iterator<Item = (usize, usize, &T)> + '_> {
+                Box::new(self.triplet_iter())
+            }
+        }
+    };
+}
+
+mm_sparse_export_impl!(CooMatrix);
+mm_sparse_export_impl!(CsrMatrix);
+mm_sparse_export_impl!(CscMatrix);
+
+impl<T: MatrixMarketScalar> MatrixMarketExport<T> for DMatrix<T> {}
+
+impl<T: MatrixMarketScalar> internal::SupportedMatrixMarketExport<T> for DMatrix<T> {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+    #[inline]
+    fn is_dense(&self) -> bool {
+        true
+    }
+    fn triplet_iter(&self) -> Box<dyn Iterator<Item = (usize, usize, &T)> + '_> {
+        let nrows = self.nrows();
+        Box::new(
+            self.iter()
+                .enumerate()
+                .map(move |(k, v)| (k % nrows, k / nrows, v)),
+        )
+    }
+}
+
+/// Writes a matrix to a Matrix Market file at the given path.
+///
+/// Sparse matrices ([CooMatrix], [CsrMatrix] and [CscMatrix]) are written in the `coordinate`
+/// format, and dense matrices ([DMatrix]) in the `array` format. The data type in the header is
+/// deduced from the scalar type: `integer` for primitive integers, `real` for `f32` and `f64`,
+/// `complex` for [Complex] numbers, and `pattern` for `()`.
+///
+/// With a storage scheme other than [General](StorageScheme::General), the matrix must be square
+/// and have the corresponding symmetry, in which case only its lower triangular part is written.
+/// For sparse matrices, the symmetry is checked on the explicitly stored entries, except for
+/// explicit zeros which are ignored. Explicitly stored entries of a `CooMatrix` that are duplicates
+/// are written as such.
+///
+/// A matrix written with this function is read back as the same matrix by
+/// [load_coo_from_matrix_market_file].
+///
+/// Errors
+/// --------
+///
+/// - [InvalidHeader](MatrixMarketErrorKind::InvalidHeader) if the scalar type does not support the
+///   storage scheme, for instance a `hermitian` real matrix, or if a dense pattern matrix is given.
+/// - [NonSquare](MatrixMarketErrorKind::NonSquare) if a non-square matrix is written with a
+///   storage scheme other than [General](StorageScheme::General).
+/// - [NotSymmetric](MatrixMarketErrorKind::NotSymmetric) if the matrix does not have the symmetry
+///   of the storage scheme.
+/// - [DiagonalError](MatrixMarketErrorKind::DiagonalError) if a skew-symmetric matrix has a non-zero
+///   diagonal entry, or if a hermitian matrix has a diagonal entry which is not real.
+/// - [IOError](MatrixMarketErrorKind::IOError) if the file can not be written.
+///
+/// Examples
+/// --------
+/// ```no_run
+/// use nalgebra_sparse::coo::CooMatrix;
+/// use nalgebra_sparse::io::{save_to_matrix_market_file, StorageScheme};
+/// let mut coo = CooMatrix::new(3, 3);
+/// coo.push(0, 0, 1.0);
+/// coo.push(2, 1, 2.0);
+/// save_to_matrix_market_file(&coo, "path/to/matrix.mtx", StorageScheme::General).unwrap();
+/// ```
+pub fn save_to_matrix_market_file<T, M, P>(
+    matrix: &M,
+    path: P,
+    storage: StorageScheme,
+) -> Result<(), MatrixMarketError>
+where
+    T: MatrixMarketScalar,
+    M: MatrixMarketExport<T>,
+    P: AsRef<Path>,
+{
+    let data = save_to_matrix_market_str(matrix, storage)?;
+    fs::write(path, data)?;
+    Ok(())
+}
+
+/// Writes a matrix to a string in the Matrix Market format.
+///
+/// See [save_to_matrix_market_file] for more information.
+///
+/// Errors
+/// --------
+///
+/// See [save_to_matrix_market_file] for a list of possible error conditions.
+///
+/// Examples
+/// --------
+/// ```
+/// use nalgebra::DMatrix;
+/// use nalgebra_sparse::coo::CooMatrix;
+/// use nalgebra_sparse::io::{load_coo_from_matrix_market_str, save_to_matrix_market_str, StorageScheme};
+/// let mut coo = CooMatrix::new(3, 3);
+/// coo.push(0, 0, 4);
+/// coo.push(2, 1, 5);
+/// coo.push(1, 2, 5);
+///
+/// let str = save_to_matrix_market_str(&coo, StorageScheme::Symmetric).unwrap();
+/// assert_eq!(str, "%%MatrixMarket matrix coordinate integer symmetric\n3 3 2\n1 1 4\n3 2 5\n");
+///
+/// let loaded = load_coo_from_matrix_market_str::<i32>(&str).unwrap();
+/// assert_eq!(DMatrix::from(&loaded), DMatrix::from(&coo));
+///
+/// // Dense matrices are written in the array format
+/// let dense = DMatrix::from_row_slice(2, 2, &[1, 2, 3, 4]);
+/// let str = save_to_matrix_market_str(&dense, StorageScheme::General).unwrap();
+/// assert_eq!(str, "%%MatrixMarket matrix array integer general\n2 2\n1\n3\n2\n4\n");
+/// ```
+pub fn save_to_matrix_market_str<T, M>(
+    matrix: &M,
+    storage: StorageScheme,
+) -> Result<String, MatrixMarketError>
+where
+    T: MatrixMarketScalar,
+    M: MatrixMarketExport<T>,
+{
+    let sparsity = if matrix.is_dense() {
+        Sparsity::Dense
+    } else {
+        Sparsity::Sparse
+    };
+    typecode_precheck(&Typecode {
+        sparsity,
+        datatype: T::data_type(),
+        storagescheme: storage,
+    })?;
+
+    let (nrows, ncols) = (matrix.nrows(), matrix.ncols());
+    if storage != StorageScheme::General && nrows != ncols {
+        return Err(MatrixMarketError::from_kind_and_message(MatrixMarketErrorKind::NonSquare, format!("(Skew-)Symmetric or hermitian matrix should be square matrix, but it has dimension {} and {}", nrows, ncols)));
+    }
+
+    let entries: Vec<_> = matrix.triplet_iter().collect();
+    if storage != StorageScheme::General {
+        check_symmetry(&entries, storage)?;
+    }
+    let is_written = |r: usize, c: usize| match storage {
+        StorageScheme::General => true,
+        StorageScheme::Symmetric | StorageScheme::Hermitian => r >= c,
+        StorageScheme::Skew => r > c,
+    };
+
+    let storage_name = match storage {
+        StorageScheme::General => "general",
+        StorageScheme::Symmetric => "symmetric",
+        StorageScheme::Skew => "skew-symmetric",
+        StorageScheme::Hermitian => "hermitian",
+    };
+    let mut data = String::new();
+    // writing to a String never fails
+    let result: fmt::Result = (|| {
+        if matrix.is_dense() {
+            writeln!(
+                data,
+                "%%MatrixMarket matrix array {} {}",
+                T::data_type(),
+                storage_name
+            )?;
+            writeln!(data, "{} {}", nrows, ncols)?;
+            for &(_, _, v) in entries.iter().filter(|&&(r, c, _)| is_written(r, c)) {
+                v.write_matrix_market(&mut data)?;
+                writeln!(data)?;
+            }
+        } else {
+            let nnz = entries
+                .iter()
+                .filter(|&&(r, c, _)| is_written(r, c))
+                .count();
+            writeln!(
+                data,
+                "%%MatrixMarket matrix coordinate {} {}",
+                T::data_type(),
+                storage_name
+            )?;
+            writeln!(data, "{} {} {}", nrows, ncols, nnz)?;
+            for &(r, c, v) in entries.iter().filter(|&&(r, c, _)| is_written(r, c)) {
+                // matrix market is 1-based
+                write!(data, "{} {}", r + 1, c + 1)?;
+                if T::data_type() != DataType::Pattern {
+                    write!(data, " ")?;
+                    v.write_matrix_market(&mut data)?;
+                }
+                writeln!(data)?;
+            }
+        }
+        Ok(())
+    })();
+    result.expect("Writing to a String does not fail.");
+    Ok(data)
+}
+
+/// Checks that the stored entries of a matrix have the symmetry of the given storage scheme.
+fn check_symmetry<T>(
+    entries: &[(usize, usize, &T)],
+    storage: StorageScheme,
+) -> Result<(), MatrixMarketError>
+where
+    T: MatrixMarketScalar,
+{
+    // Duplicate entries are summed, as when loading a matrix market file.
+    let mut summed: Vec<_> = entries.iter().map(|&(r, c, v)| (r, c, v.clone())).collect();
+    summed.sort_by_key(|&(r, c, _)| (r, c));
+    summed.dedup_by(|(r, c, v), (r_kept, c_kept, v_kept)| {
+        let is_duplicate = (*r, *c) == (*r_kept, *c_kept);
+        if is_duplicate {
+            *v_kept = v_kept.clone().add(v.clone());
+        }
+        is_duplicate
+    });
+
+    let mut lower = Vec::new();
+    let mut mirrored_upper = Vec::new();
+    for (r, c, v) in summed {
+        if r == c {
+            match storage {
+                StorageScheme::Skew if !v.is_zero() => {
+                    return Err(MatrixMarketError::from_kind_and_message(
+                        MatrixMarketErrorKind::DiagonalError,
+                        format!(
+                            "There is a diagonal element in skew matrix, in row(and column) {}",
+                            r + 1
+                        ),
+                    ));
+                }
+                StorageScheme::Hermitian if v.clone().conjugate()? != v => {
+                    return Err(MatrixMarketError::from_kind_and_message(
+                        MatrixMarketErrorKind::DiagonalError,
+                        format!(
+                            "There is a non-real diagonal element in hermitian matrix, in row(and column) {}",
+                            r + 1
+                        ),
+                    ));
+                }
+                _ => {}
+            }
+        } else if !v.is_zero() {
+            if r > c {
+                lower.push((r, c, v));
+            } else {
+                // the value that the mirrored entry must have in the lower triangle
+                let mirrored = match storage {
+                    StorageScheme::Skew => v.negative()?,
+                    StorageScheme::Hermitian => v.conjugate()?,
+                    _ => v,
+                };
+                mirrored_upper.push((c, r, mirrored));
+            }
+        }
+    }
+    // `lower` is already sorted, like `summed`.
+    mirrored_upper.sort_by_key(|&(r, c, _)| (r, c));
+    if lower != mirrored_upper {
+        return Err(MatrixMarketError::from_kind_and_message(
+            MatrixMarketErrorKind::NotSymmetric,
+            format!(
+                "The matrix does not have the symmetry of the {:?} storage scheme",
+                storage
+            ),
+        ));
+    }
+    Ok(())
+}
+
 #[inline]
 /// do a quick check it the entry is in the lower triangle part of the matrix
 fn check_lower_triangle(r: usize, c: usize) -> Result<(), MatrixMarketError> {
@@ -1088,7 +1686,7 @@ fn parse_dense_shape(
         StorageScheme::Skew => {
             // it must be square matrix, so r==c is true here
             // Skew-Symmetric should contain 1+2...+r-1  = r*(r-1)/2 entries
-            n = r * (r - 1) / 2;
+            n = r * r.saturating_sub(1) / 2;
         }
     }
 
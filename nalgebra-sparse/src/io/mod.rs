@@ -6,7 +6,7 @@
 //!
 //! | Format                                          |  Import    |   Export   |
 //! | ------------------------------------------------|------------|------------|
-//! | [Matrix market](#matrix-market-format)          |  Yes       |    No      |
+//! | [Matrix market](#matrix-market-format)          |  Yes       |    Yes     |
 //!
 //! [Matrix market]: https://math.nist.gov/MatrixMarket/formats.html
 //!
@@ -21,8 +21,15 @@
 //! We currently offer functionality for importing a Matrix market file to an instance of a
 //! [CooMatrix](crate::CooMatrix) through the function [load_coo_from_matrix_market_file]. It is also possible to load
 //! a matrix stored in the matrix market format with the function [load_coo_from_matrix_market_str].
+//! Symmetric, skew-symmetric and hermitian matrices are expanded to the full matrix by default, but can be loaded as
+//! their stored lower triangular part with [load_coo_from_matrix_market_file_with_options]. Dense matrices, stored in
+//! the `array` format, can be loaded directly as a [DMatrix](nalgebra::DMatrix) with
+//! [load_dmatrix_from_matrix_market_file] and [load_dmatrix_from_matrix_market_str].
 //!
-//! Export is currently not implemented, but [planned](https://github.com/dimforge/nalgebra/issues/1037).
+//! Sparse matrices ([CooMatrix](crate::CooMatrix), [CsrMatrix](crate::CsrMatrix) and [CscMatrix](crate::CscMatrix))
+//! and dense matrices ([DMatrix](nalgebra::DMatrix)) can be exported to a Matrix Market file with the function
+//! [save_to_matrix_market_file], or to a string with [save_to_matrix_market_str]. Symmetric, skew-symmetric and
+//! hermitian matrices can be stored compactly by choosing the corresponding [StorageScheme].
 //!
 //! Our implementation is based on the [format description](https://math.nist.gov/MatrixMarket/formats.html)
 //! on the Matrix Market website and the
@@ -32,7 +39,11 @@
 //! > "*The Matrix Market Exchange Formats: Initial Design.*" (1996).
 
 pub use self::matrix_market::{
-    load_coo_from_matrix_market_file, load_coo_from_matrix_market_str, MatrixMarketError,
-    MatrixMarketErrorKind, MatrixMarketScalar,
+    load_coo_from_matrix_market_file, load_coo_from_matrix_market_file_with_options,
+    load_coo_from_matrix_market_str, load_coo_from_matrix_market_str_with_options,
+    load_dmatrix_from_matrix_market_file, load_dmatrix_from_matrix_market_str,
+    save_to_matrix_market_file, save_to_matrix_market_str, MatrixMarketError,
+    MatrixMarketErrorKind, MatrixMarketExport, MatrixMarketLoadOptions, MatrixMarketScalar,
+    StorageScheme,
 };
 mod matrix_market;
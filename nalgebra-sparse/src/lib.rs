@@ -16,10 +16,16 @@
 //! - [CSR](csr::CsrMatrix), [CSC](csc::CscMatrix) and [COO](coo::CooMatrix) formats, and
 //!   [conversions](`convert`) between them.
 //! - Common arithmetic operations are implemented. See the [`ops`] module.
+//! - Extraction of rows, columns and submatrices, and assembly of block matrices with the
+//!   [`block!`] macro.
 //! - Sparsity patterns in CSR and CSC matrices are explicitly represented by the
 //!   [SparsityPattern](pattern::SparsityPattern) type, which encodes the invariants of the
 //!   associated index data structures.
+//! - Iterative [Krylov solvers](`solvers`) for large sparse linear systems.
+//! - [Partial eigensolvers and randomized SVD](`spectral`) for computing a few eigenpairs or
+//!   singular triplets of large matrices.
 //! - [Matrix market format support](`io`) when the `io` feature is enabled.
+//! - Parallel sparse matrix products in `ops::parallel` when the `rayon` feature is enabled.
 //! - [proptest strategies](`proptest`) for sparse matrices when the feature
 //!   `proptest-support` is enabled.
 //! - [matrixcompare support](https://crates.io/crates/matrixcompare) for effortless
@@ -148,7 +154,11 @@ extern crate pest;
 #[macro_use]
 #[cfg(feature = "io")]
 extern crate pest_derive;
+#[cfg(feature = "rayon")]
+extern crate rayon_package as rayon;
 
+#[doc(hidden)]
+pub mod block;
 pub mod convert;
 pub mod coo;
 pub mod csc;
@@ -157,7 +167,10 @@ pub mod factorization;
 #[cfg(feature = "io")]
 pub mod io;
 pub mod ops;
+pub mod ordering;
 pub mod pattern;
+pub mod solvers;
+pub mod spectral;
 
 pub(crate) mod cs;
 
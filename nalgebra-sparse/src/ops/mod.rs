@@ -6,9 +6,10 @@
 //! offer more control over allocation, and allow fusing some low-level operations for higher
 //! performance.
 //!
-//! The available operations are organized by backend. Currently, only the [`serial`] backend
-//! is available. In the future, backends that expose parallel operations may become available.
-//! All `std::ops` implementations will remain single-threaded and powered by the
+//! The available operations are organized by backend. The [`serial`] backend is always
+//! available, and the `parallel` backend, which mirrors the serial backend with multi-threaded
+//! kernels powered by [rayon](https://docs.rs/rayon), is available when the `rayon` feature is
+//! enabled. All `std::ops` implementations remain single-threaded and powered by the
 //! `serial` backend.
 //!
 //! Many routines are able to implicitly transpose matrices involved in the operation.
@@ -124,7 +125,53 @@
 //! directly calling kernels may sometimes lead to better performance. However, this should
 //! always be verified by performance profiling!
 
+macro_rules! assert_compatible_spmm_dims {
+    ($c:expr, $a:expr, $b:expr) => {{
+        use crate::ops::Op::{NoOp, Transpose};
+        match (&$a, &$b) {
+            (NoOp(ref a), NoOp(ref b)) => {
+                assert_eq!($c.nrows(), a.nrows(), "C.nrows() != A.nrows()");
+                assert_eq!($c.ncols(), b.ncols(), "C.ncols() != B.ncols()");
+                assert_eq!(a.ncols(), b.nrows(), "A.ncols() != B.nrows()");
+            }
+            (Transpose(ref a), NoOp(ref b)) => {
+                assert_eq!($c.nrows(), a.ncols(), "C.nrows() != A.ncols()");
+                assert_eq!($c.ncols(), b.ncols(), "C.ncols() != B.ncols()");
+                assert_eq!(a.nrows(), b.nrows(), "A.nrows() != B.nrows()");
+            }
+            (NoOp(ref a), Transpose(ref b)) => {
+                assert_eq!($c.nrows(), a.nrows(), "C.nrows() != A.nrows()");
+                assert_eq!($c.ncols(), b.nrows(), "C.ncols() != B.nrows()");
+                assert_eq!(a.ncols(), b.ncols(), "A.ncols() != B.ncols()");
+            }
+            (Transpose(ref a), Transpose(ref b)) => {
+                assert_eq!($c.nrows(), a.ncols(), "C.nrows() != A.ncols()");
+                assert_eq!($c.ncols(), b.nrows(), "C.ncols() != B.nrows()");
+                assert_eq!(a.nrows(), b.ncols(), "A.nrows() != B.ncols()");
+            }
+        }
+    }};
+}
+
+macro_rules! assert_compatible_spadd_dims {
+    ($c:expr, $a:expr) => {
+        use crate::ops::Op;
+        match $a {
+            Op::NoOp(a) => {
+                assert_eq!($c.nrows(), a.nrows(), "C.nrows() != A.nrows()");
+                assert_eq!($c.ncols(), a.ncols(), "C.ncols() != A.ncols()");
+            }
+            Op::Transpose(a) => {
+                assert_eq!($c.nrows(), a.ncols(), "C.nrows() != A.ncols()");
+                assert_eq!($c.ncols(), a.nrows(), "C.ncols() != A.nrows()");
+            }
+        }
+    };
+}
+
 mod impl_std_ops;
+#[cfg(feature = "rayon")]
+pub mod parallel;
 pub mod serial;
 
 /// Determines whether a matrix should be transposed in a given operation.
@@ -0,0 +1,86 @@
+use crate::cs::CsMatrix;
+use crate::ops::serial::{spadd_cs_lane, spmm_cs_dense_dot, spmm_cs_lane, OperationError};
+use crate::ops::Op;
+use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, Scalar};
+use num_traits::{One, Zero};
+use rayon::prelude::*;
+
+/// Helper functionality for implementing parallel CSR/CSC SPMM.
+///
+/// See [`spmm_cs_prealloc`](crate::ops::serial) in the serial backend for the conventions, the
+/// lanes of `C` are computed in parallel.
+pub fn spmm_cs_prealloc<T>(
+    beta: T,
+    c: &mut CsMatrix<T>,
+    alpha: T,
+    a: &CsMatrix<T>,
+    b: &CsMatrix<T>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    let c_lanes: Vec<_> = c.lane_iter_mut().collect();
+    c_lanes
+        .into_par_iter()
+        .enumerate()
+        .try_for_each(|(i, c_lane_i)| {
+            let a_lane_i = a.get_lane(i).unwrap();
+            spmm_cs_lane(beta.clone(), c_lane_i, alpha.clone(), a_lane_i, b)
+        })
+}
+
+/// Helper functionality for implementing parallel CSR/CSC SPADD `C <- beta * C + alpha * A`.
+///
+/// The lanes of `C` are computed in parallel.
+pub fn spadd_cs_prealloc<T>(
+    beta: T,
+    c: &mut CsMatrix<T>,
+    alpha: T,
+    a: &CsMatrix<T>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    let c_lanes: Vec<_> = c.lane_iter_mut().collect();
+    c_lanes
+        .into_par_iter()
+        .enumerate()
+        .try_for_each(|(i, c_lane_i)| {
+            let a_lane_i = a.get_lane(i).unwrap();
+            spadd_cs_lane(beta.clone(), c_lane_i, alpha.clone(), a_lane_i)
+        })
+}
+
+/// Helper functionality for implementing parallel CSR/CSC SPMM with a dense matrix,
+/// `C <- beta * C + alpha * A * op(B)`.
+///
+/// The implementation assumes that `a` is a CSR matrix, whose rows are processed in parallel.
+pub fn spmm_cs_dense<T>(
+    beta: T,
+    mut c: DMatrixSliceMut<'_, T>,
+    alpha: T,
+    a: &CsMatrix<T>,
+    b: Op<DMatrixSlice<'_, T>>,
+) where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    let ncols = c.ncols();
+    let b = &b;
+    // The entries of A * op(B) are computed in parallel, and stored in row-major order
+    let products: Vec<T> = (0..a.pattern().major_dim())
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            let a_row_i = a.get_lane(i).unwrap();
+            (0..ncols).map(move |j| spmm_cs_dense_dot(&a_row_i, b, j))
+        })
+        .collect();
+
+    if ncols > 0 {
+        for (i, products_i) in products.chunks_exact(ncols).enumerate() {
+            for (j, dot_ij) in products_i.iter().enumerate() {
+                let c_ij = &mut c[(i, j)];
+                *c_ij = beta.clone() * c_ij.clone() + alpha.clone() * dot_ij.clone();
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::csc::CscMatrix;
+use crate::ops::parallel::cs::{spadd_cs_prealloc, spmm_cs_dense, spmm_cs_prealloc};
+use crate::ops::serial::OperationError;
+use crate::ops::Op;
+use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, Scalar};
+use num_traits::{One, Zero};
+use std::borrow::Cow;
+
+/// Parallel sparse-dense matrix-matrix multiplication `C <- beta * C + alpha * op(A) * op(B)`.
+///
+/// The rows of `C` are computed in parallel, which requires row access to `op(A)`. Unless `A`
+/// is transposed, its transpose is therefore explicitly computed first.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved are not compatible with the expression.
+pub fn spmm_csc_dense<'a, T>(
+    beta: T,
+    c: impl Into<DMatrixSliceMut<'a, T>>,
+    alpha: T,
+    a: Op<&CscMatrix<T>>,
+    b: Op<impl Into<DMatrixSlice<'a, T>>>,
+) where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    let b = b.convert();
+    spmm_csc_dense_(beta, c.into(), alpha, a, b)
+}
+
+fn spmm_csc_dense_<T>(
+    beta: T,
+    c: DMatrixSliceMut<'_, T>,
+    alpha: T,
+    a: Op<&CscMatrix<T>>,
+    b: Op<DMatrixSlice<'_, T>>,
+) where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    assert_compatible_spmm_dims!(c, a, b);
+    // The CSC storage of A^T is the CSR storage of A, and vice versa
+    match a {
+        Op::NoOp(a) => spmm_cs_dense(beta, c, alpha, &a.transpose().cs, b),
+        Op::Transpose(a) => spmm_cs_dense(beta, c, alpha, &a.cs, b),
+    }
+}
+
+/// Parallel sparse matrix addition `C <- beta * C + alpha * op(A)`.
+///
+/// The columns of `C` are computed in parallel. If `A` is transposed, its transpose is explicitly
+/// computed first.
+///
+/// # Errors
+///
+/// If the pattern of `c` does not accommodate all the non-zero entries in `a`, an error is
+/// returned.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved are not compatible with the expression.
+pub fn spadd_csc_prealloc<T>(
+    beta: T,
+    c: &mut CscMatrix<T>,
+    alpha: T,
+    a: Op<&CscMatrix<T>>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    assert_compatible_spadd_dims!(c, a);
+    match a {
+        Op::NoOp(a) => spadd_cs_prealloc(beta, &mut c.cs, alpha, &a.cs),
+        Op::Transpose(a) => spadd_cs_prealloc(beta, &mut c.cs, alpha, &a.transpose().cs),
+    }
+}
+
+/// Parallel sparse-sparse matrix multiplication, `C <- beta * C + alpha * op(A) * op(B)`.
+///
+/// The columns of `C` are computed in parallel. Transposed matrices are explicitly computed first.
+///
+/// # Errors
+///
+/// If the sparsity pattern of `C` is not able to store the result of the operation,
+/// an error is returned.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved are not compatible with the expression.
+pub fn spmm_csc_prealloc<T>(
+    beta: T,
+    c: &mut CscMatrix<T>,
+    alpha: T,
+    a: Op<&CscMatrix<T>>,
+    b: Op<&CscMatrix<T>>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    assert_compatible_spmm_dims!(c, a, b);
+
+    use Op::{NoOp, Transpose};
+
+    match (&a, &b) {
+        (NoOp(a), NoOp(b)) => {
+            // Note: We have to reverse the order for CSC matrices
+            spmm_cs_prealloc(beta, &mut c.cs, alpha, &b.cs, &a.cs)
+        }
+        _ => {
+            let a_ref: &CscMatrix<T> = a.inner_ref();
+            let b_ref: &CscMatrix<T> = b.inner_ref();
+            let (a, b) = {
+                use Cow::*;
+                match (&a, &b) {
+                    (NoOp(_), NoOp(_)) => unreachable!(),
+                    (Transpose(a), NoOp(_)) => (Owned(a.transpose()), Borrowed(b_ref)),
+                    (NoOp(_), Transpose(b)) => (Borrowed(a_ref), Owned(b.transpose())),
+                    (Transpose(a), Transpose(b)) => (Owned(a.transpose()), Owned(b.transpose())),
+                }
+            };
+
+            spmm_csc_prealloc(beta, c, alpha, NoOp(a.as_ref()), NoOp(b.as_ref()))
+        }
+    }
+}
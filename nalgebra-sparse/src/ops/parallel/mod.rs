@@ -0,0 +1,40 @@
+//! Parallel sparse matrix arithmetic routines.
+//!
+//! **Available only when the `rayon` feature is enabled.**
+//!
+//! The routines in this module mirror the routines with the same names in the
+//! [`serial`](crate::ops::serial) backend, and are multi-threaded with [rayon](https://docs.rs/rayon).
+//! The work is distributed over the lanes of the output matrix, i.e. its rows for CSR matrices and
+//! its columns for CSC matrices. The results agree with the serial routines up to floating-point
+//! rounding, since some operations may accumulate the terms of each entry in a different order.
+//!
+//! As in the serial backend, routines with the `prealloc` suffix expect that the sparsity pattern
+//! of the output matrix has already been pre-allocated to fit the result of the operation.
+//!
+//! Transposed operands, i.e. `Op::Transpose(A)` for CSR matrices and `Op::NoOp(A)` for CSC
+//! matrices in the sparse-dense products, are handled by explicitly precomputing the transposed
+//! matrices. Avoiding these combinations avoids the corresponding allocations.
+//!
+//! # Example
+//!
+//! ```
+//! # use nalgebra::DMatrix;
+//! # use nalgebra_sparse::csr::CsrMatrix;
+//! use nalgebra_sparse::ops::{Op, parallel::spmm_csr_dense};
+//!
+//! let a = CsrMatrix::identity(100);
+//! let b = DMatrix::from_element(100, 3, 2.0);
+//! let mut c = DMatrix::zeros(100, 3);
+//!
+//! // Evaluate `c <- 0.0 * c + 1.0 * a * b` in parallel
+//! spmm_csr_dense(0.0, &mut c, 1.0, Op::NoOp(&a), Op::NoOp(&b));
+//! assert_eq!(c, b);
+//! ```
+
+mod cs;
+mod csc;
+mod csr;
+
+pub use super::serial::{OperationError, OperationErrorKind};
+pub use csc::*;
+pub use csr::*;
@@ -1,8 +1,9 @@
-use crate::cs::CsMatrix;
+use crate::cs::{CsLane, CsLaneMut, CsMatrix};
 use crate::ops::serial::{OperationError, OperationErrorKind};
 use crate::ops::Op;
+use crate::pattern::SparsityPattern;
 use crate::SparseEntryMut;
-use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, Scalar};
+use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, RealField, Scalar};
 use num_traits::{One, Zero};
 
 fn spmm_cs_unexpected_entry() -> OperationError {
@@ -30,29 +31,46 @@ pub fn spmm_cs_prealloc<T>(
 where
     T: Scalar + ClosedAdd + ClosedMul + Zero + One,
 {
-    for i in 0..c.pattern().major_dim() {
-        let a_lane_i = a.get_lane(i).unwrap();
-        let mut c_lane_i = c.get_lane_mut(i).unwrap();
-        for c_ij in c_lane_i.values_mut() {
-            *c_ij = beta.clone() * c_ij.clone();
-        }
+    for (c_lane_i, a_lane_i) in c.lane_iter_mut().zip(a.lane_iter()) {
+        spmm_cs_lane(beta.clone(), c_lane_i, alpha.clone(), a_lane_i, b)?;
+    }
 
-        for (&k, a_ik) in a_lane_i.minor_indices().iter().zip(a_lane_i.values()) {
-            let b_lane_k = b.get_lane(k).unwrap();
-            let (mut c_lane_i_cols, mut c_lane_i_values) = c_lane_i.indices_and_values_mut();
-            let alpha_aik = alpha.clone() * a_ik.clone();
-            for (j, b_kj) in b_lane_k.minor_indices().iter().zip(b_lane_k.values()) {
-                // Determine the location in C to append the value
-                let (c_local_idx, _) = c_lane_i_cols
-                    .iter()
-                    .enumerate()
-                    .find(|(_, c_col)| *c_col == j)
-                    .ok_or_else(spmm_cs_unexpected_entry)?;
+    Ok(())
+}
 
-                c_lane_i_values[c_local_idx] += alpha_aik.clone() * b_kj.clone();
-                c_lane_i_cols = &c_lane_i_cols[c_local_idx..];
-                c_lane_i_values = &mut c_lane_i_values[c_local_idx..];
-            }
+/// Computes a single lane `i` of the CSR/CSC SPMM `C <- beta * C + alpha * A * B`, given the
+/// lanes `i` of `C` and `A`.
+///
+/// Lanes are independent of each other, which lets the parallel backend share this kernel.
+pub fn spmm_cs_lane<T>(
+    beta: T,
+    mut c_lane_i: CsLaneMut<'_, T>,
+    alpha: T,
+    a_lane_i: CsLane<'_, T>,
+    b: &CsMatrix<T>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    for c_ij in c_lane_i.values_mut() {
+        *c_ij = beta.clone() * c_ij.clone();
+    }
+
+    for (&k, a_ik) in a_lane_i.minor_indices().iter().zip(a_lane_i.values()) {
+        let b_lane_k = b.get_lane(k).unwrap();
+        let (mut c_lane_i_cols, mut c_lane_i_values) = c_lane_i.indices_and_values_mut();
+        let alpha_aik = alpha.clone() * a_ik.clone();
+        for (j, b_kj) in b_lane_k.minor_indices().iter().zip(b_lane_k.values()) {
+            // Determine the location in C to append the value
+            let (c_local_idx, _) = c_lane_i_cols
+                .iter()
+                .enumerate()
+                .find(|(_, c_col)| *c_col == j)
+                .ok_or_else(spmm_cs_unexpected_entry)?;
+
+            c_lane_i_values[c_local_idx] += alpha_aik.clone() * b_kj.clone();
+            c_lane_i_cols = &c_lane_i_cols[c_local_idx..];
+            c_lane_i_values = &mut c_lane_i_values[c_local_idx..];
         }
     }
 
@@ -78,29 +96,8 @@ where
 {
     match a {
         Op::NoOp(a) => {
-            for (mut c_lane_i, a_lane_i) in c.lane_iter_mut().zip(a.lane_iter()) {
-                if beta != T::one() {
-                    for c_ij in c_lane_i.values_mut() {
-                        *c_ij *= beta.clone();
-                    }
-                }
-
-                let (mut c_minors, mut c_vals) = c_lane_i.indices_and_values_mut();
-                let (a_minors, a_vals) = (a_lane_i.minor_indices(), a_lane_i.values());
-
-                for (a_col, a_val) in a_minors.iter().zip(a_vals) {
-                    // TODO: Use exponential search instead of linear search.
-                    // If C has substantially more entries in the row than A, then a line search
-                    // will needlessly visit many entries in C.
-                    let (c_idx, _) = c_minors
-                        .iter()
-                        .enumerate()
-                        .find(|(_, c_col)| *c_col == a_col)
-                        .ok_or_else(spadd_cs_unexpected_entry)?;
-                    c_vals[c_idx] += alpha.clone() * a_val.clone();
-                    c_minors = &c_minors[c_idx..];
-                    c_vals = &mut c_vals[c_idx..];
-                }
+            for (c_lane_i, a_lane_i) in c.lane_iter_mut().zip(a.lane_iter()) {
+                spadd_cs_lane(beta.clone(), c_lane_i, alpha.clone(), a_lane_i)?;
             }
         }
         Op::Transpose(a) => {
@@ -125,6 +122,43 @@ where
     Ok(())
 }
 
+/// Computes a single lane `i` of the CSR/CSC SPADD `C <- beta * C + alpha * A`, given the
+/// lanes `i` of `C` and `A`.
+pub fn spadd_cs_lane<T>(
+    beta: T,
+    mut c_lane_i: CsLaneMut<'_, T>,
+    alpha: T,
+    a_lane_i: CsLane<'_, T>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    if beta != T::one() {
+        for c_ij in c_lane_i.values_mut() {
+            *c_ij *= beta.clone();
+        }
+    }
+
+    let (mut c_minors, mut c_vals) = c_lane_i.indices_and_values_mut();
+    let (a_minors, a_vals) = (a_lane_i.minor_indices(), a_lane_i.values());
+
+    for (a_col, a_val) in a_minors.iter().zip(a_vals) {
+        // TODO: Use exponential search instead of linear search.
+        // If C has substantially more entries in the row than A, then a line search
+        // will needlessly visit many entries in C.
+        let (c_idx, _) = c_minors
+            .iter()
+            .enumerate()
+            .find(|(_, c_col)| *c_col == a_col)
+            .ok_or_else(spadd_cs_unexpected_entry)?;
+        c_vals[c_idx] += alpha.clone() * a_val.clone();
+        c_minors = &c_minors[c_idx..];
+        c_vals = &mut c_vals[c_idx..];
+    }
+
+    Ok(())
+}
+
 /// Helper functionality for implementing CSR/CSC SPMM.
 ///
 /// The implementation essentially assumes that `a` is a CSR matrix. To use it with CSC matrices,
@@ -143,14 +177,7 @@ pub fn spmm_cs_dense<T>(
             for j in 0..c.ncols() {
                 let mut c_col_j = c.column_mut(j);
                 for (c_ij, a_row_i) in c_col_j.iter_mut().zip(a.lane_iter()) {
-                    let mut dot_ij = T::zero();
-                    for (&k, a_ik) in a_row_i.minor_indices().iter().zip(a_row_i.values()) {
-                        let b_contrib = match b {
-                            Op::NoOp(ref b) => b.index((k, j)),
-                            Op::Transpose(ref b) => b.index((j, k)),
-                        };
-                        dot_ij += a_ik.clone() * b_contrib.clone();
-                    }
+                    let dot_ij = spmm_cs_dense_dot(&a_row_i, &b, j);
                     *c_ij = beta.clone() * c_ij.clone() + alpha.clone() * dot_ij;
                 }
             }
@@ -183,3 +210,217 @@ pub fn spmm_cs_dense<T>(
         }
     }
 }
+
+/// Computes the entry `(i, j)` of `A * op(B)`, given the row `i` of `A`.
+pub fn spmm_cs_dense_dot<T>(a_row_i: &CsLane<'_, T>, b: &Op<DMatrixSlice<'_, T>>, j: usize) -> T
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    let mut dot_ij = T::zero();
+    for (&k, a_ik) in a_row_i.minor_indices().iter().zip(a_row_i.values()) {
+        let b_contrib = match b {
+            Op::NoOp(ref b) => b.index((k, j)),
+            Op::Transpose(ref b) => b.index((j, k)),
+        };
+        dot_ij += a_ik.clone() * b_contrib.clone();
+    }
+    dot_ij
+}
+
+/// Locates the diagonal entry `(k, k)` in the lane `k` of a matrix, and returns its local index
+/// and its value.
+fn spsolve_cs_diagonal<T: RealField>(
+    lane_k: &CsLane<'_, T>,
+    k: usize,
+) -> Result<(usize, T), OperationError> {
+    // TODO: Can use exponential search here to quickly skip entries
+    // (we'd like to avoid using binary search as it's very cache unfriendly
+    // and the matrix might actually *be* triangular, which would induce
+    // a severe penalty)
+    let diag_index = lane_k.minor_indices().iter().position(|&i| i == k);
+    match diag_index {
+        Some(diag_index) if lane_k.values()[diag_index] != T::zero() => {
+            Ok((diag_index, lane_k.values()[diag_index].clone()))
+        }
+        _ => Err(spsolve_encountered_zero_diagonal()),
+    }
+}
+
+/// Returns the off-diagonal entries of the lane `k` which belong to the requested triangle,
+/// given the local index of the diagonal entry.
+fn spsolve_cs_triangle_entries<'a, T>(
+    lane_k: &'a CsLane<'_, T>,
+    diag_index: usize,
+    lower: bool,
+) -> (&'a [usize], &'a [T]) {
+    let (indices, values) = (lane_k.minor_indices(), lane_k.values());
+    if lower {
+        (&indices[(diag_index + 1)..], &values[(diag_index + 1)..])
+    } else {
+        (&indices[..diag_index], &values[..diag_index])
+    }
+}
+
+fn spsolve_encountered_zero_diagonal() -> OperationError {
+    let message = "Matrix contains at least one diagonal entry that is zero.";
+    OperationError::from_kind_and_message(OperationErrorKind::Singular, String::from(message))
+}
+
+/// Helper functionality for implementing CSR/CSC triangular solves.
+///
+/// Solves the triangular system `M X = B` in place, where the lanes of `m` are interpreted as
+/// the *columns* of `M`. Only the lower (if `lower` is `true`) or upper triangular part of `M`
+/// is read. The columns of `M` are traversed in order, and each solved entry is eliminated from
+/// the remaining entries.
+pub fn spsolve_cs_by_columns<T: RealField>(
+    m: &CsMatrix<T>,
+    lower: bool,
+    mut x: DMatrixSliceMut<'_, T>,
+) -> Result<(), OperationError> {
+    let n = m.pattern().major_dim();
+
+    // Solve column-by-column
+    for j in 0..x.ncols() {
+        let mut x_col_j = x.column_mut(j);
+
+        for step in 0..n {
+            let k = if lower { step } else { n - 1 - step };
+            let m_col_k = m.get_lane(k).unwrap();
+            let (diag_index, m_kk) = spsolve_cs_diagonal(&m_col_k, k)?;
+
+            // Update entry associated with diagonal
+            x_col_j[k] /= m_kk;
+            // Copy value after updating (so we don't run into the borrow checker)
+            let x_kj = x_col_j[k].clone();
+
+            let (row_indices, m_values) = spsolve_cs_triangle_entries(&m_col_k, diag_index, lower);
+            for (&i, m_ik) in row_indices.iter().zip(m_values) {
+                x_col_j[i] -= m_ik.clone() * x_kj.clone();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Helper functionality for implementing CSR/CSC triangular solves.
+///
+/// Solves the triangular system `M X = B` in place, where the lanes of `m` are interpreted as
+/// the *rows* of `M`. Only the lower (if `lower` is `true`) or upper triangular part of `M`
+/// is read. Each entry of the solution is computed from the previously solved entries.
+pub fn spsolve_cs_by_rows<T: RealField>(
+    m: &CsMatrix<T>,
+    lower: bool,
+    mut x: DMatrixSliceMut<'_, T>,
+) -> Result<(), OperationError> {
+    let n = m.pattern().major_dim();
+
+    // Solve column-by-column
+    for j in 0..x.ncols() {
+        let mut x_col_j = x.column_mut(j);
+
+        for step in 0..n {
+            let k = if lower { step } else { n - 1 - step };
+            let m_row_k = m.get_lane(k).unwrap();
+            let (diag_index, m_kk) = spsolve_cs_diagonal(&m_row_k, k)?;
+
+            let mut x_kj = x_col_j[k].clone();
+            let (col_indices, m_values) = spsolve_cs_triangle_entries(&m_row_k, diag_index, !lower);
+            for (&i, m_ki) in col_indices.iter().zip(m_values) {
+                x_kj -= m_ki.clone() * x_col_j[i].clone();
+            }
+            x_col_j[k] = x_kj / m_kk;
+        }
+    }
+
+    Ok(())
+}
+
+/// Helper functionality for implementing CSC triangular solves with sparse right-hand sides.
+///
+/// Solves the triangular system `M X = B`, where the lanes of `m` are the columns of `M` and the
+/// lanes of `b` are the columns of `B`, and returns `X` in the same layout. The pattern of each
+/// column of `X` is the set of nodes that are reachable from the non-zero entries of the
+/// corresponding column of `B` in the graph of `M`, so that only the entries of the solution that
+/// may be non-zero are computed.
+pub fn spsolve_cs_by_columns_sparse<T: RealField>(
+    m: &CsMatrix<T>,
+    lower: bool,
+    b: &CsMatrix<T>,
+) -> Result<CsMatrix<T>, OperationError> {
+    let n = m.pattern().major_dim();
+    let mut offsets = Vec::with_capacity(b.pattern().major_dim() + 1);
+    let mut indices = Vec::new();
+    let mut values = Vec::new();
+    offsets.push(0);
+
+    // Dense workspaces, reset after each column
+    let mut x = vec![T::zero(); n];
+    let mut marks = vec![false; n];
+    let mut stack = Vec::new();
+    let mut reach = Vec::new();
+
+    for b_col_j in b.lane_iter() {
+        // Depth-first search from the non-zero entries of B, which produces the reachable nodes
+        // in reverse topological order
+        reach.clear();
+        for &root in b_col_j.minor_indices() {
+            if marks[root] {
+                continue;
+            }
+            marks[root] = true;
+            stack.push((root, 0));
+            while let Some((k, next)) = stack.last_mut() {
+                let m_col_k = m.get_lane(*k).unwrap();
+                // Only the entries in the requested triangle are edges of the graph
+                let unvisited = m_col_k
+                    .minor_indices()
+                    .iter()
+                    .enumerate()
+                    .skip(*next)
+                    .filter(|&(_, &i)| if lower { i > *k } else { i < *k })
+                    .find(|&(_, &i)| !marks[i]);
+                match unvisited {
+                    Some((local_index, &i)) => {
+                        *next = local_index + 1;
+                        marks[i] = true;
+                        stack.push((i, 0));
+                    }
+                    None => {
+                        reach.push(*k);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+
+        for (&i, b_ij) in b_col_j.minor_indices().iter().zip(b_col_j.values()) {
+            x[i] += b_ij.clone();
+        }
+
+        for &k in reach.iter().rev() {
+            let m_col_k = m.get_lane(k).unwrap();
+            let (diag_index, m_kk) = spsolve_cs_diagonal(&m_col_k, k)?;
+            x[k] /= m_kk;
+            let x_k = x[k].clone();
+            let (row_indices, m_values) = spsolve_cs_triangle_entries(&m_col_k, diag_index, lower);
+            for (&i, m_ik) in row_indices.iter().zip(m_values) {
+                x[i] -= m_ik.clone() * x_k.clone();
+            }
+        }
+
+        reach.sort_unstable();
+        for &i in &reach {
+            indices.push(i);
+            values.push(x[i].clone());
+            x[i] = T::zero();
+            marks[i] = false;
+        }
+        offsets.push(indices.len());
+    }
+
+    let pattern =
+        SparsityPattern::try_from_offsets_and_indices(b.pattern().major_dim(), n, offsets, indices)
+            .expect("The pattern of the solution is valid by construction.");
+    Ok(CsMatrix::from_pattern_and_values(pattern, values))
+}
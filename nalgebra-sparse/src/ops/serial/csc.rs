@@ -1,6 +1,9 @@
 use crate::csc::CscMatrix;
-use crate::ops::serial::cs::{spadd_cs_prealloc, spmm_cs_dense, spmm_cs_prealloc};
-use crate::ops::serial::{OperationError, OperationErrorKind};
+use crate::ops::serial::cs::{
+    spadd_cs_prealloc, spmm_cs_dense, spmm_cs_prealloc, spsolve_cs_by_columns,
+    spsolve_cs_by_columns_sparse, spsolve_cs_by_rows,
+};
+use crate::ops::serial::OperationError;
 use crate::ops::Op;
 use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, RealField, Scalar};
 use num_traits::{One, Zero};
@@ -140,116 +143,119 @@ pub fn spsolve_csc_lower_triangular<'a, T: RealField>(
         "Dimension mismatch in sparse lower triangular solver."
     );
     match l {
-        Op::NoOp(a) => spsolve_csc_lower_triangular_no_transpose(a, b),
-        Op::Transpose(a) => spsolve_csc_lower_triangular_transpose(a, b),
+        Op::NoOp(a) => spsolve_cs_by_columns(&a.cs, true, b),
+        // Due to the transposition, we're essentially solving an upper triangular system,
+        // and the columns in our matrix become rows
+        Op::Transpose(a) => spsolve_cs_by_rows(&a.cs, false, b),
     }
 }
 
-fn spsolve_csc_lower_triangular_no_transpose<T: RealField>(
-    l: &CscMatrix<T>,
-    b: DMatrixSliceMut<'_, T>,
+/// Solve the upper triangular system `op(U) X = B`.
+///
+/// Only the upper triangular part of U is read, and the result is stored in B.
+///
+/// # Errors
+///
+/// An error is returned if the system can not be solved due to the matrix being singular.
+///
+/// # Panics
+///
+/// Panics if `U` is not square, or if `U` and `B` are not dimensionally compatible.
+pub fn spsolve_csc_upper_triangular<'a, T: RealField>(
+    u: Op<&CscMatrix<T>>,
+    b: impl Into<DMatrixSliceMut<'a, T>>,
 ) -> Result<(), OperationError> {
-    let mut x = b;
-
-    // Solve column-by-column
-    for j in 0..x.ncols() {
-        let mut x_col_j = x.column_mut(j);
-
-        for k in 0..l.ncols() {
-            let l_col_k = l.col(k);
-
-            // Skip entries above the diagonal
-            // TODO: Can use exponential search here to quickly skip entries
-            // (we'd like to avoid using binary search as it's very cache unfriendly
-            // and the matrix might actually *be* lower triangular, which would induce
-            // a severe penalty)
-            let diag_csc_index = l_col_k.row_indices().iter().position(|&i| i == k);
-            if let Some(diag_csc_index) = diag_csc_index {
-                let l_kk = l_col_k.values()[diag_csc_index].clone();
-
-                if l_kk != T::zero() {
-                    // Update entry associated with diagonal
-                    x_col_j[k] /= l_kk;
-                    // Copy value after updating (so we don't run into the borrow checker)
-                    let x_kj = x_col_j[k].clone();
-
-                    let row_indices = &l_col_k.row_indices()[(diag_csc_index + 1)..];
-                    let l_values = &l_col_k.values()[(diag_csc_index + 1)..];
-
-                    // Note: The remaining entries are below the diagonal
-                    for (&i, l_ik) in row_indices.iter().zip(l_values) {
-                        let x_ij = &mut x_col_j[i];
-                        *x_ij -= l_ik.clone() * x_kj.clone();
-                    }
-
-                    x_col_j[k] = x_kj;
-                } else {
-                    return spsolve_encountered_zero_diagonal();
-                }
-            } else {
-                return spsolve_encountered_zero_diagonal();
-            }
-        }
+    let b = b.into();
+    let u_matrix = u.into_inner();
+    assert_eq!(
+        u_matrix.nrows(),
+        u_matrix.ncols(),
+        "Matrix must be square for triangular solve."
+    );
+    assert_eq!(
+        u_matrix.nrows(),
+        b.nrows(),
+        "Dimension mismatch in sparse upper triangular solver."
+    );
+    match u {
+        Op::NoOp(a) => spsolve_cs_by_columns(&a.cs, false, b),
+        Op::Transpose(a) => spsolve_cs_by_rows(&a.cs, true, b),
     }
-
-    Ok(())
 }
 
-fn spsolve_encountered_zero_diagonal() -> Result<(), OperationError> {
-    let message = "Matrix contains at least one diagonal entry that is zero.";
-    Err(OperationError::from_kind_and_message(
-        OperationErrorKind::Singular,
-        String::from(message),
-    ))
+/// Solve the lower triangular system `op(L) X = B` with a sparse right-hand side `B`.
+///
+/// Only the lower triangular part of L is read. The sparsity pattern of the returned solution `X`
+/// is determined by a reachability analysis in the graph of `L`: each column of `X` contains the
+/// entries that are reachable from the non-zero entries of the same column of `B`. Only these
+/// entries are computed, which makes the solve much cheaper than a dense solve when the solution
+/// is sparse. The pattern may contain explicitly stored zeros due to numerical cancellation.
+///
+/// If `L` is transposed, its transpose is explicitly computed first.
+///
+/// # Errors
+///
+/// An error is returned if the system can not be solved due to the matrix being singular. Only
+/// the diagonal entries of `L` that are involved in the solution are checked.
+///
+/// # Panics
+///
+/// Panics if `L` is not square, or if `L` and `B` are not dimensionally compatible.
+pub fn spsolve_csc_lower_triangular_sparse<T: RealField>(
+    l: Op<&CscMatrix<T>>,
+    b: &CscMatrix<T>,
+) -> Result<CscMatrix<T>, OperationError> {
+    let l_matrix = l.inner_ref();
+    assert_eq!(
+        l_matrix.nrows(),
+        l_matrix.ncols(),
+        "Matrix must be square for triangular solve."
+    );
+    assert_eq!(
+        l_matrix.nrows(),
+        b.nrows(),
+        "Dimension mismatch in sparse lower triangular solver."
+    );
+    let cs = match l {
+        Op::NoOp(a) => spsolve_cs_by_columns_sparse(&a.cs, true, &b.cs)?,
+        Op::Transpose(a) => spsolve_cs_by_columns_sparse(&a.transpose().cs, false, &b.cs)?,
+    };
+    Ok(CscMatrix { cs })
 }
 
-fn spsolve_csc_lower_triangular_transpose<T: RealField>(
-    l: &CscMatrix<T>,
-    b: DMatrixSliceMut<'_, T>,
-) -> Result<(), OperationError> {
-    let mut x = b;
-
-    // Solve column-by-column
-    for j in 0..x.ncols() {
-        let mut x_col_j = x.column_mut(j);
-
-        // Due to the transposition, we're essentially solving an upper triangular system,
-        // and the columns in our matrix become rows
-
-        for i in (0..l.ncols()).rev() {
-            let l_col_i = l.col(i);
-
-            // Skip entries above the diagonal
-            // TODO: Can use exponential search here to quickly skip entries
-            let diag_csc_index = l_col_i.row_indices().iter().position(|&k| i == k);
-            if let Some(diag_csc_index) = diag_csc_index {
-                let l_ii = l_col_i.values()[diag_csc_index].clone();
-
-                if l_ii != T::zero() {
-                    // // Update entry associated with diagonal
-                    // x_col_j[k] /= a_kk;
-
-                    // Copy value after updating (so we don't run into the borrow checker)
-                    let mut x_ii = x_col_j[i].clone();
-
-                    let row_indices = &l_col_i.row_indices()[(diag_csc_index + 1)..];
-                    let a_values = &l_col_i.values()[(diag_csc_index + 1)..];
-
-                    // Note: The remaining entries are below the diagonal
-                    for (k, l_ki) in row_indices.iter().zip(a_values) {
-                        let x_kj = x_col_j[*k].clone();
-                        x_ii -= l_ki.clone() * x_kj;
-                    }
-
-                    x_col_j[i] = x_ii / l_ii;
-                } else {
-                    return spsolve_encountered_zero_diagonal();
-                }
-            } else {
-                return spsolve_encountered_zero_diagonal();
-            }
-        }
-    }
-
-    Ok(())
+/// Solve the upper triangular system `op(U) X = B` with a sparse right-hand side `B`.
+///
+/// Only the upper triangular part of U is read. See [`spsolve_csc_lower_triangular_sparse`] for
+/// how the sparsity pattern of the returned solution is determined.
+///
+/// If `U` is transposed, its transpose is explicitly computed first.
+///
+/// # Errors
+///
+/// An error is returned if the system can not be solved due to the matrix being singular. Only
+/// the diagonal entries of `U` that are involved in the solution are checked.
+///
+/// # Panics
+///
+/// Panics if `U` is not square, or if `U` and `B` are not dimensionally compatible.
+pub fn spsolve_csc_upper_triangular_sparse<T: RealField>(
+    u: Op<&CscMatrix<T>>,
+    b: &CscMatrix<T>,
+) -> Result<CscMatrix<T>, OperationError> {
+    let u_matrix = u.inner_ref();
+    assert_eq!(
+        u_matrix.nrows(),
+        u_matrix.ncols(),
+        "Matrix must be square for triangular solve."
+    );
+    assert_eq!(
+        u_matrix.nrows(),
+        b.nrows(),
+        "Dimension mismatch in sparse upper triangular solver."
+    );
+    let cs = match u {
+        Op::NoOp(a) => spsolve_cs_by_columns_sparse(&a.cs, false, &b.cs)?,
+        Op::Transpose(a) => spsolve_cs_by_columns_sparse(&a.transpose().cs, true, &b.cs)?,
+    };
+    Ok(CscMatrix { cs })
 }
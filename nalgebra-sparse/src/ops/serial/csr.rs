@@ -1,8 +1,10 @@
 use crate::csr::CsrMatrix;
-use crate::ops::serial::cs::{spadd_cs_prealloc, spmm_cs_dense, spmm_cs_prealloc};
+use crate::ops::serial::cs::{
+    spadd_cs_prealloc, spmm_cs_dense, spmm_cs_prealloc, spsolve_cs_by_columns, spsolve_cs_by_rows,
+};
 use crate::ops::serial::OperationError;
 use crate::ops::Op;
-use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, Scalar};
+use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, RealField, Scalar};
 use num_traits::{One, Zero};
 use std::borrow::Cow;
 
@@ -104,3 +106,70 @@ where
         }
     }
 }
+
+/// Solve the lower triangular system `op(L) X = B`.
+///
+/// Only the lower triangular part of L is read, and the result is stored in B.
+///
+/// # Errors
+///
+/// An error is returned if the system can not be solved due to the matrix being singular.
+///
+/// # Panics
+///
+/// Panics if `L` is not square, or if `L` and `B` are not dimensionally compatible.
+pub fn spsolve_csr_lower_triangular<'a, T: RealField>(
+    l: Op<&CsrMatrix<T>>,
+    b: impl Into<DMatrixSliceMut<'a, T>>,
+) -> Result<(), OperationError> {
+    let b = b.into();
+    let l_matrix = l.into_inner();
+    assert_eq!(
+        l_matrix.nrows(),
+        l_matrix.ncols(),
+        "Matrix must be square for triangular solve."
+    );
+    assert_eq!(
+        l_matrix.nrows(),
+        b.nrows(),
+        "Dimension mismatch in sparse lower triangular solver."
+    );
+    match l {
+        Op::NoOp(a) => spsolve_cs_by_rows(&a.cs, true, b),
+        // The rows of L are the columns of the upper triangular matrix L^T
+        Op::Transpose(a) => spsolve_cs_by_columns(&a.cs, false, b),
+    }
+}
+
+/// Solve the upper triangular system `op(U) X = B`.
+///
+/// Only the upper triangular part of U is read, and the result is stored in B.
+///
+/// # Errors
+///
+/// An error is returned if the system can not be solved due to the matrix being singular.
+///
+/// # Panics
+///
+/// Panics if `U` is not square, or if `U` and `B` are not dimensionally compatible.
+pub fn spsolve_csr_upper_triangular<'a, T: RealField>(
+    u: Op<&CsrMatrix<T>>,
+    b: impl Into<DMatrixSliceMut<'a, T>>,
+) -> Result<(), OperationError> {
+    let b = b.into();
+    let u_matrix = u.into_inner();
+    assert_eq!(
+        u_matrix.nrows(),
+        u_matrix.ncols(),
+        "Matrix must be square for triangular solve."
+    );
+    assert_eq!(
+        u_matrix.nrows(),
+        b.nrows(),
+        "Dimension mismatch in sparse upper triangular solver."
+    );
+    match u {
+        Op::NoOp(a) => spsolve_cs_by_rows(&a.cs, false, b),
+        Op::Transpose(a) => spsolve_cs_by_columns(&a.cs, true, b),
+    }
+}
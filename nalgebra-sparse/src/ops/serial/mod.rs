@@ -8,50 +8,6 @@
 //! some operations which will be able to dynamically adapt the output pattern to fit the
 //! result, but these have yet to be implemented.
 
-macro_rules! assert_compatible_spmm_dims {
-    ($c:expr, $a:expr, $b:expr) => {{
-        use crate::ops::Op::{NoOp, Transpose};
-        match (&$a, &$b) {
-            (NoOp(ref a), NoOp(ref b)) => {
-                assert_eq!($c.nrows(), a.nrows(), "C.nrows() != A.nrows()");
-                assert_eq!($c.ncols(), b.ncols(), "C.ncols() != B.ncols()");
-                assert_eq!(a.ncols(), b.nrows(), "A.ncols() != B.nrows()");
-            }
-            (Transpose(ref a), NoOp(ref b)) => {
-                assert_eq!($c.nrows(), a.ncols(), "C.nrows() != A.ncols()");
-                assert_eq!($c.ncols(), b.ncols(), "C.ncols() != B.ncols()");
-                assert_eq!(a.nrows(), b.nrows(), "A.nrows() != B.nrows()");
-            }
-            (NoOp(ref a), Transpose(ref b)) => {
-                assert_eq!($c.nrows(), a.nrows(), "C.nrows() != A.nrows()");
-                assert_eq!($c.ncols(), b.nrows(), "C.ncols() != B.nrows()");
-                assert_eq!(a.ncols(), b.ncols(), "A.ncols() != B.ncols()");
-            }
-            (Transpose(ref a), Transpose(ref b)) => {
-                assert_eq!($c.nrows(), a.ncols(), "C.nrows() != A.ncols()");
-                assert_eq!($c.ncols(), b.nrows(), "C.ncols() != B.nrows()");
-                assert_eq!(a.nrows(), b.ncols(), "A.nrows() != B.ncols()");
-            }
-        }
-    }};
-}
-
-macro_rules! assert_compatible_spadd_dims {
-    ($c:expr, $a:expr) => {
-        use crate::ops::Op;
-        match $a {
-            Op::NoOp(a) => {
-                assert_eq!($c.nrows(), a.nrows(), "C.nrows() != A.nrows()");
-                assert_eq!($c.ncols(), a.ncols(), "C.ncols() != A.ncols()");
-            }
-            Op::Transpose(a) => {
-                assert_eq!($c.nrows(), a.ncols(), "C.nrows() != A.ncols()");
-                assert_eq!($c.ncols(), a.nrows(), "C.ncols() != A.nrows()");
-            }
-        }
-    };
-}
-
 mod cs;
 mod csc;
 mod csr;
@@ -60,6 +16,9 @@ mod pattern;
 pub use csc::*;
 pub use csr::*;
 pub use pattern::*;
+
+#[cfg(feature = "rayon")]
+pub(crate) use cs::{spadd_cs_lane, spmm_cs_dense_dot, spmm_cs_lane};
 use std::fmt;
 use std::fmt::Formatter;
 
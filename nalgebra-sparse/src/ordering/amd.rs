@@ -0,0 +1,125 @@
+use crate::ordering::{symmetric_adjacency, Permutation};
+use crate::pattern::SparsityPattern;
+use std::collections::BTreeSet;
+
+/// Computes an approximate minimum degree (AMD) ordering of a square sparsity pattern.
+///
+/// The ordering greedily eliminates the vertex of the graph of `A + Aᵀ` with the smallest
+/// approximate degree. Eliminations are tracked with a quotient graph, so that the memory usage
+/// stays bounded by the size of the pattern, and the degrees are approximated with the upper
+/// bound introduced by Amestoy, Davis and Duff. Elements that are covered by a newly formed
+/// element are absorbed. Unlike the reference implementation, supervariables are not detected.
+///
+/// # Panics
+///
+/// Panics if the sparsity pattern is not square.
+pub fn amd(pattern: &SparsityPattern) -> Permutation {
+    // Variable neighbors of each variable.
+    let mut vars = symmetric_adjacency(pattern);
+    let n = vars.len();
+    // Element neighbors of each variable.
+    let mut elems: Vec<Vec<usize>> = vec![Vec::new(); n];
+    // Variables adjacent to each element. An element is identified with the variable whose
+    // elimination created it.
+    let mut elem_vars: Vec<Vec<usize>> = vec![Vec::new(); n];
+
+    let mut eliminated = vec![false; n];
+    let mut absorbed = vec![false; n];
+    let mut degrees: Vec<_> = vars.iter().map(Vec::len).collect();
+    let mut queue: BTreeSet<_> = degrees.iter().cloned().zip(0..n).collect();
+
+    // Workspaces
+    let mut in_pivot_elem = vec![false; n];
+    // `external[e]` is the number of variables of the element `e` that are not adjacent to the
+    // current pivot element. Contains `usize::MAX` for elements that have not been visited.
+    let mut external = vec![usize::MAX; n];
+    let mut visited_elems = Vec::new();
+
+    let mut order = Vec::with_capacity(n);
+
+    while let Some((degree, p)) = queue.iter().next().cloned() {
+        let _ = queue.remove(&(degree, p));
+        eliminated[p] = true;
+        order.push(p);
+
+        // Form the new element `p`, and absorb all the elements adjacent to `p`.
+        let mut pivot_elem = Vec::new();
+        for &i in &vars[p] {
+            if !eliminated[i] && !in_pivot_elem[i] {
+                in_pivot_elem[i] = true;
+                pivot_elem.push(i);
+            }
+        }
+        for &e in &elems[p] {
+            for &i in &elem_vars[e] {
+                if i != p && !in_pivot_elem[i] {
+                    in_pivot_elem[i] = true;
+                    pivot_elem.push(i);
+                }
+            }
+            absorbed[e] = true;
+            elem_vars[e] = Vec::new();
+        }
+        vars[p] = Vec::new();
+        elems[p] = Vec::new();
+
+        // Prune the quotient graph: the variables of the new element are adjacent through the
+        // element, and do not need to be stored as variable neighbors anymore.
+        for &i in &pivot_elem {
+            vars[i].retain(|&j| !eliminated[j] && !in_pivot_elem[j]);
+            elems[i].retain(|&e| !absorbed[e]);
+        }
+
+        // Compute |Le \ Lp| for each element `e` adjacent to the new element.
+        for &i in &pivot_elem {
+            for &e in &elems[i] {
+                if external[e] == usize::MAX {
+                    external[e] = elem_vars[e].len();
+                    visited_elems.push(e);
+                }
+                external[e] -= 1;
+            }
+        }
+
+        // Aggressive absorption of the elements entirely covered by the new element.
+        for &e in &visited_elems {
+            if external[e] == 0 {
+                absorbed[e] = true;
+                elem_vars[e] = Vec::new();
+            }
+        }
+
+        let remaining = n - order.len();
+        for &i in &pivot_elem {
+            elems[i].retain(|&e| !absorbed[e]);
+
+            let mut d = vars[i].len() + pivot_elem.len() - 1;
+            for &e in &elems[i] {
+                d += external[e];
+            }
+            elems[i].push(p);
+
+            let d = d.min(remaining - 1);
+            if d != degrees[i] {
+                let _ = queue.remove(&(degrees[i], i));
+                degrees[i] = d;
+                let _ = queue.insert((d, i));
+            }
+        }
+
+        for &e in &visited_elems {
+            external[e] = usize::MAX;
+        }
+        visited_elems.clear();
+        for &i in &pivot_elem {
+            in_pivot_elem[i] = false;
+        }
+        elem_vars[p] = pivot_elem;
+    }
+
+    let mut inverse = vec![0; n];
+    for (i, &j) in order.iter().enumerate() {
+        inverse[j] = i;
+    }
+    Permutation::from_indices_and_inverse_unchecked(order, inverse)
+}
@@ -0,0 +1,172 @@
+//! Fill-reducing orderings and permutations of sparse matrices.
+//!
+//! The orderings provided here compute a [`Permutation`] from the [`SparsityPattern`] of a
+//! square matrix. Applying the permutation symmetrically to the matrix (see e.g.
+//! [`CsrMatrix::permute_symmetric`](crate::csr::CsrMatrix::permute_symmetric)) before
+//! factorizing it can drastically reduce the fill-in of the factors.
+//!
+//! | Ordering                                       | Notes |
+//! | -----------------------------------------------|-------|
+//! | [Approximate minimum degree](amd)              | General-purpose fill-reducing ordering for sparse direct solvers. |
+//! | [Reverse Cuthill–McKee](reverse_cuthill_mckee) | Reduces the bandwidth (and profile) of the matrix. |
+//! | [Nested dissection](nested_dissection)         | Recursively splits the graph by separators. Well-suited for meshes. |
+//!
+//! All orderings operate on the adjacency graph of the symmetrized pattern `A + Aᵀ`. Diagonal
+//! entries are ignored. For the column ordering of an unsymmetric LU factorization, the
+//! ordering can be computed from the pattern of `AᵀA` instead.
+mod amd;
+mod nested_dissection;
+mod rcm;
+
+pub use amd::*;
+pub use nested_dissection::*;
+pub use rcm::*;
+
+use crate::pattern::SparsityPattern;
+use std::error::Error;
+use std::fmt;
+
+/// A permutation of the indices `0 .. n`.
+///
+/// The permutation maps the *new* index `i` to the *old* index `indices()[i]`. Conversely,
+/// the old index `j` is mapped to the new index `inverse_indices()[j]`.
+///
+/// When applied symmetrically to a matrix `A`, the permutation produces the matrix `P A Pᵀ`
+/// whose entry `(i, j)` is the entry `(indices()[i], indices()[j])` of `A`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permutation {
+    perm: Vec<usize>,
+    inverse: Vec<usize>,
+}
+
+impl Permutation {
+    /// The identity permutation of the indices `0 .. n`.
+    pub fn identity(n: usize) -> Self {
+        Self {
+            perm: (0..n).collect(),
+            inverse: (0..n).collect(),
+        }
+    }
+
+    /// Try to construct a permutation from the old indices associated with each new index.
+    ///
+    /// Returns an error if `indices` is not a permutation of `0 .. indices.len()`.
+    pub fn try_from_indices(indices: Vec<usize>) -> Result<Self, PermutationError> {
+        let n = indices.len();
+        let mut inverse = vec![usize::MAX; n];
+
+        for (i, &j) in indices.iter().enumerate() {
+            if j >= n {
+                return Err(PermutationError::IndexOutOfBounds);
+            }
+            if inverse[j] != usize::MAX {
+                return Err(PermutationError::DuplicateIndex);
+            }
+            inverse[j] = i;
+        }
+
+        Ok(Self {
+            perm: indices,
+            inverse,
+        })
+    }
+
+    /// Constructs a permutation from its indices and inverse indices, without any check.
+    pub(crate) fn from_indices_and_inverse_unchecked(
+        perm: Vec<usize>,
+        inverse: Vec<usize>,
+    ) -> Self {
+        debug_assert_eq!(perm.len(), inverse.len());
+        debug_assert!(perm.iter().enumerate().all(|(i, &j)| inverse[j] == i));
+        Self { perm, inverse }
+    }
+
+    /// The number of permuted indices.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.perm.len()
+    }
+
+    /// Whether the permutation is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.perm.is_empty()
+    }
+
+    /// The old index associated with each new index.
+    #[must_use]
+    pub fn indices(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// The new index associated with each old index.
+    #[must_use]
+    pub fn inverse_indices(&self) -> &[usize] {
+        &self.inverse
+    }
+
+    /// The inverse permutation.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        Self {
+            perm: self.inverse.clone(),
+            inverse: self.perm.clone(),
+        }
+    }
+
+    /// Returns the old indices associated with each new index.
+    pub fn into_indices(self) -> Vec<usize> {
+        self.perm
+    }
+}
+
+/// Error type for invalid permutations.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PermutationError {
+    /// One or more indices are out of bounds.
+    IndexOutOfBounds,
+    /// One or more indices appear more than once.
+    DuplicateIndex,
+}
+
+impl fmt::Display for PermutationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PermutationError::IndexOutOfBounds => {
+                write!(f, "A permutation index is out of bounds.")
+            }
+            PermutationError::DuplicateIndex => {
+                write!(f, "A permutation index appears more than once.")
+            }
+        }
+    }
+}
+
+impl Error for PermutationError {}
+
+/// Computes the adjacency lists of the graph of the symmetrized pattern `A + Aᵀ`, without
+/// self-loops. The neighbors of each vertex are sorted.
+fn symmetric_adjacency(pattern: &SparsityPattern) -> Vec<Vec<usize>> {
+    assert_eq!(
+        pattern.major_dim(),
+        pattern.minor_dim(),
+        "Major and minor dimensions must be the same (square matrix)."
+    );
+
+    let n = pattern.major_dim();
+    let mut adj = vec![Vec::new(); n];
+    for (i, j) in pattern.entries() {
+        if i != j {
+            adj[i].push(j);
+            adj[j].push(i);
+        }
+    }
+
+    for neighbors in &mut adj {
+        neighbors.sort_unstable();
+        neighbors.dedup();
+    }
+
+    adj
+}
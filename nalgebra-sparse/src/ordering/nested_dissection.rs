@@ -0,0 +1,120 @@
+use crate::ordering::rcm::pseudo_peripheral_vertex;
+use crate::ordering::{symmetric_adjacency, Permutation};
+use crate::pattern::SparsityPattern;
+
+/// Subgraphs with at most this number of vertices are not dissected any further.
+const LEAF_SIZE: usize = 8;
+
+/// Computes a nested dissection ordering of a square sparsity pattern.
+///
+/// The graph of `A + Aᵀ` is recursively split into two parts by a vertex separator, which is
+/// ordered after both parts. The separators are taken from the middle level of the level
+/// structure rooted at a pseudo-peripheral vertex. Subgraphs that are small, or that cannot
+/// be split, are ordered in their natural order.
+///
+/// This simple level-set based dissection works best on graphs arising from meshes, for which
+/// the level sets are small.
+///
+/// # Panics
+///
+/// Panics if the sparsity pattern is not square.
+pub fn nested_dissection(pattern: &SparsityPattern) -> Permutation {
+    let adj = symmetric_adjacency(pattern);
+    let n = adj.len();
+    let mut order = Vec::with_capacity(n);
+    let mut active = vec![false; n];
+    let mut marks = vec![false; n];
+
+    // Explicit stack of subgraphs to process. A subgraph is either to be dissected, or is a
+    // separator whose vertices are emitted once both of its parts have been ordered.
+    let mut stack = vec![Subgraph::Dissect((0..n).collect())];
+
+    while let Some(subgraph) = stack.pop() {
+        let vertices = match subgraph {
+            Subgraph::Emit(vertices) => {
+                order.extend(vertices);
+                continue;
+            }
+            Subgraph::Dissect(vertices) => vertices,
+        };
+
+        if vertices.len() <= LEAF_SIZE {
+            order.extend(vertices);
+            continue;
+        }
+
+        for &v in &vertices {
+            active[v] = true;
+        }
+
+        let (_, levels) = pseudo_peripheral_vertex(&adj, vertices[0], &active, &mut marks);
+        let component_size: usize = levels.iter().map(Vec::len).sum();
+
+        if component_size < vertices.len() {
+            // The subgraph is disconnected: order its first connected component and
+            // the remaining vertices independently.
+            let component: Vec<_> = levels.into_iter().flatten().collect();
+            for &v in &component {
+                marks[v] = true;
+            }
+            let rest = vertices.iter().cloned().filter(|&v| !marks[v]).collect();
+            for &v in &vertices {
+                active[v] = false;
+                marks[v] = false;
+            }
+
+            stack.push(Subgraph::Dissect(component));
+            stack.push(Subgraph::Dissect(rest));
+            continue;
+        }
+
+        for &v in &vertices {
+            active[v] = false;
+        }
+
+        if levels.len() < 3 {
+            // No separator can be extracted from the level structure.
+            order.extend(vertices);
+            continue;
+        }
+
+        let mid = levels.len() / 2;
+
+        // Only the vertices of the middle level that are connected to the next level are needed
+        // to separate both parts.
+        for &v in &levels[mid + 1] {
+            marks[v] = true;
+        }
+        let (separator, moved): (Vec<_>, Vec<_>) = levels[mid]
+            .iter()
+            .cloned()
+            .partition(|&v| adj[v].iter().any(|&w| marks[w]));
+        for &v in &levels[mid + 1] {
+            marks[v] = false;
+        }
+
+        let first: Vec<_> = levels[..mid]
+            .iter()
+            .flatten()
+            .cloned()
+            .chain(moved)
+            .collect();
+        let second: Vec<_> = levels[mid + 1..].iter().flatten().cloned().collect();
+
+        // Processed in reverse order of insertion.
+        stack.push(Subgraph::Emit(separator));
+        stack.push(Subgraph::Dissect(second));
+        stack.push(Subgraph::Dissect(first));
+    }
+
+    let mut inverse = vec![0; n];
+    for (i, &j) in order.iter().enumerate() {
+        inverse[j] = i;
+    }
+    Permutation::from_indices_and_inverse_unchecked(order, inverse)
+}
+
+enum Subgraph {
+    Dissect(Vec<usize>),
+    Emit(Vec<usize>),
+}
@@ -0,0 +1,122 @@
+use crate::ordering::{symmetric_adjacency, Permutation};
+use crate::pattern::SparsityPattern;
+
+/// Computes the reverse Cuthill–McKee ordering of a square sparsity pattern.
+///
+/// The ordering performs a breadth-first traversal of each connected component of the graph of
+/// `A + Aᵀ`, starting from a pseudo-peripheral vertex and visiting the neighbors of each vertex
+/// by increasing degree. The resulting order is then reversed. This tends to produce a permuted
+/// matrix with a small bandwidth and profile.
+///
+/// # Panics
+///
+/// Panics if the sparsity pattern is not square.
+pub fn reverse_cuthill_mckee(pattern: &SparsityPattern) -> Permutation {
+    let adj = symmetric_adjacency(pattern);
+    let n = adj.len();
+    // A vertex is active as long as it has not been visited.
+    let mut active = vec![true; n];
+    let mut order = Vec::with_capacity(n);
+    let mut neighbors = Vec::new();
+    let mut marks = vec![false; n];
+
+    for start in 0..n {
+        if !active[start] {
+            continue;
+        }
+
+        let (root, _) = pseudo_peripheral_vertex(&adj, start, &active, &mut marks);
+        let mut head = order.len();
+        active[root] = false;
+        order.push(root);
+
+        while head < order.len() {
+            let v = order[head];
+            head += 1;
+
+            neighbors.clear();
+            neighbors.extend(adj[v].iter().cloned().filter(|&w| active[w]));
+            neighbors.sort_by_key(|&w| (adj[w].len(), w));
+
+            for &w in &neighbors {
+                active[w] = false;
+                order.push(w);
+            }
+        }
+    }
+
+    order.reverse();
+    let mut inverse = vec![0; n];
+    for (i, &j) in order.iter().enumerate() {
+        inverse[j] = i;
+    }
+    Permutation::from_indices_and_inverse_unchecked(order, inverse)
+}
+
+/// Computes the level structure rooted at `root` of the subgraph induced by the active vertices.
+///
+/// The level `k` contains all the active vertices at distance `k` from `root`. The `marks`
+/// workspace must be filled with `false`, and is restored to this state on return.
+fn level_structure(
+    adj: &[Vec<usize>],
+    root: usize,
+    active: &[bool],
+    marks: &mut [bool],
+) -> Vec<Vec<usize>> {
+    let mut levels = vec![vec![root]];
+    marks[root] = true;
+
+    loop {
+        let mut next = Vec::new();
+        for &v in levels.last().unwrap() {
+            for &w in &adj[v] {
+                if active[w] && !marks[w] {
+                    marks[w] = true;
+                    next.push(w);
+                }
+            }
+        }
+
+        if next.is_empty() {
+            break;
+        }
+        levels.push(next);
+    }
+
+    for &v in levels.iter().flatten() {
+        marks[v] = false;
+    }
+
+    levels
+}
+
+/// Finds a pseudo-peripheral vertex of the connected component of `start` in the subgraph
+/// induced by the active vertices, using the algorithm of George and Liu.
+///
+/// Returns the vertex together with its level structure.
+pub(super) fn pseudo_peripheral_vertex(
+    adj: &[Vec<usize>],
+    start: usize,
+    active: &[bool],
+    marks: &mut [bool],
+) -> (usize, Vec<Vec<usize>>) {
+    let mut root = start;
+    let mut levels = level_structure(adj, root, active, marks);
+
+    loop {
+        let candidate = *levels
+            .last()
+            .unwrap()
+            .iter()
+            .min_by_key(|&&v| (adj[v].len(), v))
+            .unwrap();
+        let candidate_levels = level_structure(adj, candidate, active, marks);
+
+        if candidate_levels.len() > levels.len() {
+            root = candidate;
+            levels = candidate_levels;
+        } else {
+            return (root, levels);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::solvers::{
+    check_dimensions, residual, solve_zero_rhs, IdentityPreconditioner, LinearOperator,
+    Preconditioner, SolverOptions, SolverReport, StopReason,
+};
+use nalgebra::{DVector, RealField};
+
+/// Solves the system `A x = b` with the biconjugate gradient stabilized method (BiCGSTAB).
+///
+/// The matrix `A` can be any non-singular square matrix. The method only requires two
+/// matrix-vector products per iteration and a small, fixed amount of memory, but its convergence
+/// can be irregular. The method stops with [`StopReason::Breakdown`] if one of its recurrences
+/// cannot be continued.
+///
+/// On input, `x` contains the initial guess. On output, it contains the approximate solution.
+///
+/// # Panics
+///
+/// Panics if `A` is not square, or if the dimensions of `b` or `x` do not match those of `A`.
+pub fn bicgstab<T, A>(
+    a: &A,
+    b: &DVector<T>,
+    x: &mut DVector<T>,
+    options: &SolverOptions<T>,
+) -> SolverReport<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+{
+    bicgstab_preconditioned(a, b, x, &IdentityPreconditioner, options)
+}
+
+/// Solves the system `A x = b` with the right-preconditioned BiCGSTAB method.
+///
+/// The method is applied to the system `A M⁻¹ y = b` with `x = M⁻¹ y`, so that the residual
+/// history contains the norms of the residual of the original system. See [`bicgstab`] for
+/// details.
+///
+/// On input, `x` contains the initial guess. On output, it contains the approximate solution.
+///
+/// # Panics
+///
+/// Panics if `A` is not square, or if the dimensions of `b` or `x` do not match those of `A`.
+pub fn bicgstab_preconditioned<T, A, M>(
+    a: &A,
+    b: &DVector<T>,
+    x: &mut DVector<T>,
+    preconditioner: &M,
+    options: &SolverOptions<T>,
+) -> SolverReport<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+    M: Preconditioner<T> + ?Sized,
+{
+    let n = check_dimensions(a, b, x);
+    let b_norm = b.norm();
+    if b_norm.is_zero() {
+        return solve_zero_rhs(x);
+    }
+    let threshold = options.tolerance.clone() * b_norm;
+
+    let mut r = DVector::zeros(n);
+    residual(a, b, x, &mut r);
+    // The shadow residual
+    let r_hat = r.clone();
+    let mut p = DVector::zeros(n);
+    let mut v = DVector::zeros(n);
+    let mut t = DVector::zeros(n);
+    // Preconditioned search direction
+    let mut z = DVector::zeros(n);
+    let mut rho = T::one();
+    let mut alpha = T::one();
+    let mut omega = T::one();
+    let mut residual_history = vec![r.norm()];
+
+    let mut iterations = 0;
+    let stop_reason = loop {
+        if residual_history[iterations] <= threshold {
+            break StopReason::Converged;
+        }
+        if iterations == options.max_iterations {
+            break StopReason::MaxIterations;
+        }
+
+        let rho_new = r_hat.dot(&r);
+        if rho_new.is_zero() {
+            break StopReason::Breakdown;
+        }
+
+        // p = r + beta * (p - omega * v)
+        let beta = (rho_new.clone() / rho) * (alpha / omega.clone());
+        rho = rho_new;
+        p.axpy(-omega, &v, T::one());
+        p.axpy(T::one(), &r, beta);
+
+        preconditioner.apply(&p, &mut z);
+        a.apply(&z, &mut v);
+        let r_hat_v = r_hat.dot(&v);
+        if r_hat_v.is_zero() {
+            break StopReason::Breakdown;
+        }
+        alpha = rho.clone() / r_hat_v;
+
+        // The residual is now s = r - alpha * v, which is stored in r
+        x.axpy(alpha.clone(), &z, T::one());
+        r.axpy(-alpha.clone(), &v, T::one());
+        let s_norm = r.norm();
+        iterations += 1;
+        if s_norm <= threshold {
+            residual_history.push(s_norm);
+            break StopReason::Converged;
+        }
+
+        preconditioner.apply(&r, &mut z);
+        a.apply(&z, &mut t);
+        let tt = t.dot(&t);
+        if tt.is_zero() {
+            residual_history.push(s_norm);
+            break StopReason::Breakdown;
+        }
+        omega = t.dot(&r) / tt;
+
+        x.axpy(omega.clone(), &z, T::one());
+        r.axpy(-omega.clone(), &t, T::one());
+        residual_history.push(r.norm());
+
+        if omega.is_zero() {
+            break StopReason::Breakdown;
+        }
+    };
+
+    SolverReport {
+        iterations,
+        residual_history,
+        stop_reason,
+    }
+}
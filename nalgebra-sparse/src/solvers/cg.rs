@@ -0,0 +1,103 @@
+use crate::solvers::{
+    check_dimensions, residual, solve_zero_rhs, IdentityPreconditioner, LinearOperator,
+    Preconditioner, SolverOptions, SolverReport, StopReason,
+};
+use nalgebra::{DVector, RealField};
+
+/// Solves the system `A x = b` with the conjugate gradient method.
+///
+/// The matrix `A` must be symmetric positive definite. This is not checked, but the method
+/// generally stops with [`StopReason::Breakdown`] or fails to converge otherwise.
+///
+/// On input, `x` contains the initial guess. On output, it contains the approximate solution.
+///
+/// # Panics
+///
+/// Panics if `A` is not square, or if the dimensions of `b` or `x` do not match those of `A`.
+pub fn conjugate_gradient<T, A>(
+    a: &A,
+    b: &DVector<T>,
+    x: &mut DVector<T>,
+    options: &SolverOptions<T>,
+) -> SolverReport<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+{
+    conjugate_gradient_preconditioned(a, b, x, &IdentityPreconditioner, options)
+}
+
+/// Solves the system `A x = b` with the preconditioned conjugate gradient method.
+///
+/// Both the matrix `A` and the preconditioner `M` must be symmetric positive definite. This is
+/// not checked, but the method generally stops with [`StopReason::Breakdown`] or fails to
+/// converge otherwise.
+///
+/// On input, `x` contains the initial guess. On output, it contains the approximate solution.
+///
+/// # Panics
+///
+/// Panics if `A` is not square, or if the dimensions of `b` or `x` do not match those of `A`.
+pub fn conjugate_gradient_preconditioned<T, A, M>(
+    a: &A,
+    b: &DVector<T>,
+    x: &mut DVector<T>,
+    preconditioner: &M,
+    options: &SolverOptions<T>,
+) -> SolverReport<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+    M: Preconditioner<T> + ?Sized,
+{
+    let n = check_dimensions(a, b, x);
+    let b_norm = b.norm();
+    if b_norm.is_zero() {
+        return solve_zero_rhs(x);
+    }
+    let threshold = options.tolerance.clone() * b_norm;
+
+    let mut r = DVector::zeros(n);
+    residual(a, b, x, &mut r);
+    let mut z = DVector::zeros(n);
+    preconditioner.apply(&r, &mut z);
+    let mut p = z.clone();
+    let mut ap = DVector::zeros(n);
+    let mut rz = r.dot(&z);
+    let mut residual_history = vec![r.norm()];
+
+    let mut iterations = 0;
+    let stop_reason = loop {
+        if residual_history[iterations] <= threshold {
+            break StopReason::Converged;
+        }
+        if iterations == options.max_iterations {
+            break StopReason::MaxIterations;
+        }
+
+        a.apply(&p, &mut ap);
+        let pap = p.dot(&ap);
+        if pap <= T::zero() || rz <= T::zero() {
+            break StopReason::Breakdown;
+        }
+
+        let alpha = rz.clone() / pap;
+        x.axpy(alpha.clone(), &p, T::one());
+        r.axpy(-alpha, &ap, T::one());
+
+        preconditioner.apply(&r, &mut z);
+        let rz_new = r.dot(&z);
+        let beta = rz_new.clone() / rz;
+        rz = rz_new;
+        p.axpy(T::one(), &z, beta);
+
+        iterations += 1;
+        residual_history.push(r.norm());
+    };
+
+    SolverReport {
+        iterations,
+        residual_history,
+        stop_reason,
+    }
+}
@@ -0,0 +1,180 @@
+use crate::solvers::{
+    check_dimensions, residual, solve_zero_rhs, IdentityPreconditioner, LinearOperator,
+    Preconditioner, SolverOptions, SolverReport, StopReason,
+};
+use nalgebra::{DMatrix, DVector, RealField};
+
+/// Solves the system `A x = b` with the restarted generalized minimal residual method
+/// (GMRES(m)).
+///
+/// The matrix `A` can be any non-singular square matrix. Each restart cycle builds an orthonormal
+/// basis of a Krylov subspace of dimension at most `restart`, and minimizes the norm of the
+/// residual over this subspace. A larger restart length generally improves the convergence, at
+/// the cost of storing `restart + 1` vectors of the size of `b`. Each inner iteration counts as
+/// one iteration.
+///
+/// The residual history contains the residual norms estimated from the least-squares problems,
+/// except at the start of each restart cycle, where the residual is computed explicitly.
+///
+/// On input, `x` contains the initial guess. On output, it contains the approximate solution.
+///
+/// # Panics
+///
+/// Panics if `restart` is zero, if `A` is not square, or if the dimensions of `b` or `x` do not
+/// match those of `A`.
+pub fn gmres<T, A>(
+    a: &A,
+    b: &DVector<T>,
+    x: &mut DVector<T>,
+    restart: usize,
+    options: &SolverOptions<T>,
+) -> SolverReport<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+{
+    gmres_preconditioned(a, b, x, &IdentityPreconditioner, restart, options)
+}
+
+/// Solves the system `A x = b` with the right-preconditioned restarted GMRES method.
+///
+/// The method is applied to the system `A M⁻¹ y = b` with `x = M⁻¹ y`, so that the minimized
+/// residual is the residual of the original system. See [`gmres`] for details.
+///
+/// On input, `x` contains the initial guess. On output, it contains the approximate solution.
+///
+/// # Panics
+///
+/// Panics if `restart` is zero, if `A` is not square, or if the dimensions of `b` or `x` do not
+/// match those of `A`.
+pub fn gmres_preconditioned<T, A, M>(
+    a: &A,
+    b: &DVector<T>,
+    x: &mut DVector<T>,
+    preconditioner: &M,
+    restart: usize,
+    options: &SolverOptions<T>,
+) -> SolverReport<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+    M: Preconditioner<T> + ?Sized,
+{
+    assert!(restart > 0, "The restart length must be positive.");
+    let n = check_dimensions(a, b, x);
+    let b_norm = b.norm();
+    if b_norm.is_zero() {
+        return solve_zero_rhs(x);
+    }
+    let threshold = options.tolerance.clone() * b_norm;
+    let m = restart.min(n);
+
+    let mut r = DVector::zeros(n);
+    // Orthonormal basis of the Krylov subspace
+    let mut basis: Vec<DVector<T>> = Vec::with_capacity(m + 1);
+    // Upper Hessenberg matrix, reduced to upper triangular form by Givens rotations
+    let mut h = DMatrix::zeros(m + 1, m);
+    let mut rotations: Vec<(T, T)> = Vec::with_capacity(m);
+    let mut g = DVector::zeros(m + 1);
+    let mut w = DVector::zeros(n);
+    let mut z = DVector::zeros(n);
+
+    let mut residual_history = Vec::new();
+    let mut iterations = 0;
+
+    let stop_reason = loop {
+        residual(a, b, x, &mut r);
+        let beta = r.norm();
+        // Replace the estimated residual norm by the computed one
+        residual_history.pop();
+        residual_history.push(beta.clone());
+
+        if beta <= threshold {
+            break StopReason::Converged;
+        }
+        if iterations == options.max_iterations {
+            break StopReason::MaxIterations;
+        }
+
+        basis.clear();
+        basis.push(r.unscale(beta.clone()));
+        rotations.clear();
+        g.fill(T::zero());
+        g[0] = beta;
+
+        let mut k = 0;
+        let mut breakdown = false;
+        while k < m && iterations < options.max_iterations {
+            // Arnoldi process, with modified Gram-Schmidt orthogonalization
+            preconditioner.apply(&basis[k], &mut z);
+            a.apply(&z, &mut w);
+            for (i, v) in basis.iter().enumerate() {
+                let hik = v.dot(&w);
+                w.axpy(-hik.clone(), v, T::one());
+                h[(i, k)] = hik;
+            }
+            let w_norm = w.norm();
+            h[(k + 1, k)] = w_norm.clone();
+
+            // Apply the previous rotations to the new column
+            for (i, (c, s)) in rotations.iter().enumerate() {
+                let (hi, hi1) = (h[(i, k)].clone(), h[(i + 1, k)].clone());
+                h[(i, k)] = c.clone() * hi.clone() + s.clone() * hi1.clone();
+                h[(i + 1, k)] = c.clone() * hi1 - s.clone() * hi;
+            }
+
+            // Compute the rotation that eliminates the subdiagonal entry
+            let (hkk, hk1k) = (h[(k, k)].clone(), h[(k + 1, k)].clone());
+            let denom = hkk.clone().hypot(hk1k.clone());
+            if denom.is_zero() {
+                // The Hessenberg matrix is singular, and the new direction cannot be used
+                breakdown = true;
+                break;
+            }
+            let (c, s) = (hkk / denom.clone(), hk1k / denom.clone());
+            h[(k, k)] = denom;
+            h[(k + 1, k)] = T::zero();
+            g[k + 1] = -s.clone() * g[k].clone();
+            g[k] = c.clone() * g[k].clone();
+            rotations.push((c, s));
+
+            k += 1;
+            iterations += 1;
+            let estimate = g[k].clone().abs();
+            residual_history.push(estimate.clone());
+
+            if estimate <= threshold || w_norm.is_zero() {
+                break;
+            }
+            basis.push(w.unscale(w_norm));
+        }
+
+        // Solve the triangular least-squares system and update the solution
+        let mut y = g.rows(0, k).into_owned();
+        for i in (0..k).rev() {
+            y[i] /= h[(i, i)].clone();
+            let yi = y[i].clone();
+            for j in 0..i {
+                y[j] -= h[(j, i)].clone() * yi.clone();
+            }
+        }
+        w.fill(T::zero());
+        for (v, yi) in basis.iter().zip(y.iter()) {
+            w.axpy(yi.clone(), v, T::one());
+        }
+        preconditioner.apply(&w, &mut z);
+        *x += &z;
+
+        if breakdown {
+            residual(a, b, x, &mut r);
+            residual_history.push(r.norm());
+            break StopReason::Breakdown;
+        }
+    };
+
+    SolverReport {
+        iterations,
+        residual_history,
+        stop_reason,
+    }
+}
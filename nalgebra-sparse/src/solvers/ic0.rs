@@ -0,0 +1,178 @@
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+use crate::solvers::ilu0::diagonal_offsets;
+use crate::solvers::{Preconditioner, PreconditionerError};
+use nalgebra::{DVector, RealField};
+
+/// The incomplete Cholesky factorization with zero fill-in, IC(0).
+///
+/// The factor `L` has the same sparsity pattern as the lower triangular part of the matrix,
+/// which must be symmetric positive definite. The preconditioner is applied by solving
+/// `L Lᵀ z = r`.
+///
+/// The incomplete factorization may fail even if the matrix is positive definite, although it
+/// always succeeds for M-matrices, such as discretizations of the Laplace operator.
+#[derive(Debug, Clone)]
+pub struct Ic0<T> {
+    l: CsrMatrix<T>,
+}
+
+impl<T: RealField> Ic0<T> {
+    /// Computes the IC(0) factorization of a square CSR matrix.
+    ///
+    /// Only the lower triangular part of the matrix is accessed, and the matrix is assumed to be
+    /// symmetric.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a diagonal entry is missing from the sparsity pattern, or if a
+    /// non-positive pivot is encountered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn from_csr(matrix: &CsrMatrix<T>) -> Result<Self, PreconditionerError> {
+        assert_eq!(matrix.nrows(), matrix.ncols(), "The matrix must be square.");
+        let mut l = matrix.lower_triangle();
+        // Check that the diagonal entries are present, so that each one is the last entry of
+        // its row in the lower triangular part
+        diagonal_offsets(&l)?;
+        factor_in_place(&mut l)?;
+        Ok(Self { l })
+    }
+
+    /// Computes the IC(0) factorization of a square CSC matrix.
+    ///
+    /// The matrix is converted to the CSR format, whose sparsity pattern is used for the factor.
+    /// Only the lower triangular part of the matrix is accessed, and the matrix is assumed to be
+    /// symmetric.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a diagonal entry is missing from the sparsity pattern, or if a
+    /// non-positive pivot is encountered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn from_csc(matrix: &CscMatrix<T>) -> Result<Self, PreconditionerError> {
+        Self::from_csr(&CsrMatrix::from(matrix))
+    }
+
+    /// Re-computes the factorization for a new matrix with the same sparsity pattern.
+    ///
+    /// The sparsity pattern and the storage of the factor are reused, which is useful when the
+    /// values of a matrix change but its sparsity pattern remains constant. Only the lower
+    /// triangular part of the matrix is accessed. If an error is returned, the factor is left
+    /// in an unspecified state and must be recomputed before being used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a non-positive pivot is encountered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lower triangular part of the sparsity pattern of the matrix differs from
+    /// the sparsity pattern of the factor.
+    pub fn refactor(&mut self, matrix: &CsrMatrix<T>) -> Result<(), PreconditionerError> {
+        let n = self.l.nrows();
+        assert!(
+            matrix.nrows() == n && matrix.ncols() == n,
+            "The matrix must have the same dimensions as the factored matrix."
+        );
+        let (offsets, indices, values) = self.l.csr_data_mut();
+
+        for i in 0..n {
+            let row = matrix.row(i);
+            let lower = row.col_indices().iter().take_while(|&&j| j <= i);
+            let row_indices = &indices[offsets[i]..offsets[i + 1]];
+            assert!(
+                lower.clone().eq(row_indices),
+                "The sparsity pattern of the matrix must match the one of the factored matrix."
+            );
+            let nnz = row_indices.len();
+            values[offsets[i]..offsets[i + 1]].clone_from_slice(&row.values()[..nnz]);
+        }
+
+        factor_in_place(&mut self.l)
+    }
+
+    /// The incomplete Cholesky factor `L`.
+    #[must_use]
+    pub fn l(&self) -> &CsrMatrix<T> {
+        &self.l
+    }
+}
+
+impl<T: RealField> Preconditioner<T> for Ic0<T> {
+    fn apply(&self, r: &DVector<T>, z: &mut DVector<T>) {
+        let n = self.l.nrows();
+        assert_eq!(
+            r.len(),
+            n,
+            "The vector must have as many rows as the matrix."
+        );
+        let (offsets, indices, values) = self.l.csr_data();
+        z.copy_from(r);
+
+        // Solve L y = r. The diagonal entry is the last entry of each row.
+        for i in 0..n {
+            let diagonal = offsets[i + 1] - 1;
+            let mut zi = z[i].clone();
+            for p in offsets[i]..diagonal {
+                zi -= values[p].clone() * z[indices[p]].clone();
+            }
+            z[i] = zi / values[diagonal].clone();
+        }
+
+        // Solve L^T z = y, accessing L^T by columns
+        for i in (0..n).rev() {
+            let diagonal = offsets[i + 1] - 1;
+            let zi = z[i].clone() / values[diagonal].clone();
+            for p in offsets[i]..diagonal {
+                z[indices[p]] -= values[p].clone() * zi.clone();
+            }
+            z[i] = zi;
+        }
+    }
+}
+
+/// Overwrites the lower triangular matrix `l`, whose diagonal entries are all present, with its
+/// IC(0) factor.
+fn factor_in_place<T: RealField>(l: &mut CsrMatrix<T>) -> Result<(), PreconditionerError> {
+    let n = l.nrows();
+    let (offsets, indices, values) = l.csr_data_mut();
+
+    for i in 0..n {
+        for p in offsets[i]..offsets[i + 1] {
+            let j = indices[p];
+            // The diagonal entry is the last entry of each row
+            let diagonal_j = offsets[j + 1] - 1;
+
+            // Compute a_ij - sum_{k < j} l_ik l_jk by merging the rows i and j
+            let mut sum = values[p].clone();
+            let (mut pi, mut pj) = (offsets[i], offsets[j]);
+            while pi < p && pj < diagonal_j {
+                match indices[pi].cmp(&indices[pj]) {
+                    std::cmp::Ordering::Less => pi += 1,
+                    std::cmp::Ordering::Greater => pj += 1,
+                    std::cmp::Ordering::Equal => {
+                        sum -= values[pi].clone() * values[pj].clone();
+                        pi += 1;
+                        pj += 1;
+                    }
+                }
+            }
+
+            if j < i {
+                values[p] = sum / values[diagonal_j].clone();
+            } else if sum > T::zero() {
+                values[p] = sum.sqrt();
+            } else {
+                return Err(PreconditionerError::NotPositiveDefinite);
+            }
+        }
+    }
+
+    Ok(())
+}
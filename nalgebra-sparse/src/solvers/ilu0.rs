@@ -0,0 +1,156 @@
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+use crate::solvers::{Preconditioner, PreconditionerError};
+use nalgebra::{DVector, RealField};
+
+/// The incomplete LU factorization with zero fill-in, ILU(0).
+///
+/// The factors `L` and `U` have the same sparsity pattern as the lower and upper triangular parts
+/// of the matrix, and are stored together in a single CSR matrix that reuses the sparsity pattern
+/// of the matrix. `L` has a unit diagonal, which is not stored. The preconditioner is applied by
+/// solving `L U z = r`.
+///
+/// No pivoting is performed, so that all the diagonal entries must be present in the sparsity
+/// pattern of the matrix.
+#[derive(Debug, Clone)]
+pub struct Ilu0<T> {
+    // Strictly lower triangular part: L, upper triangular part: U
+    lu: CsrMatrix<T>,
+    // Offset of the diagonal entry of each row
+    diagonal_offsets: Vec<usize>,
+}
+
+impl<T: RealField> Ilu0<T> {
+    /// Computes the ILU(0) factorization of a square CSR matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a diagonal entry is missing from the sparsity pattern, or if a zero
+    /// pivot is encountered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn from_csr(matrix: &CsrMatrix<T>) -> Result<Self, PreconditionerError> {
+        assert_eq!(matrix.nrows(), matrix.ncols(), "The matrix must be square.");
+        let n = matrix.nrows();
+        let mut lu = matrix.clone();
+        let diagonal_offsets = diagonal_offsets(&lu)?;
+
+        // Position of the entries of the current row, indexed by column
+        let mut positions = vec![usize::MAX; n];
+        let (offsets, indices, values) = lu.csr_data_mut();
+
+        for i in 0..n {
+            let row = offsets[i]..offsets[i + 1];
+            for p in row.clone() {
+                positions[indices[p]] = p;
+            }
+
+            // Eliminate the entries of the strictly lower triangular part of the row, keeping only
+            // the updates that fall in the sparsity pattern
+            for p in offsets[i]..diagonal_offsets[i] {
+                let k = indices[p];
+                let pivot = values[diagonal_offsets[k]].clone();
+                if pivot.is_zero() {
+                    return Err(PreconditionerError::ZeroPivot);
+                }
+                let l_ik = values[p].clone() / pivot;
+                values[p] = l_ik.clone();
+
+                for q in diagonal_offsets[k] + 1..offsets[k + 1] {
+                    let position = positions[indices[q]];
+                    if position != usize::MAX {
+                        let u_kj = values[q].clone();
+                        values[position] -= l_ik.clone() * u_kj;
+                    }
+                }
+            }
+
+            for p in row {
+                positions[indices[p]] = usize::MAX;
+            }
+        }
+
+        if diagonal_offsets.iter().any(|&p| lu.values()[p].is_zero()) {
+            return Err(PreconditionerError::ZeroPivot);
+        }
+
+        Ok(Self {
+            lu,
+            diagonal_offsets,
+        })
+    }
+
+    /// Computes the ILU(0) factorization of a square CSC matrix.
+    ///
+    /// The matrix is converted to the CSR format, whose sparsity pattern is used for the factors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a diagonal entry is missing from the sparsity pattern, or if a zero
+    /// pivot is encountered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn from_csc(matrix: &CscMatrix<T>) -> Result<Self, PreconditionerError> {
+        Self::from_csr(&CsrMatrix::from(matrix))
+    }
+
+    /// The factors `L` and `U`, stored in a single matrix.
+    ///
+    /// The strictly lower triangular part contains `L`, whose unit diagonal is not stored, and
+    /// the upper triangular part contains `U`.
+    #[must_use]
+    pub fn lu(&self) -> &CsrMatrix<T> {
+        &self.lu
+    }
+}
+
+impl<T: RealField> Preconditioner<T> for Ilu0<T> {
+    fn apply(&self, r: &DVector<T>, z: &mut DVector<T>) {
+        let n = self.lu.nrows();
+        assert_eq!(
+            r.len(),
+            n,
+            "The vector must have as many rows as the matrix."
+        );
+        let (offsets, indices, values) = self.lu.csr_data();
+        z.copy_from(r);
+
+        // Solve L y = r
+        for i in 0..n {
+            let mut zi = z[i].clone();
+            for p in offsets[i]..self.diagonal_offsets[i] {
+                zi -= values[p].clone() * z[indices[p]].clone();
+            }
+            z[i] = zi;
+        }
+
+        // Solve U z = y
+        for i in (0..n).rev() {
+            let diagonal = self.diagonal_offsets[i];
+            let mut zi = z[i].clone();
+            for p in diagonal + 1..offsets[i + 1] {
+                zi -= values[p].clone() * z[indices[p]].clone();
+            }
+            z[i] = zi / values[diagonal].clone();
+        }
+    }
+}
+
+/// Finds the offset of the diagonal entry of each row of a square CSR matrix.
+pub(super) fn diagonal_offsets<T>(
+    matrix: &CsrMatrix<T>,
+) -> Result<Vec<usize>, PreconditionerError> {
+    let (offsets, indices, _) = matrix.csr_data();
+    (0..matrix.nrows())
+        .map(|i| {
+            let row = &indices[offsets[i]..offsets[i + 1]];
+            row.binary_search(&i)
+                .map(|k| offsets[i] + k)
+                .map_err(|_| PreconditionerError::ZeroPivot)
+        })
+        .collect()
+}
@@ -0,0 +1,166 @@
+use crate::solvers::{
+    check_dimensions, residual, solve_zero_rhs, IdentityPreconditioner, LinearOperator,
+    Preconditioner, SolverOptions, SolverReport, StopReason,
+};
+use nalgebra::{DVector, RealField};
+use std::mem;
+
+/// Solves the system `A x = b` with the minimal residual method (MINRES).
+///
+/// The matrix `A` must be symmetric, but can be indefinite. This is not checked. Like the
+/// conjugate gradient method, MINRES relies on short recurrences, but minimizes the norm of the
+/// residual over the Krylov subspace instead.
+///
+/// The residual history contains the residual norms obtained from the recurrences of the method.
+///
+/// On input, `x` contains the initial guess. On output, it contains the approximate solution.
+///
+/// # Panics
+///
+/// Panics if `A` is not square, or if the dimensions of `b` or `x` do not match those of `A`.
+pub fn minres<T, A>(
+    a: &A,
+    b: &DVector<T>,
+    x: &mut DVector<T>,
+    options: &SolverOptions<T>,
+) -> SolverReport<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+{
+    minres_preconditioned(a, b, x, &IdentityPreconditioner, options)
+}
+
+/// Solves the system `A x = b` with the preconditioned minimal residual method (MINRES).
+///
+/// The matrix `A` must be symmetric, and the preconditioner `M` must be symmetric positive
+/// definite. This is not checked.
+///
+/// With a preconditioner, the method minimizes the `M⁻¹`-norm `‖r‖² = rᵀ M⁻¹ r` of the
+/// residual. The residual history contains the `M⁻¹`-norms of the residuals, and the iterations
+/// stop once `‖b - A x‖ <= tolerance * ‖b‖` in this norm.
+///
+/// On input, `x` contains the initial guess. On output, it contains the approximate solution.
+///
+/// # Panics
+///
+/// Panics if `A` is not square, or if the dimensions of `b` or `x` do not match those of `A`.
+pub fn minres_preconditioned<T, A, M>(
+    a: &A,
+    b: &DVector<T>,
+    x: &mut DVector<T>,
+    preconditioner: &M,
+    options: &SolverOptions<T>,
+) -> SolverReport<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+    M: Preconditioner<T> + ?Sized,
+{
+    let n = check_dimensions(a, b, x);
+    if b.norm().is_zero() {
+        return solve_zero_rhs(x);
+    }
+
+    // Vectors of the preconditioned Lanczos process: r1 and r2 are the two last Lanczos vectors
+    // scaled by their norm, and y = M⁻¹ r2
+    let mut y = DVector::zeros(n);
+    preconditioner.apply(b, &mut y);
+    let b_norm = b.dot(&y);
+    residual(a, b, x, &mut y);
+    let mut r1 = y.clone();
+    let mut r2 = y.clone();
+    preconditioner.apply(&r2, &mut y);
+    let beta1 = r2.dot(&y);
+    if b_norm <= T::zero() || beta1 < T::zero() {
+        // The preconditioner is not positive definite
+        return SolverReport {
+            iterations: 0,
+            residual_history: vec![r2.norm()],
+            stop_reason: StopReason::Breakdown,
+        };
+    }
+    let threshold = options.tolerance.clone() * b_norm.sqrt();
+    let beta1 = beta1.sqrt();
+    let mut v = DVector::zeros(n);
+
+    // Search directions
+    let mut w = DVector::zeros(n);
+    let mut w1 = DVector::zeros(n);
+    let mut w2 = DVector::zeros(n);
+
+    let mut beta = beta1.clone();
+    let mut old_beta = T::zero();
+    // Entries of the rotated tridiagonal matrix
+    let mut epsilon = T::zero();
+    let mut delta_bar = T::zero();
+    // The last Givens rotation
+    let (mut c, mut s) = (-T::one(), T::zero());
+    // Rotated right-hand side `beta1 * e1`, whose last entry is the norm of the residual
+    let mut phi_bar = beta1;
+    let mut residual_history = vec![phi_bar.clone()];
+
+    let mut iterations = 0;
+    let stop_reason = loop {
+        if residual_history[iterations] <= threshold {
+            break StopReason::Converged;
+        }
+        if iterations == options.max_iterations {
+            break StopReason::MaxIterations;
+        }
+
+        // Lanczos step
+        v.copy_from(&y);
+        v.unscale_mut(beta.clone());
+        a.apply(&v, &mut y);
+        if iterations > 0 {
+            y.axpy(-beta.clone() / old_beta.clone(), &r1, T::one());
+        }
+        let alpha = v.dot(&y);
+        y.axpy(-alpha.clone() / beta.clone(), &r2, T::one());
+        mem::swap(&mut r1, &mut r2);
+        r2.copy_from(&y);
+        preconditioner.apply(&r2, &mut y);
+        old_beta = beta;
+        beta = r2.dot(&y);
+        if beta < T::zero() {
+            break StopReason::Breakdown;
+        }
+        beta = beta.sqrt();
+
+        // Apply the previous rotation, and compute the rotation that eliminates the
+        // sub-diagonal entry
+        let old_epsilon = epsilon;
+        let delta = c.clone() * delta_bar.clone() + s.clone() * alpha.clone();
+        let gamma_bar = s.clone() * delta_bar - c.clone() * alpha;
+        epsilon = s.clone() * beta.clone();
+        delta_bar = -c.clone() * beta.clone();
+        let gamma = gamma_bar.clone().hypot(beta.clone());
+        if gamma.is_zero() {
+            break StopReason::Breakdown;
+        }
+        c = gamma_bar / gamma.clone();
+        s = beta.clone() / gamma.clone();
+        let phi = c.clone() * phi_bar.clone();
+        phi_bar = s.clone() * phi_bar;
+
+        // Update the search direction and the solution
+        mem::swap(&mut w1, &mut w2);
+        mem::swap(&mut w2, &mut w);
+        w.copy_from(&v);
+        w.axpy(-old_epsilon, &w1, T::one());
+        w.axpy(-delta, &w2, T::one());
+        w.unscale_mut(gamma);
+        x.axpy(phi, &w, T::one());
+
+        // If `beta` vanishes, the Krylov subspace is invariant, and `phi_bar` vanishes as well
+        iterations += 1;
+        residual_history.push(phi_bar.clone().abs());
+    };
+
+    SolverReport {
+        iterations,
+        residual_history,
+        stop_reason,
+    }
+}
@@ -0,0 +1,349 @@
+//! Iterative solvers for sparse linear systems.
+//!
+//! The solvers provided here are Krylov subspace methods, which only access the system matrix
+//! through matrix-vector products. They are well-suited for very large systems for which a
+//! direct [factorization](crate::factorization) is too expensive. The system matrix can be any
+//! type implementing [`LinearOperator`], which includes [`CsrMatrix`], [`CscMatrix`] and
+//! [`DMatrix`].
+//!
+//! | Solver                                   | Matrix                         | Notes |
+//! | -----------------------------------------|--------------------------------|-------|
+//! | [Conjugate gradient](conjugate_gradient) | Symmetric positive definite    | Short recurrences, low memory usage. |
+//! | [MINRES](minres)                         | Symmetric, possibly indefinite | Minimizes the residual norm. |
+//! | [BiCGSTAB](bicgstab)                     | General square                 | Short recurrences, irregular convergence. |
+//! | [GMRES](gmres)                           | General square                 | Minimizes the residual norm over each restart cycle. Memory usage grows with the restart length. |
+//!
+//! All solvers take an initial guess in `x`, which is overwritten by the approximate solution.
+//! The iterations stop once the Euclidean norm of the residual `b - A x` satisfies
+//! `‖b - A x‖ <= tolerance * ‖b‖`, or once the maximum number of iterations given by the
+//! [`SolverOptions`] has been reached. The outcome is described by the returned
+//! [`SolverReport`].
+//!
+//! # Preconditioning
+//!
+//! Each solver has a preconditioned variant, such as [`conjugate_gradient_preconditioned`],
+//! which takes a [`Preconditioner`] `M ≈ A`. The following preconditioners are provided, and
+//! custom preconditioners can be used by implementing the [`Preconditioner`] trait.
+//!
+//! | Preconditioner | Matrix                      | Notes |
+//! | ---------------|-----------------------------|-------|
+//! | [`Jacobi`]     | Non-zero diagonal           | Corrects bad scaling of the matrix. |
+//! | [`Ilu0`]       | General square              | Incomplete LU factorization without fill-in. |
+//! | [`Ic0`]        | Symmetric positive definite | Incomplete Cholesky factorization without fill-in. |
+//!
+//! # Example
+//!
+//! ```
+//! use nalgebra::DVector;
+//! use nalgebra_sparse::coo::CooMatrix;
+//! use nalgebra_sparse::csr::CsrMatrix;
+//! use nalgebra_sparse::solvers::{conjugate_gradient, SolverOptions};
+//!
+//! // The 1D Laplacian, which is symmetric positive definite
+//! let n = 100;
+//! let mut coo = CooMatrix::new(n, n);
+//! for i in 0..n {
+//!     coo.push(i, i, 2.0);
+//!     if i + 1 < n {
+//!         coo.push(i, i + 1, -1.0);
+//!         coo.push(i + 1, i, -1.0);
+//!     }
+//! }
+//! let a = CsrMatrix::from(&coo);
+//! let b = DVector::from_element(n, 1.0);
+//!
+//! let mut x = DVector::zeros(n);
+//! let options = SolverOptions::default().with_tolerance(1e-10);
+//! let report = conjugate_gradient(&a, &b, &mut x, &options);
+//!
+//! assert!(report.converged());
+//! assert!((&a * &x - &b).norm() <= 1e-8);
+//! ```
+mod bicgstab;
+mod cg;
+mod gmres;
+mod ic0;
+mod ilu0;
+mod minres;
+mod preconditioner;
+
+pub use bicgstab::*;
+pub use cg::*;
+pub use gmres::*;
+pub use ic0::*;
+pub use ilu0::*;
+pub use minres::*;
+pub use preconditioner::*;
+
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+use crate::ops::serial::{spmm_csc_dense, spmm_csr_dense};
+use crate::ops::Op;
+use nalgebra::{ClosedAdd, ClosedMul, DMatrix, DVector, RealField, Scalar};
+use num_traits::{One, Zero};
+
+/// A linear operator `A`, which can be applied to vectors.
+///
+/// This is the only way the iterative solvers access the system matrix. It can be implemented
+/// for matrix-free operators, for which the matrix is never assembled.
+pub trait LinearOperator<T> {
+    /// The number of rows of the operator.
+    fn nrows(&self) -> usize;
+
+    /// The number of columns of the operator.
+    fn ncols(&self) -> usize;
+
+    /// Computes `y = A x`.
+    ///
+    /// The vector `x` has `ncols()` entries, and `y` has `nrows()` entries.
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>);
+}
+
+impl<T> LinearOperator<T> for CsrMatrix<T>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        spmm_csr_dense(T::zero(), y, T::one(), Op::NoOp(self), Op::NoOp(x));
+    }
+}
+
+impl<T> LinearOperator<T> for CscMatrix<T>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        spmm_csc_dense(T::zero(), y, T::one(), Op::NoOp(self), Op::NoOp(x));
+    }
+}
+
+impl<T> LinearOperator<T> for DMatrix<T>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        y.gemv(T::one(), self, x, T::zero());
+    }
+}
+
+impl<T, A> LinearOperator<T> for &A
+where
+    A: LinearOperator<T> + ?Sized,
+{
+    fn nrows(&self) -> usize {
+        A::nrows(self)
+    }
+
+    fn ncols(&self) -> usize {
+        A::ncols(self)
+    }
+
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        A::apply(self, x, y)
+    }
+}
+
+/// A linear operator `A` whose transpose `Aᵀ` can also be applied to vectors.
+///
+/// This is required by algorithms that work with both `A` and `Aᵀ`, such as the
+/// [randomized SVD](crate::spectral::randomized_svd).
+pub trait TransposeLinearOperator<T>: LinearOperator<T> {
+    /// Computes `y = Aᵀ x`.
+    ///
+    /// The vector `x` has `nrows()` entries, and `y` has `ncols()` entries.
+    fn apply_transpose(&self, x: &DVector<T>, y: &mut DVector<T>);
+}
+
+impl<T> TransposeLinearOperator<T> for CsrMatrix<T>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    fn apply_transpose(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        spmm_csr_dense(T::zero(), y, T::one(), Op::Transpose(self), Op::NoOp(x));
+    }
+}
+
+impl<T> TransposeLinearOperator<T> for CscMatrix<T>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    fn apply_transpose(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        spmm_csc_dense(T::zero(), y, T::one(), Op::Transpose(self), Op::NoOp(x));
+    }
+}
+
+impl<T> TransposeLinearOperator<T> for DMatrix<T>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    fn apply_transpose(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        y.gemv_tr(T::one(), self, x, T::zero());
+    }
+}
+
+impl<T, A> TransposeLinearOperator<T> for &A
+where
+    A: TransposeLinearOperator<T> + ?Sized,
+{
+    fn apply_transpose(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        A::apply_transpose(self, x, y)
+    }
+}
+
+/// Stopping criteria of the iterative solvers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverOptions<T> {
+    /// The relative tolerance on the residual.
+    ///
+    /// The iterations stop once `‖b - A x‖ <= tolerance * ‖b‖`.
+    pub tolerance: T,
+    /// The maximum number of iterations.
+    pub max_iterations: usize,
+}
+
+impl<T: RealField> Default for SolverOptions<T> {
+    /// A relative tolerance of `1e-8` and at most `1000` iterations.
+    fn default() -> Self {
+        Self {
+            tolerance: nalgebra::convert(1e-8),
+            max_iterations: 1000,
+        }
+    }
+}
+
+impl<T> SolverOptions<T> {
+    /// Creates options with the given relative tolerance and maximum number of iterations.
+    pub fn new(tolerance: T, max_iterations: usize) -> Self {
+        Self {
+            tolerance,
+            max_iterations,
+        }
+    }
+
+    /// Replaces the relative tolerance on the residual.
+    #[must_use]
+    pub fn with_tolerance(self, tolerance: T) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// Replaces the maximum number of iterations.
+    #[must_use]
+    pub fn with_max_iterations(self, max_iterations: usize) -> Self {
+        Self {
+            max_iterations,
+            ..self
+        }
+    }
+}
+
+/// The reason an iterative solver stopped.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// The residual satisfies the requested tolerance.
+    Converged,
+    /// The maximum number of iterations has been reached before convergence.
+    MaxIterations,
+    /// The method broke down, i.e. a division by zero would be required to continue.
+    ///
+    /// This typically happens if the matrix does not have the properties required by the
+    /// method, for instance if it is singular, or not positive definite for the conjugate
+    /// gradient method.
+    Breakdown,
+}
+
+/// Describes the outcome of an iterative solve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolverReport<T> {
+    /// The number of iterations performed.
+    pub iterations: usize,
+    /// The Euclidean norm of the residual before the first iteration and after each iteration.
+    ///
+    /// Except for the first entry, the norms are generally not computed explicitly, but are
+    /// obtained from the recurrences of the method. They may therefore slightly differ from the
+    /// norm of `b - A x` computed explicitly.
+    pub residual_history: Vec<T>,
+    /// Why the solver stopped.
+    pub stop_reason: StopReason,
+}
+
+impl<T: Clone> SolverReport<T> {
+    /// Whether the residual satisfies the requested tolerance.
+    #[must_use]
+    pub fn converged(&self) -> bool {
+        self.stop_reason == StopReason::Converged
+    }
+
+    /// The norm of the residual when the solver stopped.
+    #[must_use]
+    pub fn residual_norm(&self) -> T {
+        self.residual_history
+            .last()
+            .cloned()
+            .expect("The residual history always contains the initial residual.")
+    }
+}
+
+/// Checks the dimensions of a system `A x = b` and returns the dimension of the system.
+fn check_dimensions<T, A>(a: &A, b: &DVector<T>, x: &DVector<T>) -> usize
+where
+    A: LinearOperator<T> + ?Sized,
+{
+    let n = a.nrows();
+    assert_eq!(n, a.ncols(), "The linear operator must be square.");
+    assert_eq!(
+        b.len(),
+        n,
+        "The right-hand side must have as many rows as the operator."
+    );
+    assert_eq!(
+        x.len(),
+        n,
+        "The solution must have as many rows as the operator."
+    );
+    n
+}
+
+/// Computes `r = b - A x`.
+fn residual<T, A>(a: &A, b: &DVector<T>, x: &DVector<T>, r: &mut DVector<T>)
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+{
+    a.apply(x, r);
+    r.axpy(T::one(), b, -T::one());
+}
+
+/// Handles the trivial system `A x = 0`, whose solution is `x = 0`.
+fn solve_zero_rhs<T: RealField>(x: &mut DVector<T>) -> SolverReport<T> {
+    x.fill(T::zero());
+    SolverReport {
+        iterations: 0,
+        residual_history: vec![T::zero()],
+        stop_reason: StopReason::Converged,
+    }
+}
@@ -0,0 +1,128 @@
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+use nalgebra::{DVector, RealField};
+use std::error::Error;
+use std::fmt;
+
+/// A preconditioner `M`, which approximates the system matrix `A`.
+///
+/// A good preconditioner is cheap to apply, and makes `M⁻¹ A` much better conditioned than `A`.
+/// The preconditioned solvers, such as
+/// [`conjugate_gradient_preconditioned`](crate::solvers::conjugate_gradient_preconditioned),
+/// access the preconditioner only through [`apply`](Self::apply).
+pub trait Preconditioner<T> {
+    /// Computes `z = M⁻¹ r`.
+    fn apply(&self, r: &DVector<T>, z: &mut DVector<T>);
+}
+
+impl<T, P> Preconditioner<T> for &P
+where
+    P: Preconditioner<T> + ?Sized,
+{
+    fn apply(&self, r: &DVector<T>, z: &mut DVector<T>) {
+        P::apply(self, r, z)
+    }
+}
+
+/// The identity preconditioner `M = I`, which amounts to no preconditioning.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IdentityPreconditioner;
+
+impl<T: Clone> Preconditioner<T> for IdentityPreconditioner {
+    fn apply(&self, r: &DVector<T>, z: &mut DVector<T>) {
+        z.clone_from(r);
+    }
+}
+
+/// The Jacobi (diagonal) preconditioner `M = diag(A)`.
+#[derive(Debug, Clone)]
+pub struct Jacobi<T> {
+    inverse_diagonal: DVector<T>,
+}
+
+impl<T: RealField> Jacobi<T> {
+    /// Constructs the Jacobi preconditioner from the diagonal of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a diagonal entry is zero.
+    pub fn from_diagonal(diagonal: &DVector<T>) -> Result<Self, PreconditionerError> {
+        if diagonal.iter().any(|d| d.is_zero()) {
+            return Err(PreconditionerError::ZeroPivot);
+        }
+        Ok(Self {
+            inverse_diagonal: diagonal.map(|d| T::one() / d),
+        })
+    }
+
+    /// Constructs the Jacobi preconditioner of a square CSR matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a diagonal entry is zero or not explicitly stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn from_csr(matrix: &CsrMatrix<T>) -> Result<Self, PreconditionerError> {
+        assert_eq!(matrix.nrows(), matrix.ncols(), "The matrix must be square.");
+        let diagonal = DVector::from_fn(matrix.nrows(), |i, _| {
+            matrix.get_entry(i, i).unwrap().into_value()
+        });
+        Self::from_diagonal(&diagonal)
+    }
+
+    /// Constructs the Jacobi preconditioner of a square CSC matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a diagonal entry is zero or not explicitly stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn from_csc(matrix: &CscMatrix<T>) -> Result<Self, PreconditionerError> {
+        assert_eq!(matrix.nrows(), matrix.ncols(), "The matrix must be square.");
+        let diagonal = DVector::from_fn(matrix.nrows(), |i, _| {
+            matrix.get_entry(i, i).unwrap().into_value()
+        });
+        Self::from_diagonal(&diagonal)
+    }
+
+    /// The inverse of the diagonal of the matrix.
+    #[must_use]
+    pub fn inverse_diagonal(&self) -> &DVector<T> {
+        &self.inverse_diagonal
+    }
+}
+
+impl<T: RealField> Preconditioner<T> for Jacobi<T> {
+    fn apply(&self, r: &DVector<T>, z: &mut DVector<T>) {
+        z.zip_zip_apply(r, &self.inverse_diagonal, |zi, ri, di| *zi = ri * di);
+    }
+}
+
+/// Possible errors produced by the construction of preconditioners.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreconditionerError {
+    /// A diagonal entry or a pivot is zero or missing from the sparsity pattern.
+    ZeroPivot,
+    /// A non-positive pivot was encountered by an incomplete Cholesky factorization.
+    NotPositiveDefinite,
+}
+
+impl fmt::Display for PreconditionerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PreconditionerError::ZeroPivot => {
+                write!(f, "A diagonal entry is zero or missing.")
+            }
+            PreconditionerError::NotPositiveDefinite => {
+                write!(f, "A non-positive pivot was encountered.")
+            }
+        }
+    }
+}
+
+impl Error for PreconditionerError {}
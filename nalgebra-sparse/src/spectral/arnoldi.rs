@@ -0,0 +1,146 @@
+use crate::solvers::LinearOperator;
+use crate::spectral::krylov::KrylovDecomposition;
+use crate::spectral::{check_eigen_arguments, EigenOptions, Rng, Which};
+use nalgebra::{Complex, ComplexField, DMatrix, DVector, Dynamic, Eigen, RealField};
+
+/// Computes `k` eigenpairs of a square linear operator with the restarted Arnoldi method.
+///
+/// The eigenvalues selected by `which` are returned sorted from the most wanted to the least
+/// wanted, and complex eigenvalues always appear in consecutive conjugate pairs. If the `k`-th
+/// eigenvalue is complex, its conjugate is also returned, so that the result may contain
+/// `k + 1` eigenpairs. The eigenvectors are normalized, and the left eigenvectors are not
+/// computed.
+///
+/// Each restart keeps an orthonormal basis of the Ritz vectors of the `k` wanted eigenvalues,
+/// along with half of the remaining ones to speed up the convergence. See [`EigenOptions`] for
+/// the convergence criterion. For symmetric operators, [`lanczos`](crate::spectral::lanczos)
+/// is more efficient and more accurate.
+///
+/// Returns `None` if the eigenpairs did not converge within the maximum number of restarts.
+///
+/// # Panics
+///
+/// Panics if the operator is not square, if `k` is zero or larger than the dimension of the
+/// operator, or if the subspace dimension given in the options is not larger than `k`.
+pub fn arnoldi<T, A>(
+    a: &A,
+    k: usize,
+    which: Which,
+    options: &EigenOptions<T>,
+) -> Option<Eigen<T, Dynamic>>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+{
+    let (n, m) = check_eigen_arguments(a, k, options);
+    let mut rng = Rng::new(options.seed);
+    let mut krylov = KrylovDecomposition::new(n, m, &mut rng);
+    let mut from = 0;
+
+    for restart in 0..=options.max_restarts {
+        krylov.extend(a, from, &mut rng);
+
+        let ritz = Eigen::new(krylov.h.rows(0, m).clone_owned());
+        let blocks = sorted_conjugate_blocks(&ritz.eigenvalues, which);
+
+        let mut wanted = 0;
+        let mut len = 0;
+        while len < k {
+            len += blocks[wanted].1;
+            wanted += 1;
+        }
+
+        let threshold = options.tolerance.clone() * ritz.eigenvalues.camax();
+        let converged = blocks[..wanted].iter().all(|&(i, _)| {
+            krylov.ritz_residual(ritz.eigenvectors[(m - 1, i)].clone().modulus()) <= threshold
+        });
+
+        // The Krylov subspace is the whole space if `m == n`.
+        if converged || m == n {
+            let indices: Vec<_> = blocks[..wanted]
+                .iter()
+                .flat_map(|&(i, size)| i..i + size)
+                .collect();
+            let y = DMatrix::from_fn(m, len, |i, j| ritz.eigenvectors[(i, indices[j])].clone());
+            let basis = krylov
+                .basis
+                .columns(0, m)
+                .map(|e| Complex::new(e, T::zero()));
+            return Some(Eigen {
+                eigenvalues: DVector::from_fn(len, |i, _| ritz.eigenvalues[indices[i]].clone()),
+                eigenvectors: basis * y,
+                left_eigenvectors: None,
+            });
+        }
+
+        if restart == options.max_restarts {
+            break;
+        }
+
+        // Real basis of the invariant subspace of `H` associated with the kept Ritz values.
+        let target = k + (m - k) / 2;
+        let mut columns = Vec::with_capacity(target + 1);
+        for &(i, size) in &blocks {
+            if columns.len() >= target || columns.len() + size >= m {
+                break;
+            }
+            let y = ritz.eigenvectors.column(i);
+            if size == 1 {
+                columns.push(real_eigenvector(&y.clone_owned()));
+            } else {
+                columns.push(y.map(|e| e.re));
+                columns.push(y.map(|e| e.im));
+            }
+        }
+        let q = if columns.is_empty() {
+            DMatrix::zeros(m, 0)
+        } else {
+            DMatrix::from_columns(&columns).qr().q()
+        };
+
+        let p = q.ncols();
+        krylov.restart(&q);
+        from = p;
+    }
+
+    None
+}
+
+/// Groups the eigenvalues computed by [`Eigen`] into real eigenvalues and conjugate pairs, and
+/// sorts these groups from the most wanted to the least wanted.
+///
+/// Each group is given by the index of its first eigenvalue and its size.
+fn sorted_conjugate_blocks<T: RealField>(
+    eigenvalues: &DVector<Complex<T>>,
+    which: Which,
+) -> Vec<(usize, usize)> {
+    let mut blocks = Vec::with_capacity(eigenvalues.len());
+    let mut i = 0;
+    while i < eigenvalues.len() {
+        let size = if eigenvalues[i].im.is_zero() || i + 1 == eigenvalues.len() {
+            1
+        } else {
+            2
+        };
+        blocks.push((i, size));
+        i += size;
+    }
+
+    let keys: Vec<_> = blocks
+        .iter()
+        .map(|&(i, _)| (eigenvalues[i].re.clone(), eigenvalues[i].im.clone()))
+        .collect();
+    which
+        .sort(&keys)
+        .into_iter()
+        .map(|block| blocks[block])
+        .collect()
+}
+
+/// Returns the real eigenvector `e^{-iφ} y` associated with a real eigenvalue, given a complex
+/// eigenvector `y` with an arbitrary phase `φ`.
+fn real_eigenvector<T: RealField>(y: &DVector<Complex<T>>) -> DVector<T> {
+    let pivot = y.icamax();
+    let phase = y[pivot].clone().conjugate() / Complex::from(y[pivot].clone().modulus());
+    y.map(|e| (e * phase.clone()).re)
+}
@@ -0,0 +1,138 @@
+use crate::solvers::LinearOperator;
+use crate::spectral::Rng;
+use nalgebra::{DMatrix, DVector, RealField};
+
+/// A Krylov decomposition `A V = V H + β v e_mᵀ` of a square linear operator `A`.
+///
+/// The `n x m` matrix `V` and the vector `v` form an orthonormal basis, stored as the `m + 1`
+/// columns of `basis`. The `(m + 1) x m` matrix `h` contains `H` in its first `m` rows, and the
+/// coupling `β e_mᵀ` to `v` in its last row. After a restart, the leading block of `H` is dense
+/// rather than upper Hessenberg, but the decomposition is extended with the Arnoldi process, so
+/// that the coupling always only involves the last column of `V`.
+pub(super) struct KrylovDecomposition<T> {
+    pub basis: DMatrix<T>,
+    pub h: DMatrix<T>,
+}
+
+impl<T: RealField> KrylovDecomposition<T> {
+    /// Initializes a decomposition of dimension `m` with a random starting vector.
+    ///
+    /// Only the starting vector is set, and the decomposition must be [extended](Self::extend)
+    /// from `0`.
+    pub fn new(n: usize, m: usize, rng: &mut Rng) -> Self {
+        let mut basis = DMatrix::zeros(n, m + 1);
+        basis.set_column(0, &rng.normal_vector(n).normalize());
+        Self {
+            basis,
+            h: DMatrix::zeros(m + 1, m),
+        }
+    }
+
+    /// The dimension `m` of the decomposition.
+    pub fn dimension(&self) -> usize {
+        self.h.ncols()
+    }
+
+    /// The residual norm of the Ritz pair `(θ, V y)`, given the last entry `y_m` of `y`.
+    pub fn ritz_residual(&self, y_m: T) -> T {
+        let m = self.dimension();
+        (self.h[(m, m - 1)].clone() * y_m).abs()
+    }
+
+    /// Extends the decomposition from its first `from` columns to `m` columns with the Arnoldi
+    /// process.
+    ///
+    /// If the Krylov subspace becomes invariant, the coupling is set to zero and the process
+    /// continues with a random vector orthogonal to the current basis.
+    pub fn extend<A>(&mut self, a: &A, from: usize, rng: &mut Rng)
+    where
+        A: LinearOperator<T> + ?Sized,
+    {
+        let n = self.basis.nrows();
+        let m = self.dimension();
+        let mut w = DVector::zeros(n);
+
+        for j in from..m {
+            a.apply(&self.basis.column(j).clone_owned(), &mut w);
+            let w_norm = w.norm();
+            let coefficients = self.orthogonalize(&mut w, j + 1);
+            self.h
+                .slice_mut((0, j), (j + 1, 1))
+                .copy_from(&coefficients);
+            for i in j + 2..=m {
+                self.h[(i, j)] = T::zero();
+            }
+
+            let beta = w.norm();
+            let breakdown_threshold =
+                w_norm * T::default_epsilon() * nalgebra::convert((j + 1) as f64);
+            if beta > breakdown_threshold {
+                self.h[(j + 1, j)] = beta.clone();
+                self.basis.set_column(j + 1, &(w.clone() / beta));
+            } else {
+                self.h[(j + 1, j)] = T::zero();
+                let v = if j + 1 < n {
+                    self.random_orthogonal_vector(j + 1, rng)
+                } else {
+                    DVector::zeros(n)
+                };
+                self.basis.set_column(j + 1, &v);
+            }
+        }
+    }
+
+    /// Restarts the decomposition from the subspace spanned by `V Q`, where the `p < m`
+    /// orthonormal columns of `q` span an invariant subspace of `H`.
+    ///
+    /// The restarted decomposition has the `p` columns `V Q`, with `Qᵀ H Q` as the projected
+    /// operator, and must be [extended](Self::extend) from `p`.
+    pub fn restart(&mut self, q: &DMatrix<T>) {
+        let m = self.dimension();
+        let p = q.ncols();
+        assert!(
+            p < m,
+            "The restarted subspace must be smaller than the full subspace."
+        );
+
+        let beta = self.h[(m, m - 1)].clone();
+        let h = q.tr_mul(&(self.h.rows(0, m) * q));
+        let basis = self.basis.columns(0, m) * q;
+        let v = self.basis.column(m).clone_owned();
+
+        self.h.fill(T::zero());
+        self.h.slice_mut((0, 0), (p, p)).copy_from(&h);
+        for j in 0..p {
+            self.h[(p, j)] = beta.clone() * q[(m - 1, j)].clone();
+        }
+        self.basis.columns_mut(0, p).copy_from(&basis);
+        self.basis.set_column(p, &v);
+    }
+
+    /// Orthogonalizes `w` against the first `len` columns of the basis with two passes of
+    /// classical Gram-Schmidt, and returns the accumulated projection coefficients.
+    fn orthogonalize(&self, w: &mut DVector<T>, len: usize) -> DVector<T> {
+        let v = self.basis.columns(0, len);
+        let mut coefficients = v.tr_mul(w);
+        w.gemv(-T::one(), &v, &coefficients, T::one());
+        let correction = v.tr_mul(w);
+        w.gemv(-T::one(), &v, &correction, T::one());
+        coefficients += correction;
+        coefficients
+    }
+
+    /// Generates a random unit vector orthogonal to the first `len < n` columns of the basis.
+    fn random_orthogonal_vector(&self, len: usize, rng: &mut Rng) -> DVector<T> {
+        loop {
+            let mut v = rng.normal_vector(self.basis.nrows());
+            self.orthogonalize(&mut v, len);
+            // Orthogonalizing again after normalization recovers the accuracy lost if the random
+            // vector was almost in the span of the basis.
+            if v.try_normalize_mut(T::zero()).is_some() {
+                self.orthogonalize(&mut v, len);
+                if v.try_normalize_mut(T::zero()).is_some() {
+                    return v;
+                }
+            }
+        }
+    }
+}
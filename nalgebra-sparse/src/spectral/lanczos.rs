@@ -0,0 +1,80 @@
+use crate::solvers::LinearOperator;
+use crate::spectral::krylov::KrylovDecomposition;
+use crate::spectral::{check_eigen_arguments, EigenOptions, Rng, Which};
+use nalgebra::{DMatrix, DVector, Dynamic, RealField, SymmetricEigen};
+
+/// Computes `k` eigenpairs of a symmetric linear operator with the thick-restart Lanczos method.
+///
+/// The eigenvalues selected by `which` are returned sorted from the most wanted to the least
+/// wanted, e.g. in decreasing order for [`Which::LargestReal`]. The `n x k` matrix of
+/// eigenvectors has orthonormal columns, so that `SymmetricEigen::recompose` returns the
+/// corresponding rank-`k` approximation of the operator.
+///
+/// The operator is assumed to be symmetric, which is not checked. The basis of the Krylov
+/// subspace is fully reorthogonalized, so that no spurious copies of the eigenvalues appear.
+/// Conversely, like all methods based on a single starting vector, the method may miss some
+/// copies of multiple eigenvalues.
+/// Each restart keeps the Ritz vectors of the `k` wanted eigenvalues, along with half of the
+/// remaining ones to speed up the convergence. See [`EigenOptions`] for the convergence
+/// criterion.
+///
+/// Returns `None` if the eigenpairs did not converge within the maximum number of restarts.
+///
+/// # Panics
+///
+/// Panics if the operator is not square, if `k` is zero or larger than the dimension of the
+/// operator, or if the subspace dimension given in the options is not larger than `k`.
+pub fn lanczos<T, A>(
+    a: &A,
+    k: usize,
+    which: Which,
+    options: &EigenOptions<T>,
+) -> Option<SymmetricEigen<T, Dynamic>>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+{
+    let (n, m) = check_eigen_arguments(a, k, options);
+    let mut rng = Rng::new(options.seed);
+    let mut krylov = KrylovDecomposition::new(n, m, &mut rng);
+    let mut from = 0;
+
+    for restart in 0..=options.max_restarts {
+        krylov.extend(a, from, &mut rng);
+
+        // `H` is symmetric up to rounding errors.
+        let h = krylov.h.rows(0, m);
+        let ritz = SymmetricEigen::new((&h + h.transpose()) * nalgebra::convert::<_, T>(0.5));
+        let eigenvalues: Vec<_> = ritz
+            .eigenvalues
+            .iter()
+            .map(|theta| (theta.clone(), T::zero()))
+            .collect();
+        let order = which.sort(&eigenvalues);
+
+        let threshold = options.tolerance.clone() * ritz.eigenvalues.amax();
+        let converged = order[..k]
+            .iter()
+            .all(|&i| krylov.ritz_residual(ritz.eigenvectors[(m - 1, i)].clone()) <= threshold);
+
+        // The Krylov subspace is the whole space if `m == n`.
+        if converged || m == n {
+            let y = DMatrix::from_fn(m, k, |i, j| ritz.eigenvectors[(i, order[j])].clone());
+            return Some(SymmetricEigen {
+                eigenvectors: krylov.basis.columns(0, m) * y,
+                eigenvalues: DVector::from_fn(k, |i, _| ritz.eigenvalues[order[i]].clone()),
+            });
+        }
+
+        if restart == options.max_restarts {
+            break;
+        }
+
+        let p = k + (m - k) / 2;
+        let q = DMatrix::from_fn(m, p, |i, j| ritz.eigenvectors[(i, order[j])].clone());
+        krylov.restart(&q);
+        from = p;
+    }
+
+    None
+}
@@ -0,0 +1,228 @@
+//! Partial eigendecompositions and truncated singular value decompositions of large matrices.
+//!
+//! The methods provided here only compute a few eigenpairs or singular triplets, and only access
+//! the matrix through matrix-vector products. They are well-suited for very large matrices for
+//! which a full dense decomposition is too expensive, for instance for the principal component
+//! analysis of large data matrices. The matrix can be any type implementing
+//! [`LinearOperator`](crate::solvers::LinearOperator) (or
+//! [`TransposeLinearOperator`](crate::solvers::TransposeLinearOperator) for the SVD), which
+//! includes [`CsrMatrix`](crate::csr::CsrMatrix), [`CscMatrix`](crate::csc::CscMatrix) and
+//! [`DMatrix`](nalgebra::DMatrix).
+//!
+//! | Method                               | Matrix           | Result |
+//! | -------------------------------------|------------------|--------|
+//! | [Lanczos](lanczos)                   | Symmetric        | [`SymmetricEigen`](nalgebra::SymmetricEigen) with `k` eigenpairs. |
+//! | [Arnoldi](arnoldi)                   | General square   | [`Eigen`](nalgebra::Eigen) with `k` (or `k + 1`) eigenpairs. |
+//! | [Randomized SVD](randomized_svd)     | General          | [`SVD`](nalgebra::SVD) with `k` singular triplets. |
+//!
+//! The results are returned in the same structures as the corresponding dense decompositions,
+//! with as many columns in the eigenvectors and singular vectors as there are computed values.
+//! For instance, `SymmetricEigen::recompose` and `SVD::recompose` return the corresponding
+//! low-rank approximations of the matrix.
+//!
+//! The Lanczos and Arnoldi methods build an orthonormal basis of a Krylov subspace, and are
+//! restarted by keeping the wanted Ritz vectors until the eigenpairs selected by [`Which`] have
+//! converged. Eigenvalues at the ends of the spectrum converge fastest. Eigenvalues of smallest
+//! magnitude generally converge slowly, in which case it is much more efficient to compute the
+//! eigenvalues of largest magnitude of the inverse of the matrix, e.g. through a factorization.
+//!
+//! All methods start from pseudo-random vectors generated from a seed given in the options, so
+//! that the results are reproducible.
+//!
+//! # Example
+//!
+//! ```
+//! use nalgebra_sparse::coo::CooMatrix;
+//! use nalgebra_sparse::csr::CsrMatrix;
+//! use nalgebra_sparse::spectral::{lanczos, EigenOptions, Which};
+//!
+//! // The 1D Laplacian, whose eigenvalues are 2 - 2 cos(j π / (n + 1))
+//! let n = 200;
+//! let mut coo = CooMatrix::new(n, n);
+//! for i in 0..n {
+//!     coo.push(i, i, 2.0);
+//!     if i + 1 < n {
+//!         coo.push(i, i + 1, -1.0);
+//!         coo.push(i + 1, i, -1.0);
+//!     }
+//! }
+//! let a: CsrMatrix<f64> = CsrMatrix::from(&coo);
+//!
+//! let eigen = lanczos(&a, 3, Which::LargestReal, &EigenOptions::default()).unwrap();
+//!
+//! for (j, lambda) in eigen.eigenvalues.iter().enumerate() {
+//!     let expected = 2.0 - 2.0 * ((n - j) as f64 * std::f64::consts::PI / (n + 1) as f64).cos();
+//!     assert!((lambda - expected).abs() <= 1e-8);
+//! }
+//! ```
+mod arnoldi;
+mod krylov;
+mod lanczos;
+mod randomized_svd;
+
+pub use arnoldi::*;
+pub use lanczos::*;
+pub use randomized_svd::*;
+
+use crate::solvers::LinearOperator;
+use nalgebra::{DVector, RealField};
+use std::cmp::Ordering;
+
+/// Selects which eigenvalues a partial eigensolver computes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Which {
+    /// The eigenvalues of largest magnitude.
+    LargestMagnitude,
+    /// The eigenvalues of smallest magnitude.
+    SmallestMagnitude,
+    /// The eigenvalues of largest real part, i.e. the largest eigenvalues of a symmetric matrix.
+    LargestReal,
+    /// The eigenvalues of smallest real part, i.e. the smallest eigenvalues of a symmetric
+    /// matrix.
+    SmallestReal,
+}
+
+impl Which {
+    /// Sorts the indices of the given eigenvalues `(re, im)` from the most wanted to the least
+    /// wanted.
+    fn sort<T: RealField>(self, eigenvalues: &[(T, T)]) -> Vec<usize> {
+        let key = |(re, im): &(T, T)| match self {
+            Which::LargestMagnitude => re.clone().hypot(im.clone()),
+            Which::SmallestMagnitude => -re.clone().hypot(im.clone()),
+            Which::LargestReal => re.clone(),
+            Which::SmallestReal => -re.clone(),
+        };
+        let mut indices: Vec<usize> = (0..eigenvalues.len()).collect();
+        indices.sort_by(|&i, &j| {
+            key(&eigenvalues[j])
+                .partial_cmp(&key(&eigenvalues[i]))
+                .unwrap_or(Ordering::Equal)
+        });
+        indices
+    }
+}
+
+/// Convergence criteria of the partial eigensolvers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EigenOptions<T> {
+    /// The relative tolerance on the residuals of the eigenpairs.
+    ///
+    /// An eigenpair `(λ, x)` with `‖x‖ = 1` has converged once `‖A x - λ x‖ <= tolerance * ρ`,
+    /// where `ρ` is the largest magnitude of the current approximations of the eigenvalues.
+    pub tolerance: T,
+    /// The maximum number of restarts of the Krylov subspace.
+    pub max_restarts: usize,
+    /// The dimension of the Krylov subspace, which must be larger than the number of computed
+    /// eigenvalues.
+    ///
+    /// A larger subspace generally reduces the number of restarts, at the cost of storing more
+    /// vectors. If `None`, the dimension is `max(2 k + 1, 20)`, where `k` is the number of
+    /// computed eigenvalues. The dimension is always clamped to the dimension of the matrix.
+    pub subspace_dimension: Option<usize>,
+    /// The seed of the pseudo-random starting vector.
+    pub seed: u64,
+}
+
+impl<T: RealField> Default for EigenOptions<T> {
+    /// A relative tolerance of `1e-10`, at most `300` restarts, the default subspace dimension
+    /// and a seed of `0`.
+    fn default() -> Self {
+        Self {
+            tolerance: nalgebra::convert(1e-10),
+            max_restarts: 300,
+            subspace_dimension: None,
+            seed: 0,
+        }
+    }
+}
+
+impl<T> EigenOptions<T> {
+    /// Replaces the relative tolerance on the residuals.
+    #[must_use]
+    pub fn with_tolerance(self, tolerance: T) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// Replaces the maximum number of restarts.
+    #[must_use]
+    pub fn with_max_restarts(self, max_restarts: usize) -> Self {
+        Self {
+            max_restarts,
+            ..self
+        }
+    }
+
+    /// Replaces the dimension of the Krylov subspace.
+    #[must_use]
+    pub fn with_subspace_dimension(self, subspace_dimension: usize) -> Self {
+        Self {
+            subspace_dimension: Some(subspace_dimension),
+            ..self
+        }
+    }
+
+    /// Replaces the seed of the starting vector.
+    #[must_use]
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self { seed, ..self }
+    }
+}
+
+/// Checks the arguments of a partial eigensolver computing `k` eigenvalues, and returns the
+/// dimension of the operator and the dimension of the Krylov subspace.
+fn check_eigen_arguments<T, A>(a: &A, k: usize, options: &EigenOptions<T>) -> (usize, usize)
+where
+    A: LinearOperator<T> + ?Sized,
+{
+    let n = a.nrows();
+    assert_eq!(n, a.ncols(), "The linear operator must be square.");
+    assert!(
+        k > 0 && k <= n,
+        "The number of eigenvalues must be positive and at most the dimension of the operator."
+    );
+    let m = options
+        .subspace_dimension
+        .unwrap_or_else(|| (2 * k + 1).max(20))
+        .min(n);
+    assert!(
+        m > k || m == n,
+        "The subspace dimension must be larger than the number of eigenvalues."
+    );
+    (n, m)
+}
+
+/// A SplitMix64 pseudo-random number generator.
+///
+/// This avoids a dependency on `rand` for the random starting vectors, which only need to be
+/// reproducible and not particularly random.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A uniformly distributed number in `[0, 1)`.
+    fn uniform(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// A normally distributed number, generated with the Box-Muller transform.
+    fn normal(&mut self) -> f64 {
+        let radius = (-2.0 * (1.0 - self.uniform()).ln()).sqrt();
+        radius * (2.0 * std::f64::consts::PI * self.uniform()).cos()
+    }
+
+    /// A vector of `n` normally distributed entries.
+    fn normal_vector<T: RealField>(&mut self, n: usize) -> DVector<T> {
+        DVector::from_fn(n, |_, _| nalgebra::convert(self.normal()))
+    }
+}
@@ -0,0 +1,134 @@
+use crate::solvers::TransposeLinearOperator;
+use crate::spectral::Rng;
+use nalgebra::{DMatrix, DVector, Dynamic, RealField, SVDAlgorithm, SVD};
+
+/// Parameters of the [randomized SVD](randomized_svd).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RandomizedSvdOptions {
+    /// The number of random vectors used in addition to the number of computed singular values.
+    ///
+    /// Oversampling makes it very likely that the random subspace captures the dominant singular
+    /// vectors.
+    pub oversampling: usize,
+    /// The number of power iterations, i.e. of applications of `A Aᵀ` to the random subspace.
+    ///
+    /// Each power iteration improves the accuracy when the singular values decay slowly, at the
+    /// cost of two passes over the operator.
+    pub power_iterations: usize,
+    /// The seed of the pseudo-random test matrix.
+    pub seed: u64,
+}
+
+impl Default for RandomizedSvdOptions {
+    /// An oversampling of `10`, `2` power iterations and a seed of `0`.
+    fn default() -> Self {
+        Self {
+            oversampling: 10,
+            power_iterations: 2,
+            seed: 0,
+        }
+    }
+}
+
+impl RandomizedSvdOptions {
+    /// Replaces the oversampling.
+    #[must_use]
+    pub fn with_oversampling(self, oversampling: usize) -> Self {
+        Self {
+            oversampling,
+            ..self
+        }
+    }
+
+    /// Replaces the number of power iterations.
+    #[must_use]
+    pub fn with_power_iterations(self, power_iterations: usize) -> Self {
+        Self {
+            power_iterations,
+            ..self
+        }
+    }
+
+    /// Replaces the seed of the test matrix.
+    #[must_use]
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self { seed, ..self }
+    }
+}
+
+/// Computes an approximation of the `k` largest singular values and the associated singular
+/// vectors of a linear operator with the randomized range finder of Halko, Martinsson and Tropp.
+///
+/// An orthonormal basis `Q` of the range of `A Ω` is computed for a random Gaussian matrix `Ω`
+/// with `k + oversampling` columns, refined by the power iterations `(A Aᵀ)^q A Ω`. The SVD of
+/// the small matrix `Qᵀ A` then gives the approximate SVD of `A`, truncated to `k` singular
+/// triplets. The approximation is very accurate when the singular values decay quickly, and
+/// the power iterations compensate for a slow decay.
+///
+/// The returned SVD always contains `U` and `Vᵀ`, with `k` orthonormal columns and rows
+/// respectively, and the singular values are sorted in decreasing order. In particular,
+/// `SVD::recompose` returns the corresponding rank-`k` approximation of the operator.
+///
+/// # Panics
+///
+/// Panics if `k` is zero or larger than the smallest dimension of the operator.
+pub fn randomized_svd<T, A>(
+    a: &A,
+    k: usize,
+    options: &RandomizedSvdOptions,
+) -> SVD<T, Dynamic, Dynamic>
+where
+    T: RealField,
+    A: TransposeLinearOperator<T> + ?Sized,
+{
+    let (nrows, ncols) = (a.nrows(), a.ncols());
+    assert!(
+        k > 0 && k <= nrows.min(ncols),
+        "The number of singular values must be positive and at most the smallest dimension of \
+         the operator."
+    );
+    let l = (k + options.oversampling).min(nrows.min(ncols));
+    let mut rng = Rng::new(options.seed);
+
+    let omega = DMatrix::from_fn(ncols, l, |_, _| nalgebra::convert(rng.normal()));
+    let mut q = apply_columns(&omega, nrows, |x, y| a.apply(x, y)).qr().q();
+    for _ in 0..options.power_iterations {
+        let z = apply_columns(&q, ncols, |x, y| a.apply_transpose(x, y))
+            .qr()
+            .q();
+        q = apply_columns(&z, nrows, |x, y| a.apply(x, y)).qr().q();
+    }
+
+    // `Bᵀ = Aᵀ Q`. The one-sided Jacobi SVD of the small matrix `B` is accurate even for its
+    // smallest singular values.
+    let b_t = apply_columns(&q, ncols, |x, y| a.apply_transpose(x, y));
+    let svd = SVD::new_with_algorithm(b_t.transpose(), true, true, SVDAlgorithm::Jacobi);
+
+    let u = svd
+        .u
+        .expect("The left singular vectors have been computed.");
+    let v_t = svd
+        .v_t
+        .expect("The right singular vectors have been computed.");
+    SVD {
+        u: Some(q * u.columns(0, k)),
+        v_t: Some(v_t.rows(0, k).clone_owned()),
+        singular_values: svd.singular_values.rows(0, k).clone_owned(),
+    }
+}
+
+/// Applies `apply` to each column of `x`, and returns the results as the columns of a matrix
+/// with `nrows` rows.
+fn apply_columns<T: RealField>(
+    x: &DMatrix<T>,
+    nrows: usize,
+    mut apply: impl FnMut(&DVector<T>, &mut DVector<T>),
+) -> DMatrix<T> {
+    let mut result = DMatrix::zeros(nrows, x.ncols());
+    let mut y = DVector::zeros(nrows);
+    for (j, column) in x.column_iter().enumerate() {
+        apply(&column.clone_owned(), &mut y);
+        result.set_column(j, &y);
+    }
+    result
+}
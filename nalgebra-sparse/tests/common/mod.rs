@@ -1,7 +1,8 @@
 use nalgebra_sparse::csc::CscMatrix;
 use nalgebra_sparse::csr::CsrMatrix;
 use nalgebra_sparse::proptest::{csc, csr};
-use proptest::strategy::Strategy;
+use proptest::collection::vec;
+use proptest::strategy::{Just, Strategy};
 use std::convert::TryFrom;
 use std::fmt::Debug;
 use std::ops::RangeInclusive;
@@ -75,3 +76,14 @@ pub fn csc_strategy() -> impl Strategy<Value = CscMatrix<i32>> {
         PROPTEST_MAX_NNZ,
     )
 }
+
+/// A list of indices in `0 .. dim`, possibly with duplicates and in arbitrary order.
+pub fn index_list_strategy(dim: usize) -> impl Strategy<Value = Vec<usize>> {
+    let max_len = if dim == 0 { 0 } else { 2 * dim };
+    vec(0..dim.max(1), 0..=max_len)
+}
+
+/// A range `start .. end` of indices in `0 .. dim`, given as the pair `(start, end)`.
+pub fn range_strategy(dim: usize) -> impl Strategy<Value = (usize, usize)> {
+    (0..=dim).prop_flat_map(move |start| (Just(start), start..=dim))
+}
@@ -1,12 +1,16 @@
 use nalgebra::DMatrix;
 use nalgebra_sparse::csc::CscMatrix;
-use nalgebra_sparse::{SparseEntry, SparseEntryMut, SparseFormatErrorKind};
+use nalgebra_sparse::proptest::csc;
+use nalgebra_sparse::{block, SparseEntry, SparseEntryMut, SparseFormatErrorKind};
 
 use proptest::prelude::*;
 use proptest::sample::subsequence;
 
 use crate::assert_panics;
-use crate::common::csc_strategy;
+use crate::common::{
+    csc_strategy, index_list_strategy, range_strategy, PROPTEST_I32_VALUE_STRATEGY,
+    PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ,
+};
 
 use std::collections::HashSet;
 
@@ -602,4 +606,130 @@ proptest! {
         prop_assert_eq!(csc.nnz(), n);
         prop_assert_eq!(DMatrix::from(&csc), DMatrix::identity(n, n));
     }
+
+    #[test]
+    fn csc_select_rows_and_columns_agree_with_dense(
+        (csc, irows, icols) in csc_strategy()
+            .prop_flat_map(|matrix| {
+                let (nrows, ncols) = (matrix.nrows(), matrix.ncols());
+                (Just(matrix), index_list_strategy(nrows), index_list_strategy(ncols))
+            }))
+    {
+        let dense = DMatrix::from(&csc);
+        let selected_rows = csc.select_rows(&irows);
+        prop_assert_eq!(DMatrix::from(&selected_rows), dense.select_rows(&irows));
+        let selected_columns = csc.select_columns(&icols);
+        prop_assert_eq!(DMatrix::from(&selected_columns), dense.select_columns(&icols));
+        let selected = csc.select_rows(&irows).select_columns(&icols);
+        prop_assert_eq!(DMatrix::from(&selected), dense.select_rows(&irows).select_columns(&icols));
+    }
+
+    #[test]
+    fn csc_submatrix_agrees_with_dense(
+        (csc, (row_start, row_end), (col_start, col_end)) in csc_strategy()
+            .prop_flat_map(|matrix| {
+                let (nrows, ncols) = (matrix.nrows(), matrix.ncols());
+                (Just(matrix), range_strategy(nrows), range_strategy(ncols))
+            }))
+    {
+        let dense = DMatrix::from(&csc);
+        let expected = dense.slice((row_start, col_start), (row_end - row_start, col_end - col_start));
+        let submatrix = csc.submatrix(row_start..row_end, col_start..col_end);
+        prop_assert_eq!(DMatrix::from(&submatrix), expected);
+        prop_assert_eq!(csc.submatrix(.., ..), csc.clone());
+        prop_assert_eq!(csc.submatrix(row_start.., ..col_end), csc.submatrix(row_start..csc.nrows(), 0..col_end));
+    }
+
+    #[test]
+    fn csc_from_blocks_agrees_with_dense(
+        blocks in (PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM)
+            .prop_flat_map(|(r0, r1, c0, c1)| {
+                let block = |r, c| csc(PROPTEST_I32_VALUE_STRATEGY, r..=r, c..=c, PROPTEST_MAX_NNZ);
+                (block(r0, c0), block(r0, c1), block(r1, c0), block(r1, c1))
+            }))
+    {
+        let (a, b, c, d) = blocks;
+        let matrix = CscMatrix::from_blocks(&[&[Some(&a), Some(&b)], &[Some(&c), Some(&d)]]);
+        let (r0, c0) = (a.nrows(), a.ncols());
+        let expected = DMatrix::from_fn(r0 + c.nrows(), c0 + b.ncols(), |i, j| {
+            match (i < r0, j < c0) {
+                (true, true) => a.get_entry(i, j),
+                (true, false) => b.get_entry(i, j - c0),
+                (false, true) => c.get_entry(i - r0, j),
+                (false, false) => d.get_entry(i - r0, j - c0),
+            }.unwrap().into_value()
+        });
+        prop_assert_eq!(DMatrix::from(&matrix), expected.clone());
+        prop_assert_eq!(matrix.nnz(), a.nnz() + b.nnz() + c.nnz() + d.nnz());
+        prop_assert_eq!(block![a, b; c, d], matrix.clone());
+
+        let top = CscMatrix::hstack(&[&a, &b]);
+        let bottom = CscMatrix::hstack(&[&c, &d]);
+        prop_assert_eq!(CscMatrix::vstack(&[&top, &bottom]), matrix.clone());
+        let left = CscMatrix::vstack(&[&a, &c]);
+        let right = CscMatrix::vstack(&[&b, &d]);
+        prop_assert_eq!(CscMatrix::hstack(&[&left, &right]), matrix.clone());
+
+        // Taking the blocks apart again
+        let (r1, c1) = (d.nrows(), d.ncols());
+        prop_assert_eq!(matrix.submatrix(..r0, c0..), b);
+        prop_assert_eq!(matrix.submatrix(r0.., ..c0), c);
+        prop_assert_eq!(matrix.submatrix(r0..r0 + r1, c0..c0 + c1), d);
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn csc_from_blocks_with_zero_blocks() {
+    let a = CscMatrix::from(&DMatrix::from_row_slice(2, 2, &[4, 1,
+                                                          1, 3]));
+    let b = CscMatrix::from(&DMatrix::from_row_slice(1, 2, &[1, 2]));
+    let b_t = b.transpose();
+    let expected = DMatrix::from_row_slice(3, 3, &[4, 1, 1,
+                                                   1, 3, 2,
+                                                   1, 2, 0]);
+
+    let saddle_point = CscMatrix::from_blocks(&[&[Some(&a), Some(&b_t)], &[Some(&b), None]]);
+    assert_eq!(DMatrix::from(&saddle_point), expected);
+    assert_eq!(saddle_point.nnz(), 8);
+    assert_eq!(block![a, b.transpose(); b, _], saddle_point);
+    assert_eq!(block![a, b_t; b, _;], saddle_point);
+
+    // Block diagonal matrix
+    let block_diagonal = block![a, _; _, CscMatrix::identity(1)];
+    assert_eq!(DMatrix::from(&block_diagonal), DMatrix::from_row_slice(3, 3, &[4, 1, 0,
+                                                                               1, 3, 0,
+                                                                               0, 0, 1]));
+
+    // Selecting rows and columns several times and in arbitrary order
+    let selected = saddle_point.select_rows(&[2, 0, 2]).select_columns(&[1, 1]);
+    assert_eq!(DMatrix::from(&selected), DMatrix::from_row_slice(3, 2, &[2, 2,
+                                                                         1, 1,
+                                                                         2, 2]));
+    let empty = saddle_point.select_rows(&[]);
+    assert_eq!((empty.nrows(), empty.ncols()), (0, 3));
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn csc_slicing_and_blocks_panic_on_invalid_input() {
+    let a = CscMatrix::<i32>::identity(2);
+    let b = CscMatrix::<i32>::identity(3);
+    assert_panics!(a.select_rows(&[0, 2]));
+    assert_panics!(a.select_columns(&[2]));
+    assert_panics!(a.submatrix(1..3, ..));
+    assert_panics!(a.submatrix(.., 2..1));
+    assert_panics!(CscMatrix::hstack(&[&a, &b]));
+    assert_panics!(CscMatrix::vstack(&[&a, &b]));
+    assert_panics!(CscMatrix::<i32>::hstack(&[]));
+    // A block column made of zero blocks only
+    assert_panics!(CscMatrix::from_blocks(&[
+        &[Some(&a), None],
+        &[Some(&a), None]
+    ]));
+    // Block rows with different numbers of blocks
+    assert_panics!(CscMatrix::from_blocks(&[
+        &[Some(&a), Some(&a)],
+        &[Some(&a)]
+    ]));
 }
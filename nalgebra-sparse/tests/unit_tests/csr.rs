@@ -1,6 +1,7 @@
 use nalgebra::DMatrix;
 use nalgebra_sparse::csr::CsrMatrix;
-use nalgebra_sparse::{SparseEntry, SparseEntryMut, SparseFormatErrorKind};
+use nalgebra_sparse::proptest::csr;
+use nalgebra_sparse::{block, SparseEntry, SparseEntryMut, SparseFormatErrorKind};
 
 use proptest::prelude::*;
 use proptest::sample::subsequence;
@@ -8,7 +9,10 @@ use proptest::sample::subsequence;
 use super::test_data_examples::InvalidCsrDataExamples;
 
 use crate::assert_panics;
-use crate::common::csr_strategy;
+use crate::common::{
+    csr_strategy, index_list_strategy, range_strategy, PROPTEST_I32_VALUE_STRATEGY,
+    PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ,
+};
 
 use std::collections::HashSet;
 
@@ -694,4 +698,130 @@ proptest! {
         prop_assert_eq!(csr.nnz(), n);
         prop_assert_eq!(DMatrix::from(&csr), DMatrix::identity(n, n));
     }
+
+    #[test]
+    fn csr_select_rows_and_columns_agree_with_dense(
+        (csr, irows, icols) in csr_strategy()
+            .prop_flat_map(|matrix| {
+                let (nrows, ncols) = (matrix.nrows(), matrix.ncols());
+                (Just(matrix), index_list_strategy(nrows), index_list_strategy(ncols))
+            }))
+    {
+        let dense = DMatrix::from(&csr);
+        let selected_rows = csr.select_rows(&irows);
+        prop_assert_eq!(DMatrix::from(&selected_rows), dense.select_rows(&irows));
+        let selected_columns = csr.select_columns(&icols);
+        prop_assert_eq!(DMatrix::from(&selected_columns), dense.select_columns(&icols));
+        let selected = csr.select_rows(&irows).select_columns(&icols);
+        prop_assert_eq!(DMatrix::from(&selected), dense.select_rows(&irows).select_columns(&icols));
+    }
+
+    #[test]
+    fn csr_submatrix_agrees_with_dense(
+        (csr, (row_start, row_end), (col_start, col_end)) in csr_strategy()
+            .prop_flat_map(|matrix| {
+                let (nrows, ncols) = (matrix.nrows(), matrix.ncols());
+                (Just(matrix), range_strategy(nrows), range_strategy(ncols))
+            }))
+    {
+        let dense = DMatrix::from(&csr);
+        let expected = dense.slice((row_start, col_start), (row_end - row_start, col_end - col_start));
+        let submatrix = csr.submatrix(row_start..row_end, col_start..col_end);
+        prop_assert_eq!(DMatrix::from(&submatrix), expected);
+        prop_assert_eq!(csr.submatrix(.., ..), csr.clone());
+        prop_assert_eq!(csr.submatrix(row_start.., ..col_end), csr.submatrix(row_start..csr.nrows(), 0..col_end));
+    }
+
+    #[test]
+    fn csr_from_blocks_agrees_with_dense(
+        blocks in (PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM)
+            .prop_flat_map(|(r0, r1, c0, c1)| {
+                let block = |r, c| csr(PROPTEST_I32_VALUE_STRATEGY, r..=r, c..=c, PROPTEST_MAX_NNZ);
+                (block(r0, c0), block(r0, c1), block(r1, c0), block(r1, c1))
+            }))
+    {
+        let (a, b, c, d) = blocks;
+        let matrix = CsrMatrix::from_blocks(&[&[Some(&a), Some(&b)], &[Some(&c), Some(&d)]]);
+        let (r0, c0) = (a.nrows(), a.ncols());
+        let expected = DMatrix::from_fn(r0 + c.nrows(), c0 + b.ncols(), |i, j| {
+            match (i < r0, j < c0) {
+                (true, true) => a.get_entry(i, j),
+                (true, false) => b.get_entry(i, j - c0),
+                (false, true) => c.get_entry(i - r0, j),
+                (false, false) => d.get_entry(i - r0, j - c0),
+            }.unwrap().into_value()
+        });
+        prop_assert_eq!(DMatrix::from(&matrix), expected.clone());
+        prop_assert_eq!(matrix.nnz(), a.nnz() + b.nnz() + c.nnz() + d.nnz());
+        prop_assert_eq!(block![a, b; c, d], matrix.clone());
+
+        let top = CsrMatrix::hstack(&[&a, &b]);
+        let bottom = CsrMatrix::hstack(&[&c, &d]);
+        prop_assert_eq!(CsrMatrix::vstack(&[&top, &bottom]), matrix.clone());
+        let left = CsrMatrix::vstack(&[&a, &c]);
+        let right = CsrMatrix::vstack(&[&b, &d]);
+        prop_assert_eq!(CsrMatrix::hstack(&[&left, &right]), matrix.clone());
+
+        // Taking the blocks apart again
+        let (r1, c1) = (d.nrows(), d.ncols());
+        prop_assert_eq!(matrix.submatrix(..r0, c0..), b);
+        prop_assert_eq!(matrix.submatrix(r0.., ..c0), c);
+        prop_assert_eq!(matrix.submatrix(r0..r0 + r1, c0..c0 + c1), d);
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn csr_from_blocks_with_zero_blocks() {
+    let a = CsrMatrix::from(&DMatrix::from_row_slice(2, 2, &[4, 1,
+                                                          1, 3]));
+    let b = CsrMatrix::from(&DMatrix::from_row_slice(1, 2, &[1, 2]));
+    let b_t = b.transpose();
+    let expected = DMatrix::from_row_slice(3, 3, &[4, 1, 1,
+                                                   1, 3, 2,
+                                                   1, 2, 0]);
+
+    let saddle_point = CsrMatrix::from_blocks(&[&[Some(&a), Some(&b_t)], &[Some(&b), None]]);
+    assert_eq!(DMatrix::from(&saddle_point), expected);
+    assert_eq!(saddle_point.nnz(), 8);
+    assert_eq!(block![a, b.transpose(); b, _], saddle_point);
+    assert_eq!(block![a, b_t; b, _;], saddle_point);
+
+    // Block diagonal matrix
+    let block_diagonal = block![a, _; _, CsrMatrix::identity(1)];
+    assert_eq!(DMatrix::from(&block_diagonal), DMatrix::from_row_slice(3, 3, &[4, 1, 0,
+                                                                               1, 3, 0,
+                                                                               0, 0, 1]));
+
+    // Selecting rows and columns several times and in arbitrary order
+    let selected = saddle_point.select_rows(&[2, 0, 2]).select_columns(&[1, 1]);
+    assert_eq!(DMatrix::from(&selected), DMatrix::from_row_slice(3, 2, &[2, 2,
+                                                                         1, 1,
+                                                                         2, 2]));
+    let empty = saddle_point.select_rows(&[]);
+    assert_eq!((empty.nrows(), empty.ncols()), (0, 3));
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn csr_slicing_and_blocks_panic_on_invalid_input() {
+    let a = CsrMatrix::<i32>::identity(2);
+    let b = CsrMatrix::<i32>::identity(3);
+    assert_panics!(a.select_rows(&[0, 2]));
+    assert_panics!(a.select_columns(&[2]));
+    assert_panics!(a.submatrix(1..3, ..));
+    assert_panics!(a.submatrix(.., 2..1));
+    assert_panics!(CsrMatrix::hstack(&[&a, &b]));
+    assert_panics!(CsrMatrix::vstack(&[&a, &b]));
+    assert_panics!(CsrMatrix::<i32>::hstack(&[]));
+    // A block column made of zero blocks only
+    assert_panics!(CsrMatrix::from_blocks(&[
+        &[Some(&a), None],
+        &[Some(&a), None]
+    ]));
+    // Block rows with different numbers of blocks
+    assert_panics!(CsrMatrix::from_blocks(&[
+        &[Some(&a), Some(&a)],
+        &[Some(&a)]
+    ]));
 }
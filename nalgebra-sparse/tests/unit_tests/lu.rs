@@ -0,0 +1,192 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::factorization::{CscLu, CscSymbolicLu, LuError};
+use nalgebra_sparse::ordering::Permutation;
+use nalgebra_sparse::proptest::csc;
+use nalgebra::{DMatrix, Matrix4};
+use nalgebra::proptest::matrix;
+
+use proptest::prelude::*;
+use matrixcompare::{assert_matrix_eq, prop_assert_matrix_eq};
+
+fn invertible() -> impl Strategy<Value=CscMatrix<f64>> {
+    let square_csc_f64 = PROPTEST_MATRIX_DIM
+        .prop_flat_map(|n| csc(value_strategy::<f64>(), n..=n, n..=n, PROPTEST_MAX_NNZ));
+    square_csc_f64
+        .prop_map(|x| {
+            // Permute the columns of a diagonally dominant matrix, so that pivoting is required
+            // while the matrix is guaranteed to be invertible
+            let n = x.ncols();
+            let dominant = DMatrix::from(&x) + DMatrix::identity(n, n) * 100.0;
+            let permuted = DMatrix::from_fn(n, n, |i, j| dominant[(i, (j + 1) % n)]);
+            CscMatrix::from(&permuted)
+        })
+}
+
+/// Computes `P A Q` from the permutations of the LU factorization.
+fn permuted(matrix: &CscMatrix<f64>, lu: &CscLu<f64>) -> DMatrix<f64> {
+    let dense = DMatrix::from(matrix);
+    let (p, q) = (lu.row_permutation().indices(), lu.column_permutation().indices());
+    DMatrix::from_fn(dense.nrows(), dense.ncols(), |i, j| dense[(p[i], q[j])])
+}
+
+proptest! {
+    #[test]
+    fn lu_correct_for_invertible_matrices(
+        matrix in invertible()
+    ) {
+        let lu = CscLu::factor(&matrix).unwrap();
+        let l = lu.l();
+        let u = lu.u();
+        let lu_product = DMatrix::from(&(l * u));
+
+        prop_assert_matrix_eq!(lu_product, permuted(&matrix, &lu), comp = abs, tol = 1e-10);
+
+        let is_unit_lower_triangular = l.triplet_iter().all(|(i, j, v)| j < i || (i == j && *v == 1.0));
+        let is_upper_triangular = u.triplet_iter().all(|(i, j, _)| i <= j);
+        prop_assert!(is_unit_lower_triangular);
+        prop_assert!(is_upper_triangular);
+    }
+
+    #[test]
+    fn lu_solve_invertible(
+        (matrix, rhs) in invertible()
+            .prop_flat_map(|csc| {
+                let rhs = matrix(value_strategy::<f64>(), csc.nrows(), PROPTEST_MATRIX_DIM);
+                (Just(csc), rhs)
+            })
+    ) {
+        let lu = CscLu::factor(&matrix).unwrap();
+
+        // solve_mut
+        {
+            let mut x = rhs.clone();
+            lu.solve_mut(&mut x);
+            prop_assert_matrix_eq!(&matrix * &x, rhs, comp=abs, tol=1e-10);
+        }
+
+        // solve
+        {
+            let x = lu.solve(&rhs);
+            prop_assert_matrix_eq!(&matrix * &x, rhs, comp=abs, tol=1e-10);
+        }
+    }
+
+    #[test]
+    fn lu_with_column_permutation_solves_invertible(
+        (matrix, rhs) in invertible()
+            .prop_flat_map(|csc| {
+                let rhs = matrix(value_strategy::<f64>(), csc.nrows(), PROPTEST_MATRIX_DIM);
+                (Just(csc), rhs)
+            })
+    ) {
+        let n = matrix.ncols();
+        let col_perm = Permutation::try_from_indices((0..n).rev().collect()).unwrap();
+        let symbolic = CscSymbolicLu::factor_with_column_permutation(matrix.pattern().clone(), col_perm);
+        let lu = CscLu::factor_numerical(symbolic, matrix.values()).unwrap();
+
+        let lu_product = DMatrix::from(&(lu.l() * lu.u()));
+        prop_assert_matrix_eq!(lu_product, permuted(&matrix, &lu), comp = abs, tol = 1e-10);
+
+        let x = lu.solve(&rhs);
+        prop_assert_matrix_eq!(&matrix * &x, rhs, comp=abs, tol=1e-10);
+    }
+}
+
+#[test]
+fn lu_requires_pivoting() {
+    let a = Matrix4::new(
+        0.0, 2.0, 0.0, 1.0,
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 3.0, 0.0,
+        4.0, 1.0, 0.0, 0.0
+    );
+    let cs_a = CscMatrix::from(&a);
+    let lu = CscLu::factor(&cs_a).unwrap();
+
+    assert_matrix_eq!(DMatrix::from(&(lu.l() * lu.u())), permuted(&cs_a, &lu), comp = abs, tol = 1e-12);
+
+    let b = DMatrix::from_column_slice(4, 1, &[1.0, 2.0, 3.0, 4.0]);
+    let x = lu.solve(&b);
+    let expected = a.lu().solve(&b.fixed_slice::<4, 1>(0, 0).into_owned()).unwrap();
+    assert_matrix_eq!(x, expected, comp = abs, tol = 1e-12);
+}
+
+#[test]
+fn lu_refactor() {
+    let a = Matrix4::new(
+        4.0, 1.0, 0.0, 0.0,
+        1.0, 4.0, 1.0, 0.0,
+        0.0, 1.0, 4.0, 1.0,
+        0.0, 0.0, 1.0, 4.0
+    );
+    let cs_a = CscMatrix::from(&a);
+    let mut lu = CscLu::factor(&cs_a).unwrap();
+
+    // Same pattern, but the values now require pivoting
+    let b = Matrix4::new(
+        0.0, 1.0, 0.0, 0.0,
+        5.0, 0.0, 1.0, 0.0,
+        0.0, 2.0, 0.0, 1.0,
+        0.0, 0.0, 3.0, 7.0
+    );
+    let mut cs_b = cs_a.clone();
+    for (i, j, v) in cs_b.triplet_iter_mut() {
+        *v = b[(i, j)];
+    }
+
+    lu.refactor(cs_b.values()).unwrap();
+    assert_matrix_eq!(DMatrix::from(&(lu.l() * lu.u())), permuted(&cs_b, &lu), comp = abs, tol = 1e-12);
+
+    let rhs = DMatrix::from_column_slice(4, 2, &[1.0, 2.0, 3.0, 4.0, -1.0, 0.0, 5.0, 2.0]);
+    let x = lu.solve(&rhs);
+    assert_matrix_eq!(&cs_b * &x, rhs, comp = abs, tol = 1e-12);
+}
+
+#[test]
+fn lu_refactor_singular_keeps_previous_factorization() {
+    let a = Matrix4::new(
+        4.0, 1.0, 0.0, 0.0,
+        1.0, 4.0, 1.0, 0.0,
+        0.0, 1.0, 4.0, 1.0,
+        0.0, 0.0, 1.0, 4.0
+    );
+    let cs_a = CscMatrix::from(&a);
+    let mut lu = CscLu::factor(&cs_a).unwrap();
+
+    // Same pattern, but the last column is zero
+    let mut cs_b = cs_a.clone();
+    for (_, j, v) in cs_b.triplet_iter_mut() {
+        if j == 3 {
+            *v = 0.0;
+        }
+    }
+    assert_eq!(lu.refactor(cs_b.values()).unwrap_err(), LuError::Singular);
+
+    // The factorization of `a` is still usable
+    assert_matrix_eq!(DMatrix::from(&(lu.l() * lu.u())), permuted(&cs_a, &lu), comp = abs, tol = 1e-12);
+    let rhs = DMatrix::from_column_slice(4, 1, &[1.0, 2.0, 3.0, 4.0]);
+    let x = lu.solve(&rhs);
+    assert_matrix_eq!(&cs_a * &x, rhs, comp = abs, tol = 1e-12);
+}
+
+#[test]
+fn lu_singular() {
+    let a = Matrix4::new(
+        1.0, 2.0, 0.0, 0.0,
+        2.0, 4.0, 0.0, 0.0,
+        0.0, 0.0, 3.0, 0.0,
+        0.0, 0.0, 0.0, 4.0
+    );
+    assert_eq!(CscLu::factor(&CscMatrix::from(&a)).unwrap_err(), LuError::Singular);
+
+    // A structurally singular matrix (empty column)
+    let a = Matrix4::new(
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 3.0, 0.0,
+        0.0, 0.0, 0.0, 4.0
+    );
+    assert_eq!(CscLu::factor(&CscMatrix::from(&a)).unwrap_err(), LuError::Singular);
+}
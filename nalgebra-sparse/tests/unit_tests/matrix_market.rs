@@ -1,8 +1,18 @@
-use matrixcompare::assert_matrix_eq;
+use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use matrixcompare::{assert_matrix_eq, prop_assert_matrix_eq};
 use nalgebra::dmatrix;
+use nalgebra::proptest::matrix;
 use nalgebra::Complex;
-use nalgebra_sparse::io::load_coo_from_matrix_market_str;
-use nalgebra_sparse::CooMatrix;
+use nalgebra::DMatrix;
+use nalgebra_sparse::io::{
+    load_coo_from_matrix_market_file, load_coo_from_matrix_market_str,
+    load_coo_from_matrix_market_str_with_options, load_dmatrix_from_matrix_market_file,
+    load_dmatrix_from_matrix_market_str, save_to_matrix_market_file, save_to_matrix_market_str,
+    MatrixMarketErrorKind, MatrixMarketLoadOptions, StorageScheme,
+};
+use nalgebra_sparse::proptest::{coo_with_duplicates, csc, csr};
+use nalgebra_sparse::{CooMatrix, CscMatrix, CsrMatrix};
+use proptest::prelude::*;
 
 #[test]
 #[rustfmt::skip]
@@ -343,3 +353,336 @@ fn test_matrixmarket_dense_complex_general() {
     ];
     assert_matrix_eq!(sparse_mat, expected);
 }
+
+proptest! {
+    #[test]
+    fn save_and_load_sparse_general_roundtrip(
+        coo in coo_with_duplicates(value_strategy::<i32>(), PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ, 5)
+    ) {
+        let str = save_to_matrix_market_str(&coo, StorageScheme::General).unwrap();
+        let loaded = load_coo_from_matrix_market_str::<i32>(&str).unwrap();
+        prop_assert_eq!(loaded.nrows(), coo.nrows());
+        prop_assert_eq!(loaded.ncols(), coo.ncols());
+        prop_assert_eq!(loaded.nnz(), coo.nnz());
+        prop_assert_eq!(DMatrix::from(&loaded), DMatrix::from(&coo));
+    }
+
+    #[test]
+    fn save_and_load_csr_csc_roundtrip(
+        matrix in csr(-1e10..1e10f64, PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ)
+    ) {
+        let str = save_to_matrix_market_str(&matrix, StorageScheme::General).unwrap();
+        let loaded = CsrMatrix::from(&load_coo_from_matrix_market_str::<f64>(&str).unwrap());
+        // Real values are written exactly
+        prop_assert_eq!(&loaded, &matrix);
+
+        let csc = CscMatrix::from(&matrix);
+        let str = save_to_matrix_market_str(&csc, StorageScheme::General).unwrap();
+        let loaded = CscMatrix::from(&load_coo_from_matrix_market_str::<f64>(&str).unwrap());
+        prop_assert_eq!(loaded, csc);
+    }
+
+    #[test]
+    fn save_and_load_symmetric_roundtrip(
+        matrix in PROPTEST_MATRIX_DIM
+            .prop_flat_map(|n| csc(value_strategy::<i32>(), n..=n, n..=n, PROPTEST_MAX_NNZ))
+            .prop_map(|a| &a + &a.transpose())
+    ) {
+        let str = save_to_matrix_market_str(&matrix, StorageScheme::Symmetric).unwrap();
+        let loaded = load_coo_from_matrix_market_str::<i32>(&str).unwrap();
+        prop_assert_matrix_eq!(loaded, matrix);
+
+        let options = MatrixMarketLoadOptions::default().with_lower_triangle_only(true);
+        let lower = load_coo_from_matrix_market_str_with_options::<i32>(&str, &options).unwrap();
+        prop_assert_matrix_eq!(lower, matrix.lower_triangle());
+
+        let skew = &matrix.lower_triangle() - &matrix.upper_triangle();
+        let str = save_to_matrix_market_str(&skew, StorageScheme::Skew).unwrap();
+        let loaded = load_coo_from_matrix_market_str::<i32>(&str).unwrap();
+        prop_assert_matrix_eq!(loaded, skew);
+    }
+
+    #[test]
+    fn save_and_load_dense_roundtrip(
+        dense in matrix(value_strategy::<i32>(), PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM)
+    ) {
+        let str = save_to_matrix_market_str(&dense, StorageScheme::General).unwrap();
+        let loaded = load_coo_from_matrix_market_str::<i32>(&str).unwrap();
+        prop_assert_matrix_eq!(loaded, dense);
+        prop_assert_eq!(load_dmatrix_from_matrix_market_str::<i32>(&str).unwrap(), dense.clone());
+
+        if dense.is_square() {
+            let symmetric = &dense + &dense.transpose();
+            let str = save_to_matrix_market_str(&symmetric, StorageScheme::Symmetric).unwrap();
+            let loaded = load_coo_from_matrix_market_str::<i32>(&str).unwrap();
+            prop_assert_matrix_eq!(loaded, symmetric);
+            prop_assert_eq!(load_dmatrix_from_matrix_market_str::<i32>(&str).unwrap(), symmetric);
+
+            let skew = &dense - &dense.transpose();
+            let str = save_to_matrix_market_str(&skew, StorageScheme::Skew).unwrap();
+            let loaded = load_coo_from_matrix_market_str::<i32>(&str).unwrap();
+            prop_assert_matrix_eq!(loaded, skew);
+        }
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_save_sparse_real_general() {
+    let mut coo = CooMatrix::new(3, 2);
+    coo.push(0, 0, 1.5);
+    coo.push(2, 1, -2e-20);
+    coo.push(1, 0, 1e300);
+    let str = save_to_matrix_market_str(&coo, StorageScheme::General).unwrap();
+    assert_eq!(str, "%%MatrixMarket matrix coordinate real general\n3 2 3\n1 1 1.5e0\n3 2 -2e-20\n2 1 1e300\n");
+
+    let loaded = load_coo_from_matrix_market_str::<f64>(&str).unwrap();
+    assert_matrix_eq!(loaded, coo);
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_save_sparse_complex_hermitian() {
+    let expected = dmatrix![
+        Complex::<f64>{re:1.0,im:0.0}, Complex::<f64>{re:2.0,im:-2.0}, Complex::<f64>{re:0.0,im:0.0};
+        Complex::<f64>{re:2.0,im:2.0}, Complex::<f64>{re:5.0,im:0.0},  Complex::<f64>{re:0.0,im:-6.0};
+        Complex::<f64>{re:0.0,im:0.0}, Complex::<f64>{re:0.0,im:6.0},  Complex::<f64>{re:8.0,im:0.0};
+    ];
+    let csr = CsrMatrix::from(&expected);
+    let str = save_to_matrix_market_str(&csr, StorageScheme::Hermitian).unwrap();
+    assert!(str.starts_with("%%MatrixMarket matrix coordinate complex hermitian\n3 3 5\n"));
+
+    let loaded = load_coo_from_matrix_market_str::<Complex<f64>>(&str).unwrap();
+    assert_matrix_eq!(loaded, expected);
+
+    // The matrix is hermitian, but not symmetric
+    let result = save_to_matrix_market_str(&csr, StorageScheme::Symmetric);
+    assert_eq!(result.unwrap_err().kind(), MatrixMarketErrorKind::NotSymmetric);
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_save_sparse_pattern() {
+    let mut coo = CooMatrix::new(4, 4);
+    coo.push(0, 0, ());
+    coo.push(3, 1, ());
+    coo.push(1, 3, ());
+    let str = save_to_matrix_market_str(&coo, StorageScheme::Symmetric).unwrap();
+    assert_eq!(str, "%%MatrixMarket matrix coordinate pattern symmetric\n4 4 2\n1 1\n4 2\n");
+
+    let loaded = load_coo_from_matrix_market_str::<()>(&str).unwrap();
+    let mut entries: Vec<_> = loaded.triplet_iter().map(|(i, j, _)| (i, j)).collect();
+    entries.sort_unstable();
+    assert_eq!(entries, vec![(0, 0), (1, 3), (3, 1)]);
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_save_dense_complex() {
+    let dense = dmatrix![
+        Complex::<f32>{re:1.0,im:2.0}, Complex::<f32>{re:3.0,im:0.0};
+        Complex::<f32>{re:0.0,im:-1.0}, Complex::<f32>{re:0.5,im:0.25};
+    ];
+    let str = save_to_matrix_market_str(&dense, StorageScheme::General).unwrap();
+    assert_eq!(str, "%%MatrixMarket matrix array complex general\n2 2\n1e0 2e0\n0e0 -1e0\n3e0 0e0\n5e-1 2.5e-1\n");
+
+    let loaded = load_coo_from_matrix_market_str::<Complex<f32>>(&str).unwrap();
+    assert_matrix_eq!(loaded, dense);
+}
+
+#[test]
+fn test_matrixmarket_save_file() {
+    let dense = DMatrix::from_row_slice(2, 3, &[1i64, 2, 3, 4, 5, 6]);
+    let path = std::env::temp_dir().join("nalgebra_sparse_test_matrixmarket_save_file.mtx");
+    save_to_matrix_market_file(&dense, &path, StorageScheme::General).unwrap();
+    let loaded = load_coo_from_matrix_market_file::<i64, _>(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_matrix_eq!(loaded, dense);
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_save_errors() {
+    let kind = |result: Result<String, nalgebra_sparse::io::MatrixMarketError>| result.unwrap_err().kind();
+
+    // Non-square matrices only support the general storage scheme
+    let coo = CooMatrix::<f64>::new(2, 3);
+    assert_eq!(kind(save_to_matrix_market_str(&coo, StorageScheme::Symmetric)), MatrixMarketErrorKind::NonSquare);
+
+    // Real matrices can not be hermitian, and pattern matrices can not be skew-symmetric or dense
+    let coo = CooMatrix::<f64>::new(2, 2);
+    assert_eq!(kind(save_to_matrix_market_str(&coo, StorageScheme::Hermitian)), MatrixMarketErrorKind::InvalidHeader);
+    let coo = CooMatrix::<()>::new(2, 2);
+    assert_eq!(kind(save_to_matrix_market_str(&coo, StorageScheme::Skew)), MatrixMarketErrorKind::InvalidHeader);
+    let dense = DMatrix::from_element(2, 2, ());
+    assert_eq!(kind(save_to_matrix_market_str(&dense, StorageScheme::General)), MatrixMarketErrorKind::InvalidHeader);
+
+    // Skew-symmetric matrices have a zero diagonal, but explicit zeros are accepted
+    let dense = dmatrix![0, -1; 1, 0];
+    assert!(save_to_matrix_market_str(&dense, StorageScheme::Skew).is_ok());
+    let dense = dmatrix![1, -1; 1, 0];
+    assert_eq!(kind(save_to_matrix_market_str(&dense, StorageScheme::Skew)), MatrixMarketErrorKind::DiagonalError);
+    assert_eq!(kind(save_to_matrix_market_str(&dense, StorageScheme::Symmetric)), MatrixMarketErrorKind::NotSymmetric);
+
+    // Hermitian matrices have a real diagonal
+    let dense = dmatrix![Complex::new(1.0, 1.0)];
+    assert_eq!(kind(save_to_matrix_market_str(&dense, StorageScheme::Hermitian)), MatrixMarketErrorKind::DiagonalError);
+
+    // A missing mirrored entry, which is fine if the stored entry is an explicit zero
+    let mut coo = CooMatrix::new(2, 2);
+    coo.push(1, 0, 0.0);
+    assert!(save_to_matrix_market_str(&coo, StorageScheme::Symmetric).is_ok());
+    coo.push(0, 1, 2.0);
+    assert_eq!(kind(save_to_matrix_market_str(&coo, StorageScheme::Symmetric)), MatrixMarketErrorKind::NotSymmetric);
+}
+
+#[test]
+fn test_matrixmarket_save_symmetric_with_duplicates() {
+    // Duplicate entries are summed when checking the symmetry
+    let mut coo = CooMatrix::new(3, 3);
+    coo.push(1, 0, 2.0);
+    coo.push(1, 0, 1.0);
+    coo.push(0, 1, 3.0);
+    // Duplicates that cancel out do not need a mirrored entry
+    coo.push(2, 0, 1.0);
+    coo.push(2, 0, -1.0);
+    let str = save_to_matrix_market_str(&coo, StorageScheme::Symmetric).unwrap();
+
+    let loaded = load_coo_from_matrix_market_str::<f64>(&str).unwrap();
+    assert_eq!(DMatrix::from(&loaded), DMatrix::from(&coo));
+
+    let mut coo = CooMatrix::new(2, 2);
+    coo.push(1, 0, ());
+    coo.push(1, 0, ());
+    coo.push(0, 1, ());
+    assert!(save_to_matrix_market_str(&coo, StorageScheme::Symmetric).is_ok());
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_load_dmatrix_array() {
+    let file_str = r#"
+%%MatrixMarket matrix array real general
+%
+2 3
+1.0
+2.0
+3.0
+4.0
+5.0
+6.0
+"#;
+    let matrix = load_dmatrix_from_matrix_market_str::<f64>(file_str).unwrap();
+    assert_eq!(matrix, dmatrix![1.0, 3.0, 5.0;
+                                2.0, 4.0, 6.0]);
+
+    let file_str = r#"
+%%MatrixMarket matrix array integer symmetric
+3 3
+1
+2
+3
+4
+5
+6
+"#;
+    let matrix = load_dmatrix_from_matrix_market_str::<i64>(file_str).unwrap();
+    assert_eq!(matrix, dmatrix![1, 2, 3;
+                                2, 4, 5;
+                                3, 5, 6]);
+
+    let file_str = r#"
+%%MatrixMarket matrix array complex hermitian
+2 2
+1.0 0.0
+2.0 3.0
+4.0 0.0
+"#;
+    let matrix = load_dmatrix_from_matrix_market_str::<Complex<f64>>(file_str).unwrap();
+    assert_eq!(matrix, dmatrix![Complex::new(1.0, 0.0), Complex::new(2.0, -3.0);
+                                Complex::new(2.0, 3.0), Complex::new(4.0, 0.0)]);
+
+    // Empty matrices
+    let matrix = load_dmatrix_from_matrix_market_str::<f32>("%%MatrixMarket matrix array real skew-symmetric\n0 0").unwrap();
+    assert_eq!(matrix.shape(), (0, 0));
+    let matrix = load_dmatrix_from_matrix_market_str::<f32>("%%MatrixMarket matrix array real general\n0 3").unwrap();
+    assert_eq!(matrix.shape(), (0, 3));
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_load_dmatrix_coordinate() {
+    // Coordinate files are densified, and duplicate entries are summed
+    let file_str = r#"
+%%MatrixMarket matrix coordinate integer general
+2 2 3
+1 1 1
+2 1 5
+1 1 2
+"#;
+    let matrix = load_dmatrix_from_matrix_market_str::<i32>(file_str).unwrap();
+    assert_eq!(matrix, dmatrix![3, 0;
+                                5, 0]);
+
+    // Errors of the sparse loader are also reported
+    let file_str = r#"
+%%MatrixMarket matrix array real symmetric
+2 3
+1.0
+"#;
+    let result = load_dmatrix_from_matrix_market_str::<f64>(file_str);
+    assert_eq!(result.unwrap_err().kind(), MatrixMarketErrorKind::NonSquare);
+
+    let file_str = r#"
+%%MatrixMarket matrix array real general
+2 2
+1.0
+"#;
+    let result = load_dmatrix_from_matrix_market_str::<f64>(file_str);
+    assert_eq!(result.unwrap_err().kind(), MatrixMarketErrorKind::EntryMismatch);
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_load_lower_triangle_only() {
+    let file_str = r#"
+%%MatrixMarket matrix coordinate real skew-symmetric
+3 3 2
+2 1 1.0
+3 2 -2.0
+"#;
+    let options = MatrixMarketLoadOptions::default().with_lower_triangle_only(true);
+    let lower = load_coo_from_matrix_market_str_with_options::<f64>(file_str, &options).unwrap();
+    assert_eq!(lower.nnz(), 2);
+    assert_matrix_eq!(lower, dmatrix![0.0,  0.0, 0.0;
+                                      1.0,  0.0, 0.0;
+                                      0.0, -2.0, 0.0]);
+
+    // The stored entries must still be in the lower triangle
+    let file_str = r#"
+%%MatrixMarket matrix coordinate real symmetric
+3 3 1
+1 2 1.0
+"#;
+    let result = load_coo_from_matrix_market_str_with_options::<f64>(file_str, &options);
+    assert_eq!(result.unwrap_err().kind(), MatrixMarketErrorKind::NotLowerTriangle);
+
+    // General matrices are not affected
+    let file_str = r#"
+%%MatrixMarket matrix coordinate real general
+2 2 1
+1 2 1.0
+"#;
+    let matrix = load_coo_from_matrix_market_str_with_options::<f64>(file_str, &options).unwrap();
+    assert_matrix_eq!(matrix, dmatrix![0.0, 1.0; 0.0, 0.0]);
+}
+
+#[test]
+fn test_matrixmarket_load_dmatrix_file() {
+    let dense = DMatrix::from_row_slice(3, 3, &[1.0, -2.0, 0.0, 2.0, 0.0, 3.5, 0.0, -3.5, 0.0]);
+    let path = std::env::temp_dir().join("nalgebra_sparse_test_matrixmarket_load_dmatrix_file.mtx");
+    save_to_matrix_market_file(&dense, &path, StorageScheme::General).unwrap();
+    let loaded = load_dmatrix_from_matrix_market_file::<f64, _>(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, dense);
+}
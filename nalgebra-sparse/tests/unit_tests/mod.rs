@@ -3,8 +3,12 @@ mod convert_serial;
 mod coo;
 mod csc;
 mod csr;
+mod lu;
 mod matrix_market;
 mod ops;
+mod ordering;
 mod pattern;
 mod proptest;
+mod solvers;
+mod spectral;
 mod test_data_examples;
@@ -2,11 +2,17 @@ use crate::common::{
     csc_strategy, csr_strategy, non_zero_i32_value_strategy, value_strategy,
     PROPTEST_I32_VALUE_STRATEGY, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ,
 };
+use nalgebra_sparse::coo::CooMatrix;
 use nalgebra_sparse::csc::CscMatrix;
 use nalgebra_sparse::csr::CsrMatrix;
+#[cfg(feature = "rayon")]
+use nalgebra_sparse::ops::parallel;
 use nalgebra_sparse::ops::serial::{
     spadd_csc_prealloc, spadd_csr_prealloc, spadd_pattern, spmm_csc_dense, spmm_csc_prealloc,
     spmm_csr_dense, spmm_csr_pattern, spmm_csr_prealloc, spsolve_csc_lower_triangular,
+    spsolve_csc_lower_triangular_sparse, spsolve_csc_upper_triangular,
+    spsolve_csc_upper_triangular_sparse, spsolve_csr_lower_triangular,
+    spsolve_csr_upper_triangular, OperationError, OperationErrorKind,
 };
 use nalgebra_sparse::ops::Op;
 use nalgebra_sparse::pattern::SparsityPattern;
@@ -1232,4 +1238,315 @@ proptest! {
         prop_assert_matrix_eq!(&a_lower.transpose() * &x, &b, comp = abs, tol = 1e-4);
     }
 
+    #[test]
+    fn csc_solve_upper_triangular(
+        (a, b, transpose)
+            in csc_square_with_non_zero_diagonals()
+                .prop_flat_map(|a| {
+                    let nrows = a.nrows();
+                    (Just(a), matrix(value_strategy::<f64>(), nrows, PROPTEST_MATRIX_DIM), trans_strategy())
+                }))
+    {
+        let a_upper = DMatrix::from(&a.upper_triangle());
+        let (op_a, op_a_upper) = if transpose {
+            (Op::Transpose(&a), a_upper.transpose())
+        } else {
+            (Op::NoOp(&a), a_upper)
+        };
+
+        let mut x = b.clone();
+        spsolve_csc_upper_triangular(op_a, &mut x).unwrap();
+        prop_assert_matrix_eq!(&op_a_upper * &x, &b, comp = abs, tol = 1e-4);
+    }
+
+    #[test]
+    fn csr_solve_triangular(
+        (a, b, transpose)
+            in csc_square_with_non_zero_diagonals()
+                .prop_flat_map(|a| {
+                    let nrows = a.nrows();
+                    (Just(CsrMatrix::from(&a)), matrix(value_strategy::<f64>(), nrows, PROPTEST_MATRIX_DIM), trans_strategy())
+                }))
+    {
+        let a_lower = DMatrix::from(&a.lower_triangle());
+        let a_upper = DMatrix::from(&a.upper_triangle());
+        let (op_a, op_a_lower, op_a_upper) = if transpose {
+            (Op::Transpose(&a), a_lower.transpose(), a_upper.transpose())
+        } else {
+            (Op::NoOp(&a), a_lower, a_upper)
+        };
+
+        let mut x = b.clone();
+        spsolve_csr_lower_triangular(op_a, &mut x).unwrap();
+        prop_assert_matrix_eq!(&op_a_lower * &x, &b, comp = abs, tol = 1e-4);
+
+        let mut x = b.clone();
+        spsolve_csr_upper_triangular(op_a, &mut x).unwrap();
+        prop_assert_matrix_eq!(&op_a_upper * &x, &b, comp = abs, tol = 1e-4);
+    }
+
+    #[test]
+    fn csc_solve_triangular_sparse_rhs(
+        (a, b, transpose)
+            in csc_square_with_non_zero_diagonals()
+                .prop_flat_map(|a| {
+                    let nrows = a.nrows();
+                    (Just(a), csc(value_strategy::<f64>(), nrows..=nrows, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ), trans_strategy())
+                }))
+    {
+        let op_a = if transpose { Op::Transpose(&a) } else { Op::NoOp(&a) };
+        let b_dense = DMatrix::from(&b);
+
+        let x = spsolve_csc_lower_triangular_sparse(op_a, &b).unwrap();
+        let mut x_dense = b_dense.clone();
+        spsolve_csc_lower_triangular(op_a, &mut x_dense).unwrap();
+        prop_assert_eq!(x.nrows(), b.nrows());
+        prop_assert_eq!(x.ncols(), b.ncols());
+        // The entries outside of the computed pattern are exactly zero
+        prop_assert_matrix_eq!(DMatrix::from(&x), x_dense, comp = abs, tol = 1e-8);
+
+        let x = spsolve_csc_upper_triangular_sparse(op_a, &b).unwrap();
+        let mut x_dense = b_dense.clone();
+        spsolve_csc_upper_triangular(op_a, &mut x_dense).unwrap();
+        prop_assert_matrix_eq!(DMatrix::from(&x), x_dense, comp = abs, tol = 1e-8);
+    }
+}
+
+#[test]
+fn csc_solve_triangular_sparse_rhs_only_computes_reachable_entries() {
+    // A lower bidiagonal matrix: the solution for `e_k` is non-zero in the rows k, k + 1, ...
+    let n = 6;
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 2.0);
+        if i + 1 < n {
+            coo.push(i + 1, i, -1.0);
+        }
+    }
+    let l = CscMatrix::from(&coo);
+    let mut b = CooMatrix::new(n, 2);
+    b.push(3, 0, 1.0);
+    b.push(5, 1, 4.0);
+    let b = CscMatrix::from(&b);
+
+    let x = spsolve_csc_lower_triangular_sparse(Op::NoOp(&l), &b).unwrap();
+    assert_eq!(x.col(0).row_indices(), &[3, 4, 5]);
+    assert_eq!(x.col(0).values(), &[0.5, 0.25, 0.125]);
+    assert_eq!(x.col(1).row_indices(), &[5]);
+    assert_eq!(x.col(1).values(), &[2.0]);
+
+    // The transposed matrix is upper triangular, and propagates towards the first rows
+    let x = spsolve_csc_lower_triangular_sparse(Op::Transpose(&l), &b).unwrap();
+    assert_eq!(x.col(0).row_indices(), &[0, 1, 2, 3]);
+    assert_eq!(x.col(1).row_indices(), &[0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn triangular_solves_report_singular_matrices() {
+    // The diagonal entry (1, 1) is missing, and (2, 2) is an explicit zero
+    let mut coo = CooMatrix::new(3, 3);
+    coo.push(0, 0, 1.0);
+    coo.push(1, 0, 1.0);
+    coo.push(2, 1, 1.0);
+    coo.push(2, 2, 0.0);
+    let csc = CscMatrix::from(&coo);
+    let csr = CsrMatrix::from(&coo);
+    let is_singular = |result: Result<(), OperationError>| {
+        matches!(
+            result.map_err(|e| *e.kind()),
+            Err(OperationErrorKind::Singular)
+        )
+    };
+
+    for op in [Op::NoOp(()), Op::Transpose(())] {
+        let b = || DMatrix::<f64>::zeros(3, 1);
+        assert!(is_singular(spsolve_csc_lower_triangular(
+            op.map_same_op(|_| &csc),
+            &mut b()
+        )));
+        assert!(is_singular(spsolve_csc_upper_triangular(
+            op.map_same_op(|_| &csc),
+            &mut b()
+        )));
+        assert!(is_singular(spsolve_csr_lower_triangular(
+            op.map_same_op(|_| &csr),
+            &mut b()
+        )));
+        assert!(is_singular(spsolve_csr_upper_triangular(
+            op.map_same_op(|_| &csr),
+            &mut b()
+        )));
+    }
+
+    // With a sparse right-hand side, only the diagonal entries that are involved are checked
+    let mut b = CooMatrix::new(3, 1);
+    b.push(0, 0, 1.0);
+    let result = spsolve_csc_lower_triangular_sparse(Op::NoOp(&csc), &CscMatrix::from(&b));
+    assert!(matches!(
+        result.unwrap_err().kind(),
+        OperationErrorKind::Singular
+    ));
+    let mut b = CooMatrix::new(3, 1);
+    b.push(2, 0, 1.0);
+    let result = spsolve_csc_upper_triangular_sparse(Op::NoOp(&csc), &CscMatrix::from(&b));
+    assert!(matches!(
+        result.unwrap_err().kind(),
+        OperationErrorKind::Singular
+    ));
+    let mut b = CooMatrix::new(3, 1);
+    b.push(0, 0, 1.0);
+    let x = spsolve_csc_upper_triangular_sparse(Op::NoOp(&csc), &CscMatrix::from(&b)).unwrap();
+    assert_eq!(x.col(0).row_indices(), &[0]);
+}
+
+#[cfg(feature = "rayon")]
+proptest! {
+    #[test]
+    fn parallel_spmm_csr_dense_agrees_with_serial(
+        SpmmCsrDenseArgs { c, beta, alpha, a, b }
+         in spmm_csr_dense_args_strategy()
+    ) {
+        let mut serial_result = c.clone();
+        spmm_csr_dense(beta, &mut serial_result, alpha, a.as_ref(), b.as_ref());
+
+        let mut parallel_result = c.clone();
+        parallel::spmm_csr_dense(beta, &mut parallel_result, alpha, a.as_ref(), b.as_ref());
+
+        prop_assert_eq!(parallel_result, serial_result);
+    }
+
+    #[test]
+    fn parallel_spmm_csc_dense_agrees_with_serial(
+        SpmmCscDenseArgs { c, beta, alpha, a, b }
+         in spmm_csc_dense_args_strategy()
+    ) {
+        let mut serial_result = c.clone();
+        spmm_csc_dense(beta, &mut serial_result, alpha, a.as_ref(), b.as_ref());
+
+        let mut parallel_result = c.clone();
+        parallel::spmm_csc_dense(beta, &mut parallel_result, alpha, a.as_ref(), b.as_ref());
+
+        prop_assert_eq!(parallel_result, serial_result);
+    }
+
+    #[test]
+    fn parallel_spadd_csr_prealloc_agrees_with_serial(
+        SpaddCsrArgs { c, beta, alpha, a } in spadd_csr_prealloc_args_strategy()
+    ) {
+        let mut serial_result = c.clone();
+        spadd_csr_prealloc(beta, &mut serial_result, alpha, a.as_ref()).unwrap();
+
+        let mut parallel_result = c.clone();
+        parallel::spadd_csr_prealloc(beta, &mut parallel_result, alpha, a.as_ref()).unwrap();
+
+        prop_assert_eq!(parallel_result, serial_result);
+    }
+
+    #[test]
+    fn parallel_spadd_csc_prealloc_agrees_with_serial(
+        SpaddCscArgs { c, beta, alpha, a } in spadd_csc_prealloc_args_strategy()
+    ) {
+        let mut serial_result = c.clone();
+        spadd_csc_prealloc(beta, &mut serial_result, alpha, a.as_ref()).unwrap();
+
+        let mut parallel_result = c.clone();
+        parallel::spadd_csc_prealloc(beta, &mut parallel_result, alpha, a.as_ref()).unwrap();
+
+        prop_assert_eq!(parallel_result, serial_result);
+    }
+
+    #[test]
+    fn parallel_spmm_csr_prealloc_agrees_with_serial(
+        SpmmCsrArgs { c, beta, alpha, a, b } in spmm_csr_prealloc_args_strategy()
+    ) {
+        let mut serial_result = c.clone();
+        spmm_csr_prealloc(beta, &mut serial_result, alpha, a.as_ref(), b.as_ref()).unwrap();
+
+        let mut parallel_result = c.clone();
+        parallel::spmm_csr_prealloc(beta, &mut parallel_result, alpha, a.as_ref(), b.as_ref()).unwrap();
+
+        prop_assert_eq!(parallel_result, serial_result);
+    }
+
+    #[test]
+    fn parallel_spmm_csc_prealloc_agrees_with_serial(
+        SpmmCscArgs { c, beta, alpha, a, b } in spmm_csc_prealloc_args_strategy()
+    ) {
+        let mut serial_result = c.clone();
+        spmm_csc_prealloc(beta, &mut serial_result, alpha, a.as_ref(), b.as_ref()).unwrap();
+
+        let mut parallel_result = c.clone();
+        parallel::spmm_csc_prealloc(beta, &mut parallel_result, alpha, a.as_ref(), b.as_ref()).unwrap();
+
+        prop_assert_eq!(parallel_result, serial_result);
+    }
+
+    #[test]
+    fn parallel_prealloc_reports_invalid_pattern(
+        (a, b) in (csr_strategy(), csr_strategy())
+            .prop_filter("Matrices must have compatible dimensions", |(a, b)| a.ncols() == b.nrows())
+    ) {
+        // An output with an empty pattern can only hold an empty product
+        let product = &a * &b;
+        let mut c = CsrMatrix::zeros(a.nrows(), b.ncols());
+        let result = parallel::spmm_csr_prealloc(1, &mut c, 1, Op::NoOp(&a), Op::NoOp(&b));
+        prop_assert_eq!(result.is_err(), product.nnz() > 0);
+
+        let mut c = CsrMatrix::zeros(a.nrows(), a.ncols());
+        let result = parallel::spadd_csr_prealloc(1, &mut c, 1, Op::NoOp(&a));
+        prop_assert_eq!(result.is_err(), a.nnz() > 0);
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_spmm_agrees_with_serial_for_large_real_matrices() {
+    // A banded matrix large enough to be split across threads
+    let n = 2000;
+    let a = CsrMatrix::from(&DMatrix::from_fn(n, n, |i, j| {
+        if i.abs_diff(j) <= 3 {
+            1.0 / (1.0 + i as f64 + 2.0 * j as f64)
+        } else {
+            0.0
+        }
+    }));
+    let b = DMatrix::from_fn(n, 3, |i, j| (i as f64 * 0.37 + j as f64).sin());
+
+    let mut serial_result = DMatrix::from_element(n, 3, 1.0);
+    let mut parallel_result = serial_result.clone();
+    spmm_csr_dense(
+        0.5,
+        &mut serial_result,
+        2.0,
+        Op::Transpose(&a),
+        Op::NoOp(&b),
+    );
+    parallel::spmm_csr_dense(
+        0.5,
+        &mut parallel_result,
+        2.0,
+        Op::Transpose(&a),
+        Op::NoOp(&b),
+    );
+    matrixcompare::assert_matrix_eq!(parallel_result, serial_result, comp = abs, tol = 1e-12);
+
+    let mut serial_result = a.clone();
+    let mut parallel_result = a.clone();
+    spmm_csr_prealloc(
+        0.5,
+        &mut serial_result,
+        2.0,
+        Op::NoOp(&a),
+        Op::NoOp(&CsrMatrix::identity(n)),
+    )
+    .unwrap();
+    parallel::spmm_csr_prealloc(
+        0.5,
+        &mut parallel_result,
+        2.0,
+        Op::NoOp(&a),
+        Op::NoOp(&CsrMatrix::identity(n)),
+    )
+    .unwrap();
+    assert_eq!(parallel_result, serial_result);
 }
@@ -0,0 +1,189 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::factorization::CscCholesky;
+use nalgebra_sparse::ordering::{amd, nested_dissection, reverse_cuthill_mckee, Permutation, PermutationError};
+use nalgebra_sparse::pattern::SparsityPattern;
+use nalgebra_sparse::proptest::{csc, csr, sparsity_pattern};
+use nalgebra::DMatrix;
+use nalgebra::proptest::matrix;
+
+use proptest::prelude::*;
+use matrixcompare::{assert_matrix_eq, prop_assert_matrix_eq};
+
+fn square_pattern() -> impl Strategy<Value=SparsityPattern> {
+    PROPTEST_MATRIX_DIM
+        .prop_flat_map(|n| sparsity_pattern(n..=n, n..=n, PROPTEST_MAX_NNZ))
+}
+
+fn positive_definite() -> impl Strategy<Value=CscMatrix<f64>> {
+    csc(value_strategy::<f64>(), PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ)
+        .prop_map(|x| x.transpose() * &x + CscMatrix::identity(x.ncols()))
+}
+
+fn is_permutation(permutation: &Permutation, n: usize) -> bool {
+    let mut indices = permutation.indices().to_vec();
+    indices.sort_unstable();
+    indices == (0..n).collect::<Vec<_>>()
+        && permutation.indices().iter().enumerate().all(|(i, &j)| permutation.inverse_indices()[j] == i)
+}
+
+/// The 5-point Laplacian on a `k x k` grid, with the vertices numbered in the given order.
+fn grid_laplacian(k: usize, numbering: impl Fn(usize) -> usize) -> CscMatrix<f64> {
+    let n = k * k;
+    let mut coo = CooMatrix::new(n, n);
+    for x in 0..k {
+        for y in 0..k {
+            let v = numbering(x * k + y);
+            coo.push(v, v, 4.0);
+            if x + 1 < k {
+                let w = numbering((x + 1) * k + y);
+                coo.push(v, w, -1.0);
+                coo.push(w, v, -1.0);
+            }
+            if y + 1 < k {
+                let w = numbering(x * k + y + 1);
+                coo.push(v, w, -1.0);
+                coo.push(w, v, -1.0);
+            }
+        }
+    }
+    CscMatrix::from(&coo)
+}
+
+fn bandwidth(matrix: &CscMatrix<f64>) -> usize {
+    matrix.triplet_iter()
+        .map(|(i, j, _)| i.abs_diff(j))
+        .max()
+        .unwrap_or(0)
+}
+
+proptest! {
+    #[test]
+    fn orderings_are_permutations(pattern in square_pattern()) {
+        let n = pattern.major_dim();
+        prop_assert!(is_permutation(&amd(&pattern), n));
+        prop_assert!(is_permutation(&reverse_cuthill_mckee(&pattern), n));
+        prop_assert!(is_permutation(&nested_dissection(&pattern), n));
+    }
+
+    #[test]
+    fn csr_permute_symmetric_matches_dense(
+        (matrix, permutation) in PROPTEST_MATRIX_DIM
+            .prop_flat_map(|n| csr(value_strategy::<i32>(), n..=n, n..=n, PROPTEST_MAX_NNZ))
+            .prop_flat_map(|m| {
+                let n = m.nrows();
+                (Just(m), Just((0..n).collect::<Vec<_>>()).prop_shuffle())
+            })
+    ) {
+        let permutation = Permutation::try_from_indices(permutation).unwrap();
+        let p = permutation.indices();
+        let dense = DMatrix::from(&matrix);
+        let expected = DMatrix::from_fn(dense.nrows(), dense.ncols(), |i, j| dense[(p[i], p[j])]);
+
+        let permuted = matrix.permute_symmetric(&permutation);
+        prop_assert_eq!(permuted.nnz(), matrix.nnz());
+        prop_assert_matrix_eq!(permuted, expected);
+
+        let csc = CscMatrix::from(&matrix).permute_symmetric(&permutation);
+        prop_assert_matrix_eq!(csc, expected);
+
+        // Applying the inverse permutation recovers the original matrix
+        prop_assert_eq!(permuted.permute_symmetric(&permutation.inverse()), matrix);
+    }
+
+    #[test]
+    fn cholesky_with_ordering_solves_positive_definite(
+        (matrix, rhs) in positive_definite()
+            .prop_flat_map(|csc| {
+                let rhs = matrix(value_strategy::<f64>(), csc.nrows(), PROPTEST_MATRIX_DIM);
+                (Just(csc), rhs)
+            })
+    ) {
+        let permutation = amd(matrix.pattern());
+        let mut cholesky = CscCholesky::factor_with_permutation(&matrix, permutation.clone()).unwrap();
+        prop_assert_eq!(cholesky.permutation(), Some(&permutation));
+
+        // L L^T = P A P^T
+        let l = cholesky.l();
+        let permuted = matrix.permute_symmetric(&permutation);
+        prop_assert_matrix_eq!(l * l.transpose(), permuted, comp = abs, tol = 1e-8);
+
+        let x = cholesky.solve(&rhs);
+        prop_assert_matrix_eq!(&matrix * &x, rhs, comp = abs, tol = 1e-6);
+
+        // Refactoring with the values of the unpermuted matrix
+        let scaled = &matrix * 2.0;
+        cholesky.refactor(scaled.values()).unwrap();
+        let x = cholesky.solve(&rhs);
+        prop_assert_matrix_eq!(&scaled * &x, rhs, comp = abs, tol = 1e-6);
+    }
+}
+
+#[test]
+fn permutation_try_from_indices() {
+    let permutation = Permutation::try_from_indices(vec![2, 0, 1]).unwrap();
+    assert_eq!(permutation.len(), 3);
+    assert_eq!(permutation.indices(), &[2, 0, 1]);
+    assert_eq!(permutation.inverse_indices(), &[1, 2, 0]);
+    assert_eq!(permutation.inverse().indices(), &[1, 2, 0]);
+    assert_eq!(Permutation::identity(3).indices(), &[0, 1, 2]);
+    assert!(Permutation::identity(0).is_empty());
+
+    assert_eq!(Permutation::try_from_indices(vec![0, 3, 1]), Err(PermutationError::IndexOutOfBounds));
+    assert_eq!(Permutation::try_from_indices(vec![0, 1, 1]), Err(PermutationError::DuplicateIndex));
+}
+
+#[test]
+fn amd_reduces_fill_on_grid_laplacian() {
+    let matrix = grid_laplacian(12, |v| v);
+    let natural = CscCholesky::factor(&matrix).unwrap();
+    let ordered = CscCholesky::factor_with_permutation(&matrix, amd(matrix.pattern())).unwrap();
+    assert!(ordered.l().nnz() < natural.l().nnz());
+
+    let dissected = CscCholesky::factor_with_permutation(&matrix, nested_dissection(matrix.pattern())).unwrap();
+    assert!(dissected.l().nnz() < natural.l().nnz());
+}
+
+#[test]
+fn rcm_reduces_bandwidth_of_shuffled_grid() {
+    let k = 10;
+    let n = k * k;
+    // A deterministic scrambling of the vertices (37 is coprime with 100)
+    let matrix = grid_laplacian(k, |v| (37 * v + 11) % n);
+    assert!(bandwidth(&matrix) > 2 * k);
+
+    let permuted = matrix.permute_symmetric(&reverse_cuthill_mckee(matrix.pattern()));
+    assert!(bandwidth(&permuted) <= 2 * k);
+}
+
+#[test]
+fn orderings_handle_disconnected_graphs() {
+    // Two disconnected paths, interleaved
+    let n = 20;
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 4.0);
+        if i + 2 < n {
+            coo.push(i, i + 2, -1.0);
+            coo.push(i + 2, i, -1.0);
+        }
+    }
+    let matrix = CsrMatrix::from(&coo);
+
+    for permutation in [amd(matrix.pattern()), reverse_cuthill_mckee(matrix.pattern()), nested_dissection(matrix.pattern())] {
+        assert!(is_permutation(&permutation, n));
+        let permuted = CscMatrix::from(&matrix.permute_symmetric(&permutation));
+        let cholesky = CscCholesky::factor(&permuted).unwrap();
+        // A path does not produce any fill-in when ordered well, and each ordering should
+        // at least not produce a dense factor
+        assert!(cholesky.l().nnz() < n * (n + 1) / 2);
+    }
+
+    let permuted = CscMatrix::from(&matrix.permute_symmetric(&reverse_cuthill_mckee(matrix.pattern())));
+    let cholesky = CscCholesky::factor(&permuted).unwrap();
+    assert_eq!(cholesky.l().nnz(), 2 * n - 2);
+    assert_matrix_eq!(cholesky.l() * cholesky.l().transpose(), permuted, comp = abs, tol = 1e-12);
+}
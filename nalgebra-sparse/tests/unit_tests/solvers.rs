@@ -0,0 +1,458 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::proptest::csr;
+use nalgebra_sparse::solvers::{bicgstab, bicgstab_preconditioned, conjugate_gradient,
+                                conjugate_gradient_preconditioned, gmres, gmres_preconditioned, minres,
+                                minres_preconditioned, Ic0, Ilu0, Jacobi, LinearOperator, Preconditioner,
+                                PreconditionerError, SolverOptions, SolverReport, StopReason};
+use nalgebra::{DMatrix, DVector};
+use nalgebra::proptest::vector;
+
+use proptest::prelude::*;
+
+fn positive_definite() -> impl Strategy<Value=CsrMatrix<f64>> {
+    csr(value_strategy::<f64>(), PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ)
+        .prop_map(|x| x.transpose() * &x + CsrMatrix::identity(x.ncols()))
+}
+
+/// Non-symmetric matrices that are strictly diagonally dominant, hence invertible.
+fn diagonally_dominant() -> impl Strategy<Value=CsrMatrix<f64>> {
+    PROPTEST_MATRIX_DIM
+        .prop_flat_map(|n| csr(value_strategy::<f64>(), n..=n, n..=n, PROPTEST_MAX_NNZ))
+        .prop_map(|x| {
+            let n = x.nrows();
+            x + CsrMatrix::identity(n) * (5.0 * n as f64 + 1.0)
+        })
+}
+
+/// Symmetric matrices that are indefinite, but invertible.
+fn symmetric_indefinite() -> impl Strategy<Value=CsrMatrix<f64>> {
+    positive_definite()
+        .prop_map(|a| {
+            // The block diagonal matrix with blocks A and -A
+            let n = a.nrows();
+            let dense = DMatrix::from(&a);
+            let mut blocks = DMatrix::zeros(2 * n, 2 * n);
+            blocks.slice_mut((0, 0), (n, n)).copy_from(&dense);
+            blocks.slice_mut((n, n), (n, n)).copy_from(&(-dense));
+            CsrMatrix::from(&blocks)
+        })
+}
+
+/// Symmetric matrices that are strictly diagonally dominant with a positive diagonal.
+fn symmetric_diagonally_dominant() -> impl Strategy<Value=CsrMatrix<f64>> {
+    diagonally_dominant()
+        .prop_map(|a| &a + a.transpose())
+}
+
+/// Tridiagonal matrices that are strictly diagonally dominant, for which incomplete
+/// factorizations are exact.
+fn tridiagonal() -> impl Strategy<Value=CsrMatrix<f64>> {
+    // The dense solvers used for comparison do not support empty matrices
+    (1..=*PROPTEST_MATRIX_DIM.end())
+        .prop_flat_map(|n| {
+            let off_diagonal = vector(value_strategy::<f64>(), n.saturating_sub(1));
+            (Just(n), off_diagonal.clone(), off_diagonal)
+        })
+        .prop_map(|(n, lower, upper)| {
+            let mut coo = CooMatrix::new(n, n);
+            for i in 0..n {
+                coo.push(i, i, 11.0);
+                if i + 1 < n {
+                    coo.push(i + 1, i, lower[i]);
+                    coo.push(i, i + 1, upper[i]);
+                }
+            }
+            CsrMatrix::from(&coo)
+        })
+}
+
+fn with_rhs(matrix: impl Strategy<Value=CsrMatrix<f64>>) -> impl Strategy<Value=(CsrMatrix<f64>, DVector<f64>)> {
+    matrix.prop_flat_map(|a| {
+        let n = a.nrows();
+        (Just(a), vector(value_strategy::<f64>(), n))
+    })
+}
+
+fn relative_residual(a: &CsrMatrix<f64>, b: &DVector<f64>, x: &DVector<f64>) -> f64 {
+    if b.norm() == 0.0 {
+        x.norm()
+    } else {
+        (a * x - b).norm() / b.norm()
+    }
+}
+
+fn check_report(report: &SolverReport<f64>) {
+    assert!(report.converged(), "{:?}", report);
+    assert_eq!(report.residual_history.len(), report.iterations + 1);
+}
+
+fn apply(preconditioner: &impl Preconditioner<f64>, r: &DVector<f64>) -> DVector<f64> {
+    let mut z = DVector::zeros(r.len());
+    preconditioner.apply(r, &mut z);
+    z
+}
+
+/// The 5-point Laplacian on a `k x k` grid.
+fn grid_laplacian(k: usize) -> CsrMatrix<f64> {
+    let n = k * k;
+    let mut coo = CooMatrix::new(n, n);
+    for x in 0..k {
+        for y in 0..k {
+            let v = x * k + y;
+            coo.push(v, v, 4.0);
+            if x + 1 < k {
+                coo.push(v, v + k, -1.0);
+                coo.push(v + k, v, -1.0);
+            }
+            if y + 1 < k {
+                coo.push(v, v + 1, -1.0);
+                coo.push(v + 1, v, -1.0);
+            }
+        }
+    }
+    CsrMatrix::from(&coo)
+}
+
+/// The 1D Laplacian of size `n`.
+fn laplacian(n: usize) -> CooMatrix<f64> {
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 2.0);
+        if i + 1 < n {
+            coo.push(i, i + 1, -1.0);
+            coo.push(i + 1, i, -1.0);
+        }
+    }
+    coo
+}
+
+proptest! {
+    #[test]
+    fn cg_solves_positive_definite((a, b) in with_rhs(positive_definite())) {
+        let mut x = DVector::zeros(b.len());
+        let options = SolverOptions::new(1e-10, 100);
+        let report = conjugate_gradient(&a, &b, &mut x, &options);
+        check_report(&report);
+        prop_assert!(relative_residual(&a, &b, &x) <= 1e-8);
+    }
+
+    #[test]
+    fn minres_solves_symmetric_indefinite((a, b) in with_rhs(symmetric_indefinite())) {
+        let mut x = DVector::zeros(b.len());
+        let options = SolverOptions::new(1e-10, 100);
+        let report = minres(&a, &b, &mut x, &options);
+        check_report(&report);
+        prop_assert!(relative_residual(&a, &b, &x) <= 1e-8);
+    }
+
+    #[test]
+    fn bicgstab_solves_diagonally_dominant((a, b) in with_rhs(diagonally_dominant())) {
+        let mut x = DVector::zeros(b.len());
+        let options = SolverOptions::new(1e-10, 100);
+        let report = bicgstab(&a, &b, &mut x, &options);
+        check_report(&report);
+        prop_assert!(relative_residual(&a, &b, &x) <= 1e-8);
+    }
+
+    #[test]
+    fn gmres_solves_diagonally_dominant((a, b) in with_rhs(diagonally_dominant()), restart in 1..8usize) {
+        let mut x = DVector::zeros(b.len());
+        let options = SolverOptions::new(1e-10, 200);
+        let report = gmres(&a, &b, &mut x, restart, &options);
+        check_report(&report);
+        prop_assert!(relative_residual(&a, &b, &x) <= 1e-8);
+    }
+
+    #[test]
+    fn full_gmres_converges_in_at_most_n_iterations((a, b) in with_rhs(diagonally_dominant())) {
+        let n = b.len();
+        let mut x = DVector::zeros(n);
+        let options = SolverOptions::new(1e-10, n);
+        let report = gmres(&a, &b, &mut x, n.max(1), &options);
+        check_report(&report);
+        prop_assert!(report.iterations <= n);
+    }
+
+    #[test]
+    fn ilu0_matches_matrix_on_pattern(a in diagonally_dominant()) {
+        let ilu = Ilu0::from_csr(&a).unwrap();
+        prop_assert_eq!(ilu.lu().pattern(), a.pattern());
+
+        let lu = DMatrix::from(ilu.lu());
+        let n = a.nrows();
+        let l = DMatrix::from_fn(n, n, |i, j| if i == j { 1.0 } else if j < i { lu[(i, j)] } else { 0.0 });
+        let u = lu.upper_triangle();
+        let product = l * u;
+        for (i, j, v) in a.triplet_iter() {
+            prop_assert!((product[(i, j)] - v).abs() <= 1e-10);
+        }
+
+        let csc = Ilu0::from_csc(&CscMatrix::from(&a)).unwrap();
+        prop_assert_eq!(csc.lu(), ilu.lu());
+    }
+
+    #[test]
+    fn ic0_matches_matrix_on_pattern(a in symmetric_diagonally_dominant()) {
+        let ic = Ic0::from_csr(&a).unwrap();
+        let lower = a.lower_triangle();
+        prop_assert_eq!(ic.l().pattern(), lower.pattern());
+
+        let l = DMatrix::from(ic.l());
+        let product = &l * l.transpose();
+        for (i, j, v) in a.triplet_iter() {
+            prop_assert!((product[(i, j)] - v).abs() <= 1e-10);
+        }
+
+        let csc = Ic0::from_csc(&CscMatrix::from(&a)).unwrap();
+        prop_assert_eq!(csc.l(), ic.l());
+    }
+
+    #[test]
+    fn ic0_refactor_matches_factorization(a in symmetric_diagonally_dominant()) {
+        let scaled = &a * 2.0;
+        let mut ic = Ic0::from_csr(&a).unwrap();
+        ic.refactor(&scaled).unwrap();
+        let expected = Ic0::from_csr(&scaled).unwrap();
+        prop_assert_eq!(ic.l(), expected.l());
+    }
+
+    #[test]
+    fn incomplete_factorizations_are_exact_for_tridiagonal_matrices((a, r) in with_rhs(tridiagonal())) {
+        let dense = DMatrix::from(&a);
+        let expected = dense.clone().lu().solve(&r).unwrap();
+        let z = apply(&Ilu0::from_csr(&a).unwrap(), &r);
+        prop_assert!((z - &expected).norm() <= 1e-10 * (1.0 + expected.norm()));
+
+        let symmetric = &a + a.transpose();
+        let dense = DMatrix::from(&symmetric);
+        let expected = dense.clone().cholesky().unwrap().solve(&r);
+        let z = apply(&Ic0::from_csr(&symmetric).unwrap(), &r);
+        prop_assert!((z - &expected).norm() <= 1e-10 * (1.0 + expected.norm()));
+    }
+
+    #[test]
+    fn preconditioned_solvers_solve((a, b) in with_rhs(symmetric_diagonally_dominant())) {
+        let options = SolverOptions::new(1e-10, 100);
+        let jacobi = Jacobi::from_csr(&a).unwrap();
+        let ic = Ic0::from_csr(&a).unwrap();
+        let ilu = Ilu0::from_csr(&a).unwrap();
+        let n = b.len();
+
+        let mut x = DVector::zeros(n);
+        check_report(&conjugate_gradient_preconditioned(&a, &b, &mut x, &ic, &options));
+        prop_assert!(relative_residual(&a, &b, &x) <= 1e-8);
+
+        let mut x = DVector::zeros(n);
+        check_report(&minres_preconditioned(&a, &b, &mut x, &jacobi, &options));
+        prop_assert!(relative_residual(&a, &b, &x) <= 1e-8);
+
+        let mut x = DVector::zeros(n);
+        check_report(&bicgstab_preconditioned(&a, &b, &mut x, &ilu, &options));
+        prop_assert!(relative_residual(&a, &b, &x) <= 1e-8);
+
+        let mut x = DVector::zeros(n);
+        check_report(&gmres_preconditioned(&a, &b, &mut x, &ilu, 5, &options));
+        prop_assert!(relative_residual(&a, &b, &x) <= 1e-8);
+    }
+}
+
+#[test]
+fn solvers_accept_csr_csc_and_dense_operators() {
+    let n = 50;
+    let coo = laplacian(n);
+    let csr = CsrMatrix::from(&coo);
+    let csc = CscMatrix::from(&coo);
+    let dense = DMatrix::from(&coo);
+    let b = DVector::from_fn(n, |i, _| (i as f64).sin());
+    let options = SolverOptions::default().with_tolerance(1e-10);
+
+    let operators: [&dyn LinearOperator<f64>; 3] = [&csr, &csc, &dense];
+    for a in operators {
+        let mut y = DVector::zeros(n);
+        a.apply(&b, &mut y);
+        assert!((&y - &csr * &b).norm() <= 1e-12);
+
+        let solvers: [fn(&dyn LinearOperator<f64>, &DVector<f64>, &mut DVector<f64>, &SolverOptions<f64>) -> SolverReport<f64>; 4] = [
+            |a, b, x, options| conjugate_gradient(a, b, x, options),
+            |a, b, x, options| minres(a, b, x, options),
+            |a, b, x, options| bicgstab(a, b, x, options),
+            |a, b, x, options| gmres(a, b, x, 20, options),
+        ];
+        for solve in solvers {
+            let mut x = DVector::zeros(n);
+            let report = solve(a, &b, &mut x, &options);
+            check_report(&report);
+            assert!(relative_residual(&csr, &b, &x) <= 1e-8);
+        }
+    }
+}
+
+#[test]
+fn cg_converges_in_n_iterations_on_laplacian() {
+    let n = 30;
+    let a = CsrMatrix::from(&laplacian(n));
+    let b = DVector::from_element(n, 1.0);
+    let mut x = DVector::zeros(n);
+    let report = conjugate_gradient(&a, &b, &mut x, &SolverOptions::new(1e-12, 1000));
+    check_report(&report);
+    assert!(report.iterations <= n);
+    assert!(report.residual_norm() <= 1e-12 * b.norm());
+}
+
+#[test]
+fn solvers_stop_at_iteration_limit() {
+    let n = 100;
+    let a = CsrMatrix::from(&laplacian(n));
+    let b = DVector::from_element(n, 1.0);
+    let options = SolverOptions::new(1e-12, 5);
+
+    let reports = [
+        conjugate_gradient(&a, &b, &mut DVector::zeros(n), &options),
+        minres(&a, &b, &mut DVector::zeros(n), &options),
+        bicgstab(&a, &b, &mut DVector::zeros(n), &options),
+        gmres(&a, &b, &mut DVector::zeros(n), 3, &options),
+    ];
+    for report in &reports {
+        assert_eq!(report.stop_reason, StopReason::MaxIterations);
+        assert_eq!(report.iterations, 5);
+        assert_eq!(report.residual_history.len(), 6);
+        assert_eq!(report.residual_history[0], b.norm());
+    }
+}
+
+#[test]
+fn solvers_use_initial_guess() {
+    let n = 20;
+    let a = CsrMatrix::from(&laplacian(n));
+    let solution = DVector::from_fn(n, |i, _| i as f64);
+    let b = &a * &solution;
+    let options = SolverOptions::default();
+
+    let mut x = solution.clone();
+    let report = conjugate_gradient(&a, &b, &mut x, &options);
+    assert!(report.converged());
+    assert_eq!(report.iterations, 0);
+    assert_eq!(x, solution);
+}
+
+#[test]
+fn solvers_handle_zero_rhs() {
+    let n = 10;
+    let a = CsrMatrix::from(&laplacian(n));
+    let b = DVector::zeros(n);
+    let options = SolverOptions::default();
+
+    let mut x = DVector::from_element(n, 1.0);
+    let report = gmres(&a, &b, &mut x, 5, &options);
+    assert!(report.converged());
+    assert_eq!(report.iterations, 0);
+    assert_eq!(x, DVector::zeros(n));
+}
+
+#[test]
+fn cg_breaks_down_on_negative_definite_matrix() {
+    let n = 10;
+    let a = CsrMatrix::identity(n) * -1.0;
+    let b = DVector::from_element(n, 1.0);
+    let mut x = DVector::zeros(n);
+    let report = conjugate_gradient(&a, &b, &mut x, &SolverOptions::default());
+    assert_eq!(report.stop_reason, StopReason::Breakdown);
+    assert!(!report.converged());
+
+    // MINRES handles symmetric indefinite matrices
+    let report = minres(&a, &b, &mut x, &SolverOptions::default());
+    assert!(report.converged());
+    assert!((&a * &x - &b).norm() <= 1e-8);
+}
+
+#[test]
+fn jacobi_divides_by_diagonal() {
+    let a = CsrMatrix::from(&DMatrix::from_row_slice(3, 3, &[
+        2.0, 1.0, 0.0,
+        1.0, 4.0, 0.0,
+        0.0, 3.0, -5.0
+    ]));
+    let jacobi = Jacobi::from_csr(&a).unwrap();
+    let z = apply(&jacobi, &DVector::from_column_slice(&[1.0, 2.0, 10.0]));
+    assert_eq!(z, DVector::from_column_slice(&[0.5, 0.5, -2.0]));
+    assert_eq!(jacobi.inverse_diagonal(), Jacobi::from_csc(&CscMatrix::from(&a)).unwrap().inverse_diagonal());
+}
+
+#[test]
+fn preconditioners_reject_missing_diagonal() {
+    let a = CsrMatrix::from(&DMatrix::from_row_slice(2, 2, &[
+        0.0, 1.0,
+        1.0, 1.0
+    ]));
+    assert_eq!(Jacobi::from_csr(&a).unwrap_err(), PreconditionerError::ZeroPivot);
+    assert_eq!(Ilu0::from_csr(&a).unwrap_err(), PreconditionerError::ZeroPivot);
+    assert_eq!(Ic0::from_csr(&a).unwrap_err(), PreconditionerError::ZeroPivot);
+
+    let indefinite = CsrMatrix::from(&DMatrix::from_row_slice(2, 2, &[
+        1.0, 2.0,
+        2.0, 1.0
+    ]));
+    assert_eq!(Ic0::from_csr(&indefinite).unwrap_err(), PreconditionerError::NotPositiveDefinite);
+}
+
+#[test]
+#[should_panic(expected = "The sparsity pattern of the matrix must match the one of the factored matrix.")]
+fn ic0_refactor_rejects_different_pattern() {
+    let mut ic = Ic0::from_csr(&CsrMatrix::<f64>::identity(2)).unwrap();
+    let a = CsrMatrix::from(&DMatrix::from_row_slice(2, 2, &[
+        2.0, 1.0,
+        1.0, 2.0
+    ]));
+    let _ = ic.refactor(&a);
+}
+
+#[test]
+fn preconditioners_reduce_iterations_on_grid_laplacian() {
+    let a = grid_laplacian(20);
+    let n = a.nrows();
+    let b = DVector::from_fn(n, |i, _| ((i * 7) % 11) as f64 - 5.0);
+    let options = SolverOptions::new(1e-10, 1000);
+
+    let plain = conjugate_gradient(&a, &b, &mut DVector::zeros(n), &options);
+    let ic = conjugate_gradient_preconditioned(&a, &b, &mut DVector::zeros(n), &Ic0::from_csr(&a).unwrap(), &options);
+    check_report(&plain);
+    check_report(&ic);
+    assert!(ic.iterations < plain.iterations);
+
+    let plain = gmres(&a, &b, &mut DVector::zeros(n), 30, &options);
+    let ilu = gmres_preconditioned(&a, &b, &mut DVector::zeros(n), &Ilu0::from_csr(&a).unwrap(), 30, &options);
+    check_report(&plain);
+    check_report(&ilu);
+    assert!(ilu.iterations < plain.iterations);
+
+    let plain = bicgstab(&a, &b, &mut DVector::zeros(n), &options);
+    let ilu = bicgstab_preconditioned(&a, &b, &mut DVector::zeros(n), &Ilu0::from_csr(&a).unwrap(), &options);
+    check_report(&plain);
+    check_report(&ilu);
+    assert!(ilu.iterations < plain.iterations);
+}
+
+#[test]
+fn jacobi_handles_badly_scaled_matrices() {
+    // Scale the rows and columns of the Laplacian symmetrically
+    let a = grid_laplacian(10);
+    let n = a.nrows();
+    let scaling = DVector::from_fn(n, |i, _| 10f64.powi((i % 5) as i32));
+    let mut scaled = a.clone();
+    for (i, j, v) in scaled.triplet_iter_mut() {
+        *v *= scaling[i] * scaling[j];
+    }
+    let b = DVector::from_element(n, 1.0);
+    let options = SolverOptions::new(1e-10, 1000);
+
+    let plain = minres(&scaled, &b, &mut DVector::zeros(n), &options);
+    let mut x = DVector::zeros(n);
+    let jacobi = minres_preconditioned(&scaled, &b, &mut x, &Jacobi::from_csr(&scaled).unwrap(), &options);
+    check_report(&jacobi);
+    assert!(jacobi.iterations < plain.iterations);
+    assert!(relative_residual(&scaled, &b, &x) <= 1e-6);
+}
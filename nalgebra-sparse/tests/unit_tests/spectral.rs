@@ -0,0 +1,386 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::proptest::csr;
+use nalgebra_sparse::solvers::TransposeLinearOperator;
+use nalgebra_sparse::spectral::{arnoldi, lanczos, randomized_svd, EigenOptions, RandomizedSvdOptions, Which};
+use nalgebra::{Complex, ComplexField, DMatrix, DVector, Eigen, SymmetricEigen, SVD};
+
+use proptest::prelude::*;
+
+fn symmetric() -> impl Strategy<Value=CsrMatrix<f64>> {
+    // The dense decompositions used for comparison do not support empty matrices
+    (1..=*PROPTEST_MATRIX_DIM.end())
+        .prop_flat_map(|n| csr(value_strategy::<f64>(), n..=n, n..=n, PROPTEST_MAX_NNZ))
+        .prop_map(|x| &x + x.transpose())
+}
+
+/// Non-symmetric matrices with disjoint Gershgorin discs, hence with well-separated real
+/// eigenvalues, which are also well-separated in magnitude.
+fn separated_spectrum() -> impl Strategy<Value=CsrMatrix<f64>> {
+    (1..=*PROPTEST_MATRIX_DIM.end())
+        .prop_flat_map(|n| csr(value_strategy::<f64>(), n..=n, n..=n, PROPTEST_MAX_NNZ))
+        .prop_map(|x| {
+            let n = x.nrows();
+            let radius = 5.0 * n as f64;
+            let diagonal = DMatrix::from_diagonal(&DVector::from_fn(n, |i, _| (i as f64 - 1.7) * 4.0 * (radius + 1.0)));
+            x + CsrMatrix::from(&diagonal)
+        })
+}
+
+fn nonempty() -> impl Strategy<Value=CsrMatrix<f64>> {
+    let dim = 1..=*PROPTEST_MATRIX_DIM.end();
+    csr(value_strategy::<f64>(), dim.clone(), dim, PROPTEST_MAX_NNZ)
+}
+
+/// The 1D Laplacian, whose eigenvalues are `2 - 2 cos(j π / (n + 1))` for `j = 1, ..., n`.
+fn laplacian(n: usize) -> CsrMatrix<f64> {
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 2.0);
+        if i + 1 < n {
+            coo.push(i, i + 1, -1.0);
+            coo.push(i + 1, i, -1.0);
+        }
+    }
+    CsrMatrix::from(&coo)
+}
+
+/// The sorted eigenvalues of the 1D Laplacian.
+fn laplacian_eigenvalues(n: usize) -> Vec<f64> {
+    (1..=n)
+        .map(|j| 2.0 - 2.0 * (j as f64 * std::f64::consts::PI / (n + 1) as f64).cos())
+        .collect()
+}
+
+/// A deterministic dense matrix with pseudo-random entries.
+fn pseudo_random(nrows: usize, ncols: usize) -> DMatrix<f64> {
+    DMatrix::from_fn(nrows, ncols, |i, j| ((i * ncols + j) as f64 * 1.618).sin())
+}
+
+/// A matrix `U Σ Vᵀ` with the singular values `σ_j = 2^(-j)`.
+fn decaying_singular_values(nrows: usize, ncols: usize) -> DMatrix<f64> {
+    let r = nrows.min(ncols);
+    let u = pseudo_random(nrows, r).qr().q();
+    let v = pseudo_random(ncols, r).qr().q();
+    let sigma = DMatrix::from_diagonal(&DVector::from_fn(r, |j, _| 0.5f64.powi(j as i32)));
+    u * sigma * v.transpose()
+}
+
+fn sorted(mut values: Vec<f64>) -> Vec<f64> {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    values
+}
+
+fn check_symmetric_eigen(a: &DMatrix<f64>, eigen: &SymmetricEigen<f64, nalgebra::Dynamic>, tol: f64) {
+    let k = eigen.eigenvalues.len();
+    let x = &eigen.eigenvectors;
+    assert_eq!(x.shape(), (a.nrows(), k));
+    assert!((x.transpose() * x - DMatrix::identity(k, k)).amax() <= tol);
+    let scale = a.amax().max(1.0);
+    assert!((a * x - x * DMatrix::from_diagonal(&eigen.eigenvalues)).amax() <= tol * scale);
+}
+
+fn check_eigen(a: &DMatrix<f64>, eigen: &Eigen<f64, nalgebra::Dynamic>, tol: f64) {
+    let k = eigen.eigenvalues.len();
+    let x = &eigen.eigenvectors;
+    assert_eq!(x.shape(), (a.nrows(), k));
+    assert!(eigen.left_eigenvectors.is_none());
+    let a = a.map(|e| Complex::new(e, 0.0));
+    let scale = a.camax().max(1.0);
+    for j in 0..k {
+        let x = x.column(j);
+        assert!((x.norm() - 1.0).abs() <= tol);
+        assert!((&a * x - x * eigen.eigenvalues[j]).camax() <= tol * scale);
+    }
+}
+
+fn check_svd(svd: &SVD<f64, nalgebra::Dynamic, nalgebra::Dynamic>, nrows: usize, ncols: usize, k: usize) {
+    let u = svd.u.as_ref().unwrap();
+    let v_t = svd.v_t.as_ref().unwrap();
+    assert_eq!(u.shape(), (nrows, k));
+    assert_eq!(v_t.shape(), (k, ncols));
+    assert_eq!(svd.singular_values.len(), k);
+    assert!((u.transpose() * u - DMatrix::identity(k, k)).amax() <= 1e-10);
+    assert!((v_t * v_t.transpose() - DMatrix::identity(k, k)).amax() <= 1e-10);
+    assert!(svd.singular_values.as_slice().windows(2).all(|w| w[0] >= w[1]));
+}
+
+proptest! {
+    #[test]
+    fn lanczos_matches_dense_eigenvalues(a in symmetric(), k in 1..=6usize) {
+        let n = a.nrows();
+        let k = k.min(n);
+        let dense = DMatrix::from(&a);
+        let expected = sorted(SymmetricEigen::new(dense.clone()).eigenvalues.iter().cloned().collect());
+        let scale = dense.amax().max(1.0);
+
+        let eigen = lanczos(&a, k, Which::LargestReal, &EigenOptions::default()).unwrap();
+        check_symmetric_eigen(&dense, &eigen, 1e-8);
+        for (lambda, expected) in eigen.eigenvalues.iter().zip(expected.iter().rev()) {
+            prop_assert!((lambda - expected).abs() <= 1e-8 * scale);
+        }
+
+        let eigen = lanczos(&a, k, Which::SmallestReal, &EigenOptions::default()).unwrap();
+        check_symmetric_eigen(&dense, &eigen, 1e-8);
+        for (lambda, expected) in eigen.eigenvalues.iter().zip(&expected) {
+            prop_assert!((lambda - expected).abs() <= 1e-8 * scale);
+        }
+    }
+
+    #[test]
+    fn arnoldi_matches_dense_eigenvalues(a in separated_spectrum(), k in 1..=6usize) {
+        let n = a.nrows();
+        let k = k.min(n);
+        let dense = DMatrix::from(&a);
+        let expected: Vec<f64> = Eigen::new(dense.clone()).eigenvalues.iter().map(|e| e.re).collect();
+        let scale = dense.amax().max(1.0);
+
+        for which in [Which::LargestMagnitude, Which::SmallestMagnitude, Which::LargestReal, Which::SmallestReal] {
+            let eigen = arnoldi(&a, k, which, &EigenOptions::default()).unwrap();
+            prop_assert_eq!(eigen.eigenvalues.len(), k);
+            check_eigen(&dense, &eigen, 1e-8);
+
+            let mut expected = expected.clone();
+            match which {
+                Which::LargestMagnitude => expected.sort_by(|a, b| b.abs().partial_cmp(&a.abs()).unwrap()),
+                Which::SmallestMagnitude => expected.sort_by(|a, b| a.abs().partial_cmp(&b.abs()).unwrap()),
+                Which::LargestReal => expected.sort_by(|a, b| b.partial_cmp(a).unwrap()),
+                Which::SmallestReal => expected.sort_by(|a, b| a.partial_cmp(b).unwrap()),
+            }
+            for (lambda, expected) in eigen.eigenvalues.iter().zip(&expected) {
+                prop_assert_eq!(lambda.im, 0.0);
+                prop_assert!((lambda.re - expected).abs() <= 1e-8 * scale);
+            }
+        }
+    }
+
+    #[test]
+    fn randomized_svd_is_exact_without_truncation(a in nonempty()) {
+        let (nrows, ncols) = (a.nrows(), a.ncols());
+        let k = nrows.min(ncols);
+        let dense = DMatrix::from(&a);
+        let expected = SVD::new(dense.clone(), false, false).singular_values;
+
+        let svd = randomized_svd(&a, k, &RandomizedSvdOptions::default());
+        check_svd(&svd, nrows, ncols, k);
+        prop_assert!((&svd.singular_values - expected).amax() <= 1e-10 * dense.amax().max(1.0));
+        prop_assert!((svd.recompose().unwrap() - dense).amax() <= 1e-10 * a.nnz().max(1) as f64);
+    }
+}
+
+#[test]
+fn csr_csc_and_dense_operators_apply_transpose() {
+    let a = pseudo_random(7, 4);
+    let csr = CsrMatrix::from(&a);
+    let csc = CscMatrix::from(&a);
+    let x = DVector::from_fn(7, |i, _| i as f64 - 3.0);
+
+    let operators: [&dyn TransposeLinearOperator<f64>; 3] = [&csr, &csc, &a];
+    for op in operators {
+        let mut y = DVector::zeros(4);
+        op.apply_transpose(&x, &mut y);
+        assert!((y - a.tr_mul(&x)).amax() <= 1e-12);
+    }
+}
+
+#[test]
+fn lanczos_restarts_on_large_laplacian() {
+    let n = 200;
+    let a = laplacian(n);
+    let expected = laplacian_eigenvalues(n);
+    let dense = DMatrix::from(&a);
+
+    let eigen = lanczos(&a, 4, Which::LargestReal, &EigenOptions::default()).unwrap();
+    check_symmetric_eigen(&dense, &eigen, 1e-8);
+    for (lambda, expected) in eigen.eigenvalues.iter().zip(expected.iter().rev()) {
+        assert!((lambda - expected).abs() <= 1e-8);
+    }
+
+    // The smallest eigenvalues of the Laplacian are poorly separated relative to the spread of the
+    // spectrum, and need a larger subspace
+    let options = EigenOptions::default().with_subspace_dimension(40);
+    let eigen = lanczos(&a, 3, Which::SmallestMagnitude, &options).unwrap();
+    check_symmetric_eigen(&dense, &eigen, 1e-8);
+    for (lambda, expected) in eigen.eigenvalues.iter().zip(&expected) {
+        assert!((lambda - expected).abs() <= 1e-8);
+    }
+}
+
+#[test]
+fn lanczos_recompose_is_low_rank_approximation() {
+    let a = decaying_singular_values(80, 80);
+    let a = &a * a.transpose();
+    let eigen = lanczos(&a, 10, Which::LargestMagnitude, &EigenOptions::default()).unwrap();
+    let error = (eigen.recompose() - &a).norm();
+    // The eigenvalues of A are 4^(-j)
+    assert!(error <= 1.1 * 0.25f64.powi(10) * (1.0 / (1.0 - 0.0625f64)).sqrt());
+}
+
+#[test]
+fn lanczos_handles_zero_and_small_operators() {
+    let zero = CsrMatrix::<f64>::zeros(50, 50);
+    let eigen = lanczos(&zero, 3, Which::LargestMagnitude, &EigenOptions::default()).unwrap();
+    assert_eq!(eigen.eigenvalues, DVector::zeros(3));
+    check_symmetric_eigen(&DMatrix::zeros(50, 50), &eigen, 1e-12);
+
+    let a = DMatrix::from_diagonal(&DVector::from_vec(vec![3.0, -5.0, 1.0]));
+    let eigen = lanczos(&a, 3, Which::LargestMagnitude, &EigenOptions::default()).unwrap();
+    assert!((eigen.eigenvalues - DVector::from_vec(vec![-5.0, 3.0, 1.0])).amax() <= 1e-12);
+}
+
+#[test]
+fn lanczos_eigenvectors_with_nearly_equal_ritz_values() {
+    // The projection of this matrix has two close eigenvalues, whose eigenvectors were inaccurate.
+    let a = DMatrix::from_row_slice(3, 3, &[
+        -8.746344978930681, 0.06835019301601163, 0.0,
+        0.06835019301601163, 0.0, 0.0,
+        0.0, 0.0, 0.0,
+    ]);
+
+    for seed in 0..8 {
+        let options = EigenOptions::default().with_seed(seed);
+        for which in [Which::LargestReal, Which::SmallestReal] {
+            let eigen = lanczos(&a, 3, which, &options).unwrap();
+            check_symmetric_eigen(&a, &eigen, 1e-12);
+        }
+    }
+}
+
+#[test]
+fn lanczos_gives_up_at_restart_limit() {
+    let a = laplacian(500);
+    let options = EigenOptions::default().with_max_restarts(0);
+    assert!(lanczos(&a, 3, Which::SmallestReal, &options).is_none());
+}
+
+#[test]
+fn arnoldi_finds_complex_eigenvalues_of_large_matrix() {
+    let n = 200;
+    let dense = pseudo_random(n, n);
+    let a = CsrMatrix::from(&dense);
+    let expected = Eigen::new(dense.clone()).eigenvalues;
+    let mut expected_moduli: Vec<f64> = expected.iter().map(|e| e.modulus()).collect();
+    expected_moduli.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+    let eigen = arnoldi(&a, 6, Which::LargestMagnitude, &EigenOptions::default()).unwrap();
+    check_eigen(&dense, &eigen, 1e-8);
+    for (lambda, expected) in eigen.eigenvalues.iter().zip(&expected_moduli) {
+        assert!((lambda.modulus() - expected).abs() <= 1e-8 * expected_moduli[0]);
+    }
+
+    // Conjugate pairs are consecutive
+    let mut j = 0;
+    while j < eigen.eigenvalues.len() {
+        let lambda = eigen.eigenvalues[j];
+        if lambda.im == 0.0 {
+            j += 1;
+        } else {
+            assert!((eigen.eigenvalues[j + 1] - lambda.conj()).modulus() <= 1e-8 * expected_moduli[0]);
+            j += 2;
+        }
+    }
+}
+
+#[test]
+fn arnoldi_finds_rightmost_eigenvalues() {
+    // An upper triangular matrix with eigenvalues 1, ..., n, and a non-normal upper part
+    let n = 150;
+    let dense = DMatrix::from_fn(n, n, |i, j| {
+        if i == j { (i + 1) as f64 } else if j == i + 1 { 1.0 } else { 0.0 }
+    });
+    let a = CsrMatrix::from(&dense);
+
+    let eigen = arnoldi(&a, 3, Which::LargestReal, &EigenOptions::default()).unwrap();
+    check_eigen(&dense, &eigen, 1e-8);
+    for (j, lambda) in eigen.eigenvalues.iter().enumerate() {
+        assert!((lambda - Complex::new((n - j) as f64, 0.0)).modulus() <= 1e-8 * n as f64);
+    }
+
+    let eigen = arnoldi(&a, 2, Which::SmallestReal, &EigenOptions::default().with_max_restarts(3000)).unwrap();
+    check_eigen(&dense, &eigen, 1e-8);
+    for (j, lambda) in eigen.eigenvalues.iter().enumerate() {
+        assert!((lambda - Complex::new((j + 1) as f64, 0.0)).modulus() <= 1e-8 * n as f64);
+    }
+}
+
+#[test]
+fn arnoldi_matches_lanczos_on_symmetric_operators() {
+    let a = laplacian(100);
+    let symmetric = lanczos(&a, 5, Which::LargestReal, &EigenOptions::default()).unwrap();
+    let general = arnoldi(&a, 5, Which::LargestReal, &EigenOptions::default()).unwrap();
+    assert_eq!(general.eigenvalues.len(), 5);
+    for j in 0..5 {
+        assert_eq!(general.eigenvalues[j].im, 0.0);
+        assert!((general.eigenvalues[j].re - symmetric.eigenvalues[j]).abs() <= 1e-8);
+    }
+}
+
+#[test]
+fn randomized_svd_approximates_top_singular_triplets() {
+    let (nrows, ncols, k) = (300, 120, 8);
+    let dense = decaying_singular_values(nrows, ncols);
+    let csr = CsrMatrix::from(&dense);
+    let csc = CscMatrix::from(&dense);
+
+    let operators: [&dyn TransposeLinearOperator<f64>; 3] = [&csr, &csc, &dense];
+    for a in operators {
+        let svd = randomized_svd(a, k, &RandomizedSvdOptions::default());
+        check_svd(&svd, nrows, ncols, k);
+        for j in 0..k {
+            assert!((svd.singular_values[j] - 0.5f64.powi(j as i32)).abs() <= 1e-10);
+        }
+
+        // Singular vectors: A v_j = σ_j u_j
+        let u = svd.u.as_ref().unwrap();
+        let v_t = svd.v_t.as_ref().unwrap();
+        for j in 0..k {
+            let mut av = DVector::zeros(nrows);
+            a.apply(&v_t.row(j).transpose(), &mut av);
+            assert!((av - u.column(j) * svd.singular_values[j]).norm() <= 1e-10);
+        }
+
+        // The best rank-k approximation has error σ_k
+        let error = (svd.recompose().unwrap() - &dense).norm();
+        let optimal = DVector::from_fn(ncols - k, |j, _| 0.5f64.powi((j + k) as i32)).norm();
+        assert!(error <= 1.01 * optimal);
+    }
+}
+
+#[test]
+fn power_iterations_improve_randomized_svd_for_slow_decay() {
+    // Singular values decaying like 1 / (j + 1)
+    let (nrows, ncols, k) = (200, 150, 5);
+    let u = pseudo_random(nrows, ncols).qr().q();
+    let v = pseudo_random(ncols, ncols).qr().q();
+    let sigma = DMatrix::from_diagonal(&DVector::from_fn(ncols, |j, _| 1.0 / (j + 1) as f64));
+    let dense = u * sigma * v.transpose();
+    let expected = SVD::new(dense.clone(), false, false).singular_values;
+
+    let error = |power_iterations| {
+        let options = RandomizedSvdOptions::default().with_power_iterations(power_iterations).with_seed(7);
+        let svd = randomized_svd(&dense, k, &options);
+        check_svd(&svd, nrows, ncols, k);
+        (svd.singular_values - expected.rows(0, k)).amax()
+    };
+    let error_0 = error(0);
+    let error_4 = error(4);
+    assert!(error_4 < error_0);
+    assert!(error_4 <= 1e-3);
+}
+
+#[test]
+#[should_panic]
+fn randomized_svd_panics_on_too_many_singular_values() {
+    let a = pseudo_random(5, 3);
+    randomized_svd(&a, 4, &RandomizedSvdOptions::default());
+}
+
+#[test]
+#[should_panic]
+fn lanczos_panics_on_too_small_subspace() {
+    let a = laplacian(100);
+    lanczos(&a, 10, Which::LargestReal, &EigenOptions::default().with_subspace_dimension(10));
+}
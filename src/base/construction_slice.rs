@@ -1,9 +1,87 @@
-use crate::base::dimension::{Const, Dim, DimName, Dynamic};
+use std::fmt;
+
+use crate::base::dimension::{Const, Dim, DimName, Dynamic, U1};
 use crate::base::matrix_slice::{SliceStorage, SliceStorageMut};
 use crate::base::{MatrixSlice, MatrixSliceMutMN, Scalar};
 
 use num_rational::Ratio;
 
+/// The error returned by the checked constructors of matrix slices from `&[T]` and `&mut [T]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SliceError {
+    /// The input data buffer does not contain enough elements for the requested dimensions and
+    /// strides.
+    BufferTooSmall {
+        /// The minimal number of elements required, saturated to `usize::MAX` if it overflows.
+        required: usize,
+        /// The number of elements of the input data buffer.
+        len: usize,
+    },
+    /// The dimensions and strides of a mutable matrix slice result in several indices referring
+    /// to the same element of the input data buffer.
+    AliasedIndices,
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SliceError::BufferTooSmall { required, len } => write!(
+                f,
+                "Matrix slice: input data buffer too small ({} elements required, {} given).",
+                required, len
+            ),
+            SliceError::AliasedIndices => write!(
+                f,
+                "Matrix slice: dimensions and strides result in aliased indices."
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SliceError {}
+
+/// Checks that a data buffer of length `len` is large enough to contain a matrix with the given
+/// dimensions and strides.
+fn check_buffer_len(
+    len: usize,
+    nrows: usize,
+    ncols: usize,
+    rstride: usize,
+    cstride: usize,
+) -> Result<(), SliceError> {
+    let required = if nrows == 0 || ncols == 0 {
+        0
+    } else {
+        (nrows - 1)
+            .saturating_mul(rstride)
+            .saturating_add((ncols - 1).saturating_mul(cstride))
+            .saturating_add(1)
+    };
+
+    if len >= required {
+        Ok(())
+    } else {
+        Err(SliceError::BufferTooSmall { required, len })
+    }
+}
+
+/// Checks whether the given dimensions and strides result in distinct matrix indices referring
+/// to the same element of the data buffer.
+fn has_aliased_indices(nrows: usize, ncols: usize, rstride: usize, cstride: usize) -> bool {
+    nrows.saturating_mul(ncols) > 1
+        && match (rstride, cstride) {
+            (0, 0) => true,      // matrix[(0, 0)] == index[(nrows - 1, ncols - 1)],
+            (0, _) => nrows > 1, // matrix[(0, 0)] == index[(nrows - 1, 0)],
+            (_, 0) => ncols > 1, // matrix[(0, 0)] == index[(0, ncols - 1)],
+            (_, _) => {
+                // matrix[(0, numer)] == index[(denom, 0)]
+                let ratio = Ratio::new(rstride, cstride);
+                nrows > *ratio.denom() && ncols > *ratio.numer()
+            }
+        }
+}
+
 /// # Creating matrix slices from `&[T]`
 impl<'a, T: Scalar, R: Dim, C: Dim, RStride: Dim, CStride: Dim>
     MatrixSlice<'a, T, R, C, RStride, CStride>
@@ -55,6 +133,49 @@ impl<'a, T: Scalar, R: Dim, C: Dim, RStride: Dim, CStride: Dim>
             Self::from_slice_with_strides_generic_unchecked(data, 0, nrows, ncols, rstride, cstride)
         }
     }
+
+    /// Creates a matrix slice from an array and with dimensions and strides specified by generic types instances.
+    ///
+    /// Returns an error if the input data array does not contain enough elements.
+    /// The generic types `R`, `C`, `RStride`, `CStride` can either be type-level integers or integers wrapped with `Dynamic::new()`.
+    #[inline]
+    pub fn try_from_slice_with_strides_generic(
+        data: &'a [T],
+        nrows: R,
+        ncols: C,
+        rstride: RStride,
+        cstride: CStride,
+    ) -> Result<Self, SliceError> {
+        check_buffer_len(
+            data.len(),
+            nrows.value(),
+            ncols.value(),
+            rstride.value(),
+            cstride.value(),
+        )?;
+
+        unsafe {
+            Ok(Self::from_slice_with_strides_generic_unchecked(
+                data, 0, nrows, ncols, rstride, cstride,
+            ))
+        }
+    }
+}
+
+impl<'a, T: Scalar, R: Dim, C: Dim> MatrixSlice<'a, T, R, C, C, U1> {
+    /// Creates a matrix slice from an array storing its components in row-major order, and with
+    /// dimensions specified by generic types instances.
+    ///
+    /// Returns an error if the input data array does not contain enough elements.
+    /// The generic types `R` and `C` can either be type-level integers or integers wrapped with `Dynamic::new()`.
+    #[inline]
+    pub fn from_row_major_slice_generic(
+        data: &'a [T],
+        nrows: R,
+        ncols: C,
+    ) -> Result<Self, SliceError> {
+        Self::try_from_slice_with_strides_generic(data, nrows, ncols, ncols, Const::<1>)
+    }
 }
 
 impl<'a, T: Scalar, R: Dim, C: Dim> MatrixSlice<'a, T, R, C> {
@@ -86,8 +207,8 @@ impl<'a, T: Scalar, R: Dim, C: Dim> MatrixSlice<'a, T, R, C> {
 }
 
 macro_rules! impl_constructors(
-    ($($Dims: ty),*; $(=> $DimIdent: ident: $DimBound: ident),*; $($gargs: expr),*; $($args: ident),*) => {
-        impl<'a, T: Scalar, $($DimIdent: $DimBound),*> MatrixSlice<'a, T, $($Dims),*> {
+    ($R: ty, $C: ty; $(=> $DimIdent: ident: $DimBound: ident),*; $($gargs: expr),*; $($args: ident),*) => {
+        impl<'a, T: Scalar, $($DimIdent: $DimBound),*> MatrixSlice<'a, T, $R, $C> {
             /// Creates a new matrix slice from the given data array.
             ///
             /// Panics if `data` does not contain enough elements.
@@ -101,9 +222,29 @@ macro_rules! impl_constructors(
             pub unsafe fn from_slice_unchecked(data: &'a [T], start: usize, $($args: usize),*) -> Self {
                 Self::from_slice_generic_unchecked(data, start, $($gargs),*)
             }
+
+            /// Creates a new matrix slice from the given data array storing the components in
+            /// row-major order, e.g., a C array or a row-major NumPy array.
+            ///
+            /// Returns an error if `data` does not contain enough elements.
+            #[inline]
+            pub fn from_row_major_slice(data: &'a [T], $($args: usize),*)
+                -> Result<MatrixSlice<'a, T, $R, $C, $C, U1>, SliceError> {
+                MatrixSlice::from_row_major_slice_generic(data, $($gargs),*)
+            }
+
+            /// Creates a new matrix slice with the specified strides from the given data array.
+            ///
+            /// Returns an error if `data` does not contain enough elements.
+            #[inline]
+            pub fn from_strided_slice(data: &'a [T], $($args: usize,)* rstride: usize, cstride: usize)
+                -> Result<MatrixSlice<'a, T, $R, $C, Dynamic, Dynamic>, SliceError> {
+                MatrixSlice::try_from_slice_with_strides_generic(
+                    data, $($gargs,)* Dynamic::new(rstride), Dynamic::new(cstride))
+            }
         }
 
-        impl<'a, T: Scalar, $($DimIdent: $DimBound, )*> MatrixSlice<'a, T, $($Dims,)* Dynamic, Dynamic> {
+        impl<'a, T: Scalar, $($DimIdent: $DimBound, )*> MatrixSlice<'a, T, $R, $C, Dynamic, Dynamic> {
             /// Creates a new matrix slice with the specified strides from the given data array.
             ///
             /// Panics if `data` does not contain enough elements.
@@ -190,24 +331,12 @@ impl<'a, T: Scalar, R: Dim, C: Dim, RStride: Dim, CStride: Dim>
         );
 
         assert!(
-            {
-                let nrows = nrows.value();
-                let ncols = ncols.value();
-                let rstride = rstride.value();
-                let cstride = cstride.value();
-
-                nrows * ncols <= 1
-                    || match (rstride, cstride) {
-                        (0, 0) => false,      // otherwise: matrix[(0, 0)] == index[(nrows - 1, ncols - 1)],
-                        (0, _) => nrows <= 1, // otherwise: matrix[(0, 0)] == index[(nrows - 1, 0)],
-                        (_, 0) => ncols <= 1, // otherwise: matrix[(0, 0)] == index[(0, ncols - 1)],
-                        (_, _) => {
-                            // otherwise: matrix[(0, numer)] == index[(denom, 0)]
-                            let ratio = Ratio::new(rstride, cstride);
-                            nrows <= *ratio.denom() || ncols <= *ratio.numer()
-                        }
-                    }
-            },
+            !has_aliased_indices(
+                nrows.value(),
+                ncols.value(),
+                rstride.value(),
+                cstride.value()
+            ),
             "Matrix slice: dimensions and strides result in aliased indices."
         );
 
@@ -215,6 +344,50 @@ impl<'a, T: Scalar, R: Dim, C: Dim, RStride: Dim, CStride: Dim>
             Self::from_slice_with_strides_generic_unchecked(data, 0, nrows, ncols, rstride, cstride)
         }
     }
+
+    /// Creates a mutable matrix slice from an array and with dimensions and strides specified by generic types instances.
+    ///
+    /// Returns an error if the input data array does not contain enough elements, or if the
+    /// dimensions and strides result in distinct indices referring to the same element.
+    /// The generic types `R`, `C`, `RStride`, `CStride` can either be type-level integers or integers wrapped with `Dynamic::new()`.
+    #[inline]
+    pub fn try_from_slice_with_strides_generic(
+        data: &'a mut [T],
+        nrows: R,
+        ncols: C,
+        rstride: RStride,
+        cstride: CStride,
+    ) -> Result<Self, SliceError> {
+        let (nrows_val, ncols_val) = (nrows.value(), ncols.value());
+        let (rstride_val, cstride_val) = (rstride.value(), cstride.value());
+        check_buffer_len(data.len(), nrows_val, ncols_val, rstride_val, cstride_val)?;
+
+        if has_aliased_indices(nrows_val, ncols_val, rstride_val, cstride_val) {
+            return Err(SliceError::AliasedIndices);
+        }
+
+        unsafe {
+            Ok(Self::from_slice_with_strides_generic_unchecked(
+                data, 0, nrows, ncols, rstride, cstride,
+            ))
+        }
+    }
+}
+
+impl<'a, T: Scalar, R: Dim, C: Dim> MatrixSliceMutMN<'a, T, R, C, C, U1> {
+    /// Creates a mutable matrix slice from an array storing its components in row-major order,
+    /// and with dimensions specified by generic types instances.
+    ///
+    /// Returns an error if the input data array does not contain enough elements.
+    /// The generic types `R` and `C` can either be type-level integers or integers wrapped with `Dynamic::new()`.
+    #[inline]
+    pub fn from_row_major_slice_generic(
+        data: &'a mut [T],
+        nrows: R,
+        ncols: C,
+    ) -> Result<Self, SliceError> {
+        Self::try_from_slice_with_strides_generic(data, nrows, ncols, ncols, Const::<1>)
+    }
 }
 
 impl<'a, T: Scalar, R: Dim, C: Dim> MatrixSliceMutMN<'a, T, R, C> {
@@ -246,8 +419,8 @@ impl<'a, T: Scalar, R: Dim, C: Dim> MatrixSliceMutMN<'a, T, R, C> {
 }
 
 macro_rules! impl_constructors_mut(
-    ($($Dims: ty),*; $(=> $DimIdent: ident: $DimBound: ident),*; $($gargs: expr),*; $($args: ident),*) => {
-        impl<'a, T: Scalar, $($DimIdent: $DimBound),*> MatrixSliceMutMN<'a, T, $($Dims),*> {
+    ($R: ty, $C: ty; $(=> $DimIdent: ident: $DimBound: ident),*; $($gargs: expr),*; $($args: ident),*) => {
+        impl<'a, T: Scalar, $($DimIdent: $DimBound),*> MatrixSliceMutMN<'a, T, $R, $C> {
             /// Creates a new mutable matrix slice from the given data array.
             ///
             /// Panics if `data` does not contain enough elements.
@@ -261,9 +434,30 @@ macro_rules! impl_constructors_mut(
             pub unsafe fn from_slice_unchecked(data: &'a mut [T], start: usize, $($args: usize),*) -> Self {
                 Self::from_slice_generic_unchecked(data, start, $($gargs),*)
             }
+
+            /// Creates a new mutable matrix slice from the given data array storing the components in
+            /// row-major order, e.g., a C array or a row-major NumPy array.
+            ///
+            /// Returns an error if `data` does not contain enough elements.
+            #[inline]
+            pub fn from_row_major_slice(data: &'a mut [T], $($args: usize),*)
+                -> Result<MatrixSliceMutMN<'a, T, $R, $C, $C, U1>, SliceError> {
+                MatrixSliceMutMN::from_row_major_slice_generic(data, $($gargs),*)
+            }
+
+            /// Creates a new mutable matrix slice with the specified strides from the given data array.
+            ///
+            /// Returns an error if `data` does not contain enough elements, or if the strides
+            /// result in distinct indices referring to the same element.
+            #[inline]
+            pub fn from_strided_slice(data: &'a mut [T], $($args: usize,)* rstride: usize, cstride: usize)
+                -> Result<MatrixSliceMutMN<'a, T, $R, $C, Dynamic, Dynamic>, SliceError> {
+                MatrixSliceMutMN::try_from_slice_with_strides_generic(
+                    data, $($gargs,)* Dynamic::new(rstride), Dynamic::new(cstride))
+            }
         }
 
-        impl<'a, T: Scalar, $($DimIdent: $DimBound, )*> MatrixSliceMutMN<'a, T, $($Dims,)* Dynamic, Dynamic> {
+        impl<'a, T: Scalar, $($DimIdent: $DimBound, )*> MatrixSliceMutMN<'a, T, $R, $C, Dynamic, Dynamic> {
             /// Creates a new mutable matrix slice with the specified strides from the given data array.
             ///
             /// Panics if `data` does not contain enough elements.
@@ -1,3 +1,6 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
 use num::{One, Zero};
 use std::cmp;
 #[cfg(any(feature = "std", feature = "alloc"))]
@@ -9,7 +12,10 @@ use crate::base::constraint::{DimEq, SameNumberOfColumns, SameNumberOfRows, Shap
 #[cfg(any(feature = "std", feature = "alloc"))]
 use crate::base::dimension::Dynamic;
 use crate::base::dimension::{Const, Dim, DimAdd, DimDiff, DimMin, DimMinimum, DimSub, DimSum, U1};
+use crate::base::indexing::MatrixElements;
 use crate::base::storage::{RawStorage, RawStorageMut, ReshapableStorage};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::DVector;
 use crate::base::{DefaultAllocator, Matrix, OMatrix, RowVector, Scalar, Vector};
 use crate::{Storage, UninitMatrix};
 use std::mem::MaybeUninit;
@@ -104,6 +110,26 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         // Safety: res is now fully initialized.
         unsafe { res.assume_init() }
     }
+
+    /// Creates a new column vector with the elements of `self` satisfying the given predicate,
+    /// in column-major order.
+    ///
+    /// Use [`Matrix::index`] with a boolean mask or a list of `(row, column)` pairs to select
+    /// elements based on their position instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DVector, Matrix2x3};
+    /// let m = Matrix2x3::new(1.0, -2.0, 3.0,
+    ///                        -4.0, 5.0, -6.0);
+    /// assert_eq!(m.select_where(|e| *e > 0.0), DVector::from_vec(vec![1.0, 5.0, 3.0]));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[must_use]
+    pub fn select_where(&self, mut f: impl FnMut(&T) -> bool) -> DVector<T> {
+        let selected: Vec<T> = self.iter().filter(|e| f(e)).cloned().collect();
+        DVector::from_vec(selected)
+    }
 }
 
 /// # Set rows, columns, and diagonal
@@ -159,6 +185,109 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
     {
         self.column_mut(i).copy_from(column);
     }
+
+    /// Fills the selected rows of this matrix with the rows of the given matrix, in order.
+    ///
+    /// This is the converse of [`Matrix::select_rows`]. If a row index appears several times,
+    /// the corresponding row of `self` is set to the last matching row of `rows`.
+    ///
+    /// Panics if a row index is out of bounds, or if the number of rows of `rows` differs from
+    /// the number of row indices.
+    #[inline]
+    pub fn set_rows<'a, I, R2: Dim, C2: Dim, S2>(&mut self, irows: I, rows: &Matrix<T, R2, C2, S2>)
+    where
+        I: IntoIterator<Item = &'a usize>,
+        I::IntoIter: ExactSizeIterator,
+        S2: RawStorage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfColumns<C, C2>,
+    {
+        let irows = irows.into_iter();
+        assert_eq!(irows.len(), rows.nrows(), "Mismatched number of rows.");
+
+        for (source, i) in irows.enumerate() {
+            assert!(*i < self.nrows(), "Row index out of bounds.");
+            self.row_mut(*i).copy_from(&rows.row(source));
+        }
+    }
+
+    /// Fills the selected columns of this matrix with the columns of the given matrix, in order.
+    ///
+    /// This is the converse of [`Matrix::select_columns`]. If a column index appears several
+    /// times, the corresponding column of `self` is set to the last matching column of `columns`.
+    ///
+    /// Panics if a column index is out of bounds, or if the number of columns of `columns`
+    /// differs from the number of column indices.
+    #[inline]
+    pub fn set_columns<'a, I, R2: Dim, C2: Dim, S2>(
+        &mut self,
+        icols: I,
+        columns: &Matrix<T, R2, C2, S2>,
+    ) where
+        I: IntoIterator<Item = &'a usize>,
+        I::IntoIter: ExactSizeIterator,
+        S2: RawStorage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+    {
+        let icols = icols.into_iter();
+        assert_eq!(
+            icols.len(),
+            columns.ncols(),
+            "Mismatched number of columns."
+        );
+
+        for (source, j) in icols.enumerate() {
+            assert!(*j < self.ncols(), "Column index out of bounds.");
+            self.column_mut(*j).copy_from(&columns.column(source));
+        }
+    }
+
+    /// Sets the selected elements of this matrix to the components of the given vector, in order.
+    ///
+    /// The elements are selected by an array or a slice of `(row, column)` pairs, or by a boolean
+    /// mask with the same shape as `self`, as when gathering them with [`Matrix::index`]. If an
+    /// element is selected several times, it is set to the last matching component of `values`.
+    ///
+    /// Panics if an index is out of bounds, or if the number of selected elements differs from
+    /// the dimension of `values`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DVector, Matrix2x3, Vector2};
+    /// let mut m = Matrix2x3::zeros();
+    /// m.scatter([(1, 0), (0, 2)], &Vector2::new(1.0, 2.0));
+    /// assert_eq!(m, Matrix2x3::new(0.0, 0.0, 2.0,
+    ///                              1.0, 0.0, 0.0));
+    ///
+    /// let mask = m.map(|e| e == 0.0);
+    /// m.scatter(&mask, &DVector::from_element(4, -1.0));
+    /// assert_eq!(m, Matrix2x3::new(-1.0, -1.0, 2.0,
+    ///                              1.0, -1.0, -1.0));
+    /// ```
+    #[inline]
+    pub fn scatter<I, D: Dim, S2>(&mut self, indices: I, values: &Vector<T, D, S2>)
+    where
+        I: MatrixElements<R, C>,
+        S2: RawStorage<T, D>,
+        ShapeConstraint: DimEq<I::Length, D>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        assert!(
+            indices.contained_by(nrows, ncols),
+            "Matrix index out of bounds."
+        );
+        assert_eq!(
+            indices.length().value(),
+            values.len(),
+            "Mismatched number of elements."
+        );
+
+        let mut k = 0;
+        indices.for_each_index(|i, j| {
+            // Safety: all indices are in range, and there are as many as components of `values`.
+            unsafe { *self.data.get_unchecked_mut(i, j) = values.vget_unchecked(k).clone() };
+            k += 1;
+        });
+    }
 }
 
 /// # In-place filling
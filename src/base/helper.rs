@@ -1,6 +1,9 @@
 #[cfg(feature = "arbitrary")]
 use quickcheck::{Arbitrary, Gen};
 
+use crate::base::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
+use crate::base::dimension::Dim;
+
 #[cfg(feature = "rand-no-std")]
 use rand::{
     distributions::{Distribution, Standard},
@@ -29,3 +32,41 @@ where
     use std::iter;
     iter::repeat(()).map(|_| g.gen()).find(f).unwrap()
 }
+
+/// Checks that two blocks of the same block row of a `stack!` invocation have the same number of
+/// rows, and returns it.
+#[doc(hidden)]
+#[inline]
+pub fn stack_same_nrows<R1: Dim, R2: Dim>(
+    nrows1: R1,
+    nrows2: R2,
+) -> <ShapeConstraint as SameNumberOfRows<R1, R2>>::Representative
+where
+    ShapeConstraint: SameNumberOfRows<R1, R2>,
+{
+    assert_eq!(
+        nrows1.value(),
+        nrows2.value(),
+        "stack!: the blocks of a block row must have the same number of rows."
+    );
+    Dim::from_usize(nrows1.value())
+}
+
+/// Checks that two blocks of the same block column of a `stack!` invocation have the same number
+/// of columns, and returns it.
+#[doc(hidden)]
+#[inline]
+pub fn stack_same_ncols<C1: Dim, C2: Dim>(
+    ncols1: C1,
+    ncols2: C2,
+) -> <ShapeConstraint as SameNumberOfColumns<C1, C2>>::Representative
+where
+    ShapeConstraint: SameNumberOfColumns<C1, C2>,
+{
+    assert_eq!(
+        ncols1.value(),
+        ncols2.value(),
+        "stack!: the blocks of a block column must have the same number of columns."
+    );
+    Dim::from_usize(ncols1.value())
+}
@@ -1,11 +1,17 @@
 //! Indexing
 #![allow(clippy::reversed_empty_ranges)]
 
+use crate::base::allocator::Allocator;
+use crate::base::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
 use crate::base::storage::{RawStorage, RawStorageMut};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::DVector;
 use crate::base::{
-    Const, Dim, DimDiff, DimName, DimSub, Dynamic, Matrix, MatrixSlice, MatrixSliceMut, Scalar, U1,
+    Const, DefaultAllocator, Dim, DimDiff, DimName, DimSub, Dynamic, Matrix, MatrixSlice,
+    MatrixSliceMut, OVector, SVector, Scalar, U1,
 };
 
+use std::mem::MaybeUninit;
 use std::ops;
 
 // N.B.: Not a public trait!
@@ -641,6 +647,226 @@ where
     }
 }
 
+/// A helper trait for sets of matrix elements, used by gather and scatter operations.
+///
+/// This is implemented by arrays and slices of `(row, column)` pairs, and by boolean masks with
+/// the same shape as the indexed matrix. Indexing a matrix with any of them gathers the selected
+/// elements into a column vector, which is statically sized when indexing with an array:
+///
+/// ```
+/// # use nalgebra::*;
+/// let matrix = Matrix2x3::new(0, 2, 4,
+///                             1, 3, 5);
+///
+/// assert_eq!(matrix.index([(1, 0), (0, 2)]), Vector2::new(1, 4));
+/// assert_eq!(matrix.index(&[(1, 2), (1, 2), (0, 0)][..]), DVector::from_vec(vec![5, 5, 0]));
+/// assert_eq!(matrix.get([(2, 0)]), None);
+///
+/// let mask = matrix.map(|e| e % 3 == 0);
+/// assert_eq!(matrix.index(&mask), DVector::from_vec(vec![0, 3]));
+/// ```
+///
+/// Conversely, [`Matrix::scatter`] sets the selected elements of a matrix.
+///
+/// # Safety
+///
+/// Implementors must ensure that, whenever `contained_by` returns `true` for a given shape,
+/// `for_each_index` calls its closure exactly `length` times and only with indices within
+/// that shape.
+pub unsafe trait MatrixElements<R: Dim, C: Dim> {
+    /// The number of selected elements.
+    type Length: Dim;
+
+    /// The number of selected elements.
+    #[doc(hidden)]
+    fn length(&self) -> Self::Length;
+
+    /// Produces true if all the selected elements are contained by a matrix with the given shape.
+    #[doc(hidden)]
+    fn contained_by(&self, nrows: R, ncols: C) -> bool;
+
+    /// Calls `f` with the row and column indices of each selected element, in order.
+    #[doc(hidden)]
+    fn for_each_index(&self, f: impl FnMut(usize, usize));
+}
+
+unsafe impl<R: Dim, C: Dim, const D: usize> MatrixElements<R, C> for [(usize, usize); D] {
+    type Length = Const<D>;
+
+    #[inline]
+    fn length(&self) -> Self::Length {
+        Const::<D>
+    }
+
+    #[inline]
+    fn contained_by(&self, nrows: R, ncols: C) -> bool {
+        MatrixElements::contained_by(&&self[..], nrows, ncols)
+    }
+
+    #[inline]
+    fn for_each_index(&self, f: impl FnMut(usize, usize)) {
+        MatrixElements::<R, C>::for_each_index(&&self[..], f)
+    }
+}
+
+unsafe impl<R: Dim, C: Dim> MatrixElements<R, C> for &[(usize, usize)] {
+    type Length = Dynamic;
+
+    #[inline]
+    fn length(&self) -> Self::Length {
+        Dynamic::new(self.len())
+    }
+
+    #[inline]
+    fn contained_by(&self, nrows: R, ncols: C) -> bool {
+        let (nrows, ncols) = (nrows.value(), ncols.value());
+        self.iter().all(|&(i, j)| i < nrows && j < ncols)
+    }
+
+    #[inline]
+    fn for_each_index(&self, mut f: impl FnMut(usize, usize)) {
+        for &(i, j) in self.iter() {
+            f(i, j)
+        }
+    }
+}
+
+unsafe impl<R, C, R2, C2, S2> MatrixElements<R, C> for &Matrix<bool, R2, C2, S2>
+where
+    R: Dim,
+    C: Dim,
+    R2: Dim,
+    C2: Dim,
+    S2: RawStorage<bool, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfColumns<C, C2>,
+{
+    type Length = Dynamic;
+
+    #[inline]
+    fn length(&self) -> Self::Length {
+        Dynamic::new(self.iter().filter(|e| **e).count())
+    }
+
+    #[inline]
+    fn contained_by(&self, nrows: R, ncols: C) -> bool {
+        self.shape() == (nrows.value(), ncols.value())
+    }
+
+    #[inline]
+    fn for_each_index(&self, mut f: impl FnMut(usize, usize)) {
+        let nrows = self.nrows();
+
+        for (k, e) in self.iter().enumerate() {
+            if *e {
+                f(k % nrows, k / nrows)
+            }
+        }
+    }
+}
+
+/// Gathers the selected elements of `matrix` into a column vector, without bound checking.
+#[inline]
+unsafe fn gather_unchecked<T, R, C, S, I>(
+    indices: &I,
+    matrix: &Matrix<T, R, C, S>,
+) -> OVector<T, I::Length>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+    I: MatrixElements<R, C>,
+    DefaultAllocator: Allocator<T, I::Length>,
+{
+    let mut res = Matrix::uninit(indices.length(), Const::<1>);
+    let mut k = 0;
+
+    indices.for_each_index(|i, j| {
+        *res.vget_unchecked_mut(k) = MaybeUninit::new(matrix.data.get_unchecked(i, j).clone());
+        k += 1;
+    });
+
+    // Safety: res is now fully initialized.
+    res.assume_init()
+}
+
+// GATHER ELEMENTS BY ARRAYS OF 2D COORDINATES, SLICES OF 2D COORDINATES, OR MASKS
+
+impl<'a, T, R, C, S, const D: usize> MatrixIndex<'a, T, R, C, S> for [(usize, usize); D]
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    type Output = SVector<T, D>;
+
+    #[doc(hidden)]
+    #[inline(always)]
+    fn contained_by(&self, matrix: &Matrix<T, R, C, S>) -> bool {
+        let (nrows, ncols) = matrix.shape_generic();
+        MatrixElements::contained_by(self, nrows, ncols)
+    }
+
+    #[doc(hidden)]
+    #[inline(always)]
+    unsafe fn get_unchecked(self, matrix: &'a Matrix<T, R, C, S>) -> Self::Output {
+        gather_unchecked(&self, matrix)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'a, T, R, C, S> MatrixIndex<'a, T, R, C, S> for &[(usize, usize)]
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    type Output = DVector<T>;
+
+    #[doc(hidden)]
+    #[inline(always)]
+    fn contained_by(&self, matrix: &Matrix<T, R, C, S>) -> bool {
+        let (nrows, ncols) = matrix.shape_generic();
+        MatrixElements::contained_by(self, nrows, ncols)
+    }
+
+    #[doc(hidden)]
+    #[inline(always)]
+    unsafe fn get_unchecked(self, matrix: &'a Matrix<T, R, C, S>) -> Self::Output {
+        gather_unchecked(&self, matrix)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'a, T, R, C, S, R2, C2, S2> MatrixIndex<'a, T, R, C, S> for &Matrix<bool, R2, C2, S2>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+    R2: Dim,
+    C2: Dim,
+    S2: RawStorage<bool, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfColumns<C, C2>,
+{
+    type Output = DVector<T>;
+
+    #[doc(hidden)]
+    #[inline(always)]
+    fn contained_by(&self, matrix: &Matrix<T, R, C, S>) -> bool {
+        let (nrows, ncols) = matrix.shape_generic();
+        MatrixElements::contained_by(self, nrows, ncols)
+    }
+
+    #[doc(hidden)]
+    #[inline(always)]
+    unsafe fn get_unchecked(self, matrix: &'a Matrix<T, R, C, S>) -> Self::Output {
+        gather_unchecked(&self, matrix)
+    }
+}
+
 macro_rules! impl_index_pair {
     (
       $R: ident,
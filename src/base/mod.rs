@@ -8,6 +8,8 @@ pub mod default_allocator;
 pub mod dimension;
 pub mod iter;
 mod ops;
+#[cfg(feature = "rayon")]
+pub mod par_iter;
 pub mod storage;
 
 mod alias;
@@ -25,8 +27,12 @@ mod matrix;
 mod matrix_simba;
 mod matrix_slice;
 mod norm;
+#[cfg(feature = "rayon")]
+mod par_ops;
 mod properties;
 mod scalar;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod stack;
 mod statistics;
 mod swizzle;
 mod unit;
@@ -52,7 +58,10 @@ pub use self::dimension::*;
 pub use self::alias::*;
 pub use self::alias_slice::*;
 pub use self::array_storage::*;
+pub use self::construction_slice::SliceError;
 pub use self::matrix_slice::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::stack::*;
 pub use self::storage::*;
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub use self::vec_storage::*;
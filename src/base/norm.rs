@@ -5,6 +5,8 @@ use num::Zero;
 use std::ops::Neg;
 
 use crate::allocator::Allocator;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::DMatrix;
 use crate::base::{DefaultAllocator, Dim, DimName, Matrix, Normed, OMatrix, OVector};
 use crate::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
 use crate::storage::{Storage, StorageMut};
@@ -48,6 +50,18 @@ pub struct LpNorm(pub i32);
 /// L-infinite norm aka. Chebytchev norm aka. uniform norm aka. suppremum norm.
 #[derive(Copy, Clone, Debug)]
 pub struct UniformNorm;
+/// Matrix norm induced by the L1 norm on vectors, aka. maximum absolute column sum norm.
+#[derive(Copy, Clone, Debug)]
+pub struct InducedOneNorm;
+/// Matrix norm induced by the L-infinite norm on vectors, aka. maximum absolute row sum norm.
+#[derive(Copy, Clone, Debug)]
+pub struct InducedInfinityNorm;
+/// Matrix norm induced by the euclidean norm on vectors, aka. spectral norm.
+///
+/// It is equal to the largest singular value, and is computed with a singular value decomposition.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Copy, Clone, Debug)]
+pub struct SpectralNorm;
 
 impl<T: SimdComplexField> Norm<T> for EuclideanNorm {
     #[inline]
@@ -157,6 +171,131 @@ impl<T: SimdComplexField> Norm<T> for UniformNorm {
     }
 }
 
+impl<T: SimdComplexField> Norm<T> for InducedOneNorm {
+    #[inline]
+    fn norm<R, C, S>(&self, m: &Matrix<T, R, C, S>) -> T::SimdRealField
+    where
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+    {
+        m.column_iter().fold(T::SimdRealField::zero(), |acc, col| {
+            acc.simd_max(col.fold(T::SimdRealField::zero(), |a, b| a + b.simd_modulus()))
+        })
+    }
+
+    #[inline]
+    fn metric_distance<R1, C1, S1, R2, C2, S2>(
+        &self,
+        m1: &Matrix<T, R1, C1, S1>,
+        m2: &Matrix<T, R2, C2, S2>,
+    ) -> T::SimdRealField
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: Storage<T, R1, C1>,
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        m1.column_iter().zip(m2.column_iter()).fold(
+            T::SimdRealField::zero(),
+            |acc, (col1, col2)| {
+                let sum = col1.zip_fold(&col2, T::SimdRealField::zero(), |a, b, c| {
+                    a + (b - c).simd_modulus()
+                });
+                acc.simd_max(sum)
+            },
+        )
+    }
+}
+
+impl<T: SimdComplexField> Norm<T> for InducedInfinityNorm {
+    #[inline]
+    fn norm<R, C, S>(&self, m: &Matrix<T, R, C, S>) -> T::SimdRealField
+    where
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+    {
+        m.row_iter().fold(T::SimdRealField::zero(), |acc, row| {
+            acc.simd_max(row.fold(T::SimdRealField::zero(), |a, b| a + b.simd_modulus()))
+        })
+    }
+
+    #[inline]
+    fn metric_distance<R1, C1, S1, R2, C2, S2>(
+        &self,
+        m1: &Matrix<T, R1, C1, S1>,
+        m2: &Matrix<T, R2, C2, S2>,
+    ) -> T::SimdRealField
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: Storage<T, R1, C1>,
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        m1.row_iter()
+            .zip(m2.row_iter())
+            .fold(T::SimdRealField::zero(), |acc, (row1, row2)| {
+                let sum = row1.zip_fold(&row2, T::SimdRealField::zero(), |a, b, c| {
+                    a + (b - c).simd_modulus()
+                });
+                acc.simd_max(sum)
+            })
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: ComplexField> Norm<T> for SpectralNorm {
+    #[inline]
+    fn norm<R, C, S>(&self, m: &Matrix<T, R, C, S>) -> T::RealField
+    where
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+    {
+        let m = DMatrix::from_iterator(m.nrows(), m.ncols(), m.iter().cloned());
+        largest_singular_value(m)
+    }
+
+    #[inline]
+    fn metric_distance<R1, C1, S1, R2, C2, S2>(
+        &self,
+        m1: &Matrix<T, R1, C1, S1>,
+        m2: &Matrix<T, R2, C2, S2>,
+    ) -> T::RealField
+    where
+        R1: Dim,
+        C1: Dim,
+        S1: Storage<T, R1, C1>,
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        let diff = DMatrix::from_iterator(
+            m1.nrows(),
+            m1.ncols(),
+            m1.iter().zip(m2.iter()).map(|(a, b)| a.clone() - b.clone()),
+        );
+        largest_singular_value(diff)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn largest_singular_value<T: ComplexField>(m: DMatrix<T>) -> T::RealField {
+    use crate::RealField;
+
+    m.singular_values_unordered()
+        .iter()
+        .fold(T::RealField::zero(), |acc, s| acc.max(s.clone()))
+}
+
 /// # Magnitude and norms
 impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     /// The squared L2 norm of this vector.
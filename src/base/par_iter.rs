@@ -0,0 +1,251 @@
+//! Parallel iterators for matrices compatible with rayon.
+//!
+//! **Available only when the `rayon` feature is enabled.**
+
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+use crate::base::dimension::{Const, Dim, U1};
+use crate::base::storage::{RawStorage, RawStorageMut};
+use crate::base::{Matrix, MatrixSlice, MatrixSliceMut, SliceStorage, SliceStorageMut};
+
+macro_rules! par_column_iter {
+    (struct $Name: ident, $Producer: ident for $Storage: ident -> $Ptr: ty,
+     $Ref: ty, $Slice: ident, $SliceStorage: ident, [$($Bounds: tt)*]) => {
+        /// The rayon producer of the columns of a matrix, given by their index range.
+        #[derive(Debug)]
+        struct $Producer<'a, T, R: Dim, RStride: Dim, CStride: Dim> {
+            ptr: $Ptr,
+            nrows: R,
+            strides: (RStride, CStride),
+            range: Range<usize>,
+            _phantoms: PhantomData<$Ref>,
+        }
+
+        // Safety: the producers built by splitting a producer own disjoint sets of columns.
+        unsafe impl<'a, T: $($Bounds)*, R: Dim, RStride: Dim, CStride: Dim> Send
+            for $Producer<'a, T, R, RStride, CStride>
+        {
+        }
+
+        impl<'a, T, R: Dim, RStride: Dim, CStride: Dim> $Producer<'a, T, R, RStride, CStride> {
+            /// The `j`-th column of the matrix.
+            ///
+            /// # Safety
+            /// `j` must be in the range of this producer.
+            #[inline]
+            unsafe fn column(&self, j: usize) -> $Slice<'a, T, R, U1, RStride, CStride> {
+                // The pointer is not dereferenced if the column is empty, in which case it
+                // may be dangling.
+                let ptr = self.ptr.wrapping_add(j * self.strides.1.value());
+                Matrix::from_data($SliceStorage::from_raw_parts(
+                    ptr,
+                    (self.nrows, Const::<1>),
+                    self.strides,
+                ))
+            }
+        }
+
+        impl<'a, T, R: Dim, RStride: Dim, CStride: Dim> Iterator
+            for $Producer<'a, T, R, RStride, CStride>
+        {
+            type Item = $Slice<'a, T, R, U1, RStride, CStride>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let j = self.range.next()?;
+                // Safety: `j` was in the range of this producer.
+                Some(unsafe { self.column(j) })
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.range.size_hint()
+            }
+        }
+
+        impl<'a, T, R: Dim, RStride: Dim, CStride: Dim> DoubleEndedIterator
+            for $Producer<'a, T, R, RStride, CStride>
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let j = self.range.next_back()?;
+                // Safety: `j` was in the range of this producer.
+                Some(unsafe { self.column(j) })
+            }
+        }
+
+        impl<'a, T, R: Dim, RStride: Dim, CStride: Dim> ExactSizeIterator
+            for $Producer<'a, T, R, RStride, CStride>
+        {
+        }
+
+        impl<'a, T: $($Bounds)*, R: Dim, RStride: Dim, CStride: Dim> Producer
+            for $Producer<'a, T, R, RStride, CStride>
+        {
+            type Item = $Slice<'a, T, R, U1, RStride, CStride>;
+            type IntoIter = Self;
+
+            #[inline]
+            fn into_iter(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn split_at(self, index: usize) -> (Self, Self) {
+                let mid = self.range.start + index;
+                let left = $Producer {
+                    ptr: self.ptr,
+                    nrows: self.nrows,
+                    strides: self.strides,
+                    range: self.range.start..mid,
+                    _phantoms: PhantomData,
+                };
+                let right = $Producer {
+                    range: mid..self.range.end,
+                    ..self
+                };
+                (left, right)
+            }
+        }
+
+        impl<'a, T: $($Bounds)*, R: Dim, C: Dim, S: 'a + $Storage<T, R, C>> ParallelIterator
+            for $Name<'a, T, R, C, S>
+        {
+            type Item = $Slice<'a, T, R, U1, S::RStride, S::CStride>;
+
+            #[inline]
+            fn drive_unindexed<Cons>(self, consumer: Cons) -> Cons::Result
+            where
+                Cons: UnindexedConsumer<Self::Item>,
+            {
+                bridge(self, consumer)
+            }
+
+            #[inline]
+            fn opt_len(&self) -> Option<usize> {
+                Some(self.producer.range.len())
+            }
+        }
+
+        impl<'a, T: $($Bounds)*, R: Dim, C: Dim, S: 'a + $Storage<T, R, C>> IndexedParallelIterator
+            for $Name<'a, T, R, C, S>
+        {
+            #[inline]
+            fn len(&self) -> usize {
+                self.producer.range.len()
+            }
+
+            #[inline]
+            fn drive<Cons: Consumer<Self::Item>>(self, consumer: Cons) -> Cons::Result {
+                bridge(self, consumer)
+            }
+
+            #[inline]
+            fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+                callback.callback(self.producer)
+            }
+        }
+    };
+}
+
+/// A parallel iterator through the columns of a matrix.
+///
+/// This is created by [`Matrix::par_column_iter`].
+#[derive(Debug)]
+pub struct ParColumnIter<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> {
+    producer: ColumnProducer<'a, T, R, S::RStride, S::CStride>,
+    _phantoms: PhantomData<fn() -> (C, S)>,
+}
+
+/// A parallel iterator through the mutable columns of a matrix.
+///
+/// This is created by [`Matrix::par_column_iter_mut`].
+#[derive(Debug)]
+pub struct ParColumnIterMut<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> {
+    producer: ColumnProducerMut<'a, T, R, S::RStride, S::CStride>,
+    _phantoms: PhantomData<fn() -> (C, S)>,
+}
+
+par_column_iter!(struct ParColumnIter, ColumnProducer for RawStorage -> *const T,
+    &'a T, MatrixSlice, SliceStorage, [Send + Sync]);
+par_column_iter!(struct ParColumnIterMut, ColumnProducerMut for RawStorageMut -> *mut T,
+    &'a mut T, MatrixSliceMut, SliceStorageMut, [Send]);
+
+/// # Parallel iterators
+///
+/// **Available only when the `rayon` feature is enabled.**
+impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Iterates in parallel through the columns of this matrix.
+    ///
+    /// The returned iterator is an indexed parallel iterator, so that the columns can be
+    /// enumerated or zipped with other indexed parallel iterators.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate rayon_package as rayon;
+    /// # use nalgebra::DMatrix;
+    /// use rayon::prelude::*;
+    ///
+    /// let m = DMatrix::from_fn(4, 100, |i, j| (i + j) as f64);
+    /// let norms: Vec<f64> = m.par_column_iter().map(|col| col.norm()).collect();
+    ///
+    /// for (j, norm) in norms.iter().enumerate() {
+    ///     assert_eq!(*norm, m.column(j).norm());
+    /// }
+    /// ```
+    #[inline]
+    pub fn par_column_iter(&self) -> ParColumnIter<'_, T, R, C, S>
+    where
+        T: Send + Sync,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        ParColumnIter {
+            producer: ColumnProducer {
+                ptr: self.data.ptr(),
+                nrows,
+                strides: self.data.strides(),
+                range: 0..ncols.value(),
+                _phantoms: PhantomData,
+            },
+            _phantoms: PhantomData,
+        }
+    }
+
+    /// Mutably iterates in parallel through the columns of this matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate rayon_package as rayon;
+    /// # use nalgebra::DMatrix;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut m = DMatrix::from_element(4, 100, 1.0);
+    /// m.par_column_iter_mut()
+    ///     .enumerate()
+    ///     .for_each(|(j, mut col)| col *= j as f64);
+    ///
+    /// assert_eq!(m, DMatrix::from_fn(4, 100, |_, j| j as f64));
+    /// ```
+    #[inline]
+    pub fn par_column_iter_mut(&mut self) -> ParColumnIterMut<'_, T, R, C, S>
+    where
+        T: Send,
+        S: RawStorageMut<T, R, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        ParColumnIterMut {
+            producer: ColumnProducerMut {
+                ptr: self.data.ptr_mut(),
+                nrows,
+                strides: self.data.strides(),
+                range: 0..ncols.value(),
+                _phantoms: PhantomData,
+            },
+            _phantoms: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,476 @@
+use std::mem::MaybeUninit;
+
+use num::{One, Zero};
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefMutIterator, ParallelIterator};
+use simba::scalar::{ClosedAdd, ClosedMul, Field, SupersetOf};
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Const, Dim, U1};
+use crate::base::storage::{RawStorage, RawStorageMut};
+use crate::base::{
+    DMatrix, DefaultAllocator, Matrix, OMatrix, OVector, RowOVector, Scalar, VectorSlice,
+};
+
+/// The approximate number of matrix entries processed sequentially by each task of the parallel
+/// reductions along the rows of a matrix.
+///
+/// The columns are grouped in chunks of a size that only depends on the shape of the matrix, so
+/// that the results do not depend on the number of threads.
+const CHUNK_SIZE: usize = 4096;
+
+/// # Parallel elementwise mapping
+///
+/// **Available only when the `rayon` feature is enabled.**
+///
+/// The columns of the matrix are distributed among the threads of the rayon thread pool. The
+/// results are identical to those of the sequential methods with the same names without the
+/// `par_` prefix.
+impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Returns a matrix containing the result of `f` applied to each of its entries, computed in
+    /// parallel.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_fn(100, 100, |i, j| (i * j) as f64);
+    /// assert_eq!(m.par_map(|e| e.sqrt()), m.map(|e| e.sqrt()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn par_map<T2, F>(&self, f: F) -> OMatrix<T2, R, C>
+    where
+        T: Scalar + Send + Sync,
+        T2: Scalar + Send,
+        F: Fn(T) -> T2 + Sync,
+        DefaultAllocator: Allocator<T2, R, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let mut res = Matrix::uninit(nrows, ncols);
+
+        res.par_column_iter_mut()
+            .zip(self.par_column_iter())
+            .for_each(|(mut out, column)| {
+                for (out, a) in out.iter_mut().zip(column.iter()) {
+                    *out = MaybeUninit::new(f(a.clone()));
+                }
+            });
+
+        // Safety: res is now fully initialized.
+        unsafe { res.assume_init() }
+    }
+
+    /// Returns a matrix containing the result of `f` applied to each entries of `self` and
+    /// `rhs`, computed in parallel.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let a = DMatrix::from_fn(100, 100, |i, j| (i + j) as f64);
+    /// let b = DMatrix::from_fn(100, 100, |i, j| (i * j) as f64);
+    /// assert_eq!(a.par_zip_map(&b, |x, y| x.max(y)), a.zip_map(&b, |x, y| x.max(y)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn par_zip_map<T2, N3, S2, F>(&self, rhs: &Matrix<T2, R, C, S2>, f: F) -> OMatrix<N3, R, C>
+    where
+        T: Scalar + Send + Sync,
+        T2: Scalar + Send + Sync,
+        N3: Scalar + Send,
+        S2: RawStorage<T2, R, C>,
+        F: Fn(T, T2) -> N3 + Sync,
+        DefaultAllocator: Allocator<N3, R, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        assert_eq!(
+            (nrows.value(), ncols.value()),
+            rhs.shape(),
+            "Matrix simultaneous traversal error: dimension mismatch."
+        );
+        let mut res = Matrix::uninit(nrows, ncols);
+
+        res.par_column_iter_mut()
+            .zip(self.par_column_iter())
+            .zip(rhs.par_column_iter())
+            .for_each(|((mut out, column), rhs_column)| {
+                for ((out, a), b) in out.iter_mut().zip(column.iter()).zip(rhs_column.iter()) {
+                    *out = MaybeUninit::new(f(a.clone(), b.clone()));
+                }
+            });
+
+        // Safety: res is now fully initialized.
+        unsafe { res.assume_init() }
+    }
+
+    /// Replaces each component of `self` by the result of a closure `f` applied on it, in
+    /// parallel.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let mut m = DMatrix::from_fn(100, 100, |i, j| (i + j) as f64);
+    /// let expected = m.map(|e| 2.0 * e);
+    /// m.par_apply(|e| *e *= 2.0);
+    /// assert_eq!(m, expected);
+    /// ```
+    #[inline]
+    pub fn par_apply<F>(&mut self, f: F)
+    where
+        T: Send,
+        S: RawStorageMut<T, R, C>,
+        F: Fn(&mut T) + Sync,
+    {
+        self.par_column_iter_mut()
+            .for_each(|mut column| column.iter_mut().for_each(&f));
+    }
+}
+
+/// # Parallel folding on columns and rows
+///
+/// **Available only when the `rayon` feature is enabled.**
+impl<T: Scalar + Send + Sync, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Returns a row vector where each element is the result of the application of `f` on the
+    /// corresponding column of the original matrix, computed in parallel.
+    #[inline]
+    #[must_use]
+    pub fn par_compress_rows(
+        &self,
+        f: impl Fn(VectorSlice<'_, T, R, S::RStride, S::CStride>) -> T + Sync,
+    ) -> RowOVector<T, C>
+    where
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        let ncols = self.shape_generic().1;
+        let mut res = Matrix::uninit(Const::<1>, ncols);
+
+        res.as_mut_slice()
+            .par_iter_mut()
+            .zip(self.par_column_iter())
+            .for_each(|(out, column)| *out = MaybeUninit::new(f(column)));
+
+        // Safety: res is now fully initialized.
+        unsafe { res.assume_init() }
+    }
+
+    /// Returns a column vector where each element is the result of the application of `f` on
+    /// the corresponding column of the original matrix, computed in parallel.
+    ///
+    /// This is the same as `self.par_compress_rows(f).transpose()`.
+    #[inline]
+    #[must_use]
+    pub fn par_compress_rows_tr(
+        &self,
+        f: impl Fn(VectorSlice<'_, T, R, S::RStride, S::CStride>) -> T + Sync,
+    ) -> OVector<T, C>
+    where
+        DefaultAllocator: Allocator<T, C>,
+    {
+        let ncols = self.shape_generic().1;
+        let mut res = Matrix::uninit(ncols, Const::<1>);
+
+        res.as_mut_slice()
+            .par_iter_mut()
+            .zip(self.par_column_iter())
+            .for_each(|(out, column)| *out = MaybeUninit::new(f(column)));
+
+        // Safety: res is now fully initialized.
+        unsafe { res.assume_init() }
+    }
+
+    /// Folds the columns of this matrix in parallel.
+    ///
+    /// The columns are split into chunks of consecutive columns, and the components of the
+    /// columns of each chunk are folded in parallel with `f` into a vector filled with `init`.
+    /// The results of the chunks are then combined sequentially and in order with `combine`,
+    /// starting from a vector filled with `init`. The chunks only depend on the shape of the
+    /// matrix, so that the result is deterministic.
+    fn par_fold_columns(
+        &self,
+        init: T,
+        f: impl Fn(&mut T, T) + Sync,
+        combine: impl Fn(&mut T, T),
+    ) -> OVector<T, R>
+    where
+        DefaultAllocator: Allocator<T, R>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let chunk_size = (CHUNK_SIZE / nrows.value().max(1)).max(1);
+        // `usize::div_ceil` is not available on the older compilers we support.
+        #[allow(clippy::manual_div_ceil)]
+        let nchunks = (ncols.value() + chunk_size - 1) / chunk_size;
+        let mut partials = DMatrix::from_element(nrows.value(), nchunks, init.clone());
+
+        partials
+            .par_column_iter_mut()
+            .zip(self.par_column_iter().chunks(chunk_size))
+            .for_each(|(mut out, columns)| {
+                for column in columns {
+                    for (out, a) in out.iter_mut().zip(column.iter()) {
+                        f(out, a.clone());
+                    }
+                }
+            });
+
+        let mut res = OVector::from_element_generic(nrows, Const::<1>, init);
+        for partial in partials.column_iter() {
+            for (out, a) in res.iter_mut().zip(partial.iter()) {
+                combine(out, a.clone());
+            }
+        }
+        res
+    }
+}
+
+/// # Parallel statistics
+///
+/// **Available only when the `rayon` feature is enabled.**
+///
+/// These are the parallel counterparts of the common statistics operations. The reductions are
+/// performed in an order that does not depend on the number of threads, so that their results
+/// are deterministic. However, they may differ from the results of the sequential methods by
+/// rounding errors, since the operations are not performed in the same order.
+impl<T: Scalar + Send + Sync, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// The sum of all the elements of this matrix, computed in parallel.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_fn(100, 100, |i, j| (i + j) as f64);
+    /// assert_eq!(m.par_sum(), 990000.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn par_sum(&self) -> T
+    where
+        T: ClosedAdd + Zero,
+    {
+        let sums: Vec<T> = self.par_column_iter().map(|column| column.sum()).collect();
+        sums.into_iter().fold(T::zero(), |a, b| a + b)
+    }
+
+    /// The sum of all the rows of this matrix, computed in parallel.
+    #[inline]
+    #[must_use]
+    pub fn par_row_sum(&self) -> RowOVector<T, C>
+    where
+        T: ClosedAdd + Zero,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        self.par_compress_rows(|column| column.sum())
+    }
+
+    /// The sum of all the rows of this matrix, computed in parallel. The result is transposed
+    /// and returned as a column vector.
+    #[inline]
+    #[must_use]
+    pub fn par_row_sum_tr(&self) -> OVector<T, C>
+    where
+        T: ClosedAdd + Zero,
+        DefaultAllocator: Allocator<T, C>,
+    {
+        self.par_compress_rows_tr(|column| column.sum())
+    }
+
+    /// The sum of all the columns of this matrix, computed in parallel.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_fn(100, 1000, |i, _| i as f64);
+    /// assert_eq!(m.par_column_sum(), m.column(0) * 1000.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn par_column_sum(&self) -> OVector<T, R>
+    where
+        T: ClosedAdd + Zero,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        self.par_fold_columns(T::zero(), |out, a| *out += a, |out, a| *out += a)
+    }
+
+    /// The product of all the elements of this matrix, computed in parallel.
+    #[inline]
+    #[must_use]
+    pub fn par_product(&self) -> T
+    where
+        T: ClosedMul + One,
+    {
+        let products: Vec<T> = self
+            .par_column_iter()
+            .map(|column| column.product())
+            .collect();
+        products.into_iter().fold(T::one(), |a, b| a * b)
+    }
+
+    /// The product of all the rows of this matrix, computed in parallel.
+    #[inline]
+    #[must_use]
+    pub fn par_row_product(&self) -> RowOVector<T, C>
+    where
+        T: ClosedMul + One,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        self.par_compress_rows(|column| column.product())
+    }
+
+    /// The product of all the rows of this matrix, computed in parallel. The result is
+    /// transposed and returned as a column vector.
+    #[inline]
+    #[must_use]
+    pub fn par_row_product_tr(&self) -> OVector<T, C>
+    where
+        T: ClosedMul + One,
+        DefaultAllocator: Allocator<T, C>,
+    {
+        self.par_compress_rows_tr(|column| column.product())
+    }
+
+    /// The product of all the columns of this matrix, computed in parallel.
+    #[inline]
+    #[must_use]
+    pub fn par_column_product(&self) -> OVector<T, R>
+    where
+        T: ClosedMul + One,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        self.par_fold_columns(T::one(), |out, a| *out *= a, |out, a| *out *= a)
+    }
+
+    /// The variance of all the elements of this matrix, computed in parallel.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_fn(100, 100, |i, j| (i + j) as f64);
+    /// assert_relative_eq!(m.par_variance(), m.variance(), epsilon = 1.0e-10);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn par_variance(&self) -> T
+    where
+        T: Field + SupersetOf<f64>,
+    {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            let sums: Vec<(T, T)> = self
+                .par_column_iter()
+                .map(|column| {
+                    column.iter().cloned().fold((T::zero(), T::zero()), |a, b| {
+                        (a.0 + b.clone() * b.clone(), a.1 + b)
+                    })
+                })
+                .collect();
+            let val = sums
+                .into_iter()
+                .fold((T::zero(), T::zero()), |a, b| (a.0 + b.0, a.1 + b.1));
+            let denom = T::one() / crate::convert::<_, T>(self.len() as f64);
+            let vd = val.1 * denom.clone();
+            val.0 * denom - vd.clone() * vd
+        }
+    }
+
+    /// The variance of all the rows of this matrix, computed in parallel.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_fn(100, 100, |i, j| (i * j) as f64);
+    /// assert_eq!(m.par_row_variance(), m.row_variance());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn par_row_variance(&self) -> RowOVector<T, C>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        self.par_compress_rows(|column| column.variance())
+    }
+
+    /// The variance of all the rows of this matrix, computed in parallel. The result is
+    /// transposed and returned as a column vector.
+    #[inline]
+    #[must_use]
+    pub fn par_row_variance_tr(&self) -> OVector<T, C>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, C>,
+    {
+        self.par_compress_rows_tr(|column| column.variance())
+    }
+
+    /// The variance of all the columns of this matrix, computed in parallel.
+    #[inline]
+    #[must_use]
+    pub fn par_column_variance(&self) -> OVector<T, R>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        let mut mean = self.par_column_mean();
+        mean.apply(|e| *e = -(e.clone() * e.clone()));
+
+        let denom = T::one() / crate::convert::<_, T>(self.ncols() as f64);
+        let mean_squares = self.par_fold_columns(
+            T::zero(),
+            |out, a| *out += denom.clone() * a.clone() * a,
+            |out, a| *out += a,
+        );
+        mean + mean_squares
+    }
+
+    /// The mean of all the elements of this matrix, computed in parallel.
+    #[inline]
+    #[must_use]
+    pub fn par_mean(&self) -> T
+    where
+        T: Field + SupersetOf<f64>,
+    {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.par_sum() / crate::convert(self.len() as f64)
+        }
+    }
+
+    /// The mean of all the rows of this matrix, computed in parallel.
+    #[inline]
+    #[must_use]
+    pub fn par_row_mean(&self) -> RowOVector<T, C>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        self.par_compress_rows(|column| column.mean())
+    }
+
+    /// The mean of all the rows of this matrix, computed in parallel. The result is transposed
+    /// and returned as a column vector.
+    #[inline]
+    #[must_use]
+    pub fn par_row_mean_tr(&self) -> OVector<T, C>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, C>,
+    {
+        self.par_compress_rows_tr(|column| column.mean())
+    }
+
+    /// The mean of all the columns of this matrix, computed in parallel.
+    #[inline]
+    #[must_use]
+    pub fn par_column_mean(&self) -> OVector<T, R>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        let denom = T::one() / crate::convert::<_, T>(self.ncols() as f64);
+        self.par_fold_columns(
+            T::zero(),
+            |out, a| *out += denom.clone() * a,
+            |out, a| *out += a,
+        )
+    }
+}
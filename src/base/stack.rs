@@ -0,0 +1,127 @@
+use std::borrow::Borrow;
+use std::mem::MaybeUninit;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{Dim, Dynamic};
+use crate::base::storage::RawStorage;
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar};
+
+/// Concatenates the given matrices horizontally, i.e., side by side.
+///
+/// All the matrices must have the same number of rows. The number of rows of the result is
+/// statically known if it is statically known for the input matrices, while its number of
+/// columns is always dynamic. Use the `stack!` macro instead to concatenate
+/// matrices of different types, or to obtain a result with statically known dimensions.
+///
+/// Panics if the matrices do not all have the same number of rows.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{hstack, DMatrix, Dynamic, Matrix2, OMatrix, U2};
+/// let a = Matrix2::new(1, 2,
+///                      5, 6);
+/// let b = Matrix2::new(3, 4,
+///                      7, 8);
+///
+/// let m: OMatrix<i32, U2, Dynamic> = hstack(&[a, b]);
+/// assert_eq!(m, DMatrix::from_row_slice(2, 4, &[1, 2, 3, 4,
+///                                               5, 6, 7, 8]));
+///
+/// // Matrices can also be concatenated through references.
+/// let c = DMatrix::from_row_slice(2, 3, &[0, 0, 0, 1, 1, 1]);
+/// let d = DMatrix::from_row_slice(2, 1, &[2, 3]);
+/// assert_eq!(hstack(&[&c, &d]).ncols(), 4);
+/// ```
+#[must_use]
+pub fn hstack<T, R, C, S, B>(blocks: &[B]) -> OMatrix<T, R, Dynamic>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+    B: Borrow<Matrix<T, R, C, S>>,
+    DefaultAllocator: Allocator<T, R, Dynamic>,
+{
+    let nrows = blocks.first().map_or_else(
+        || R::from_usize(R::try_to_usize().unwrap_or(0)),
+        |block| block.borrow().shape_generic().0,
+    );
+    let ncols = blocks.iter().map(|block| block.borrow().ncols()).sum();
+    let mut res = Matrix::uninit(nrows, Dynamic::new(ncols));
+    let mut offset = 0;
+
+    for block in blocks {
+        let block = block.borrow();
+        assert_eq!(
+            block.nrows(),
+            nrows.value(),
+            "hstack: all the matrices must have the same number of rows."
+        );
+
+        res.generic_slice_mut((0, offset), block.shape_generic())
+            .zip_apply(block, |out, e| *out = MaybeUninit::new(e));
+        offset += block.ncols();
+    }
+
+    // Safety: res is now fully initialized.
+    unsafe { res.assume_init() }
+}
+
+/// Concatenates the given matrices vertically, i.e., on top of each other.
+///
+/// All the matrices must have the same number of columns. The number of columns of the result
+/// is statically known if it is statically known for the input matrices, while its number of
+/// rows is always dynamic. Use the `stack!` macro instead to concatenate
+/// matrices of different types, or to obtain a result with statically known dimensions.
+///
+/// Panics if the matrices do not all have the same number of columns.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{vstack, DMatrix, Matrix2x3, RowVector3};
+/// let a = Matrix2x3::new(1, 2, 3,
+///                        4, 5, 6);
+/// let b = Matrix2x3::new(7, 8, 9,
+///                        10, 11, 12);
+///
+/// let m = vstack(&[&a, &b, &a]);
+/// assert_eq!(m.shape(), (6, 3));
+/// assert_eq!(m.row(3), RowVector3::new(10, 11, 12));
+///
+/// let rows: Vec<_> = (0..4).map(|i| RowVector3::repeat(i)).collect();
+/// assert_eq!(vstack(&rows), DMatrix::from_fn(4, 3, |i, _| i as i32));
+/// ```
+#[must_use]
+pub fn vstack<T, R, C, S, B>(blocks: &[B]) -> OMatrix<T, Dynamic, C>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+    B: Borrow<Matrix<T, R, C, S>>,
+    DefaultAllocator: Allocator<T, Dynamic, C>,
+{
+    let ncols = blocks.first().map_or_else(
+        || C::from_usize(C::try_to_usize().unwrap_or(0)),
+        |block| block.borrow().shape_generic().1,
+    );
+    let nrows = blocks.iter().map(|block| block.borrow().nrows()).sum();
+    let mut res = Matrix::uninit(Dynamic::new(nrows), ncols);
+    let mut offset = 0;
+
+    for block in blocks {
+        let block = block.borrow();
+        assert_eq!(
+            block.ncols(),
+            ncols.value(),
+            "vstack: all the matrices must have the same number of columns."
+        );
+
+        res.generic_slice_mut((offset, 0), block.shape_generic())
+            .zip_apply(block, |out, e| *out = MaybeUninit::new(e));
+        offset += block.nrows();
+    }
+
+    // Safety: res is now fully initialized.
+    unsafe { res.assume_init() }
+}
@@ -1,6 +1,10 @@
 use crate::allocator::Allocator;
-use crate::storage::RawStorage;
-use crate::{Const, DefaultAllocator, Dim, Matrix, OVector, RowOVector, Scalar, VectorSlice, U1};
+use crate::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
+use crate::storage::{RawStorage, Storage};
+use crate::{
+    Const, DefaultAllocator, Dim, Matrix, OMatrix, OVector, RealField, RowOVector, Scalar, Vector,
+    VectorSlice, U1,
+};
 use num::{One, Zero};
 use simba::scalar::{ClosedAdd, ClosedMul, Field, SupersetOf};
 use std::mem::MaybeUninit;
@@ -520,3 +524,909 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
         })
     }
 }
+
+/// # Cumulative sums and products
+impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// The cumulative sum of the rows of this matrix.
+    ///
+    /// The `i`-th row of the result is the sum of the first `i + 1` rows of `self`, so that its
+    /// last row is equal to `self.row_sum()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix3x2;
+    ///
+    /// let m = Matrix3x2::new(1, 2,
+    ///                        3, 4,
+    ///                        5, 6);
+    /// assert_eq!(m.row_cumsum(), Matrix3x2::new(1, 2,
+    ///                                           4, 6,
+    ///                                           9, 12));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_cumsum(&self) -> OMatrix<T, R, C>
+    where
+        T: ClosedAdd,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let mut res = self.clone_owned();
+
+        for mut column in res.column_iter_mut() {
+            for i in 1..column.len() {
+                let prev = column[i - 1].clone();
+                column[i] += prev;
+            }
+        }
+
+        res
+    }
+
+    /// The cumulative sum of the columns of this matrix.
+    ///
+    /// The `j`-th column of the result is the sum of the first `j + 1` columns of `self`, so
+    /// that its last column is equal to `self.column_sum()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// assert_eq!(m.column_cumsum(), Matrix2x3::new(1, 3, 6,
+    ///                                              4, 9, 15));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_cumsum(&self) -> OMatrix<T, R, C>
+    where
+        T: ClosedAdd,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let mut res = self.clone_owned();
+
+        for j in 1..res.ncols() {
+            for i in 0..res.nrows() {
+                let prev = res[(i, j - 1)].clone();
+                res[(i, j)] += prev;
+            }
+        }
+
+        res
+    }
+
+    /// The cumulative product of the rows of this matrix.
+    ///
+    /// The `i`-th row of the result is the component-wise product of the first `i + 1` rows of
+    /// `self`, so that its last row is equal to `self.row_product()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix3x2;
+    ///
+    /// let m = Matrix3x2::new(1, 2,
+    ///                        3, 4,
+    ///                        5, 6);
+    /// assert_eq!(m.row_cumprod(), Matrix3x2::new(1, 2,
+    ///                                            3, 8,
+    ///                                            15, 48));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_cumprod(&self) -> OMatrix<T, R, C>
+    where
+        T: ClosedMul,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let mut res = self.clone_owned();
+
+        for mut column in res.column_iter_mut() {
+            for i in 1..column.len() {
+                let prev = column[i - 1].clone();
+                column[i] *= prev;
+            }
+        }
+
+        res
+    }
+
+    /// The cumulative product of the columns of this matrix.
+    ///
+    /// The `j`-th column of the result is the component-wise product of the first `j + 1`
+    /// columns of `self`, so that its last column is equal to `self.column_product()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// assert_eq!(m.column_cumprod(), Matrix2x3::new(1, 2, 6,
+    ///                                               4, 20, 120));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_cumprod(&self) -> OMatrix<T, R, C>
+    where
+        T: ClosedMul,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let mut res = self.clone_owned();
+
+        for j in 1..res.ncols() {
+            for i in 0..res.nrows() {
+                let prev = res[(i, j - 1)].clone();
+                res[(i, j)] *= prev;
+            }
+        }
+
+        res
+    }
+}
+
+/// # Order statistics
+impl<T: RealField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// The `q`-th quantile of all the elements of this matrix.
+    ///
+    /// The quantile is linearly interpolated between the two closest elements, i.e., if the
+    /// `n` elements sorted in increasing order are `x₀ ≤ … ≤ xₙ₋₁` and `h = q (n - 1)`, the
+    /// result is `x⌊h⌋ + (h - ⌊h⌋) (x⌊h⌋₊₁ - x⌊h⌋)`. This is the default method of NumPy and R.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix is empty, if `q` is not in `[0, 1]`, or if this matrix contains NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(6.0, 1.0, 4.0,
+    ///                        2.0, 5.0, 3.0);
+    /// assert_eq!(m.quantile(0.0), 1.0);
+    /// assert_eq!(m.quantile(0.25), 2.25);
+    /// assert_eq!(m.quantile(1.0), 6.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn quantile(&self, q: T) -> T
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        assert!(
+            !self.is_empty(),
+            "Cannot compute the quantile of an empty matrix."
+        );
+        assert!(
+            q >= T::zero() && q <= T::one(),
+            "The quantile must be in [0, 1]."
+        );
+        // NaN is the only value that is not comparable with itself.
+        assert!(
+            self.iter().all(|e| e.partial_cmp(e).is_some()),
+            "Cannot compute the quantile of a matrix containing NaN."
+        );
+
+        let mut sorted = self.clone_owned();
+        let sorted = sorted.as_mut_slice();
+        sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let n = sorted.len();
+        let h = q * crate::convert::<_, T>((n - 1) as f64);
+        let lower = h.clone().floor();
+        let i = crate::try_convert::<_, f64>(lower.clone()).unwrap_or(0.0) as usize;
+
+        if i + 1 >= n {
+            sorted[n - 1].clone()
+        } else {
+            let lo = sorted[i].clone();
+            let hi = sorted[i + 1].clone();
+            lo.clone() + (h - lower) * (hi - lo)
+        }
+    }
+
+    /// The `q`-th quantile of all the rows of this matrix.
+    ///
+    /// Use `.row_quantile_tr` if you need the result in a column vector instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 6.0,
+    ///                        3.0, 4.0,
+    ///                        2.0, 5.0);
+    /// assert_eq!(m.row_quantile(0.75), RowVector2::new(2.5, 5.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_quantile(&self, q: T) -> RowOVector<T, C>
+    where
+        DefaultAllocator: Allocator<T, U1, C> + Allocator<T, R>,
+    {
+        self.compress_rows(|col| col.quantile(q.clone()))
+    }
+
+    /// The `q`-th quantile of all the rows of this matrix. The result is transposed and returned
+    /// as a column vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, Vector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 6.0,
+    ///                        3.0, 4.0,
+    ///                        2.0, 5.0);
+    /// assert_eq!(m.row_quantile_tr(0.75), Vector2::new(2.5, 5.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_quantile_tr(&self, q: T) -> OVector<T, C>
+    where
+        DefaultAllocator: Allocator<T, C> + Allocator<T, R>,
+    {
+        self.compress_rows_tr(|col| col.quantile(q.clone()))
+    }
+
+    /// The `q`-th quantile of all the columns of this matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 3.0, 2.0,
+    ///                        6.0, 4.0, 5.0);
+    /// assert_eq!(m.column_quantile(0.75), Vector2::new(2.5, 5.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_quantile(&self, q: T) -> OVector<T, R>
+    where
+        DefaultAllocator: Allocator<T, R> + Allocator<T, U1, C>,
+    {
+        let nrows = self.shape_generic().0;
+        OVector::from_fn_generic(nrows, Const::<1>, |i, _| self.row(i).quantile(q.clone()))
+    }
+
+    /// The median of all the elements of this matrix.
+    ///
+    /// This is the same as `self.quantile(0.5)`, i.e., the median of an even number of elements
+    /// is the mean of the two middle elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix is empty, or if it contains NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(6.0, 1.0, 4.0,
+    ///                        2.0, 5.0, 3.0);
+    /// assert_eq!(m.median(), 3.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn median(&self) -> T
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        self.quantile(crate::convert(0.5))
+    }
+
+    /// The median of all the rows of this matrix.
+    ///
+    /// Use `.row_median_tr` if you need the result in a column vector instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 6.0,
+    ///                        3.0, 4.0,
+    ///                        2.0, 5.0);
+    /// assert_eq!(m.row_median(), RowVector2::new(2.0, 5.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_median(&self) -> RowOVector<T, C>
+    where
+        DefaultAllocator: Allocator<T, U1, C> + Allocator<T, R>,
+    {
+        self.row_quantile(crate::convert(0.5))
+    }
+
+    /// The median of all the rows of this matrix. The result is transposed and returned as a
+    /// column vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, Vector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 6.0,
+    ///                        3.0, 4.0,
+    ///                        2.0, 5.0);
+    /// assert_eq!(m.row_median_tr(), Vector2::new(2.0, 5.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_median_tr(&self) -> OVector<T, C>
+    where
+        DefaultAllocator: Allocator<T, C> + Allocator<T, R>,
+    {
+        self.row_quantile_tr(crate::convert(0.5))
+    }
+
+    /// The median of all the columns of this matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x4, Vector2};
+    ///
+    /// let m = Matrix2x4::new(1.0, 3.0, 2.0, 7.0,
+    ///                        6.0, 4.0, 5.0, 0.0);
+    /// assert_eq!(m.column_median(), Vector2::new(2.5, 4.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_median(&self) -> OVector<T, R>
+    where
+        DefaultAllocator: Allocator<T, R> + Allocator<T, U1, C>,
+    {
+        self.column_quantile(crate::convert(0.5))
+    }
+}
+
+/// # Weighted statistics
+impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// The weighted mean of all the elements of this matrix.
+    ///
+    /// Each element of `self` is weighted by the element of `weights` at the same position. The
+    /// weights do not have to be normalized, but their sum must not be zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let w = Matrix2x3::new(1.0, 0.0, 0.0,
+    ///                        0.0, 0.0, 3.0);
+    /// assert_eq!(m.weighted_mean(&w), 4.75);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn weighted_mean<R2: Dim, C2: Dim, S2>(&self, weights: &Matrix<T, R2, C2, S2>) -> T
+    where
+        T: Field,
+        S2: RawStorage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfColumns<C, C2>,
+    {
+        assert_eq!(self.shape(), weights.shape(), "Mismatched dimensions.");
+
+        if self.is_empty() {
+            T::zero()
+        } else {
+            let (sum, total_weight) = self.iter().zip(weights.iter()).fold(
+                (T::zero(), T::zero()),
+                |(sum, total_weight), (e, w)| {
+                    (sum + w.clone() * e.clone(), total_weight + w.clone())
+                },
+            );
+            sum / total_weight
+        }
+    }
+
+    /// The weighted variance of all the elements of this matrix.
+    ///
+    /// This is the weighted mean of the squared deviations from the weighted mean, with the
+    /// element-wise weights given by `weights`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let w = Matrix2x3::new(1.0, 0.0, 0.0,
+    ///                        0.0, 0.0, 3.0);
+    /// assert_eq!(m.weighted_variance(&w), 4.6875);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn weighted_variance<R2: Dim, C2: Dim, S2>(&self, weights: &Matrix<T, R2, C2, S2>) -> T
+    where
+        T: Field,
+        S2: RawStorage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfColumns<C, C2>,
+    {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            let mean = self.weighted_mean(weights);
+            let (sum, total_weight) = self.iter().zip(weights.iter()).fold(
+                (T::zero(), T::zero()),
+                |(sum, total_weight), (e, w)| {
+                    let diff = e.clone() - mean.clone();
+                    (
+                        sum + w.clone() * diff.clone() * diff,
+                        total_weight + w.clone(),
+                    )
+                },
+            );
+            sum / total_weight
+        }
+    }
+
+    /// The weighted mean of all the rows of this matrix.
+    ///
+    /// The `i`-th row is weighted by `weights[i]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2, Vector3};
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        3.0, 4.0,
+    ///                        5.0, 6.0);
+    /// assert_eq!(m.row_weighted_mean(&Vector3::new(1.0, 0.0, 3.0)), RowVector2::new(4.0, 5.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_weighted_mean<R2: Dim, S2>(&self, weights: &Vector<T, R2, S2>) -> RowOVector<T, C>
+    where
+        T: Field,
+        S2: RawStorage<T, R2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        assert_eq!(self.nrows(), weights.len(), "Mismatched dimensions.");
+        self.compress_rows(|col| col.weighted_mean(weights))
+    }
+
+    /// The weighted variance of all the rows of this matrix.
+    ///
+    /// The `i`-th row is weighted by `weights[i]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2, Vector3};
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        3.0, 4.0,
+    ///                        5.0, 6.0);
+    /// assert_eq!(m.row_weighted_variance(&Vector3::new(1.0, 0.0, 3.0)), RowVector2::new(3.0, 3.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_weighted_variance<R2: Dim, S2>(
+        &self,
+        weights: &Vector<T, R2, S2>,
+    ) -> RowOVector<T, C>
+    where
+        T: Field,
+        S2: RawStorage<T, R2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        assert_eq!(self.nrows(), weights.len(), "Mismatched dimensions.");
+        self.compress_rows(|col| col.weighted_variance(weights))
+    }
+
+    /// The weighted mean of all the columns of this matrix.
+    ///
+    /// The `j`-th column is weighted by `weights[j]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2, Vector3};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// assert_eq!(m.column_weighted_mean(&Vector3::new(1.0, 0.0, 3.0)), Vector2::new(2.5, 5.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_weighted_mean<C2: Dim, S2>(&self, weights: &Vector<T, C2, S2>) -> OVector<T, R>
+    where
+        T: Field,
+        S2: RawStorage<T, C2>,
+        ShapeConstraint: SameNumberOfRows<C, C2>,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        assert_eq!(self.ncols(), weights.len(), "Mismatched dimensions.");
+
+        let nrows = self.shape_generic().0;
+        let total_weight = weights.iter().cloned().fold(T::zero(), |a, b| a + b);
+        let mut res = OVector::zeros_generic(nrows, Const::<1>);
+
+        for (j, w) in weights.iter().enumerate() {
+            res.axpy(w.clone(), &self.column(j), T::one());
+        }
+
+        res / total_weight
+    }
+
+    /// The weighted variance of all the columns of this matrix.
+    ///
+    /// The `j`-th column is weighted by `weights[j]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2, Vector3};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// assert_eq!(m.column_weighted_variance(&Vector3::new(1.0, 0.0, 3.0)), Vector2::new(0.75, 0.75));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_weighted_variance<C2: Dim, S2>(
+        &self,
+        weights: &Vector<T, C2, S2>,
+    ) -> OVector<T, R>
+    where
+        T: Field,
+        S2: RawStorage<T, C2>,
+        ShapeConstraint: SameNumberOfRows<C, C2>,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        let mean = self.column_weighted_mean(weights);
+        let total_weight = weights.iter().cloned().fold(T::zero(), |a, b| a + b);
+        let mut res = OVector::zeros_generic(mean.shape_generic().0, Const::<1>);
+
+        for (j, w) in weights.iter().enumerate() {
+            let column = self.column(j);
+            for i in 0..res.nrows() {
+                let diff = column[i].clone() - mean[i].clone();
+                res[i] += w.clone() * diff.clone() * diff;
+            }
+        }
+
+        res / total_weight
+    }
+}
+
+/// # Covariance and correlation
+impl<T: RealField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// The covariance matrix of the rows of this matrix, each row being an observation of the
+    /// variables associated to the columns.
+    ///
+    /// The element `(i, j)` of the result is the covariance of the `i`-th and `j`-th columns of
+    /// `self`. Like `.variance()`, the covariances are normalized by the number of observations,
+    /// so that the diagonal of the result is equal to `self.row_variance_tr()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix3x2};
+    ///
+    /// let observations = Matrix3x2::new(1.0, 6.0,
+    ///                                   2.0, 4.0,
+    ///                                   3.0, 2.0);
+    /// let cov = observations.row_covariance();
+    /// assert_eq!(cov, Matrix2::new(2.0 / 3.0, -4.0 / 3.0,
+    ///                              -4.0 / 3.0, 8.0 / 3.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_covariance(&self) -> OMatrix<T, C, C>
+    where
+        DefaultAllocator: Allocator<T, R, C> + Allocator<T, U1, C> + Allocator<T, C, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+
+        if nrows.value() == 0 {
+            return OMatrix::zeros_generic(ncols, ncols);
+        }
+
+        let mean = self.row_mean();
+        let mut centered = self.clone_owned();
+        for mut row in centered.row_iter_mut() {
+            row -= &mean;
+        }
+
+        let denom = T::one() / crate::convert::<_, T>(nrows.value() as f64);
+        centered.tr_mul(&centered) * denom
+    }
+
+    /// The covariance matrix of the columns of this matrix, each column being an observation of
+    /// the variables associated to the rows.
+    ///
+    /// The element `(i, j)` of the result is the covariance of the `i`-th and `j`-th rows of
+    /// `self`. Like `.variance()`, the covariances are normalized by the number of observations,
+    /// so that the diagonal of the result is equal to `self.column_variance()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix2x3};
+    ///
+    /// let observations = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                                   6.0, 4.0, 2.0);
+    /// let cov = observations.column_covariance();
+    /// assert_eq!(cov, Matrix2::new(2.0 / 3.0, -4.0 / 3.0,
+    ///                              -4.0 / 3.0, 8.0 / 3.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_covariance(&self) -> OMatrix<T, R, R>
+    where
+        DefaultAllocator: Allocator<T, R> + Allocator<T, R, R>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let mut res = OMatrix::zeros_generic(nrows, nrows);
+
+        if ncols.value() == 0 {
+            return res;
+        }
+
+        let mean = self.column_mean();
+        let denom = T::one() / crate::convert::<_, T>(ncols.value() as f64);
+
+        for column in self.column_iter() {
+            let centered = column - &mean;
+            res.ger(denom.clone(), &centered, &centered, T::one());
+        }
+
+        res
+    }
+
+    /// The correlation matrix of the rows of this matrix, each row being an observation of the
+    /// variables associated to the columns.
+    ///
+    /// The element `(i, j)` of the result is the Pearson correlation coefficient of the `i`-th
+    /// and `j`-th columns of `self`. The correlations involving a constant column are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Matrix4x3};
+    ///
+    /// let observations = Matrix4x3::new(1.0, 2.0, 4.0,
+    ///                                   2.0, 4.0, 3.0,
+    ///                                   3.0, 6.0, 2.0,
+    ///                                   4.0, 8.0, 1.0);
+    /// let corr = observations.row_correlation();
+    /// assert_relative_eq!(corr, Matrix3::new(1.0, 1.0, -1.0,
+    ///                                        1.0, 1.0, -1.0,
+    ///                                        -1.0, -1.0, 1.0), epsilon = 1.0e-12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_correlation(&self) -> OMatrix<T, C, C>
+    where
+        DefaultAllocator:
+            Allocator<T, R, C> + Allocator<T, U1, C> + Allocator<T, C, C> + Allocator<T, C>,
+    {
+        correlation_from_covariance(self.row_covariance())
+    }
+
+    /// The correlation matrix of the columns of this matrix, each column being an observation
+    /// of the variables associated to the rows.
+    ///
+    /// The element `(i, j)` of the result is the Pearson correlation coefficient of the `i`-th
+    /// and `j`-th rows of `self`. The correlations involving a constant row are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix2x4};
+    ///
+    /// let observations = Matrix2x4::new(1.0, 2.0, 3.0, 4.0,
+    ///                                   2.0, 1.0, 4.0, 3.0);
+    /// let corr = observations.column_correlation();
+    /// assert_relative_eq!(corr, Matrix2::new(1.0, 0.6,
+    ///                                        0.6, 1.0), epsilon = 1.0e-12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_correlation(&self) -> OMatrix<T, R, R>
+    where
+        DefaultAllocator: Allocator<T, R> + Allocator<T, R, R>,
+    {
+        correlation_from_covariance(self.column_covariance())
+    }
+}
+
+/// Normalizes the given covariance matrix into a correlation matrix.
+fn correlation_from_covariance<T: RealField, D: Dim>(mut cov: OMatrix<T, D, D>) -> OMatrix<T, D, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+{
+    let std_devs = cov.map_diagonal(|e| e.sqrt());
+
+    for j in 0..cov.ncols() {
+        for i in 0..cov.nrows() {
+            cov[(i, j)] /= std_devs[i].clone() * std_devs[j].clone();
+        }
+    }
+
+    cov
+}
+
+/// The population standard deviation of the elements of `m`, computed with a centered two-pass
+/// algorithm.
+///
+/// Unlike `m.variance().sqrt()`, this does not suffer from cancellation. The elements are shifted
+/// by the first one before being centered, so that the result is exactly zero for constant inputs.
+fn centered_std<T: RealField, R: Dim, C: Dim, S: RawStorage<T, R, C>>(m: &Matrix<T, R, C, S>) -> T {
+    let shift = match m.iter().next() {
+        Some(first) => first.clone(),
+        None => return T::zero(),
+    };
+
+    let denom = T::one() / crate::convert::<_, T>(m.len() as f64);
+    let mean = m
+        .iter()
+        .fold(T::zero(), |acc, e| acc + (e.clone() - shift.clone()))
+        * denom.clone();
+    let variance = m.iter().fold(T::zero(), |acc, e| {
+        let diff = e.clone() - shift.clone() - mean.clone();
+        acc + diff.clone() * diff
+    }) * denom;
+
+    variance.max(T::zero()).sqrt()
+}
+
+/// # Moving window statistics
+impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// The moving mean of the rows of this matrix over a window of `window` rows.
+    ///
+    /// The `i`-th row of the result is the mean of the rows `max(i + 1, window) - window ..= i`
+    /// of `self`, i.e., of the `window` last rows up to the `i`-th, or of all the first `i + 1`
+    /// rows if there are less than `window` of them. The result has the same dimensions as `self`.
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix4x2;
+    ///
+    /// let m = Matrix4x2::new(1.0, 8.0,
+    ///                        3.0, 6.0,
+    ///                        5.0, 4.0,
+    ///                        7.0, 2.0);
+    /// assert_eq!(m.row_moving_mean(2), Matrix4x2::new(1.0, 8.0,
+    ///                                                 2.0, 7.0,
+    ///                                                 4.0, 5.0,
+    ///                                                 6.0, 3.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_moving_mean(&self, window: usize) -> OMatrix<T, R, C>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        assert!(window > 0, "The moving window must not be empty.");
+        let (nrows, ncols) = self.shape_generic();
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| {
+            self.slice_range((i + 1).saturating_sub(window)..=i, j)
+                .mean()
+        })
+    }
+
+    /// The moving standard deviation of the rows of this matrix over a window of `window` rows.
+    ///
+    /// The `i`-th row of the result is the standard deviation of the same rows of `self` as the
+    /// `i`-th row of `self.row_moving_mean(window)`. Like `.variance()`, the variances are
+    /// normalized by the number of rows in the window.
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix4x2;
+    ///
+    /// let m = Matrix4x2::new(1.0, 8.0,
+    ///                        3.0, 6.0,
+    ///                        5.0, 2.0,
+    ///                        7.0, 2.0);
+    /// assert_eq!(m.row_moving_std(2), Matrix4x2::new(0.0, 0.0,
+    ///                                                1.0, 1.0,
+    ///                                                1.0, 2.0,
+    ///                                                1.0, 0.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_moving_std(&self, window: usize) -> OMatrix<T, R, C>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        assert!(window > 0, "The moving window must not be empty.");
+        let (nrows, ncols) = self.shape_generic();
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| {
+            centered_std(&self.slice_range((i + 1).saturating_sub(window)..=i, j))
+        })
+    }
+
+    /// The moving mean of the columns of this matrix over a window of `window` columns.
+    ///
+    /// The `j`-th column of the result is the mean of the columns
+    /// `max(j + 1, window) - window ..= j` of `self`, i.e., of the `window` last columns up to
+    /// the `j`-th, or of all the first `j + 1` columns if there are less than `window` of them.
+    /// The result has the same dimensions as `self`.
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x4;
+    ///
+    /// let m = Matrix2x4::new(1.0, 3.0, 5.0, 7.0,
+    ///                        8.0, 6.0, 4.0, 2.0);
+    /// assert_eq!(m.column_moving_mean(3), Matrix2x4::new(1.0, 2.0, 3.0, 5.0,
+    ///                                                    8.0, 7.0, 6.0, 4.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_moving_mean(&self, window: usize) -> OMatrix<T, R, C>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        assert!(window > 0, "The moving window must not be empty.");
+        let (nrows, ncols) = self.shape_generic();
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| {
+            self.slice_range(i, (j + 1).saturating_sub(window)..=j)
+                .mean()
+        })
+    }
+
+    /// The moving standard deviation of the columns of this matrix over a window of `window`
+    /// columns.
+    ///
+    /// The `j`-th column of the result is the standard deviation of the same columns of `self`
+    /// as the `j`-th column of `self.column_moving_mean(window)`. Like `.variance()`, the
+    /// variances are normalized by the number of columns in the window.
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x4;
+    ///
+    /// let m = Matrix2x4::new(1.0, 3.0, 5.0, 7.0,
+    ///                        8.0, 6.0, 2.0, 2.0);
+    /// assert_eq!(m.column_moving_std(2), Matrix2x4::new(0.0, 1.0, 1.0, 1.0,
+    ///                                                   0.0, 1.0, 2.0, 0.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_moving_std(&self, window: usize) -> OMatrix<T, R, C>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        assert!(window > 0, "The moving window must not be empty.");
+        let (nrows, ncols) = self.shape_generic();
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| {
+            centered_std(&self.slice_range(i, (j + 1).saturating_sub(window)..=j))
+        })
+    }
+}
@@ -69,6 +69,8 @@ an optimized set of tools for computer graphics and physics. Those features incl
 * 3D projections for computer graphics: `Perspective3`, `Orthographic3`.
 * Matrix factorizations: `Cholesky`, `QR`, `LU`, `FullPivLU`, `SVD`, `Schur`, `Hessenberg`, `SymmetricEigen`.
 * Insertion and removal of rows of columns of a matrix.
+* Parallel column iterators, elementwise operations and statistics on matrices when the `rayon`
+  feature is enabled.
 */
 
 #![deny(
@@ -93,6 +95,9 @@ an optimized set of tools for computer graphics and physics. Those features incl
 #[cfg(feature = "rand-no-std")]
 extern crate rand_package as rand;
 
+#[cfg(feature = "rayon")]
+extern crate rayon_package as rayon;
+
 #[cfg(feature = "serde-serialize-no-std")]
 #[macro_use]
 extern crate serde;
@@ -139,7 +144,7 @@ pub use crate::sparse::*;
 pub use base as core;
 
 #[cfg(feature = "macros")]
-pub use nalgebra_macros::{dmatrix, dvector, matrix, point, vector};
+pub use nalgebra_macros::{dmatrix, dvector, matrix, point, stack, vector};
 
 use simba::scalar::SupersetOf;
 use std::cmp::{self, Ordering, PartialOrd};
@@ -2,15 +2,18 @@
 use serde::{Deserialize, Serialize};
 
 use num::One;
-use simba::scalar::ComplexField;
+use simba::scalar::{ComplexField, SupersetOf};
 use simba::simd::SimdComplexField;
 
 use crate::allocator::Allocator;
 use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, Vector};
-use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::constraint::{AreMultipliable, SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimAdd, DimDiff, DimSub, DimSum, U1};
 use crate::storage::{Storage, StorageMut};
 
+use crate::linalg::norm_estimate::{estimate_one_norm, rcond_from_estimates};
+use crate::linalg::refinement::{self, RefinedSolution};
+
 /// The Cholesky decomposition of a symmetric-definite-positive matrix.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
 #[cfg_attr(
@@ -241,6 +244,67 @@ where
         Some(Cholesky { chol: matrix })
     }
 
+    /// Solves the linear system `a * x = b` with iterative refinement, where `a` is the matrix
+    /// that was decomposed and `x` is the unknown to be determined.
+    ///
+    /// The system is first solved with this decomposition, then the solution is improved by
+    /// iterative refinement: the residual `b - a * x` is computed with the matrix `a` that was
+    /// decomposed, in the possibly higher precision of `U`, and the correction is obtained by
+    /// solving the system with this decomposition again. This makes it possible to decompose the
+    /// matrix in `f32` and still get a solution accurate in `f64`, as long as the matrix is not
+    /// too ill-conditioned.
+    ///
+    /// The refinement stops when the backward error reaches the machine epsilon of `U`, when it
+    /// stops decreasing significantly, or after `max_iter` steps.
+    #[must_use]
+    pub fn solve_refined<U, R2: Dim, C2: Dim, S2, S3>(
+        &self,
+        a: &Matrix<U, D, D, S2>,
+        b: &Matrix<U, R2, C2, S3>,
+        max_iter: usize,
+    ) -> RefinedSolution<U, R2, C2>
+    where
+        U: ComplexField + SupersetOf<T>,
+        S2: Storage<U, D, D>,
+        S3: Storage<U, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D> + AreMultipliable<D, D, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2> + Allocator<U, R2, C2>,
+    {
+        refinement::solve_refined(a, b, max_iter, |x| {
+            self.solve_mut(x);
+            true
+        })
+        .expect("The Cholesky solve cannot fail.")
+    }
+
+    /// Estimates the reciprocal of the 1-norm condition number of the decomposed matrix.
+    ///
+    /// The 1-norms of the decomposed matrix and of its inverse are estimated with the
+    /// Hager–Higham algorithm, which only needs a few matrix-vector products and triangular
+    /// solves, so the result is only an estimate of the reciprocal condition number. Values close
+    /// to the machine epsilon indicate that solutions of linear systems with this matrix are
+    /// unreliable.
+    #[must_use]
+    pub fn rcond(&self) -> T::RealField
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let dim = self.chol.shape_generic().0;
+        let l = self.l();
+
+        // The decomposed matrix is hermitian, so its adjoint doesn't need a special treatment.
+        let norm = estimate_one_norm(dim, |x, _| {
+            *x = &l * l.ad_mul(x);
+            true
+        });
+        let inv_norm = estimate_one_norm(dim, |x, _| {
+            self.solve_mut(x);
+            true
+        });
+
+        rcond_from_estimates::<T>(norm, inv_norm)
+    }
+
     /// Given the Cholesky decomposition of a matrix `M`, a scalar `sigma` and a vector `v`,
     /// performs a rank one update such that we end up with the decomposition of `M + sigma * (v * v.adjoint())`.
     #[inline]
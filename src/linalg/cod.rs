@@ -0,0 +1,198 @@
+use num::Zero;
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Unit};
+use crate::dimension::{Const, Dim, DimMin, DimMinimum};
+use simba::scalar::ComplexField;
+
+use crate::geometry::Reflection;
+use crate::linalg::{householder, ColPivQR};
+
+/// The complete orthogonal decomposition of a general matrix.
+///
+/// The decomposed matrix `M` is equal to `Q * T * Z` where `Q` has orthonormal columns, `Z` has
+/// orthonormal rows, and `T` is a square matrix of the form `[T11 0; 0 0]`. The block `T11` is
+/// a lower-triangular matrix with a real positive diagonal, and its dimension is the numerical
+/// rank of `M`.
+///
+/// It is computed from the QR decomposition with column pivoting of `M`, whose diagonal elements
+/// with a modulus smaller than or equal to a given tolerance are considered to be zero, followed
+/// by an LQ decomposition of the first `rank` rows of its upper-trapezoidal factor.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, R, C> +
+                           Allocator<T, DimMinimum<R, C>> +
+                           Allocator<(usize, usize), DimMinimum<R, C>> +
+                           Allocator<T, DimMinimum<R, C>, C>,
+         ColPivQR<T, R, C>: Serialize,
+         OMatrix<T, DimMinimum<R, C>, C>: Serialize,
+         OVector<T, DimMinimum<R, C>>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, R, C> +
+                           Allocator<T, DimMinimum<R, C>> +
+                           Allocator<(usize, usize), DimMinimum<R, C>> +
+                           Allocator<T, DimMinimum<R, C>, C>,
+         ColPivQR<T, R, C>: Deserialize<'de>,
+         OMatrix<T, DimMinimum<R, C>, C>: Deserialize<'de>,
+         OVector<T, DimMinimum<R, C>>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct COD<T: ComplexField, R: DimMin<C>, C: Dim>
+where
+    DefaultAllocator: Allocator<T, R, C>
+        + Allocator<T, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>
+        + Allocator<T, DimMinimum<R, C>, C>,
+{
+    col_piv_qr: ColPivQR<T, R, C>,
+    // The LQ decomposition of the first `rank` rows of the `R` factor of `col_piv_qr`, stored
+    // the same way as in `LQ`. The remaining rows are zero.
+    tz: OMatrix<T, DimMinimum<R, C>, C>,
+    tz_diag: OVector<T, DimMinimum<R, C>>,
+    rank: usize,
+}
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim> Copy for COD<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C>
+        + Allocator<T, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>
+        + Allocator<T, DimMinimum<R, C>, C>,
+    ColPivQR<T, R, C>: Copy,
+    OMatrix<T, DimMinimum<R, C>, C>: Copy,
+    OVector<T, DimMinimum<R, C>>: Copy,
+{
+}
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim> COD<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C>
+        + Allocator<T, R>
+        + Allocator<T, C>
+        + Allocator<T, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>
+        + Allocator<T, DimMinimum<R, C>, C>,
+{
+    /// Computes the complete orthogonal decomposition using householder reflections.
+    ///
+    /// The diagonal elements of the column-pivoted QR decomposition of `matrix` with a modulus
+    /// smaller than or equal to `eps` are considered to be zero.
+    pub fn new(matrix: OMatrix<T, R, C>, eps: T::RealField) -> Self {
+        assert!(
+            eps >= T::RealField::zero(),
+            "COD decomposition: the tolerance must be non-negative."
+        );
+
+        let (nrows, ncols) = matrix.shape_generic();
+        let min_nrows_ncols = nrows.min(ncols);
+
+        let col_piv_qr = ColPivQR::new(matrix);
+        let rank = col_piv_qr.rank(eps);
+
+        let mut tz = col_piv_qr.r();
+        tz.rows_range_mut(rank..).fill(T::zero());
+
+        let mut tz_diag = Matrix::zeros_generic(min_nrows_ncols, Const::<1>);
+        let mut work = Matrix::zeros_generic(min_nrows_ncols, Const::<1>);
+        let mut axis_packed = Matrix::zeros_generic(ncols, Const::<1>);
+
+        for i in 0..rank {
+            tz_diag[i] =
+                householder::clear_row_unchecked(&mut tz, &mut axis_packed, &mut work, i, 0);
+        }
+
+        COD {
+            col_piv_qr,
+            tz,
+            tz_diag,
+            rank,
+        }
+    }
+
+    /// The numerical rank of the decomposed matrix.
+    ///
+    /// This is the dimension of the nonzero block of `T`.
+    #[inline]
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Computes the matrix `Q` with orthonormal columns of this decomposition.
+    #[must_use]
+    pub fn q(&self) -> OMatrix<T, R, DimMinimum<R, C>>
+    where
+        DefaultAllocator: Allocator<T, R, DimMinimum<R, C>>,
+    {
+        self.col_piv_qr.q()
+    }
+
+    /// Retrieves the square matrix `T` of this decomposition.
+    ///
+    /// Only its leading `rank` rows and columns are nonzero, and they form a lower-triangular
+    /// matrix.
+    #[must_use]
+    pub fn t(&self) -> OMatrix<T, DimMinimum<R, C>, DimMinimum<R, C>>
+    where
+        DefaultAllocator: Allocator<T, DimMinimum<R, C>, DimMinimum<R, C>>,
+    {
+        let min_nrows_ncols = self.tz.shape_generic().0;
+        let mut res = self.tz.columns_generic(0, min_nrows_ncols).lower_triangle();
+        res.set_partial_diagonal(
+            self.tz_diag
+                .iter()
+                .map(|e| T::from_real(e.clone().modulus())),
+        );
+        res
+    }
+
+    /// Computes the matrix `Z` with orthonormal rows of this decomposition.
+    ///
+    /// The column permutation of the underlying QR decomposition is already applied to `Z`.
+    #[must_use]
+    pub fn z(&self) -> OMatrix<T, DimMinimum<R, C>, C> {
+        let (min_nrows_ncols, ncols) = self.tz.shape_generic();
+
+        let mut res = Matrix::identity_generic(min_nrows_ncols, ncols);
+        let mut work = Matrix::zeros_generic(min_nrows_ncols, Const::<1>);
+        let mut axis_packed = Matrix::zeros_generic(ncols, Const::<1>);
+
+        for i in (0..self.rank).rev() {
+            let axis = self.tz.slice_range(i, i..);
+            let mut axis_packed = axis_packed.rows_range_mut(i..);
+            axis_packed.tr_copy_from(&axis);
+            // TODO: sometimes, the axis might have a zero magnitude.
+            let refl = Reflection::new(Unit::new_unchecked(axis_packed), T::zero());
+
+            let mut res_rows = res.slice_range_mut(i.., i..);
+            refl.reflect_rows_with_sign(
+                &mut res_rows,
+                &mut work.rows_range_mut(i..),
+                self.tz_diag[i].clone().signum(),
+            );
+        }
+
+        self.col_piv_qr.p().inv_permute_columns(&mut res);
+        res
+    }
+
+    /// Unpacks this decomposition into its three matrix factors `(Q, T, Z)`.
+    pub fn unpack(
+        self,
+    ) -> (
+        OMatrix<T, R, DimMinimum<R, C>>,
+        OMatrix<T, DimMinimum<R, C>, DimMinimum<R, C>>,
+        OMatrix<T, DimMinimum<R, C>, C>,
+    )
+    where
+        DefaultAllocator:
+            Allocator<T, R, DimMinimum<R, C>> + Allocator<T, DimMinimum<R, C>, DimMinimum<R, C>>,
+    {
+        (self.q(), self.t(), self.z())
+    }
+}
@@ -6,7 +6,7 @@ use crate::allocator::{Allocator, Reallocator};
 use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, OVector, Unit};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimMin, DimMinimum};
-use crate::storage::StorageMut;
+use crate::storage::{Storage, StorageMut};
 use crate::ComplexField;
 
 use crate::geometry::Reflection;
@@ -198,59 +198,182 @@ where
             refl.reflect_with_sign(&mut rhs_rows, self.diag[i].clone().signum().conjugate());
         }
     }
-}
 
-impl<T: ComplexField, D: DimMin<D, Output = D>> ColPivQR<T, D, D>
-where
-    DefaultAllocator:
-        Allocator<T, D, D> + Allocator<T, D> + Allocator<(usize, usize), DimMinimum<D, D>>,
-{
-    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    /// The numerical rank of the decomposed matrix.
     ///
-    /// Returns `None` if `self` is not invertible.
-    #[must_use = "Did you mean to use solve_mut()?"]
-    pub fn solve<R2: Dim, C2: Dim, S2>(
+    /// This is the number of leading diagonal elements of `R` with a modulus greater than `eps`.
+    #[must_use]
+    pub fn rank(&self, eps: T::RealField) -> usize {
+        self.diag
+            .iter()
+            .map(|e| e.clone().modulus())
+            .take_while(|e| *e > eps)
+            .count()
+    }
+
+    /// Solves the linear least-squares problem `min ‖self * x - b‖`, where `x` is the unknown to
+    /// be determined.
+    ///
+    /// The decomposed matrix must have at least as many rows as columns. Returns the solution
+    /// together with the norm of the residual `self * x - b` (its Frobenius norm if `b` has
+    /// several columns), or `None` if the decomposed matrix does not have full column rank. Use
+    /// `solve_min_norm` for rank-deficient problems.
+    #[must_use]
+    pub fn solve_least_squares<R2: Dim, C2: Dim, S2>(
         &self,
         b: &Matrix<T, R2, C2, S2>,
-    ) -> Option<OMatrix<T, R2, C2>>
+    ) -> Option<(OMatrix<T, C, C2>, T::RealField)>
     where
-        S2: StorageMut<T, R2, C2>,
-        ShapeConstraint: SameNumberOfRows<R2, D>,
-        DefaultAllocator: Allocator<T, R2, C2>,
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator: Allocator<T, R2, C2> + Allocator<T, C, C2>,
     {
-        let mut res = b.clone_owned();
+        let (nrows, ncols) = self.col_piv_qr.shape_generic();
+        assert_eq!(
+            nrows.value(),
+            b.nrows(),
+            "ColPivQR least squares: matrix dimension mismatch."
+        );
+        assert!(
+            nrows.value() >= ncols.value(),
+            "ColPivQR least squares: the matrix must have at least as many rows as columns."
+        );
 
-        if self.solve_mut(&mut res) {
-            Some(res)
+        let mut qtb = b.clone_owned();
+        self.q_tr_mul(&mut qtb);
+
+        let residual = qtb.rows_range(ncols.value()..).norm();
+        let mut x = qtb.rows_generic(0, ncols).into_owned();
+
+        if self.solve_upper_triangular_mut(&mut x) {
+            self.p.inv_permute_rows(&mut x);
+            Some((x, residual))
         } else {
             None
         }
     }
 
-    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    /// Computes the minimum-norm solution of the linear least-squares problem
+    /// `min ‖self * x - b‖`, where `x` is the unknown to be determined.
     ///
-    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` is
-    /// overwritten with garbage.
-    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    /// The decomposed matrix may have any shape and rank. Its numerical rank is determined by
+    /// `self.rank(eps)`: the trailing rows of `R` starting at the first diagonal element with a
+    /// modulus smaller than or equal to `eps` are assumed to be zero. Among all the solutions of
+    /// the resulting problem, the one with the smallest norm is returned, together with the norm
+    /// of its residual (its Frobenius norm if `b` has several columns).
+    ///
+    /// The residual norm is the one of the truncated problem. It differs from `‖self * x - b‖`
+    /// by at most about `eps * ‖x‖`.
+    #[must_use]
+    pub fn solve_min_norm<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        eps: T::RealField,
+    ) -> (OMatrix<T, C, C2>, T::RealField)
     where
-        S2: StorageMut<T, R2, C2>,
-        ShapeConstraint: SameNumberOfRows<R2, D>,
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator:
+            Allocator<T, R2, C2> + Allocator<T, C, C2> + Allocator<T, DimMinimum<R, C>, C>,
     {
+        assert!(
+            eps >= T::RealField::zero(),
+            "ColPivQR min-norm solve: the epsilon must be non-negative."
+        );
+        let (nrows, ncols) = self.col_piv_qr.shape_generic();
         assert_eq!(
-            self.col_piv_qr.nrows(),
+            nrows.value(),
             b.nrows(),
-            "ColPivQR solve matrix dimension mismatch."
-        );
-        assert!(
-            self.col_piv_qr.is_square(),
-            "ColPivQR solve: unable to solve a non-square system."
+            "ColPivQR min-norm solve: matrix dimension mismatch."
         );
 
-        self.q_tr_mul(b);
-        let solved = self.solve_upper_triangular_mut(b);
-        self.p.inv_permute_rows(b);
+        let rank = self.rank(eps);
+        let ncols_b = b.shape_generic().1;
 
-        solved
+        let mut qtb = b.clone_owned();
+        self.q_tr_mul(&mut qtb);
+        let residual = qtb.rows_range(rank..).norm();
+
+        // The `rank` leading rows `[R11 R12]` of `R` are reduced to `[T 0] * Z` by applying
+        // householder reflections from the right, where `T` is upper-triangular and `Z` unitary.
+        // The reflection eliminating `R12` from the `i`-th row acts on the columns `i` and
+        // `rank..`. Its axis is stored in `heads[i]` and in the `i`-th row of `R12`.
+        let mut r = self.r();
+        let mut heads = Matrix::zeros_generic(nrows.min(ncols), Const::<1>);
+
+        for i in (0..rank).rev() {
+            let tail_norm_squared = r.slice_range(i, rank..).norm_squared();
+
+            if tail_norm_squared.is_zero() {
+                continue;
+            }
+
+            // The diagonal element is real and positive, so the reflection maps the
+            // (conjugated) row onto `-norm * e1`.
+            let norm = (r[(i, i)].clone().modulus_squared() + tail_norm_squared.clone()).sqrt();
+            let head = r[(i, i)].clone() + T::from_real(norm.clone());
+            let factor = crate::convert::<_, T::RealField>(2.0)
+                / (head.clone().modulus_squared() + tail_norm_squared);
+
+            r[(i, i)] = T::from_real(-norm);
+            for j in rank..ncols.value() {
+                r[(i, j)] = r[(i, j)].clone().conjugate();
+            }
+
+            for k in 0..i {
+                let mut dot = head.clone() * r[(k, i)].clone();
+                for j in rank..ncols.value() {
+                    dot += r[(i, j)].clone() * r[(k, j)].clone();
+                }
+                let dot = dot.scale(factor.clone());
+
+                r[(k, i)] -= head.clone().conjugate() * dot.clone();
+                for j in rank..ncols.value() {
+                    let coeff = r[(i, j)].clone().conjugate() * dot.clone();
+                    r[(k, j)] -= coeff;
+                }
+            }
+
+            heads[i] = head;
+        }
+
+        // Solve `T * z = Qᴴ * b`, then compute `x = Zᴴ * [z; 0]`.
+        let mut x = Matrix::zeros_generic(ncols, ncols_b);
+
+        for c in 0..x.ncols() {
+            for i in (0..rank).rev() {
+                let mut coeff = qtb[(i, c)].clone();
+                for j in i + 1..rank {
+                    coeff -= r[(i, j)].clone() * x[(j, c)].clone();
+                }
+                x[(i, c)] = coeff / r[(i, i)].clone();
+            }
+
+            for i in 0..rank {
+                let head: T = heads[i].clone();
+
+                if head.is_zero() {
+                    continue;
+                }
+
+                let mut dot = head.clone().conjugate() * x[(i, c)].clone();
+                let mut norm_squared = head.clone().modulus_squared();
+                for j in rank..ncols.value() {
+                    dot += r[(i, j)].clone().conjugate() * x[(j, c)].clone();
+                    norm_squared += r[(i, j)].clone().modulus_squared();
+                }
+                let dot = dot.scale(crate::convert::<_, T::RealField>(2.0) / norm_squared);
+
+                x[(i, c)] -= head * dot.clone();
+                for j in rank..ncols.value() {
+                    let coeff = r[(i, j)].clone() * dot.clone();
+                    x[(j, c)] -= coeff;
+                }
+            }
+        }
+
+        self.p.inv_permute_rows(&mut x);
+        (x, residual)
     }
 
     // TODO: duplicate code from the `solve` module.
@@ -260,9 +383,8 @@ where
     ) -> bool
     where
         S2: StorageMut<T, R2, C2>,
-        ShapeConstraint: SameNumberOfRows<R2, D>,
     {
-        let dim = self.col_piv_qr.nrows();
+        let dim = self.diag.len();
 
         for k in 0..b.ncols() {
             let mut b = b.column_mut(k);
@@ -287,6 +409,60 @@ where
 
         true
     }
+}
+
+impl<T: ComplexField, D: DimMin<D, Output = D>> ColPivQR<T, D, D>
+where
+    DefaultAllocator:
+        Allocator<T, D, D> + Allocator<T, D> + Allocator<(usize, usize), DimMinimum<D, D>>,
+{
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `None` if `self` is not invertible.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` is
+    /// overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.col_piv_qr.nrows(),
+            b.nrows(),
+            "ColPivQR solve matrix dimension mismatch."
+        );
+        assert!(
+            self.col_piv_qr.is_square(),
+            "ColPivQR solve: unable to solve a non-square system."
+        );
+
+        self.q_tr_mul(b);
+        let solved = self.solve_upper_triangular_mut(b);
+        self.p.inv_permute_rows(b);
+
+        solved
+    }
 
     /// Computes the inverse of the decomposed matrix.
     ///
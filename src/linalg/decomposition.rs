@@ -1,8 +1,9 @@
 use crate::storage::Storage;
 use crate::{
-    Allocator, Bidiagonal, Cholesky, ColPivQR, ComplexField, DefaultAllocator, Dim, DimDiff,
-    DimMin, DimMinimum, DimSub, FullPivLU, Hessenberg, Matrix, OMatrix, RealField, Schur,
-    SymmetricEigen, SymmetricTridiagonal, LU, QR, SVD, U1, UDU,
+    Allocator, Bidiagonal, Cholesky, ColPivQR, Complex, ComplexField, DefaultAllocator, Dim,
+    DimDiff, DimMin, DimMinimum, DimSub, Eigen, FullPivLU, GeneralizedEigen, GeneralizedSchur,
+    GeneralizedSymmetricEigen, Hessenberg, Matrix, OMatrix, RealField, SVDAlgorithm, Schur,
+    SymmetricEigen, SymmetricTridiagonal, COD, LDLT, LQ, LU, QR, RQ, SVD, U1, UDU,
 };
 
 /// # Rectangular matrix decomposition
@@ -14,6 +15,9 @@ use crate::{
 /// | -------------------------|---------------------|--------------|
 /// | QR                       | `Q * R`             | `Q` is an unitary matrix, and `R` is upper-triangular. |
 /// | QR with column pivoting  | `Q * R * P⁻¹`       | `Q` is an unitary matrix, and `R` is upper-triangular. `P` is a permutation matrix. |
+/// | LQ                       | `L * Q`             | `L` is lower-triangular, and `Q` has orthonormal rows. |
+/// | RQ                       | `R * Q`             | `R` is upper-triangular with a non-negative diagonal, and `Q` has orthonormal rows. |
+/// | Complete orthogonal      | `Q * T * Z`         | `Q` has orthonormal columns, `Z` has orthonormal rows, and `T` is lower-triangular with a leading nonzero block whose dimension is the numerical rank. |
 /// | LU with partial pivoting | `P⁻¹ * L * U`       | `L` is lower-triangular with a diagonal filled with `1` and `U` is upper-triangular. `P` is a permutation matrix. |
 /// | LU with full pivoting    | `P⁻¹ * L * U * Q⁻¹` | `L` is lower-triangular with a diagonal filled with `1` and `U` is upper-triangular. `P` and `Q` are permutation matrices. |
 /// | SVD                      | `U * Σ * Vᵀ`        | `U` and `V` are two orthogonal matrices and `Σ` is a diagonal matrix containing the singular values. |
@@ -74,6 +78,43 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         ColPivQR::new(self.into_owned())
     }
 
+    /// Computes the LQ decomposition of this matrix.
+    pub fn lq(self) -> LQ<T, R, C>
+    where
+        R: DimMin<C>,
+        DefaultAllocator:
+            Allocator<T, R, C> + Allocator<T, R> + Allocator<T, C> + Allocator<T, DimMinimum<R, C>>,
+    {
+        LQ::new(self.into_owned())
+    }
+
+    /// Computes the RQ decomposition of this matrix.
+    pub fn rq(self) -> RQ<T, R, C>
+    where
+        R: DimMin<C>,
+        DefaultAllocator:
+            Allocator<T, R, C> + Allocator<T, R> + Allocator<T, C> + Allocator<T, DimMinimum<R, C>>,
+    {
+        RQ::new(self.into_owned())
+    }
+
+    /// Computes the complete orthogonal decomposition of this matrix.
+    ///
+    /// The numerical rank of this matrix is the number of diagonal elements of its QR
+    /// decomposition with column pivoting that have a modulus greater than `eps`.
+    pub fn cod(self, eps: T::RealField) -> COD<T, R, C>
+    where
+        R: DimMin<C>,
+        DefaultAllocator: Allocator<T, R, C>
+            + Allocator<T, R>
+            + Allocator<T, C>
+            + Allocator<T, DimMinimum<R, C>>
+            + Allocator<(usize, usize), DimMinimum<R, C>>
+            + Allocator<T, DimMinimum<R, C>, C>,
+    {
+        COD::new(self.into_owned(), eps)
+    }
+
     /// Computes the Singular Value Decomposition using implicit shift.
     /// The singular values are guaranteed to be sorted in descending order.
     /// If this order is not required consider using `svd_unordered`.
@@ -188,6 +229,33 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         SVD::try_new_unordered(self.into_owned(), compute_u, compute_v, eps, max_niter)
     }
 
+    /// Computes the Singular Value Decomposition using the given algorithm.
+    /// The singular values are guaranteed to be sorted in descending order.
+    pub fn svd_with_algorithm(
+        self,
+        compute_u: bool,
+        compute_v: bool,
+        algorithm: SVDAlgorithm,
+    ) -> SVD<T, R, C>
+    where
+        R: DimMin<C>,
+        DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
+        DefaultAllocator: Allocator<T, R, C>
+            + Allocator<T, C, R>
+            + Allocator<T, C>
+            + Allocator<T, R>
+            + Allocator<T, DimDiff<DimMinimum<R, C>, U1>>
+            + Allocator<T, DimMinimum<R, C>, C>
+            + Allocator<T, R, DimMinimum<R, C>>
+            + Allocator<T, DimMinimum<R, C>>
+            + Allocator<T::RealField, DimMinimum<R, C>>
+            + Allocator<T::RealField, DimDiff<DimMinimum<R, C>, U1>>
+            + Allocator<(usize, usize), DimMinimum<R, C>>
+            + Allocator<(T::RealField, usize), DimMinimum<R, C>>,
+    {
+        SVD::new_with_algorithm(self.into_owned(), compute_u, compute_v, algorithm)
+    }
+
     /// Computes the Polar Decomposition of  a `matrix` (indirectly uses SVD).
     pub fn polar(self) -> (OMatrix<T, R, R>, OMatrix<T, R, C>)
     where
@@ -255,8 +323,13 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 /// | Hessenberg               | `Q * H * Qᵀ`             | `Q` is a unitary matrix and `H` an upper-Hessenberg matrix. |
 /// | Cholesky                 | `L * Lᵀ`                 | `L` is a lower-triangular matrix. |
 /// | UDU                      | `U * D * Uᵀ`             | `U` is a upper-triangular matrix, and `D` a diagonal matrix. |
+/// | LDLᵀ with Bunch-Kaufman pivoting | `Pᵀ * L * D * Lᵀ * P` | `L` is lower-triangular with a diagonal filled with `1`, and `D` a block-diagonal matrix with `1x1` and `2x2` blocks. `P` is a permutation matrix. |
 /// | Schur decomposition      | `Q * T * Qᵀ`             | `Q` is an unitary matrix and `T` a quasi-upper-triangular matrix. |
+/// | Eigendecomposition       | `V * Λ * V⁻¹`            | `V` is a complex matrix of eigenvectors, and `Λ` is a complex diagonal matrix. |
 /// | Symmetric eigendecomposition | `Q ~ Λ ~ Qᵀ`   | `Q` is an unitary matrix, and `Λ` is a real diagonal matrix. |
+/// | Generalized Schur decomposition | `(Q * S * Zᵀ, Q * T * Zᵀ)` | `Q` and `Z` are unitary matrices, `S` is quasi-upper-triangular and `T` is upper-triangular. |
+/// | Generalized eigendecomposition | `A * X = B * X * Λ` | `X` is a complex matrix of eigenvectors, and `Λ` is given as a pair of diagonal matrices `(α, β)` with `λ = α / β`. |
+/// | Generalized symmetric eigendecomposition | `A * X = B * X * Λ` | `X` is a `B`-orthonormal matrix of eigenvectors, and `Λ` is a real diagonal matrix. |
 /// | Symmetric tridiagonalization | `Q ~ T ~ Qᵀ`   | `Q` is an unitary matrix, and `T` is a tridiagonal matrix. |
 impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
     /// Attempts to compute the Cholesky decomposition of this matrix.
@@ -282,6 +355,17 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
         UDU::new(self.into_owned())
     }
 
+    /// Computes the LDLᵀ decomposition with Bunch-Kaufman pivoting of this matrix.
+    ///
+    /// The input matrix `self` is assumed to be symmetric (or hermitian) and only the
+    /// lower-triangular part is read. Unlike the Cholesky decomposition, it may be indefinite.
+    pub fn ldlt(self) -> LDLT<T, D>
+    where
+        DefaultAllocator: Allocator<T, D, D> + Allocator<T, D> + Allocator<(usize, usize), D>,
+    {
+        LDLT::new(self.into_owned())
+    }
+
     /// Computes the Hessenberg decomposition of this matrix using householder reflections.
     pub fn hessenberg(self) -> Hessenberg<T, D>
     where
@@ -325,6 +409,86 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
         Schur::try_new(self.into_owned(), eps, max_niter)
     }
 
+    /// Computes the eigenvalues and the right eigenvectors of this general real square matrix.
+    ///
+    /// Use `Eigen::new_with_left_eigenvectors` if the left eigenvectors are needed too.
+    pub fn eigen(self) -> Eigen<T, D>
+    where
+        T: RealField,
+        D: DimSub<U1>, // For Hessenberg.
+        DefaultAllocator: Allocator<T, D, DimDiff<D, U1>>
+            + Allocator<T, DimDiff<D, U1>>
+            + Allocator<T, D, D>
+            + Allocator<T, D>
+            + Allocator<Complex<T>, D, D>
+            + Allocator<Complex<T>, D>,
+    {
+        Eigen::new(self.into_owned())
+    }
+
+    /// Attempts to compute the eigendecomposition of this general real square matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `compute_left` − set this to `true` to enable the computation of left eigenvectors.
+    /// * `eps`          − tolerance used to determine when a value converged to 0.
+    /// * `max_niter`    − maximum total number of iterations performed by the algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    ///   continues indefinitely until convergence.
+    pub fn try_eigen(self, compute_left: bool, eps: T, max_niter: usize) -> Option<Eigen<T, D>>
+    where
+        T: RealField,
+        D: DimSub<U1>, // For Hessenberg.
+        DefaultAllocator: Allocator<T, D, DimDiff<D, U1>>
+            + Allocator<T, DimDiff<D, U1>>
+            + Allocator<T, D, D>
+            + Allocator<T, D>
+            + Allocator<Complex<T>, D, D>
+            + Allocator<Complex<T>, D>,
+    {
+        Eigen::try_new(self.into_owned(), compute_left, eps, max_niter)
+    }
+
+    /// Computes the generalized Schur decomposition of the pair formed by this matrix and `b`.
+    pub fn generalized_schur(self, b: OMatrix<T, D, D>) -> GeneralizedSchur<T, D>
+    where
+        DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+    {
+        GeneralizedSchur::new(self.into_owned(), b)
+    }
+
+    /// Computes the generalized eigenvalues and right eigenvectors of the pair formed by this
+    /// general real square matrix and `b`.
+    pub fn generalized_eigen(self, b: OMatrix<T, D, D>) -> GeneralizedEigen<T, D>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, D, D>
+            + Allocator<T, D>
+            + Allocator<Complex<T>, D, D>
+            + Allocator<Complex<T>, D>,
+    {
+        GeneralizedEigen::new(self.into_owned(), b)
+    }
+
+    /// Computes the generalized eigendecomposition of this symmetric matrix with respect to the
+    /// symmetric positive-definite matrix `b`.
+    ///
+    /// Only the lower-triangular parts (including the diagonals) of `self` and `b` are read.
+    /// Returns `None` if `b` is not positive-definite.
+    pub fn generalized_symmetric_eigen(
+        self,
+        b: OMatrix<T, D, D>,
+    ) -> Option<GeneralizedSymmetricEigen<T, D>>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, D, D>
+            + Allocator<T, DimDiff<D, U1>>
+            + Allocator<T::RealField, D>
+            + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        GeneralizedSymmetricEigen::new(self.into_owned(), b)
+    }
+
     /// Computes the eigendecomposition of this symmetric matrix.
     ///
     /// Only the lower-triangular part (including the diagonal) of `m` is read.
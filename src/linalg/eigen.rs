@@ -1,114 +1,285 @@
 #[cfg(feature = "serde-serialize-no-std")]
 use serde::{Deserialize, Serialize};
 
+use num::Zero;
 use num_complex::Complex;
-use simba::scalar::ComplexField;
-use std::cmp;
-use std::fmt::Display;
-use std::ops::MulAssign;
+use simba::scalar::{ComplexField, RealField};
 
 use crate::allocator::Allocator;
-use crate::base::dimension::{Dim, DimDiff, DimSub, Dynamic, U1, U2, U3};
-use crate::base::storage::Storage;
-use crate::base::{
-    DefaultAllocator, Hessenberg, OMatrix, OVector, SquareMatrix, Unit, Vector2, Vector3,
-};
-use crate::constraint::{DimEq, ShapeConstraint};
-
-use crate::geometry::{Reflection, UnitComplex};
-use crate::linalg::householder;
+use crate::base::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::base::{DefaultAllocator, Matrix2, OMatrix, OVector, Vector2};
 use crate::linalg::Schur;
 
-/// Eigendecomposition of a real matrix with real eigenvalues (or complex eigen values for complex matrices).
+/// Eigendecomposition of a general real square matrix.
+///
+/// The eigenvalues and eigenvectors are complex in general. They are computed from the real
+/// Schur decomposition of the input matrix.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde-serialize-no-std",
-    serde(bound(serialize = "DefaultAllocator: Allocator<T, D>,
-         OVector<T, D>: Serialize,
-         OMatrix<T, D, D>: Serialize"))
+    serde(bound(serialize = "DefaultAllocator: Allocator<Complex<T>, D, D> +
+                           Allocator<Complex<T>, D>,
+         OVector<Complex<T>, D>: Serialize,
+         OMatrix<Complex<T>, D, D>: Serialize"))
 )]
 #[cfg_attr(
     feature = "serde-serialize-no-std",
-    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D>,
-         OVector<T, D>: Serialize,
-         OMatrix<T, D, D>: Deserialize<'de>"))
+    serde(bound(deserialize = "DefaultAllocator: Allocator<Complex<T>, D, D> +
+                           Allocator<Complex<T>, D>,
+         OVector<Complex<T>, D>: Deserialize<'de>,
+         OMatrix<Complex<T>, D, D>: Deserialize<'de>"))
 )]
 #[derive(Clone, Debug)]
-pub struct Eigen<T: ComplexField, D: Dim>
+pub struct Eigen<T: RealField, D: Dim>
 where
-    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+    DefaultAllocator: Allocator<Complex<T>, D, D> + Allocator<Complex<T>, D>,
 {
-    pub eigenvectors: OMatrix<T, D, D>,
-    pub eigenvalues: OVector<T, D>,
+    /// The eigenvalues of the decomposed matrix.
+    ///
+    /// Complex eigenvalues always appear in consecutive conjugate pairs.
+    pub eigenvalues: OVector<Complex<T>, D>,
+
+    /// The normalized right eigenvectors of the decomposed matrix.
+    ///
+    /// The i-th column `v` satisfies `A * v = λ * v` where `λ` is the i-th eigenvalue.
+    pub eigenvectors: OMatrix<Complex<T>, D, D>,
+
+    /// The normalized left eigenvectors of the decomposed matrix, if they have been computed.
+    ///
+    /// The i-th column `u` satisfies `uᴴ * A = λ * uᴴ` where `λ` is the i-th eigenvalue.
+    pub left_eigenvectors: Option<OMatrix<Complex<T>, D, D>>,
 }
 
-impl<T: ComplexField, D: Dim> Copy for Eigen<T, D>
+impl<T: RealField, D: Dim> Copy for Eigen<T, D>
 where
-    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
-    OMatrix<T, D, D>: Copy,
-    OVector<T, D>: Copy,
+    DefaultAllocator: Allocator<Complex<T>, D, D> + Allocator<Complex<T>, D>,
+    OMatrix<Complex<T>, D, D>: Copy,
+    OVector<Complex<T>, D>: Copy,
 {
 }
 
-impl<T: ComplexField, D: Dim> Eigen<T, D>
+impl<T: RealField, D: Dim> Eigen<T, D>
 where
-    D: DimSub<U1>,                                   // For Hessenberg.
-    ShapeConstraint: DimEq<Dynamic, DimDiff<D, U1>>, // For Hessenberg.
+    D: DimSub<U1>, // For Hessenberg.
     DefaultAllocator: Allocator<T, D, DimDiff<D, U1>>
         + Allocator<T, DimDiff<D, U1>>
         + Allocator<T, D, D>
-        + Allocator<T, D>,
-    // XXX: for debug
-    DefaultAllocator: Allocator<usize, D, D>,
-    OMatrix<T, D, D>: Display,
+        + Allocator<T, D>
+        + Allocator<Complex<T>, D, D>
+        + Allocator<Complex<T>, D>,
 {
-    /// Computes the eigendecomposition of a diagonalizable matrix with Complex eigenvalues.
-    pub fn new(m: OMatrix<T, D, D>) -> Option<Eigen<T, D>> {
+    /// Computes the eigenvalues and right eigenvectors of a square matrix.
+    pub fn new(m: OMatrix<T, D, D>) -> Self {
+        Self::try_new(m, false, T::default_epsilon(), 0).unwrap()
+    }
+
+    /// Computes the eigenvalues as well as the right and left eigenvectors of a square matrix.
+    pub fn new_with_left_eigenvectors(m: OMatrix<T, D, D>) -> Self {
+        Self::try_new(m, true, T::default_epsilon(), 0).unwrap()
+    }
+
+    /// Attempts to compute the eigendecomposition of a square matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `compute_left` − set this to `true` to enable the computation of left eigenvectors.
+    /// * `eps`          − tolerance used to determine when a value converged to 0.
+    /// * `max_niter`    − maximum total number of iterations performed by the Schur
+    ///   decomposition. If this number of iteration is exceeded, `None` is returned. If
+    ///   `niter == 0`, then the algorithm continues indefinitely until convergence.
+    pub fn try_new(
+        m: OMatrix<T, D, D>,
+        compute_left: bool,
+        eps: T,
+        max_niter: usize,
+    ) -> Option<Self> {
         assert!(
             m.is_square(),
             "Unable to compute the eigendecomposition of a non-square matrix."
         );
 
-        let dim = m.nrows();
-        let (mut eigenvectors, mut eigenvalues) = Schur::new(m, 0).unwrap().unpack();
+        let (q, t) = Schur::try_new(m, eps, max_niter)?.unpack();
+        let q = q.map(|e| Complex::new(e, T::zero()));
+        let mut t = t.map(|e| Complex::new(e, T::zero()));
 
-        println!("Schur eigenvalues: {}", eigenvalues);
+        // Reduce the real quasi-upper-triangular Schur form to a complex upper-triangular one.
+        let q = Self::triangularize_2x2_blocks(q, &mut t);
 
-        // Check that the eigenvalues are all Complex.
-        for i in 0..dim - 1 {
-            if !eigenvalues[(i + 1, i)].is_zero() {
-                return None;
-            }
-        }
+        let dim = t.nrows();
+        let eigenvalues = t.map_diagonal(|e| e);
 
-        for j in 1..dim {
-            for i in 0..j {
-                let diff = eigenvalues[(i, i)] - eigenvalues[(j, j)];
+        // Used to perturb the pivots of the triangular solves in case of
+        // (nearly) repeated eigenvalues.
+        let small = t.camax().max(T::one()) * T::default_epsilon();
 
-                if diff.is_zero() && !eigenvalues[(i, j)].is_zero() {
-                    return None;
-                }
+        let mut right = OMatrix::zeros_generic(t.shape_generic().0, t.shape_generic().1);
 
-                let z = -eigenvalues[(i, j)] / diff;
+        for k in 0..dim {
+            let lambda = t[(k, k)].clone();
+            let mut x = right.column_mut(k);
+            x[k] = Complex::new(T::one(), T::zero());
 
-                for k in j + 1..dim {
-                    eigenvalues[(i, k)] -= z * eigenvalues[(j, k)];
+            // Back substitution on (T - λI) * x = 0 with x[k] = 1.
+            for i in (0..k).rev() {
+                let mut acc = Complex::<T>::zero();
+                for j in i + 1..=k {
+                    acc += t[(i, j)].clone() * x[j].clone();
                 }
 
-                for k in 0..dim {
-                    eigenvectors[(k, j)] += z * eigenvectors[(k, i)];
-                }
+                x[i] = -acc / Self::perturbed_pivot(t[(i, i)].clone() - lambda.clone(), &small);
             }
         }
 
-        // Normalize the eigenvector basis.
-        for i in 0..dim {
-            let _ = eigenvectors.column_mut(i).normalize_mut();
+        let mut eigenvectors = &q * right;
+        for mut v in eigenvectors.column_iter_mut() {
+            let _ = v.normalize_mut();
         }
 
+        let left_eigenvectors = if compute_left {
+            let mut left = OMatrix::zeros_generic(t.shape_generic().0, t.shape_generic().1);
+
+            for k in 0..dim {
+                let lambda = t[(k, k)].clone();
+                let mut z = left.column_mut(k);
+                z[k] = Complex::new(T::one(), T::zero());
+
+                // Forward substitution on zᵀ * (T - λI) = 0 with z[k] = 1.
+                for i in k + 1..dim {
+                    let mut acc = Complex::<T>::zero();
+                    for j in k..i {
+                        acc += z[j].clone() * t[(j, i)].clone();
+                    }
+
+                    z[i] = -acc / Self::perturbed_pivot(t[(i, i)].clone() - lambda.clone(), &small);
+                }
+
+                // The left eigenvector is conj(z) because zᵀ * T = λ * zᵀ.
+                z.conjugate_mut();
+            }
+
+            let mut left = &q * left;
+            for mut u in left.column_iter_mut() {
+                let _ = u.normalize_mut();
+            }
+
+            Some(left)
+        } else {
+            None
+        };
+
         Some(Eigen {
+            eigenvalues,
             eigenvectors,
-            eigenvalues: eigenvalues.diagonal(),
+            left_eigenvectors,
         })
     }
+
+    /// Applies complex unitary transformations to `t` (and accumulates them into `q`) so that
+    /// each 2x2 diagonal block of the real Schur form becomes upper-triangular.
+    fn triangularize_2x2_blocks(
+        mut q: OMatrix<Complex<T>, D, D>,
+        t: &mut OMatrix<Complex<T>, D, D>,
+    ) -> OMatrix<Complex<T>, D, D> {
+        let dim = t.nrows();
+        let mut k = 0;
+
+        while k + 1 < dim {
+            if t[(k + 1, k)].is_zero() {
+                k += 1;
+                continue;
+            }
+
+            let block = Matrix2::new(
+                t[(k, k)].clone(),
+                t[(k, k + 1)].clone(),
+                t[(k + 1, k)].clone(),
+                t[(k + 1, k + 1)].clone(),
+            );
+
+            if let Some(g) = Self::block_basis(&block) {
+                // t := gᴴ * t
+                for j in k..dim {
+                    let a = t[(k, j)].clone();
+                    let b = t[(k + 1, j)].clone();
+                    t[(k, j)] = g[(0, 0)].clone().conjugate() * a.clone()
+                        + g[(1, 0)].clone().conjugate() * b.clone();
+                    t[(k + 1, j)] =
+                        g[(0, 1)].clone().conjugate() * a + g[(1, 1)].clone().conjugate() * b;
+                }
+
+                // t := t * g and q := q * g
+                Self::rotate_columns(t, k + 2, k, &g);
+                Self::rotate_columns(&mut q, dim, k, &g);
+
+                t[(k + 1, k)] = Complex::zero();
+            }
+
+            k += 2;
+        }
+
+        q
+    }
+
+    /// Multiplies the columns `k` and `k + 1` of the first `nrows` rows of `mat` by `g`.
+    fn rotate_columns(
+        mat: &mut OMatrix<Complex<T>, D, D>,
+        nrows: usize,
+        k: usize,
+        g: &Matrix2<Complex<T>>,
+    ) {
+        for i in 0..nrows {
+            let a = mat[(i, k)].clone();
+            let b = mat[(i, k + 1)].clone();
+            mat[(i, k)] = a.clone() * g[(0, 0)].clone() + b.clone() * g[(1, 0)].clone();
+            mat[(i, k + 1)] = a * g[(0, 1)].clone() + b * g[(1, 1)].clone();
+        }
+    }
+
+    /// Computes the unitary matrix `g` such that `gᴴ * block * g` is upper-triangular.
+    ///
+    /// Returns `None` if `block` is already upper-triangular.
+    fn block_basis(block: &Matrix2<Complex<T>>) -> Option<Matrix2<Complex<T>>> {
+        let a = block[(0, 0)].clone();
+        let b = block[(0, 1)].clone();
+        let c = block[(1, 0)].clone();
+        let d = block[(1, 1)].clone();
+
+        if c.is_zero() {
+            return None;
+        }
+
+        // NOTE: use the same eigenvalue formula as `Schur::complex_eigenvalues` so that the
+        // eigenvalues are reported in the same order.
+        let half: T = crate::convert(0.5);
+        let val = (a.clone() - d.clone()) * half.clone();
+        let discr = b.clone() * c.clone() + val.clone() * val;
+        let half_tra = (a.clone() + d.clone()) * half;
+        let lambda = half_tra + discr.sqrt();
+
+        // The eigenvector of `block` associated to `lambda` is either `(b, λ - a)` or
+        // `(λ - d, c)`. Pick the one with the largest norm for numerical stability.
+        let v1 = Vector2::new(b, lambda.clone() - a);
+        let v2 = Vector2::new(lambda - d, c);
+        let v = if v1.norm_squared() > v2.norm_squared() {
+            v1
+        } else {
+            v2
+        };
+        let (v, _) = crate::Unit::new_and_get(v);
+
+        Some(Matrix2::new(
+            v[0].clone(),
+            -v[1].clone().conjugate(),
+            v[1].clone(),
+            v[0].clone().conjugate(),
+        ))
+    }
+
+    fn perturbed_pivot(pivot: Complex<T>, small: &T) -> Complex<T> {
+        if pivot.clone().norm1() < *small {
+            Complex::new(small.clone(), T::zero())
+        } else {
+            pivot
+        }
+    }
 }
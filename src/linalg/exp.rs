@@ -548,6 +548,16 @@ where
         }
         x
     }
+
+    /// Raises this matrix to the real power `exp`.
+    ///
+    /// This is computed as `exp(exp * ln(self))` from the principal logarithm of this matrix.
+    /// Returns `None` if this matrix has an eigenvalue on the closed negative real axis, in which
+    /// case it has no principal logarithm.
+    #[must_use]
+    pub fn powf(&self, exp: T::RealField) -> Option<Self> {
+        Some(self.ln()?.scale(exp).exp())
+    }
 }
 
 #[cfg(test)]
@@ -3,12 +3,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::allocator::Allocator;
 use crate::base::{DefaultAllocator, Matrix, OMatrix};
-use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::constraint::{AreMultipliable, SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimMin, DimMinimum};
 use crate::storage::{Storage, StorageMut};
-use simba::scalar::ComplexField;
+use simba::scalar::{ComplexField, SupersetOf};
 
 use crate::linalg::lu;
+use crate::linalg::refinement::{self, RefinedSolution};
 use crate::linalg::PermutationSequence;
 
 /// LU decomposition with full row and column pivoting.
@@ -212,6 +213,37 @@ where
         }
     }
 
+    /// Solves the linear system `a * x = b` with iterative refinement, where `a` is the matrix
+    /// that was decomposed and `x` is the unknown to be determined.
+    ///
+    /// The system is first solved with this decomposition, then the solution is improved by
+    /// iterative refinement: the residual `b - a * x` is computed with the matrix `a` that was
+    /// decomposed, in the possibly higher precision of `U`, and the correction is obtained by
+    /// solving the system with this decomposition again. This makes it possible to decompose the
+    /// matrix in `f32` and still get a solution accurate in `f64`, as long as the matrix is not
+    /// too ill-conditioned.
+    ///
+    /// The refinement stops when the backward error reaches the machine epsilon of `U`, when it
+    /// stops decreasing significantly, or after `max_iter` steps.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    #[must_use]
+    pub fn solve_refined<U, R2: Dim, C2: Dim, S2, S3>(
+        &self,
+        a: &Matrix<U, D, D, S2>,
+        b: &Matrix<U, R2, C2, S3>,
+        max_iter: usize,
+    ) -> Option<RefinedSolution<U, R2, C2>>
+    where
+        U: ComplexField + SupersetOf<T>,
+        S2: Storage<U, D, D>,
+        S3: Storage<U, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D> + AreMultipliable<D, D, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2> + Allocator<U, R2, C2>,
+    {
+        refinement::solve_refined(a, b, max_iter, |x| self.solve_mut(x))
+    }
+
     /// Computes the inverse of the decomposed matrix.
     ///
     /// Returns `None` if the decomposed matrix is not invertible.
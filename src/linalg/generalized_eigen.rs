@@ -0,0 +1,175 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use num::Zero;
+use num_complex::Complex;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Const, Dim};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector};
+use crate::linalg::generalized_schur::decouple_2x2;
+use crate::linalg::GeneralizedSchur;
+
+/// Generalized eigendecomposition of a pair of general real square matrices.
+///
+/// The generalized eigenvalues `λ` and eigenvectors `x` satisfy `A * x = λ * B * x`. Each
+/// eigenvalue is represented as a pair `(α, β)` with `λ = α / β`, so that infinite eigenvalues,
+/// which occur if `B` is singular, have `β = 0`. They are computed from the generalized Schur
+/// decomposition of the input matrices.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<Complex<T>, D, D> +
+                           Allocator<Complex<T>, D> +
+                           Allocator<T, D>,
+         OVector<Complex<T>, D>: Serialize,
+         OVector<T, D>: Serialize,
+         OMatrix<Complex<T>, D, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<Complex<T>, D, D> +
+                           Allocator<Complex<T>, D> +
+                           Allocator<T, D>,
+         OVector<Complex<T>, D>: Deserialize<'de>,
+         OVector<T, D>: Deserialize<'de>,
+         OMatrix<Complex<T>, D, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct GeneralizedEigen<T: RealField, D: Dim>
+where
+    DefaultAllocator: Allocator<Complex<T>, D, D> + Allocator<Complex<T>, D> + Allocator<T, D>,
+{
+    /// The numerators `α` of the generalized eigenvalues.
+    ///
+    /// Complex eigenvalues always appear in consecutive conjugate pairs.
+    pub alphas: OVector<Complex<T>, D>,
+
+    /// The real non-negative denominators `β` of the generalized eigenvalues.
+    pub betas: OVector<T, D>,
+
+    /// The normalized right generalized eigenvectors.
+    ///
+    /// The i-th column `x` satisfies `β * A * x = α * B * x` where `(α, β)` is the i-th
+    /// eigenvalue.
+    pub eigenvectors: OMatrix<Complex<T>, D, D>,
+}
+
+impl<T: RealField, D: Dim> Copy for GeneralizedEigen<T, D>
+where
+    DefaultAllocator: Allocator<Complex<T>, D, D> + Allocator<Complex<T>, D> + Allocator<T, D>,
+    OMatrix<Complex<T>, D, D>: Copy,
+    OVector<Complex<T>, D>: Copy,
+    OVector<T, D>: Copy,
+{
+}
+
+impl<T: RealField, D: Dim> GeneralizedEigen<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, D>
+        + Allocator<Complex<T>, D, D>
+        + Allocator<Complex<T>, D>,
+{
+    /// Computes the generalized eigenvalues and right eigenvectors of the square matrices `a`
+    /// and `b`.
+    pub fn new(a: OMatrix<T, D, D>, b: OMatrix<T, D, D>) -> Self {
+        Self::try_new(a, b, T::default_epsilon(), 0).unwrap()
+    }
+
+    /// Attempts to compute the generalized eigendecomposition of the square matrices `a` and
+    /// `b`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the QZ algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    ///   continues indefinitely until convergence.
+    pub fn try_new(
+        a: OMatrix<T, D, D>,
+        b: OMatrix<T, D, D>,
+        eps: T,
+        max_niter: usize,
+    ) -> Option<Self> {
+        let (_, s, t, z) = GeneralizedSchur::try_new(a, b, eps, max_niter)?.unpack();
+        let mut s = s.map(|e| Complex::new(e, T::zero()));
+        let mut t = t.map(|e| Complex::new(e, T::zero()));
+        let mut z = z.map(|e| Complex::new(e, T::zero()));
+        let dim = s.nrows();
+
+        // Reduce the real quasi-upper-triangular `S` to a complex upper-triangular one.
+        let mut m = 0;
+        while m + 1 < dim {
+            if s[(m + 1, m)].is_zero() {
+                m += 1;
+            } else {
+                let _ = decouple_2x2(&mut s, &mut t, None, Some(&mut z), m);
+                m += 2;
+            }
+        }
+
+        // Used to perturb the pivots of the triangular solves in case of
+        // (nearly) repeated eigenvalues.
+        let small = s.camax().max(t.camax()).max(T::one()) * T::default_epsilon();
+
+        let mut y = OMatrix::zeros_generic(s.shape_generic().0, s.shape_generic().1);
+
+        for k in 0..dim {
+            let (alpha, beta) = (s[(k, k)].clone(), t[(k, k)].clone());
+            let mut x = y.column_mut(k);
+            x[k] = Complex::new(T::one(), T::zero());
+
+            // Solve `(β * S - α * T) * x = 0` by back substitution.
+            for i in (0..k).rev() {
+                let mut rhs = Complex::<T>::zero();
+                for j in i + 1..=k {
+                    rhs -= (s[(i, j)].clone() * beta.clone() - t[(i, j)].clone() * alpha.clone())
+                        * x[j].clone();
+                }
+
+                let mut pivot =
+                    s[(i, i)].clone() * beta.clone() - t[(i, i)].clone() * alpha.clone();
+                if pivot.clone().norm1() < small {
+                    pivot = Complex::new(small.clone(), T::zero());
+                }
+
+                x[i] = rhs / pivot;
+            }
+        }
+
+        let mut eigenvectors = z * y;
+        for mut col in eigenvectors.column_iter_mut() {
+            let _ = col.normalize_mut();
+        }
+
+        let dim_generic = s.shape_generic().0;
+        let mut alphas = Matrix::zeros_generic(dim_generic, Const::<1>);
+        let mut betas = Matrix::zeros_generic(dim_generic, Const::<1>);
+
+        for i in 0..dim {
+            let (modulus, sign) = t[(i, i)].clone().to_exp();
+            alphas[i] = s[(i, i)].clone() * sign.conj();
+            betas[i] = modulus;
+        }
+
+        // Enforce the conjugate symmetry of the eigenvalues of the 2x2 blocks.
+        let mut i = 0;
+        while i + 1 < dim {
+            if alphas[i].im != T::zero() {
+                alphas[i + 1] = alphas[i].clone().conj();
+                betas[i + 1] = betas[i].clone();
+                i += 2;
+            } else {
+                i += 1;
+            }
+        }
+
+        Some(Self {
+            alphas,
+            betas,
+            eigenvectors,
+        })
+    }
+}
@@ -0,0 +1,591 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+use num::Zero;
+use num_complex::Complex as NumComplex;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Const, Dim, Dynamic, U2};
+use crate::base::{DefaultAllocator, Matrix2, OMatrix, OVector, Vector2};
+use crate::linalg::givens::GivensRotation;
+use crate::linalg::schur::compute_2x2_eigvals;
+use crate::Matrix;
+
+/// Generalized Schur decomposition of a pair of square matrices, computed with the QZ algorithm.
+///
+/// The matrices `A` and `B` are decomposed as `A = Q * S * Zᴴ` and `B = Q * T * Zᴴ` where `Q`
+/// and `Z` are unitary, `S` is upper-quasitriangular and `T` is upper-triangular. The generalized
+/// eigenvalues `λ` of the pencil, i.e., such that `A * x = λ * B * x`, are represented as pairs
+/// `(α, β)` with `λ = α / β`. An eigenvalue is infinite if `β` is zero.
+///
+/// If this is a real matrix pair, this will be a real generalized Schur decomposition: `S` may
+/// contain `2x2` diagonal blocks corresponding to pairs of complex conjugate eigenvalues.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D, D>,
+         OMatrix<T, D, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D, D>,
+         OMatrix<T, D, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct GeneralizedSchur<T: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    q: OMatrix<T, D, D>,
+    s: OMatrix<T, D, D>,
+    t: OMatrix<T, D, D>,
+    z: OMatrix<T, D, D>,
+}
+
+impl<T: ComplexField, D: Dim> Copy for GeneralizedSchur<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+    OMatrix<T, D, D>: Copy,
+{
+}
+
+impl<T: ComplexField, D: Dim> GeneralizedSchur<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+{
+    /// Computes the generalized Schur decomposition of the square matrices `a` and `b`.
+    pub fn new(a: OMatrix<T, D, D>, b: OMatrix<T, D, D>) -> Self {
+        Self::try_new(a, b, T::RealField::default_epsilon(), 0).unwrap()
+    }
+
+    /// Attempts to compute the generalized Schur decomposition of the square matrices `a` and
+    /// `b`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    ///   continues indefinitely until convergence.
+    pub fn try_new(
+        a: OMatrix<T, D, D>,
+        b: OMatrix<T, D, D>,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        assert!(
+            a.is_square() && a.shape() == b.shape(),
+            "Unable to compute the generalized Schur decomposition of non-square matrices or of matrices with different dimensions."
+        );
+
+        let dim = a.shape_generic().0;
+        let mut q = OMatrix::identity_generic(dim, dim);
+        let mut z = OMatrix::identity_generic(dim, dim);
+        let (mut s, mut t) = (a, b);
+
+        let amax_s = s.camax();
+        let amax_t = t.camax();
+
+        if !amax_s.is_zero() {
+            s.unscale_mut(amax_s.clone());
+        }
+
+        if !amax_t.is_zero() {
+            t.unscale_mut(amax_t.clone());
+        }
+
+        reduce_to_hessenberg_triangular(&mut s, &mut t, &mut q, &mut z);
+
+        // Absolute tolerances for negligible subdiagonal elements of `S` and diagonal elements
+        // of `T`. The Frobenius norms are invariant under the unitary transformations applied
+        // below.
+        let s_tol = eps.clone() * s.norm();
+        let t_tol = eps.clone() * t.norm();
+
+        let is_negligible = |s: &OMatrix<T, D, D>, i: usize| {
+            let off_diag = s[(i, i - 1)].clone().norm1();
+            off_diag <= s_tol
+                || off_diag
+                    <= eps.clone() * (s[(i, i)].clone().norm1() + s[(i - 1, i - 1)].clone().norm1())
+        };
+
+        let mut niter = 0;
+        let mut end = dim.value().saturating_sub(1);
+
+        while end > 0 {
+            if is_negligible(&s, end) {
+                s[(end, end - 1)] = T::zero();
+                end -= 1;
+                continue;
+            }
+
+            let mut start = end - 1;
+            while start > 0 && !is_negligible(&s, start) {
+                start -= 1;
+            }
+
+            if start > 0 {
+                s[(start, start - 1)] = T::zero();
+            }
+
+            if let Some(j) = (start..=end).find(|&j| t[(j, j)].clone().norm1() <= t_tol) {
+                // Infinite eigenvalue: move the zero of `T` to the bottom of the active block,
+                // which then deflates.
+                t[(j, j)] = T::zero();
+                push_infinite_eigenvalue(&mut s, &mut t, &mut q, &mut z, start, j, end);
+                end -= 1;
+            } else if end == start + 1 {
+                let _ = decouple_2x2(&mut s, &mut t, Some(&mut q), Some(&mut z), start);
+                end = start.saturating_sub(1);
+            } else {
+                niter += 1;
+                qz_sweep(&mut s, &mut t, &mut q, &mut z, start, end, niter % 10 == 0);
+
+                if niter == max_niter {
+                    return None;
+                }
+            }
+        }
+
+        if !amax_s.is_zero() {
+            s.scale_mut(amax_s);
+        }
+
+        if !amax_t.is_zero() {
+            t.scale_mut(amax_t);
+        }
+
+        Some(GeneralizedSchur { q, s, t, z })
+    }
+
+    /// Retrieves the unitary matrices `Q` and `Z`, the upper-quasitriangular matrix `S` and the
+    /// upper-triangular matrix `T` such that the decomposed matrices equal `Q * S * Zᴴ` and
+    /// `Q * T * Zᴴ`.
+    pub fn unpack(
+        self,
+    ) -> (
+        OMatrix<T, D, D>,
+        OMatrix<T, D, D>,
+        OMatrix<T, D, D>,
+        OMatrix<T, D, D>,
+    ) {
+        (self.q, self.s, self.t, self.z)
+    }
+
+    /// Computes the generalized eigenvalues of the decomposed matrix pair as pairs `(α, β)`.
+    ///
+    /// Each `β` has a real non-negative value. Returns `None` if some eigenvalues are complex.
+    #[must_use]
+    pub fn eigenvalues(&self) -> Option<(OVector<T, D>, OVector<T, D>)> {
+        let dim = self.s.nrows();
+
+        if (1..dim).any(|i| !self.s[(i, i - 1)].is_zero()) {
+            return None;
+        }
+
+        let mut alphas = self.s.map_diagonal(|e| e);
+        let mut betas = self.t.map_diagonal(|e| e);
+
+        for i in 0..dim {
+            let (beta, alpha) = normalize_pair(alphas[i].clone(), betas[i].clone());
+            alphas[i] = alpha;
+            betas[i] = T::from_real(beta);
+        }
+
+        Some((alphas, betas))
+    }
+
+    /// Computes the complex generalized eigenvalues of the decomposed matrix pair as pairs
+    /// `(α, β)`.
+    ///
+    /// Each `β` has a real non-negative value, and complex eigenvalues appear in consecutive
+    /// conjugate pairs.
+    #[must_use]
+    pub fn complex_eigenvalues(&self) -> (OVector<NumComplex<T>, D>, OVector<T, D>)
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<NumComplex<T>, D>,
+    {
+        let dim = self.s.shape_generic().0;
+        let mut alphas = Matrix::zeros_generic(dim, Const::<1>);
+        let mut betas = Matrix::zeros_generic(dim, Const::<1>);
+        let mut m = 0;
+
+        while m < dim.value() {
+            if m + 1 < dim.value() && !self.s[(m + 1, m)].is_zero() {
+                let mut s2: Matrix2<NumComplex<T>> = self
+                    .s
+                    .fixed_slice::<2, 2>(m, m)
+                    .map(|e| NumComplex::new(e, T::zero()));
+                let mut t2: Matrix2<NumComplex<T>> = self
+                    .t
+                    .fixed_slice::<2, 2>(m, m)
+                    .map(|e| NumComplex::new(e, T::zero()));
+                let _ = decouple_2x2::<NumComplex<T>, U2>(&mut s2, &mut t2, None, None, 0);
+
+                for i in 0..2 {
+                    let (beta, alpha) = normalize_pair(s2[(i, i)].clone(), t2[(i, i)].clone());
+                    alphas[m + i] = alpha;
+                    betas[m + i] = beta;
+                }
+
+                // Enforce the conjugate symmetry of the pair.
+                alphas[m + 1] = alphas[m].clone().conj();
+                betas[m + 1] = betas[m].clone();
+                m += 2;
+            } else {
+                let (beta, alpha) = normalize_pair(self.s[(m, m)].clone(), self.t[(m, m)].clone());
+                alphas[m] = NumComplex::new(alpha, T::zero());
+                betas[m] = beta;
+                m += 1;
+            }
+        }
+
+        (alphas, betas)
+    }
+}
+
+/// Scales the pair `(alpha, beta)` by a unit-modulus factor so that `beta` becomes real and
+/// non-negative. Returns the modulus of `beta` and the scaled `alpha`.
+fn normalize_pair<T: ComplexField>(alpha: T, beta: T) -> (T::RealField, T) {
+    let (modulus, sign) = beta.to_exp();
+    (modulus, alpha * sign.conjugate())
+}
+
+/// Applies the rotation `rot` to the rows `i` and `i + 1` of `m`, starting at the column `j0`.
+fn rotate_rows_from<T: ComplexField, D: Dim>(
+    rot: &GivensRotation<T>,
+    m: &mut OMatrix<T, D, D>,
+    i: usize,
+    j0: usize,
+) where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    let ncols = m.ncols();
+    rot.rotate(&mut m.generic_slice_mut((i, j0), (Const::<2>, Dynamic::new(ncols - j0))));
+}
+
+/// Applies the rotation `rot` to the columns `j` and `j + 1` of the first `nrows` rows of `m`.
+fn rotate_columns_upto<T: ComplexField, D: Dim>(
+    rot: &GivensRotation<T>,
+    m: &mut OMatrix<T, D, D>,
+    j: usize,
+    nrows: usize,
+) where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    rot.rotate_rows(&mut m.generic_slice_mut((0, j), (Dynamic::new(nrows), Const::<2>)));
+}
+
+/// Left-multiplies the rows `i` and `i + 1` of the pencil `(s, t)` by `rot`, starting at the
+/// columns `js` and `jt` respectively, and accumulates its adjoint into `q`.
+fn rotate_pencil_rows<T: ComplexField, D: Dim>(
+    rot: &GivensRotation<T>,
+    s: &mut OMatrix<T, D, D>,
+    t: &mut OMatrix<T, D, D>,
+    q: Option<&mut OMatrix<T, D, D>>,
+    i: usize,
+    (js, jt): (usize, usize),
+) where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    rotate_rows_from(rot, s, i, js);
+    rotate_rows_from(rot, t, i, jt);
+
+    if let Some(q) = q {
+        let nrows = q.nrows();
+        rotate_columns_upto(&rot.inverse(), q, i, nrows);
+    }
+}
+
+/// Right-multiplies the columns `j` and `j + 1` of the pencil `(s, t)` by `rot`, restricted to
+/// their first `ns` and `nt` rows respectively, and accumulates it into `z`.
+fn rotate_pencil_columns<T: ComplexField, D: Dim>(
+    rot: &GivensRotation<T>,
+    s: &mut OMatrix<T, D, D>,
+    t: &mut OMatrix<T, D, D>,
+    z: Option<&mut OMatrix<T, D, D>>,
+    j: usize,
+    (ns, nt): (usize, usize),
+) where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    rotate_columns_upto(rot, s, j, ns);
+    rotate_columns_upto(rot, t, j, nt);
+
+    if let Some(z) = z {
+        let nrows = z.nrows();
+        rotate_columns_upto(rot, z, j, nrows);
+    }
+}
+
+/// The rotation that cancels the second component of the column `(x, y)` when applied from
+/// the left.
+fn cancel_below<T: ComplexField>(x: T, y: T) -> Option<GivensRotation<T>> {
+    GivensRotation::cancel_y(&Vector2::new(x, y)).map(|(rot, _)| rot)
+}
+
+/// The rotation that cancels the first component of the row `(x, y)` when applied from the
+/// right.
+fn cancel_left<T: ComplexField>(x: T, y: T) -> Option<GivensRotation<T>> {
+    GivensRotation::cancel_y(&Vector2::new(y, x)).map(|(rot, _)| rot)
+}
+
+/// Reduces the pencil `(s, t)` to a pencil where `s` is upper-Hessenberg and `t` is
+/// upper-triangular.
+fn reduce_to_hessenberg_triangular<T: ComplexField, D: Dim>(
+    s: &mut OMatrix<T, D, D>,
+    t: &mut OMatrix<T, D, D>,
+    q: &mut OMatrix<T, D, D>,
+    z: &mut OMatrix<T, D, D>,
+) where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    let dim = s.nrows();
+
+    for j in 0..dim {
+        for i in (j + 1..dim).rev() {
+            if let Some(rot) = cancel_below(t[(i - 1, j)].clone(), t[(i, j)].clone()) {
+                rotate_pencil_rows(&rot, s, t, Some(q), i - 1, (0, j));
+            }
+            t[(i, j)] = T::zero();
+        }
+    }
+
+    for j in 0..dim.saturating_sub(2) {
+        for i in (j + 2..dim).rev() {
+            if let Some(rot) = cancel_below(s[(i - 1, j)].clone(), s[(i, j)].clone()) {
+                rotate_pencil_rows(&rot, s, t, Some(q), i - 1, (j, i - 1));
+            }
+            s[(i, j)] = T::zero();
+
+            if let Some(rot) = cancel_left(t[(i, i - 1)].clone(), t[(i, i)].clone()) {
+                rotate_pencil_columns(&rot, s, t, Some(z), i - 1, (dim, i + 1));
+            }
+            t[(i, i - 1)] = T::zero();
+        }
+    }
+}
+
+/// Moves the zero diagonal element `t[(j, j)]` of the unreduced block `start..=end` to
+/// `t[(end, end)]`, and cancels `s[(end, end - 1)]` so that the infinite eigenvalue deflates.
+fn push_infinite_eigenvalue<T: ComplexField, D: Dim>(
+    s: &mut OMatrix<T, D, D>,
+    t: &mut OMatrix<T, D, D>,
+    q: &mut OMatrix<T, D, D>,
+    z: &mut OMatrix<T, D, D>,
+    start: usize,
+    j: usize,
+    end: usize,
+) where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    for i in j..end {
+        if let Some(rot) = cancel_below(t[(i, i + 1)].clone(), t[(i + 1, i + 1)].clone()) {
+            let js = if i > start { i - 1 } else { i };
+            rotate_pencil_rows(&rot, s, t, Some(q), i, (js, i + 1));
+        }
+        t[(i + 1, i + 1)] = T::zero();
+
+        if i > start {
+            if let Some(rot) = cancel_left(s[(i + 1, i - 1)].clone(), s[(i + 1, i)].clone()) {
+                rotate_pencil_columns(&rot, s, t, Some(z), i - 1, (i + 2, i + 1));
+            }
+            s[(i + 1, i - 1)] = T::zero();
+        }
+    }
+
+    if let Some(rot) = cancel_left(s[(end, end - 1)].clone(), s[(end, end)].clone()) {
+        rotate_pencil_columns(&rot, s, t, Some(z), end - 1, (end + 1, end + 1));
+    }
+    s[(end, end - 1)] = T::zero();
+}
+
+/// Performs one implicit double-shift QZ sweep on the unreduced block `start..=end`, which must
+/// have at least three rows.
+///
+/// The shifts are the eigenvalues of the trailing `2x2` block of `s * t⁻¹`, or ad-hoc
+/// exceptional shifts if `exceptional` is `true`.
+fn qz_sweep<T: ComplexField, D: Dim>(
+    s: &mut OMatrix<T, D, D>,
+    t: &mut OMatrix<T, D, D>,
+    q: &mut OMatrix<T, D, D>,
+    z: &mut OMatrix<T, D, D>,
+    start: usize,
+    end: usize,
+    exceptional: bool,
+) where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    let (p, e) = (start, end);
+
+    // Inverse of the trailing 3x3 block of `t`.
+    let i00 = T::one() / t[(e - 2, e - 2)].clone();
+    let i11 = T::one() / t[(e - 1, e - 1)].clone();
+    let i22 = T::one() / t[(e, e)].clone();
+    let i01 = -t[(e - 2, e - 1)].clone() * i00.clone() * i11.clone();
+    let i12 = -t[(e - 1, e)].clone() * i11.clone() * i22.clone();
+    let i02 = -(t[(e - 2, e - 1)].clone() * i12.clone() + t[(e - 2, e)].clone() * i22.clone())
+        * i00.clone();
+
+    // Trailing 2x2 block of `s * t⁻¹`.
+    let hmm = s[(e - 1, e - 2)].clone() * i01 + s[(e - 1, e - 1)].clone() * i11.clone();
+    let hmn = s[(e - 1, e - 2)].clone() * i02
+        + s[(e - 1, e - 1)].clone() * i12.clone()
+        + s[(e - 1, e)].clone() * i22.clone();
+    let hnm = s[(e, e - 1)].clone() * i11;
+    let hnn = s[(e, e - 1)].clone() * i12 + s[(e, e)].clone() * i22;
+
+    let (tra, det) = if exceptional {
+        let ex = hnm.clone().norm1() + (s[(e - 1, e - 2)].clone() * i00).norm1();
+        let ex = T::from_real(ex);
+        let h = ex.clone() * crate::convert(0.75) + hnn;
+        (
+            h.clone() + h.clone(),
+            h.clone() * h + ex.clone() * ex * crate::convert(0.4375),
+        )
+    } else {
+        (hnn.clone() + hmm.clone(), hnn * hmm - hnm * hmn)
+    };
+
+    // First column of `(s * t⁻¹)² - tra * s * t⁻¹ + det * I`.
+    let j00 = T::one() / t[(p, p)].clone();
+    let j11 = T::one() / t[(p + 1, p + 1)].clone();
+    let j01 = -t[(p, p + 1)].clone() * j00.clone() * j11.clone();
+
+    let h11 = s[(p, p)].clone() * j00.clone();
+    let h21 = s[(p + 1, p)].clone() * j00;
+    let h12 = s[(p, p)].clone() * j01.clone() + s[(p, p + 1)].clone() * j11.clone();
+    let h22 = s[(p + 1, p)].clone() * j01 + s[(p + 1, p + 1)].clone() * j11.clone();
+    let h32 = s[(p + 2, p + 1)].clone() * j11;
+
+    let mut shift_column = Some((
+        h11.clone() * h11.clone() + h12 * h21.clone() - tra.clone() * h11.clone() + det,
+        h21.clone() * (h11 + h22 - tra),
+        h21 * h32,
+    ));
+
+    for k in p..e {
+        let js = if k == p { p } else { k - 1 };
+
+        // The first transformation is determined by the shifts, the next ones by the bulge.
+        let (x, mut y, w) = shift_column.take().unwrap_or_else(|| {
+            let w = if k + 2 <= e {
+                s[(k + 2, k - 1)].clone()
+            } else {
+                T::zero()
+            };
+            (s[(k, k - 1)].clone(), s[(k + 1, k - 1)].clone(), w)
+        });
+
+        // Chase the bulge of `s` one column down.
+        if k + 2 <= e {
+            if let Some((rot, norm)) = GivensRotation::cancel_y(&Vector2::new(y.clone(), w)) {
+                rotate_pencil_rows(&rot, s, t, Some(q), k + 1, (js, k + 1));
+                y = norm;
+            }
+        }
+
+        if let Some(rot) = cancel_below(x, y) {
+            rotate_pencil_rows(&rot, s, t, Some(q), k, (js, k));
+        }
+
+        if k > p {
+            s[(k + 1, k - 1)] = T::zero();
+
+            if k + 2 <= e {
+                s[(k + 2, k - 1)] = T::zero();
+            }
+        }
+
+        // Restore the triangular structure of `t`.
+        let ns = (k + 4).min(e + 1);
+
+        if k + 2 <= e {
+            if let Some(rot) = cancel_left(t[(k + 2, k + 1)].clone(), t[(k + 2, k + 2)].clone()) {
+                rotate_pencil_columns(&rot, s, t, Some(z), k + 1, (ns, k + 3));
+            }
+            t[(k + 2, k + 1)] = T::zero();
+        }
+
+        if let Some(rot) = cancel_left(t[(k + 1, k)].clone(), t[(k + 1, k + 1)].clone()) {
+            rotate_pencil_columns(&rot, s, t, Some(z), k, (ns, k + 2));
+        }
+        t[(k + 1, k)] = T::zero();
+    }
+}
+
+/// Reduces the `2x2` diagonal block of the pencil `(s, t)` starting at `(m, m)` to
+/// upper-triangular form, if its eigenvalues are elements of `T`.
+///
+/// Returns `false` if the block could not be reduced, e.g., because it has complex eigenvalues
+/// while `T` is real.
+pub(crate) fn decouple_2x2<T: ComplexField, D: Dim>(
+    s: &mut OMatrix<T, D, D>,
+    t: &mut OMatrix<T, D, D>,
+    q: Option<&mut OMatrix<T, D, D>>,
+    z: Option<&mut OMatrix<T, D, D>>,
+    m: usize,
+) -> bool
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    let n = m + 1;
+
+    if s[(n, m)].is_zero() {
+        return true;
+    }
+
+    let (t00, t01, t11) = (t[(m, m)].clone(), t[(m, n)].clone(), t[(n, n)].clone());
+
+    if t00.is_zero() || t11.is_zero() {
+        return false;
+    }
+
+    // The eigenvalues of the block pencil are those of `t⁻¹ * s`.
+    let i00 = T::one() / t00.clone();
+    let i11 = T::one() / t11.clone();
+    let i01 = -t01.clone() * i00.clone() * i11.clone();
+    let block = s.fixed_slice::<2, 2>(m, m);
+    let tinv_s = Matrix2::new(
+        i00.clone() * block[(0, 0)].clone() + i01.clone() * block[(1, 0)].clone(),
+        i00 * block[(0, 1)].clone() + i01 * block[(1, 1)].clone(),
+        i11.clone() * block[(1, 0)].clone(),
+        i11 * block[(1, 1)].clone(),
+    );
+
+    let lambda = match compute_2x2_eigvals::<T, _>(&tinv_s) {
+        Some((lambda, _)) => lambda,
+        None => return false,
+    };
+
+    // Right null vector of `s - λ * t`, computed from its row with the largest norm.
+    let n00 = s[(m, m)].clone() - lambda.clone() * t00;
+    let n01 = s[(m, n)].clone() - lambda.clone() * t01;
+    let n10 = s[(n, m)].clone();
+    let n11 = s[(n, n)].clone() - lambda * t11;
+
+    let (x0, x1) =
+        if n00.clone().norm1() + n01.clone().norm1() >= n10.clone().norm1() + n11.clone().norm1() {
+            (n01, -n00)
+        } else {
+            (n11, -n10)
+        };
+
+    if x0.is_zero() && x1.is_zero() {
+        return false;
+    }
+
+    let rot = GivensRotation::new(x0, x1).0;
+    rotate_pencil_columns(&rot, s, t, z, m, (n + 1, n + 1));
+
+    if let Some(rot) = cancel_below(t[(m, m)].clone(), t[(n, m)].clone()) {
+        rotate_pencil_rows(&rot, s, t, q, m, (m, m));
+    }
+
+    s[(n, m)] = T::zero();
+    t[(n, m)] = T::zero();
+    true
+}
@@ -0,0 +1,140 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, OMatrix, OVector};
+use crate::dimension::{Dim, DimDiff, DimSub, U1};
+use simba::scalar::ComplexField;
+
+use crate::linalg::{Cholesky, SymmetricEigen};
+
+/// Generalized eigendecomposition of a symmetric-definite matrix pair.
+///
+/// The generalized eigenvalues `λ` and eigenvectors `x` satisfy `A * x = λ * B * x` where `A`
+/// is symmetric (or hermitian) and `B` is symmetric (or hermitian) positive-definite. The
+/// problem is reduced to a standard symmetric eigenproblem through the Cholesky decomposition
+/// `B = L * Lᴴ`, so that all the eigenvalues are real and the eigenvectors are `B`-orthonormal,
+/// i.e., `Xᴴ * B * X = I`.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<T::RealField, D>,
+         OVector<T::RealField, D>: Serialize,
+         OMatrix<T, D, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<T::RealField, D>,
+         OVector<T::RealField, D>: Deserialize<'de>,
+         OMatrix<T, D, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct GeneralizedSymmetricEigen<T: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T::RealField, D>,
+{
+    /// The `B`-orthonormal generalized eigenvectors.
+    pub eigenvectors: OMatrix<T, D, D>,
+
+    /// The unsorted generalized eigenvalues.
+    pub eigenvalues: OVector<T::RealField, D>,
+}
+
+impl<T: ComplexField, D: Dim> Copy for GeneralizedSymmetricEigen<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T::RealField, D>,
+    OMatrix<T, D, D>: Copy,
+    OVector<T::RealField, D>: Copy,
+{
+}
+
+impl<T: ComplexField, D: Dim> GeneralizedSymmetricEigen<T, D>
+where
+    D: DimSub<U1>,
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T::RealField, D>
+        + Allocator<T::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the generalized eigendecomposition of the symmetric matrix `a` with respect to
+    /// the symmetric positive-definite matrix `b`.
+    ///
+    /// Only the lower-triangular parts (including the diagonals) of `a` and `b` are read.
+    /// Returns `None` if `b` is not positive-definite.
+    pub fn new(a: OMatrix<T, D, D>, b: OMatrix<T, D, D>) -> Option<Self> {
+        Self::try_new(a, b, T::RealField::default_epsilon(), 0)
+    }
+
+    /// Attempts to compute the generalized eigendecomposition of the symmetric matrix `a` with
+    /// respect to the symmetric positive-definite matrix `b`, with user-specified convergence
+    /// parameters.
+    ///
+    /// Only the lower-triangular parts (including the diagonals) of `a` and `b` are read.
+    /// Returns `None` if `b` is not positive-definite.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    ///   continues indefinitely until convergence.
+    pub fn try_new(
+        a: OMatrix<T, D, D>,
+        b: OMatrix<T, D, D>,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        assert!(
+            a.is_square() && a.shape() == b.shape(),
+            "Unable to compute the generalized eigendecomposition of non-square matrices or of matrices with different dimensions."
+        );
+
+        let l = Cholesky::new(b)?.unpack();
+
+        // Compute `C = L⁻¹ * A * L⁻ᴴ` from the lower-triangular part of `A`.
+        let mut c = a;
+        for j in 1..c.ncols() {
+            for i in 0..j {
+                c[(i, j)] = c[(j, i)].clone().conjugate();
+            }
+        }
+
+        l.solve_lower_triangular_unchecked_mut(&mut c);
+        c.adjoint_mut();
+        l.solve_lower_triangular_unchecked_mut(&mut c);
+
+        let SymmetricEigen {
+            mut eigenvectors,
+            eigenvalues,
+        } = SymmetricEigen::try_new(c, eps, max_niter)?;
+
+        // The eigenvectors `Y` of `C` yield the generalized eigenvectors `X = L⁻ᴴ * Y`.
+        l.ad_solve_lower_triangular_unchecked_mut(&mut eigenvectors);
+
+        Some(Self {
+            eigenvectors,
+            eigenvalues,
+        })
+    }
+
+    /// Rebuild the matrix `A` of the decomposed pair from the matrix `B`.
+    ///
+    /// This computes `B * X * Λ * Xᴴ * B`, and is useful if some of the eigenvalues have been
+    /// manually modified.
+    #[must_use]
+    pub fn recompose(&self, b: &OMatrix<T, D, D>) -> OMatrix<T, D, D> {
+        let bx = b * &self.eigenvectors;
+        let mut bx_lambda = bx.clone();
+
+        for i in 0..self.eigenvalues.len() {
+            let val = self.eigenvalues[i].clone();
+            bx_lambda.column_mut(i).scale_mut(val);
+        }
+
+        bx_lambda * bx.adjoint()
+    }
+}
@@ -0,0 +1,427 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use num::{One, Zero};
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, OVector};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::Dim;
+use crate::storage::{Storage, StorageMut};
+
+use crate::linalg::PermutationSequence;
+
+/// The LDLᵀ decomposition with Bunch-Kaufman pivoting of a symmetric matrix, which may be
+/// indefinite.
+///
+/// The decomposed matrix `A` is factored as `P * A * Pᵀ = L * D * Lᴴ`, where `P` is a
+/// permutation matrix, `L` is lower-triangular with a diagonal filled with `1`, and `D` is a
+/// block-diagonal hermitian matrix made of `1x1` and `2x2` blocks.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<T, D> +
+                           Allocator<(usize, usize), D>,
+         OMatrix<T, D, D>: Serialize,
+         OVector<T, D>: Serialize,
+         PermutationSequence<D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<T, D> +
+                           Allocator<(usize, usize), D>,
+         OMatrix<T, D, D>: Deserialize<'de>,
+         OVector<T, D>: Deserialize<'de>,
+         PermutationSequence<D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct LDLT<T: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D> + Allocator<(usize, usize), D>,
+{
+    // The strictly lower-triangular part of `L`, and the diagonal of `D`.
+    ldlt: OMatrix<T, D, D>,
+    // The subdiagonal of `D`, which is non-zero exactly at the first row of each 2x2 block.
+    subdiagonal: OVector<T, D>,
+    p: PermutationSequence<D>,
+}
+
+impl<T: ComplexField, D: Dim> Copy for LDLT<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D> + Allocator<(usize, usize), D>,
+    OMatrix<T, D, D>: Copy,
+    OVector<T, D>: Copy,
+    PermutationSequence<D>: Copy,
+{
+}
+
+impl<T: ComplexField, D: Dim> LDLT<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D> + Allocator<(usize, usize), D>,
+{
+    /// Computes the LDLᵀ decomposition with Bunch-Kaufman pivoting of `matrix`.
+    ///
+    /// The input matrix is assumed to be symmetric (or hermitian) and only the lower-triangular
+    /// part is read. Unlike the Cholesky decomposition, the matrix may be indefinite or singular.
+    ///
+    /// Ref.: "Some stable methods for calculating inertia and solving symmetric linear systems",
+    /// J. R. Bunch and L. Kaufman, Mathematics of Computation 31 (1977)
+    pub fn new(mut matrix: OMatrix<T, D, D>) -> Self {
+        assert!(matrix.is_square(), "The input matrix must be square.");
+
+        let dim = matrix.shape_generic().0;
+        let n = dim.value();
+
+        // Restore the full hermitian matrix from its lower-triangular part, so that the
+        // symmetric interchanges of rows and columns are simple swaps.
+        for j in 0..n {
+            for i in 0..j {
+                matrix[(i, j)] = matrix[(j, i)].clone().conjugate();
+            }
+            matrix[(j, j)] = T::from_real(matrix[(j, j)].clone().real());
+        }
+
+        // The growth factor is minimized by alpha = (1 + sqrt(17)) / 8.
+        let alpha: T::RealField = (crate::convert::<_, T::RealField>(17.0).sqrt()
+            + T::RealField::one())
+            / crate::convert(8.0);
+        let mut subdiagonal = OVector::zeros_generic(dim, Const::<1>);
+        let mut p = PermutationSequence::identity_generic(dim);
+
+        let mut k = 0;
+        while k < n {
+            let abs_akk = matrix[(k, k)].clone().norm1();
+            let (imax, col_max) = if k + 1 < n {
+                let imax = matrix.slice_range(k + 1.., k).icamax() + k + 1;
+                (imax, matrix[(imax, k)].clone().norm1())
+            } else {
+                (k, T::RealField::zero())
+            };
+
+            // This also selects a (zero) 1x1 pivot if the column is already eliminated.
+            let (pivot, step) = if abs_akk >= alpha.clone() * col_max.clone() {
+                (k, 1)
+            } else {
+                // The largest off-diagonal entry of the row `imax` of the trailing submatrix.
+                let row_max = (k..n)
+                    .filter(|&j| j != imax)
+                    .map(|j| matrix[(imax, j)].clone().norm1())
+                    .fold(T::RealField::zero(), |max, x| max.max(x));
+
+                if abs_akk * row_max.clone() >= alpha.clone() * col_max.clone() * col_max {
+                    (k, 1)
+                } else if matrix[(imax, imax)].clone().norm1() >= alpha.clone() * row_max {
+                    (imax, 1)
+                } else {
+                    (imax, 2)
+                }
+            };
+
+            let kk = k + step - 1;
+            if pivot != kk {
+                p.append_permutation(kk, pivot);
+                // This also swaps the rows of the columns of `L` computed so far.
+                matrix.swap_rows(kk, pivot);
+                matrix.swap_columns(kk, pivot);
+            }
+
+            if step == 1 {
+                let d = matrix[(k, k)].clone();
+                if !d.is_zero() {
+                    // A₂₂ -= a₂₁ a₂₁ᴴ / d, then l = a₂₁ / d.
+                    for j in k + 1..n {
+                        let factor = -matrix[(j, k)].clone().conjugate() / d.clone();
+                        let (mut col_j, col_k) = matrix.columns_range_pair_mut(j, k);
+                        col_j.rows_range_mut(k + 1..).axpy(
+                            factor,
+                            &col_k.rows_range(k + 1..),
+                            T::one(),
+                        );
+                    }
+                    let mut col = matrix.slice_range_mut(k + 1.., k);
+                    col /= d;
+                }
+            } else {
+                let d11 = T::from_real(matrix[(k, k)].clone().real());
+                let d22 = T::from_real(matrix[(k + 1, k + 1)].clone().real());
+                let d21 = matrix[(k + 1, k)].clone();
+                let det = d11.clone() * d22.clone() - d21.clone() * d21.clone().conjugate();
+
+                // A₂₂ -= W D⁻¹ Wᴴ, where W is the 2-column block below the pivot.
+                for j in k + 2..n {
+                    let y0 = matrix[(j, k)].clone().conjugate();
+                    let y1 = matrix[(j, k + 1)].clone().conjugate();
+                    let v0 = (d22.clone() * y0.clone() - d21.clone().conjugate() * y1.clone())
+                        / det.clone();
+                    let v1 = (d11.clone() * y1 - d21.clone() * y0) / det.clone();
+
+                    let (mut col_j, w) = matrix.columns_range_pair_mut(j, k..k + 2);
+                    let mut col_j = col_j.rows_range_mut(k + 2..);
+                    col_j.axpy(-v0, &w.slice_range(k + 2.., 0), T::one());
+                    col_j.axpy(-v1, &w.slice_range(k + 2.., 1), T::one());
+                }
+
+                // L = W D⁻¹
+                for i in k + 2..n {
+                    let w0 = matrix[(i, k)].clone();
+                    let w1 = matrix[(i, k + 1)].clone();
+                    matrix[(i, k)] =
+                        (w0.clone() * d22.clone() - w1.clone() * d21.clone()) / det.clone();
+                    matrix[(i, k + 1)] =
+                        (w1 * d11.clone() - w0 * d21.clone().conjugate()) / det.clone();
+                }
+
+                matrix[(k + 1, k)] = T::zero();
+                subdiagonal[k] = d21;
+            }
+
+            k += step;
+        }
+
+        matrix.fill_upper_triangle(T::zero(), 1);
+
+        LDLT {
+            ldlt: matrix,
+            subdiagonal,
+            p,
+        }
+    }
+
+    /// The size of the diagonal block of `D` starting at the row `k`.
+    fn block_size(&self, k: usize) -> usize {
+        if k + 1 < self.ldlt.nrows() && !self.subdiagonal[k].is_zero() {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// The lower-triangular matrix `L` of this decomposition, with a diagonal filled with `1`.
+    #[must_use]
+    pub fn l(&self) -> OMatrix<T, D, D> {
+        let mut l = self.ldlt.clone();
+        l.fill_diagonal(T::one());
+        l
+    }
+
+    /// The block-diagonal hermitian matrix `D` of this decomposition.
+    #[must_use]
+    pub fn d(&self) -> OMatrix<T, D, D> {
+        let mut d = OMatrix::from_diagonal(&self.ldlt.diagonal());
+        for k in 0..self.ldlt.nrows().saturating_sub(1) {
+            d[(k + 1, k)] = self.subdiagonal[k].clone();
+            d[(k, k + 1)] = self.subdiagonal[k].clone().conjugate();
+        }
+        d
+    }
+
+    /// The symmetric permutation `P` of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn p(&self) -> &PermutationSequence<D> {
+        &self.p
+    }
+
+    /// The permutation and the two factors of this decomposition: `(P, L, D)`.
+    #[inline]
+    pub fn unpack(self) -> (PermutationSequence<D>, OMatrix<T, D, D>, OMatrix<T, D, D>) {
+        let l = self.l();
+        let d = self.d();
+        (self.p, l, d)
+    }
+
+    /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
+    ///
+    /// The result is stored on `b`. If the decomposed matrix is not invertible, this returns
+    /// `false` and its input `b` may be overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let n = self.ldlt.nrows();
+        assert_eq!(n, b.nrows(), "LDLT solve matrix dimension mismatch.");
+
+        if n == 0 {
+            return true;
+        }
+
+        // A = Pᵀ L D Lᴴ P
+        self.p.permute_rows(b);
+        let _ = self.ldlt.solve_lower_triangular_with_diag_mut(b, T::one());
+
+        let mut k = 0;
+        while k < n {
+            if self.block_size(k) == 1 {
+                let d = self.ldlt[(k, k)].clone().real();
+                if d.is_zero() {
+                    return false;
+                }
+                b.row_mut(k).unscale_mut(d);
+                k += 1;
+            } else {
+                let d11 = self.ldlt[(k, k)].clone();
+                let d22 = self.ldlt[(k + 1, k + 1)].clone();
+                let d21 = self.subdiagonal[k].clone();
+                let det = d11.clone() * d22.clone() - d21.clone() * d21.clone().conjugate();
+                if det.is_zero() {
+                    return false;
+                }
+
+                for j in 0..b.ncols() {
+                    let (x, y) = (b[(k, j)].clone(), b[(k + 1, j)].clone());
+                    b[(k, j)] = (d22.clone() * x.clone() - d21.clone().conjugate() * y.clone())
+                        / det.clone();
+                    b[(k + 1, j)] = (d11.clone() * y - d21.clone() * x) / det.clone();
+                }
+                k += 2;
+            }
+        }
+
+        // Solve with the unit upper-triangular matrix Lᴴ.
+        for j in 0..b.ncols() {
+            let mut col = b.column_mut(j);
+            for i in (0..n - 1).rev() {
+                let dot = self
+                    .ldlt
+                    .slice_range(i + 1.., i)
+                    .dotc(&col.rows_range(i + 1..));
+                col[i] -= dot;
+            }
+        }
+
+        self.p.inv_permute_rows(b);
+        true
+    }
+
+    /// Computes the inverse of the decomposed matrix.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<OMatrix<T, D, D>> {
+        let shape = self.ldlt.shape_generic();
+        let mut res = OMatrix::identity_generic(shape.0, shape.1);
+
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    #[must_use]
+    pub fn determinant(&self) -> T::RealField {
+        let n = self.ldlt.nrows();
+        let mut det = T::RealField::one();
+
+        let mut k = 0;
+        while k < n {
+            if self.block_size(k) == 1 {
+                det *= self.ldlt[(k, k)].clone().real();
+                k += 1;
+            } else {
+                det *= self.block_determinant(k);
+                k += 2;
+            }
+        }
+
+        det
+    }
+
+    /// Indicates if the decomposed matrix is invertible.
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        let n = self.ldlt.nrows();
+
+        let mut k = 0;
+        while k < n {
+            let size = self.block_size(k);
+            if (size == 1 && self.ldlt[(k, k)].is_zero())
+                || (size == 2 && self.block_determinant(k).is_zero())
+            {
+                return false;
+            }
+            k += size;
+        }
+
+        true
+    }
+
+    /// Computes the inertia of the decomposed matrix, i.e., its number of positive, negative and
+    /// zero eigenvalues: `(positive, negative, zero)`.
+    ///
+    /// By Sylvester's law of inertia, this is also the inertia of `D`. Note that because of
+    /// rounding errors, the eigenvalues of a singular matrix are generally not counted as zero.
+    #[must_use]
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        let n = self.ldlt.nrows();
+        let (mut positive, mut negative, mut zero) = (0, 0, 0);
+        let mut count = |x: T::RealField| {
+            if x > T::RealField::zero() {
+                positive += 1;
+            } else if x < T::RealField::zero() {
+                negative += 1;
+            } else {
+                zero += 1;
+            }
+        };
+
+        let mut k = 0;
+        while k < n {
+            if self.block_size(k) == 1 {
+                count(self.ldlt[(k, k)].clone().real());
+                k += 1;
+            } else {
+                let det = self.block_determinant(k);
+                let trace =
+                    self.ldlt[(k, k)].clone().real() + self.ldlt[(k + 1, k + 1)].clone().real();
+
+                if det < T::RealField::zero() {
+                    // The eigenvalues of the block have opposite signs.
+                    count(T::RealField::one());
+                    count(-T::RealField::one());
+                } else if det > T::RealField::zero() {
+                    count(trace.clone());
+                    count(trace);
+                } else {
+                    count(trace);
+                    count(T::RealField::zero());
+                }
+                k += 2;
+            }
+        }
+
+        (positive, negative, zero)
+    }
+
+    /// The determinant of the 2x2 diagonal block of `D` starting at the row `k`.
+    fn block_determinant(&self, k: usize) -> T::RealField {
+        self.ldlt[(k, k)].clone().real() * self.ldlt[(k + 1, k + 1)].clone().real()
+            - self.subdiagonal[k].clone().modulus_squared()
+    }
+}
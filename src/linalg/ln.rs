@@ -0,0 +1,71 @@
+//! This module provides the principal logarithm (ln) function to square matrices.
+
+use crate::{
+    base::{
+        allocator::Allocator,
+        dimension::{DimMin, DimMinimum},
+        DefaultAllocator,
+    },
+    convert, ComplexField, OMatrix,
+};
+
+/// Nodes and weights of the 8-point Gauss–Legendre quadrature on `[0, 1]`.
+const GAUSS_LEGENDRE_8: [(f64, f64); 8] = [
+    (0.019855071751231856, 0.05061426814518809),
+    (0.10166676129318658, 0.11119051722668723),
+    (0.2372337950418355, 0.1568533229389437),
+    (0.4082826787521751, 0.181341891689181),
+    (0.591717321247825, 0.181341891689181),
+    (0.7627662049581645, 0.1568533229389437),
+    (0.8983332387068135, 0.11119051722668723),
+    (0.9801449282487682, 0.05061426814518809),
+];
+
+/// Maximum number of square roots taken before evaluating the logarithm.
+const MAX_SQUARE_ROOTS: u32 = 63;
+
+impl<T: ComplexField, D> OMatrix<T, D, D>
+where
+    D: DimMin<D, Output = D>,
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), DimMinimum<D, D>>,
+{
+    /// Computes the principal logarithm of this matrix.
+    ///
+    /// The principal logarithm is the unique logarithm whose eigenvalues all have an imaginary
+    /// part in `(-π, π)`. It is computed with the inverse scaling and squaring method: square
+    /// roots are taken until the matrix is close to the identity, then the logarithm is evaluated
+    /// with a diagonal Padé approximant.
+    ///
+    /// Returns `None` if this matrix has an eigenvalue on the closed negative real axis, in which
+    /// case it has no principal logarithm. In particular, a real matrix with a real negative
+    /// eigenvalue of odd multiplicity has no real logarithm at all.
+    #[must_use]
+    pub fn ln(&self) -> Option<Self> {
+        let (nrows, ncols) = self.shape_generic();
+        let ident = Self::identity_generic(nrows, ncols);
+
+        let mut a = self.clone();
+        let mut s = 0;
+
+        while (&a - &ident).norm() > convert(0.25) {
+            if s == MAX_SQUARE_ROOTS {
+                return None;
+            }
+
+            a = a.sqrt()?;
+            s += 1;
+        }
+
+        // The 8-point Gauss–Legendre quadrature of `log(I + X) = ∫ X * (I + t * X)⁻¹ dt` over
+        // `[0, 1]` is the [8/8] Padé approximant of the logarithm.
+        let x = a - &ident;
+        let mut res = Self::zeros_generic(nrows, ncols);
+
+        for (node, weight) in GAUSS_LEGENDRE_8.iter() {
+            let denom = &ident + &x * convert::<f64, T>(*node);
+            res += denom.lu().solve(&x)? * convert::<f64, T>(*weight);
+        }
+
+        Some(res * convert::<f64, T>((1u64 << s) as f64))
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Unit};
+use crate::dimension::{Const, Dim, DimMin, DimMinimum};
+use simba::scalar::ComplexField;
+
+use crate::geometry::Reflection;
+use crate::linalg::householder;
+use std::mem::MaybeUninit;
+
+/// The LQ decomposition of a general matrix.
+///
+/// This is the transposed counterpart of the QR decomposition: the decomposed matrix is equal to
+/// `L * Q` where `L` is lower-trapezoidal and `Q` has orthonormal rows. It is mostly useful for
+/// matrices with more columns than rows.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, R, C> +
+                           Allocator<T, DimMinimum<R, C>>,
+         OMatrix<T, R, C>: Serialize,
+         OVector<T, DimMinimum<R, C>>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, R, C> +
+                           Allocator<T, DimMinimum<R, C>>,
+         OMatrix<T, R, C>: Deserialize<'de>,
+         OVector<T, DimMinimum<R, C>>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct LQ<T: ComplexField, R: DimMin<C>, C: Dim>
+where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<T, DimMinimum<R, C>>,
+{
+    lq: OMatrix<T, R, C>,
+    diag: OVector<T, DimMinimum<R, C>>,
+}
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim> Copy for LQ<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<T, DimMinimum<R, C>>,
+    OMatrix<T, R, C>: Copy,
+    OVector<T, DimMinimum<R, C>>: Copy,
+{
+}
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim> LQ<T, R, C>
+where
+    DefaultAllocator:
+        Allocator<T, R, C> + Allocator<T, R> + Allocator<T, C> + Allocator<T, DimMinimum<R, C>>,
+{
+    /// Computes the LQ decomposition using householder reflections.
+    pub fn new(mut matrix: OMatrix<T, R, C>) -> Self {
+        let (nrows, ncols) = matrix.shape_generic();
+        let min_nrows_ncols = nrows.min(ncols);
+
+        if min_nrows_ncols.value() == 0 {
+            return LQ {
+                lq: matrix,
+                diag: Matrix::zeros_generic(min_nrows_ncols, Const::<1>),
+            };
+        }
+
+        let mut diag = Matrix::uninit(min_nrows_ncols, Const::<1>);
+        let mut work = Matrix::zeros_generic(nrows, Const::<1>);
+        let mut axis_packed = Matrix::zeros_generic(ncols, Const::<1>);
+
+        for i in 0..min_nrows_ncols.value() {
+            diag[i] = MaybeUninit::new(householder::clear_row_unchecked(
+                &mut matrix,
+                &mut axis_packed,
+                &mut work,
+                i,
+                0,
+            ));
+        }
+
+        // Safety: diag is now fully initialized.
+        let diag = unsafe { diag.assume_init() };
+        LQ { lq: matrix, diag }
+    }
+
+    /// Retrieves the lower trapezoidal submatrix `L` of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn l(&self) -> OMatrix<T, R, DimMinimum<R, C>>
+    where
+        DefaultAllocator: Allocator<T, R, DimMinimum<R, C>>,
+    {
+        let (nrows, ncols) = self.lq.shape_generic();
+        let mut res = self
+            .lq
+            .columns_generic(0, nrows.min(ncols))
+            .lower_triangle();
+        res.set_partial_diagonal(self.diag.iter().map(|e| T::from_real(e.clone().modulus())));
+        res
+    }
+
+    /// Computes the matrix `Q` with orthonormal rows of this decomposition.
+    #[must_use]
+    pub fn q(&self) -> OMatrix<T, DimMinimum<R, C>, C>
+    where
+        DefaultAllocator: Allocator<T, DimMinimum<R, C>, C>,
+    {
+        let (nrows, ncols) = self.lq.shape_generic();
+        let min_nrows_ncols = nrows.min(ncols);
+
+        // NOTE: we could build the identity matrix and call q_mul on it.
+        // Instead we don't so that we take in account the matrix sparseness.
+        let mut res = Matrix::identity_generic(min_nrows_ncols, ncols);
+        let mut work = Matrix::zeros_generic(min_nrows_ncols, Const::<1>);
+        let mut axis_packed = Matrix::zeros_generic(ncols, Const::<1>);
+
+        for i in (0..min_nrows_ncols.value()).rev() {
+            let axis = self.lq.slice_range(i, i..);
+            let mut axis_packed = axis_packed.rows_range_mut(i..);
+            axis_packed.tr_copy_from(&axis);
+            // TODO: sometimes, the axis might have a zero magnitude.
+            let refl = Reflection::new(Unit::new_unchecked(axis_packed), T::zero());
+
+            let mut res_rows = res.slice_range_mut(i.., i..);
+            refl.reflect_rows_with_sign(
+                &mut res_rows,
+                &mut work.rows_range_mut(i..),
+                self.diag[i].clone().signum(),
+            );
+        }
+
+        res
+    }
+
+    /// Unpacks this decomposition into its two matrix factors `(L, Q)`.
+    pub fn unpack(
+        self,
+    ) -> (
+        OMatrix<T, R, DimMinimum<R, C>>,
+        OMatrix<T, DimMinimum<R, C>, C>,
+    )
+    where
+        DefaultAllocator: Allocator<T, R, DimMinimum<R, C>> + Allocator<T, DimMinimum<R, C>, C>,
+    {
+        (self.l(), self.q())
+    }
+
+    #[doc(hidden)]
+    pub fn lq_internal(&self) -> &OMatrix<T, R, C> {
+        &self.lq
+    }
+}
@@ -0,0 +1,63 @@
+use simba::scalar::ComplexField;
+
+use crate::base::allocator::Allocator;
+use crate::base::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::base::dimension::{Dim, DimMin, DimMinimum};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, Matrix, OMatrix};
+
+use crate::linalg::ColPivQR;
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// Computes the minimum-norm solution `x` of the linear least-squares problem
+    /// `min ‖self * x - b‖`, and the norm of its residual.
+    ///
+    /// This relies on the QR decomposition with column pivoting of `self`, whose diagonal
+    /// elements with a modulus smaller than or equal to `eps` are considered to be zero. If
+    /// `self` has full column rank, the solution is computed by `ColPivQR::solve_least_squares`.
+    /// Otherwise, the problem is rank-deficient or underdetermined and the solution is computed by
+    /// `ColPivQR::solve_min_norm`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3x2, Vector3, Vector2};
+    /// // Fit a line `y = a * t + b` through three points.
+    /// let m = Matrix3x2::new(0.0, 1.0,
+    ///                        1.0, 1.0,
+    ///                        2.0, 1.0);
+    /// let y = Vector3::new(1.0, 2.0, 4.0);
+    ///
+    /// let (x, residual) = m.lstsq(&y, 1.0e-10);
+    /// assert_relative_eq!(x, Vector2::new(1.5, 5.0 / 6.0), epsilon = 1.0e-10);
+    /// assert_relative_eq!(residual, (m * x - y).norm(), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn lstsq<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        eps: T::RealField,
+    ) -> (OMatrix<T, C, C2>, T::RealField)
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator: Allocator<T, R, C>
+            + Allocator<T, R>
+            + Allocator<T, DimMinimum<R, C>>
+            + Allocator<(usize, usize), DimMinimum<R, C>>
+            + Allocator<T, R2, C2>
+            + Allocator<T, C, C2>
+            + Allocator<T, DimMinimum<R, C>, C>,
+    {
+        let col_piv_qr = ColPivQR::new(self.clone_owned());
+
+        if col_piv_qr.rank(eps.clone()) == self.ncols() {
+            if let Some(res) = col_piv_qr.solve_least_squares(b) {
+                return res;
+            }
+        }
+
+        col_piv_qr.solve_min_norm(b, eps)
+    }
+}
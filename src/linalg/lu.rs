@@ -3,12 +3,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::allocator::{Allocator, Reallocator};
 use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar};
-use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::constraint::{AreMultipliable, SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimMin, DimMinimum};
 use crate::storage::{Storage, StorageMut};
-use simba::scalar::{ComplexField, Field};
+use num::Zero;
+use simba::scalar::{ComplexField, Field, SupersetOf};
 use std::mem;
 
+use crate::linalg::norm_estimate::{estimate_one_norm, rcond_from_estimates};
+use crate::linalg::refinement::{self, RefinedSolution};
 use crate::linalg::PermutationSequence;
 
 /// LU decomposition with partial (row) pivoting.
@@ -258,6 +261,54 @@ where
         self.lu.solve_upper_triangular_mut(b)
     }
 
+    /// Solves the linear system `a * x = b` with iterative refinement, where `a` is the matrix
+    /// that was decomposed and `x` is the unknown to be determined.
+    ///
+    /// The system is first solved with this decomposition, then the solution is improved by
+    /// iterative refinement: the residual `b - a * x` is computed with the matrix `a` that was
+    /// decomposed, in the possibly higher precision of `U`, and the correction is obtained by
+    /// solving the system with this decomposition again. This makes it possible to decompose the
+    /// matrix in `f32` and still get a solution accurate in `f64`, as long as the matrix is not
+    /// too ill-conditioned.
+    ///
+    /// The refinement stops when the backward error reaches the machine epsilon of `U`, when it
+    /// stops decreasing significantly, or after `max_iter` steps.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let a = Matrix3::new(4.0, 1.0, 2.0,
+    ///                      1.0, 5.0, 3.0,
+    ///                      2.0, 3.0, 6.0);
+    /// let b = Vector3::new(1.0, 2.0, 3.0);
+    ///
+    /// // Decompose in single precision, refine in double precision.
+    /// let lu = a.cast::<f32>().lu();
+    /// let refined = lu.solve_refined(&a, &b, 10).unwrap();
+    ///
+    /// assert!(refined.backward_error <= 1.0e-15);
+    /// assert!((a * refined.solution - b).norm() <= 1.0e-14);
+    /// ```
+    #[must_use]
+    pub fn solve_refined<U, R2: Dim, C2: Dim, S2, S3>(
+        &self,
+        a: &Matrix<U, D, D, S2>,
+        b: &Matrix<U, R2, C2, S3>,
+        max_iter: usize,
+    ) -> Option<RefinedSolution<U, R2, C2>>
+    where
+        U: ComplexField + SupersetOf<T>,
+        S2: Storage<U, D, D>,
+        S3: Storage<U, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D> + AreMultipliable<D, D, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2> + Allocator<U, R2, C2>,
+    {
+        refinement::solve_refined(a, b, max_iter, |x| self.solve_mut(x))
+    }
+
     /// Computes the inverse of the decomposed matrix.
     ///
     /// Returns `None` if the matrix is not invertible.
@@ -295,6 +346,22 @@ where
         self.solve_mut(out)
     }
 
+    /// Computes the natural logarithm of the modulus of the determinant of the decomposed matrix.
+    ///
+    /// Unlike `self.determinant().modulus().ln()`, this does not overflow or underflow for large
+    /// matrices.
+    #[must_use]
+    pub(crate) fn ln_abs_determinant(&self) -> T::RealField {
+        assert!(
+            self.lu.is_square(),
+            "LU determinant: unable to compute the determinant of a non-square matrix."
+        );
+
+        (0..self.lu.nrows()).fold(T::RealField::zero(), |acc, i| {
+            acc + self.lu[(i, i)].clone().modulus().ln()
+        })
+    }
+
     /// Computes the determinant of the decomposed matrix.
     #[must_use]
     pub fn determinant(&self) -> T {
@@ -328,6 +395,47 @@ where
 
         true
     }
+
+    /// Estimates the reciprocal of the 1-norm condition number of the decomposed matrix.
+    ///
+    /// The 1-norms of the decomposed matrix and of its inverse are estimated with the
+    /// Hager–Higham algorithm, which only needs a few matrix-vector products and triangular
+    /// solves, so the result is only an estimate of the reciprocal condition number. It is 0 if
+    /// the decomposed matrix is not invertible. Values close to the machine epsilon indicate that
+    /// solutions of linear systems with this matrix are unreliable.
+    #[must_use]
+    pub fn rcond(&self) -> T::RealField
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let dim = self.lu.shape_generic().0;
+        let (l, u) = (self.l(), self.u());
+
+        // The decomposed matrix is `P⁻¹ * L * U`.
+        let norm = estimate_one_norm(dim, |x, adjoint| {
+            if adjoint {
+                self.p.permute_rows(x);
+                *x = u.ad_mul(&l.ad_mul(x));
+            } else {
+                *x = &l * (&u * &*x);
+                self.p.inv_permute_rows(x);
+            }
+
+            true
+        });
+        let inv_norm = estimate_one_norm(dim, |x, adjoint| {
+            if adjoint {
+                let solved =
+                    u.ad_solve_upper_triangular_mut(x) && l.ad_solve_lower_triangular_mut(x);
+                self.p.inv_permute_rows(x);
+                solved
+            } else {
+                self.solve_mut(x)
+            }
+        });
+
+        rcond_from_estimates::<T>(norm, inv_norm)
+    }
 }
 
 #[doc(hidden)]
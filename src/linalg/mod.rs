@@ -3,6 +3,7 @@
 pub mod balancing;
 mod bidiagonal;
 mod cholesky;
+mod cod;
 mod convolution;
 mod determinant;
 // TODO: this should not be needed. However, the exp uses
@@ -10,42 +11,63 @@ mod determinant;
 // get rid of these to allow exp to be used on a no-std context.
 mod col_piv_qr;
 mod decomposition;
+mod eigen;
 #[cfg(feature = "std")]
 mod exp;
 mod full_piv_lu;
+mod generalized_eigen;
+mod generalized_schur;
+mod generalized_symmetric_eigen;
 pub mod givens;
 mod hessenberg;
 pub mod householder;
 mod inverse;
+mod ldlt;
+mod ln;
+mod lq;
+mod lstsq;
 mod lu;
+mod norm_estimate;
 mod permutation_sequence;
 mod pow;
 mod qr;
+mod refinement;
+mod rq;
 mod schur;
+mod sign;
 mod solve;
+mod sqrt;
 mod svd;
 mod svd2;
 mod svd3;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod svd_dc;
+mod svd_jacobi;
 mod symmetric_eigen;
 mod symmetric_tridiagonal;
 mod udu;
 
-//// TODO: Not complete enough for publishing.
-//// This handles only cases where each eigenvalue has multiplicity one.
-// mod eigen;
-
 pub use self::bidiagonal::*;
 pub use self::cholesky::*;
+pub use self::cod::*;
 pub use self::col_piv_qr::*;
 pub use self::convolution::*;
+pub use self::eigen::*;
 #[cfg(feature = "std")]
 pub use self::exp::*;
 pub use self::full_piv_lu::*;
+pub use self::generalized_eigen::*;
+pub use self::generalized_schur::*;
+pub use self::generalized_symmetric_eigen::*;
 pub use self::hessenberg::*;
+pub use self::ldlt::*;
+pub use self::lq::*;
 pub use self::lu::*;
 pub use self::permutation_sequence::*;
 pub use self::pow::*;
 pub use self::qr::*;
+pub use self::refinement::*;
+pub use self::rq::*;
 pub use self::schur::*;
 pub use self::svd::*;
 pub use self::symmetric_eigen::*;
@@ -0,0 +1,139 @@
+//! Estimation of the 1-norm of a square matrix only known through its products with vectors.
+
+use num::{One, Zero};
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, OVector};
+use crate::convert;
+use crate::dimension::{Const, Dim};
+
+/// Maximum number of iterations of the Hager–Higham estimator.
+const MAX_ITERATIONS: usize = 5;
+
+/// Estimates the 1-norm of a square matrix `A` with `dim` rows with the Hager–Higham algorithm.
+///
+/// The matrix is only accessed through `apply(x, adjoint)`, which must overwrite `x` with `A * x`
+/// if `adjoint` is `false` and with `Aᴴ * x` otherwise. Returns `None` if `apply` returns `false`.
+/// The estimate is a lower bound of the actual norm, and is exact in most cases.
+pub(crate) fn estimate_one_norm<T, D, F>(dim: D, mut apply: F) -> Option<T::RealField>
+where
+    T: ComplexField,
+    D: Dim,
+    F: FnMut(&mut OVector<T, D>, bool) -> bool,
+    DefaultAllocator: Allocator<T, D>,
+{
+    let n = dim.value();
+
+    if n == 0 {
+        return Some(T::RealField::zero());
+    }
+
+    let mut x = OVector::repeat_generic(dim, Const::<1>, T::from_real(convert(1.0 / n as f64)));
+
+    if !apply(&mut x, false) {
+        return None;
+    }
+
+    let mut est = vector_one_norm(&x);
+    let mut j = 0;
+
+    for iter in 0..MAX_ITERATIONS {
+        // The next candidate is the column maximizing the gradient `Aᴴ * sign(A * x)`.
+        let mut z = x.map(sign);
+
+        if !apply(&mut z, true) {
+            return None;
+        }
+
+        let j_new = imax_modulus(&z);
+
+        if iter > 0 && z[j_new].clone().modulus() <= z[j].clone().real() {
+            break;
+        }
+
+        j = j_new;
+        x.fill(T::zero());
+        x[j] = T::one();
+
+        if !apply(&mut x, false) {
+            return None;
+        }
+
+        let new_est = vector_one_norm(&x);
+
+        if new_est <= est {
+            break;
+        }
+
+        est = new_est;
+    }
+
+    // Alternative estimate protecting against the rare matrices where the iteration fails.
+    if n > 1 {
+        for i in 0..n {
+            let val: T::RealField = convert(1.0 + i as f64 / (n - 1) as f64);
+            x[i] = T::from_real(if i % 2 == 0 { val } else { -val });
+        }
+
+        if !apply(&mut x, false) {
+            return None;
+        }
+
+        let alt_est = vector_one_norm(&x) * convert(2.0 / (3 * n) as f64);
+        est = est.max(alt_est);
+    }
+
+    Some(est)
+}
+
+fn vector_one_norm<T: ComplexField, D: Dim>(x: &OVector<T, D>) -> T::RealField
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    x.iter()
+        .fold(T::RealField::zero(), |acc, e| acc + e.clone().modulus())
+}
+
+fn imax_modulus<T: ComplexField, D: Dim>(x: &OVector<T, D>) -> usize
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    let mut imax = 0;
+    let mut max = T::RealField::zero();
+
+    for (i, e) in x.iter().enumerate() {
+        let modulus = e.clone().modulus();
+
+        if modulus > max {
+            imax = i;
+            max = modulus;
+        }
+    }
+
+    imax
+}
+
+fn sign<T: ComplexField>(e: T) -> T {
+    let modulus = e.clone().modulus();
+
+    if modulus.is_zero() {
+        T::one()
+    } else {
+        e.unscale(modulus)
+    }
+}
+
+/// Computes the reciprocal `1 / (‖A‖₁ * ‖A⁻¹‖₁)` of the 1-norm condition number from the estimates
+/// of both norms, or zero if any of them is unavailable or zero.
+pub(crate) fn rcond_from_estimates<T: ComplexField>(
+    norm: Option<T::RealField>,
+    inv_norm: Option<T::RealField>,
+) -> T::RealField {
+    match (norm, inv_norm) {
+        (Some(norm), Some(inv_norm)) if !norm.is_zero() && !inv_norm.is_zero() => {
+            T::RealField::one() / (norm * inv_norm)
+        }
+        _ => T::RealField::zero(),
+    }
+}
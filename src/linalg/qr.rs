@@ -3,17 +3,23 @@ use num::Zero;
 use serde::{Deserialize, Serialize};
 
 use crate::allocator::{Allocator, Reallocator};
-use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Unit};
-use crate::constraint::{SameNumberOfRows, ShapeConstraint};
-use crate::dimension::{Const, Dim, DimMin, DimMinimum};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, RowVector, Unit, Vector, Vector2};
+use crate::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Const, Dim, DimAdd, DimDiff, DimMin, DimMinimum, DimSub, DimSum, U1};
 use crate::storage::{Storage, StorageMut};
 use simba::scalar::ComplexField;
 
 use crate::geometry::Reflection;
+use crate::linalg::givens::GivensRotation;
 use crate::linalg::householder;
+use crate::linalg::norm_estimate::{estimate_one_norm, rcond_from_estimates};
 use std::mem::MaybeUninit;
 
 /// The QR decomposition of a general matrix.
+///
+/// The decomposition can be updated after a rank-one modification, or the insertion or removal
+/// of a row or column of the decomposed matrix, using givens rotations instead of recomputing it
+/// from the modified matrix.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde-serialize-no-std",
@@ -168,6 +174,394 @@ where
             refl.reflect_with_sign(&mut rhs_rows, self.diag[i].clone().signum().conjugate());
         }
     }
+
+    /// Solves the linear least-squares problem `min ‖self * x - b‖`, where `x` is the unknown to
+    /// be determined.
+    ///
+    /// The decomposed matrix must have at least as many rows as columns. Returns the solution
+    /// together with the norm of the residual `self * x - b` (its Frobenius norm if `b` has
+    /// several columns), or `None` if the decomposed matrix does not have full column rank. Use
+    /// `ColPivQR::solve_min_norm` for rank-deficient problems.
+    #[must_use]
+    pub fn solve_least_squares<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<(OMatrix<T, C, C2>, T::RealField)>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator: Allocator<T, R2, C2> + Allocator<T, C, C2>,
+    {
+        let (nrows, ncols) = self.qr.shape_generic();
+        assert_eq!(
+            nrows.value(),
+            b.nrows(),
+            "QR least squares: matrix dimension mismatch."
+        );
+        assert!(
+            nrows.value() >= ncols.value(),
+            "QR least squares: the matrix must have at least as many rows as columns."
+        );
+
+        let mut qtb = b.clone_owned();
+        self.q_tr_mul(&mut qtb);
+
+        let residual = qtb.rows_range(ncols.value()..).norm();
+        let mut x = qtb.rows_generic(0, ncols).into_owned();
+
+        if self.solve_upper_triangular_mut(&mut x) {
+            Some((x, residual))
+        } else {
+            None
+        }
+    }
+
+    // TODO: duplicate code from the `solve` module.
+    fn solve_upper_triangular_mut<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &mut Matrix<T, R2, C2, S2>,
+    ) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        let dim = self.diag.len();
+
+        for k in 0..b.ncols() {
+            let mut b = b.column_mut(k);
+            for i in (0..dim).rev() {
+                let coeff;
+
+                unsafe {
+                    let diag = self.diag.vget_unchecked(i).clone().modulus();
+
+                    if diag.is_zero() {
+                        return false;
+                    }
+
+                    coeff = b.vget_unchecked(i).clone().unscale(diag);
+                    *b.vget_unchecked_mut(i) = coeff.clone();
+                }
+
+                b.rows_range_mut(..i)
+                    .axpy(-coeff, &self.qr.slice_range(..i, i), T::one());
+            }
+        }
+
+        true
+    }
+}
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim> QR<T, R, C>
+where
+    DefaultAllocator:
+        Allocator<T, R, C> + Allocator<T, R, R> + Allocator<T, R> + Allocator<T, DimMinimum<R, C>>,
+{
+    /// Given the QR decomposition of a matrix `M` and two vectors `u` and `v`, performs a rank
+    /// one update such that we end up with the decomposition of `M + u * v.adjoint()`.
+    pub fn rank_one_update<R2: Dim, S2, C2: Dim, S3>(
+        &mut self,
+        u: &Vector<T, R2, S2>,
+        v: &Vector<T, C2, S3>,
+    ) where
+        S2: Storage<T, R2, U1>,
+        S3: Storage<T, C2, U1>,
+        ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfRows<C2, C>,
+    {
+        let (nrows, ncols) = self.qr.shape();
+        assert_eq!(
+            u.nrows(),
+            nrows,
+            "The vector `u` must have as many rows as the factored matrix."
+        );
+        assert_eq!(
+            v.nrows(),
+            ncols,
+            "The vector `v` must have as many rows as the factored matrix has columns."
+        );
+
+        if nrows == 0 {
+            return;
+        }
+
+        let (mut q, mut r) = self.explicit_factors();
+        let mut w = q.ad_mul(u);
+
+        // Reduce `Qᴴ * u` to a multiple of the first basis vector, which makes `R`
+        // upper-Hessenberg. `R + w * vᴴ` is then upper-Hessenberg too.
+        for k in (1..nrows).rev() {
+            let v = Vector2::new(w[k - 1].clone(), w[k].clone());
+
+            if let Some((rot, norm)) = GivensRotation::cancel_y(&v) {
+                w[k - 1] = norm;
+                w[k] = T::zero();
+                rot.rotate(&mut r.rows_range_mut(k - 1..k + 1));
+                rot.inverse()
+                    .rotate_rows(&mut q.fixed_columns_mut::<2>(k - 1));
+            }
+        }
+
+        for j in 0..ncols {
+            r[(0, j)] += w[0].clone() * v[j].clone().conjugate();
+        }
+
+        hessenberg_to_triangular(&mut q, &mut r, 0);
+        *self = Self::from_explicit_factors(q, r);
+    }
+
+    /// Updates the decomposition such that we get the decomposition of the factored matrix with
+    /// the column `col` inserted at the `j`th position.
+    pub fn insert_column<R2, S2>(&self, j: usize, col: Vector<T, R2, S2>) -> QR<T, R, DimSum<C, U1>>
+    where
+        C: DimAdd<U1>,
+        R: DimMin<DimSum<C, U1>>,
+        R2: Dim,
+        S2: Storage<T, R2, U1>,
+        DefaultAllocator:
+            Allocator<T, R, DimSum<C, U1>> + Allocator<T, DimMinimum<R, DimSum<C, U1>>>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+    {
+        let (nrows, ncols) = self.qr.shape_generic();
+        assert_eq!(
+            col.nrows(),
+            nrows.value(),
+            "The new column must have as many rows as the factored matrix."
+        );
+        assert!(
+            j <= ncols.value(),
+            "j needs to be within the bound of the new matrix."
+        );
+
+        let (mut q, r) = self.explicit_factors();
+        let mut w = q.ad_mul(&col);
+
+        let mut new_r: OMatrix<T, R, DimSum<C, U1>> =
+            Matrix::zeros_generic(nrows, ncols.add(Const::<1>));
+        new_r
+            .slice_range_mut(0.., ..j)
+            .copy_from(&r.slice_range(0.., ..j));
+        new_r
+            .slice_range_mut(0.., j + 1..)
+            .copy_from(&r.slice_range(0.., j..));
+
+        // Cancel the components of `Qᴴ * col` below the diagonal, from the bottom. The rows
+        // involved are zero in the columns before `j`, and the columns after `j` remain
+        // upper-triangular.
+        for k in (j + 1..nrows.value()).rev() {
+            let v = Vector2::new(w[k - 1].clone(), w[k].clone());
+
+            if let Some((rot, norm)) = GivensRotation::cancel_y(&v) {
+                w[k - 1] = norm;
+                w[k] = T::zero();
+                rot.rotate(&mut new_r.slice_range_mut(k - 1..k + 1, j + 1..));
+                rot.inverse()
+                    .rotate_rows(&mut q.fixed_columns_mut::<2>(k - 1));
+            }
+        }
+
+        new_r.slice_range_mut(0.., j).copy_from(&w.rows_range(0..));
+        QR::from_explicit_factors(q, new_r)
+    }
+
+    /// Updates the decomposition such that we get the decomposition of the factored matrix with
+    /// its `j`th column removed.
+    #[must_use]
+    pub fn remove_column(&self, j: usize) -> QR<T, R, DimDiff<C, U1>>
+    where
+        C: DimSub<U1>,
+        R: DimMin<DimDiff<C, U1>>,
+        DefaultAllocator:
+            Allocator<T, R, DimDiff<C, U1>> + Allocator<T, DimMinimum<R, DimDiff<C, U1>>>,
+    {
+        let (nrows, ncols) = self.qr.shape_generic();
+        assert!(ncols.value() > 0, "The matrix needs at least one column.");
+        assert!(
+            j < ncols.value(),
+            "j needs to be within the bound of the matrix."
+        );
+
+        let (mut q, r) = self.explicit_factors();
+
+        // Removing a column makes the trailing columns upper-Hessenberg.
+        let mut new_r = Matrix::zeros_generic(nrows, ncols.sub(Const::<1>));
+        new_r
+            .slice_range_mut(0.., ..j)
+            .copy_from(&r.slice_range(0.., ..j));
+        new_r
+            .slice_range_mut(0.., j..)
+            .copy_from(&r.slice_range(0.., j + 1..));
+
+        hessenberg_to_triangular(&mut q, &mut new_r, j);
+        QR::from_explicit_factors(q, new_r)
+    }
+
+    /// Updates the decomposition such that we get the decomposition of the factored matrix with
+    /// the row `row` inserted at the `i`th position.
+    pub fn insert_row<C2, S2>(&self, i: usize, row: RowVector<T, C2, S2>) -> QR<T, DimSum<R, U1>, C>
+    where
+        R: DimAdd<U1>,
+        DimSum<R, U1>: DimMin<C>,
+        C2: Dim,
+        S2: Storage<T, U1, C2>,
+        DefaultAllocator: Allocator<T, DimSum<R, U1>, C>
+            + Allocator<T, DimSum<R, U1>, DimSum<R, U1>>
+            + Allocator<T, DimSum<R, U1>>
+            + Allocator<T, DimMinimum<DimSum<R, U1>, C>>,
+        ShapeConstraint: SameNumberOfColumns<C, C2>,
+    {
+        let (nrows, ncols) = self.qr.shape_generic();
+        assert_eq!(
+            row.ncols(),
+            ncols.value(),
+            "The new row must have as many columns as the factored matrix."
+        );
+        assert!(
+            i <= nrows.value(),
+            "i needs to be within the bound of the new matrix."
+        );
+
+        let (q, r) = self.explicit_factors();
+
+        // The matrix with the new row moved to the top is decomposed as
+        // `diag(1, Q) * [row; R]`, where `[row; R]` is upper-Hessenberg.
+        let new_nrows = nrows.add(Const::<1>);
+        let mut new_q = Matrix::zeros_generic(new_nrows, new_nrows);
+        new_q[(i, 0)] = T::one();
+        new_q
+            .slice_range_mut(..i, 1..)
+            .copy_from(&q.slice_range(..i, 0..));
+        new_q
+            .slice_range_mut(i + 1.., 1..)
+            .copy_from(&q.slice_range(i.., 0..));
+
+        let mut new_r = Matrix::zeros_generic(new_nrows, ncols);
+        new_r.row_mut(0).copy_from(&row);
+        new_r
+            .slice_range_mut(1.., 0..)
+            .copy_from(&r.slice_range(0.., 0..));
+
+        hessenberg_to_triangular(&mut new_q, &mut new_r, 0);
+        QR::from_explicit_factors(new_q, new_r)
+    }
+
+    /// Updates the decomposition such that we get the decomposition of the factored matrix with
+    /// its `i`th row removed.
+    #[must_use]
+    pub fn remove_row(&self, i: usize) -> QR<T, DimDiff<R, U1>, C>
+    where
+        R: DimSub<U1>,
+        DimDiff<R, U1>: DimMin<C>,
+        DefaultAllocator: Allocator<T, DimDiff<R, U1>, C>
+            + Allocator<T, DimDiff<R, U1>, DimDiff<R, U1>>
+            + Allocator<T, DimDiff<R, U1>>
+            + Allocator<T, DimMinimum<DimDiff<R, U1>, C>>,
+    {
+        let (nrows, ncols) = self.qr.shape_generic();
+        assert!(nrows.value() > 0, "The matrix needs at least one row.");
+        assert!(
+            i < nrows.value(),
+            "i needs to be within the bound of the matrix."
+        );
+
+        let (mut q, mut r) = self.explicit_factors();
+
+        // Rotate the `i`th row of `Q` to a multiple of the first basis vector, which makes `R`
+        // upper-Hessenberg. The first column of `Q` is then a multiple of the `i`th basis
+        // vector, and the first row of `R` can be dropped along with it.
+        for k in (1..nrows.value()).rev() {
+            let v = Vector2::new(
+                q[(i, k - 1)].clone().conjugate(),
+                q[(i, k)].clone().conjugate(),
+            );
+
+            if let Some((rot, _)) = GivensRotation::cancel_y(&v) {
+                rot.rotate(&mut r.rows_range_mut(k - 1..k + 1));
+                rot.inverse()
+                    .rotate_rows(&mut q.fixed_columns_mut::<2>(k - 1));
+            }
+        }
+
+        let new_nrows = nrows.sub(Const::<1>);
+        let mut new_q = Matrix::zeros_generic(new_nrows, new_nrows);
+        new_q
+            .slice_range_mut(..i, 0..)
+            .copy_from(&q.slice_range(..i, 1..));
+        new_q
+            .slice_range_mut(i.., 0..)
+            .copy_from(&q.slice_range(i + 1.., 1..));
+
+        let mut new_r = Matrix::zeros_generic(new_nrows, ncols);
+        new_r
+            .slice_range_mut(0.., 0..)
+            .copy_from(&r.slice_range(1.., 0..));
+
+        QR::from_explicit_factors(new_q, new_r)
+    }
+
+    /// The explicit unitary factor `Q` and upper-trapezoidal factor `R` of this decomposition.
+    fn explicit_factors(&self) -> (OMatrix<T, R, R>, OMatrix<T, R, C>) {
+        let nrows = self.qr.shape_generic().0;
+        let mut r = self.qr.upper_triangle();
+        r.set_partial_diagonal(self.diag.iter().map(|e| T::from_real(e.clone().modulus())));
+
+        let mut q = Matrix::identity_generic(nrows, nrows);
+
+        for i in (0..self.diag.len()).rev() {
+            let axis = self.qr.slice_range(i.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), T::zero());
+
+            let mut q_rows = q.slice_range_mut(i.., i..);
+            refl.reflect_with_sign(&mut q_rows, self.diag[i].clone().signum());
+        }
+
+        (q, r)
+    }
+
+    /// Builds the decomposition from its explicit factors.
+    ///
+    /// The strictly lower-triangular part of `r` is assumed to be zero, up to rounding errors.
+    fn from_explicit_factors(mut q: OMatrix<T, R, R>, mut r: OMatrix<T, R, C>) -> Self {
+        let (nrows, ncols) = r.shape_generic();
+        let mut diag = Matrix::zeros_generic(nrows.min(ncols), Const::<1>);
+        let dim = diag.len();
+        r.fill_lower_triangle(T::zero(), 1);
+
+        // Store the first columns of `Q` as householder reflections, as `QR::new` does. This
+        // decomposes them as `H * Rq`, where `H` is the product of the reflections and `Rq` is
+        // upper-triangular (and equal to the identity up to rounding errors).
+        for i in 0..dim {
+            diag[i] = householder::clear_column_unchecked(&mut q, i, 0, None);
+        }
+
+        // Replace `R` by `Rq * R`. The signs of the reflections are then chosen so that the
+        // diagonal of `R` is real and non-negative, which scales the rows of `R` by `phase`.
+        let mut phase = T::one();
+
+        for i in 0..dim {
+            for j in i..ncols.value() {
+                let mut coeff = T::from_real(diag[i].clone().modulus()) * r[(i, j)].clone();
+
+                for l in i + 1..dim.min(j + 1) {
+                    coeff += q[(i, l)].clone() * r[(l, j)].clone();
+                }
+
+                r[(i, j)] = coeff;
+            }
+
+            let q_sign = diag[i].clone().signum();
+            let (modulus, r_sign) = r[(i, i)].clone().to_exp();
+            diag[i] = q_sign.clone() * r_sign * phase.clone().conjugate() * T::from_real(modulus);
+            // If the diagonal element is zero, its sign is the one `q()` will actually use.
+            phase *= diag[i].clone().signum() * q_sign.conjugate();
+
+            for j in i + 1..ncols.value() {
+                r[(i, j)] *= phase.clone().conjugate();
+            }
+
+            r.slice_range_mut(i.., i).copy_from(&q.slice_range(i.., i));
+        }
+
+        QR { qr: r, diag }
+    }
 }
 
 impl<T: ComplexField, D: DimMin<D, Output = D>> QR<T, D, D>
@@ -219,41 +613,6 @@ where
         self.solve_upper_triangular_mut(b)
     }
 
-    // TODO: duplicate code from the `solve` module.
-    fn solve_upper_triangular_mut<R2: Dim, C2: Dim, S2>(
-        &self,
-        b: &mut Matrix<T, R2, C2, S2>,
-    ) -> bool
-    where
-        S2: StorageMut<T, R2, C2>,
-        ShapeConstraint: SameNumberOfRows<R2, D>,
-    {
-        let dim = self.qr.nrows();
-
-        for k in 0..b.ncols() {
-            let mut b = b.column_mut(k);
-            for i in (0..dim).rev() {
-                let coeff;
-
-                unsafe {
-                    let diag = self.diag.vget_unchecked(i).clone().modulus();
-
-                    if diag.is_zero() {
-                        return false;
-                    }
-
-                    coeff = b.vget_unchecked(i).clone().unscale(diag);
-                    *b.vget_unchecked_mut(i) = coeff.clone();
-                }
-
-                b.rows_range_mut(..i)
-                    .axpy(-coeff, &self.qr.slice_range(..i, i), T::one());
-            }
-        }
-
-        true
-    }
-
     /// Computes the inverse of the decomposed matrix.
     ///
     /// Returns `None` if the decomposed matrix is not invertible.
@@ -292,6 +651,41 @@ where
         true
     }
 
+    /// Estimates the reciprocal of the 1-norm condition number of the decomposed matrix.
+    ///
+    /// The 1-norms of the decomposed matrix and of its inverse are estimated with the
+    /// Hager–Higham algorithm, which only needs a few matrix-vector products and triangular
+    /// solves, so the result is only an estimate of the reciprocal condition number. It is 0 if
+    /// the decomposed matrix is not invertible. Values close to the machine epsilon indicate that
+    /// solutions of linear systems with this matrix are unreliable.
+    #[must_use]
+    pub fn rcond(&self) -> T::RealField {
+        let dim = self.qr.shape_generic().0;
+        let (q, r) = (self.q(), self.r());
+
+        let norm = estimate_one_norm(dim, |x, adjoint| {
+            if adjoint {
+                self.q_tr_mul(x);
+                *x = r.ad_mul(x);
+            } else {
+                *x = &q * (&r * &*x);
+            }
+
+            true
+        });
+        let inv_norm = estimate_one_norm(dim, |x, adjoint| {
+            if adjoint {
+                let solved = r.ad_solve_upper_triangular_mut(x);
+                *x = &q * &*x;
+                solved
+            } else {
+                self.solve_mut(x)
+            }
+        });
+
+        rcond_from_estimates::<T>(norm, inv_norm)
+    }
+
     // /// Computes the determinant of the decomposed matrix.
     // pub fn determinant(&self) -> T {
     //     let dim = self.qr.nrows();
@@ -305,3 +699,26 @@ where
     //     res self.q_determinant()
     // }
 }
+
+/// Applies givens rotations to the rows of the upper-Hessenberg matrix `r`, starting at the
+/// column `start`, so that it becomes upper-triangular. The inverse rotations are applied to the
+/// columns of `q` so that `q * r` remains unchanged.
+fn hessenberg_to_triangular<T: ComplexField, R: Dim, C: Dim, S1, S2>(
+    q: &mut Matrix<T, R, R, S1>,
+    r: &mut Matrix<T, R, C, S2>,
+    start: usize,
+) where
+    S1: StorageMut<T, R, R>,
+    S2: StorageMut<T, R, C>,
+{
+    let (nrows, ncols) = r.shape();
+
+    for k in start..ncols.min(nrows.saturating_sub(1)) {
+        let v = Vector2::new(r[(k, k)].clone(), r[(k + 1, k)].clone());
+
+        if let Some((rot, _)) = GivensRotation::cancel_y(&v) {
+            rot.rotate(&mut r.slice_range_mut(k..k + 2, k..));
+            rot.inverse().rotate_rows(&mut q.fixed_columns_mut::<2>(k));
+        }
+    }
+}
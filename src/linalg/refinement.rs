@@ -0,0 +1,124 @@
+use approx::AbsDiffEq;
+use num::Zero;
+use simba::scalar::{ComplexField, RealField, SupersetOf};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, InducedInfinityNorm, Matrix, OMatrix, UniformNorm};
+use crate::constraint::{AreMultipliable, SameNumberOfRows, ShapeConstraint};
+use crate::dimension::Dim;
+use crate::storage::Storage;
+
+/// The solution of a linear system improved by iterative refinement.
+///
+/// This is returned by `LU::solve_refined`, `FullPivLU::solve_refined` and
+/// `Cholesky::solve_refined`.
+#[derive(Clone, Debug)]
+pub struct RefinedSolution<T: ComplexField, R: Dim, C: Dim>
+where
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    /// The refined solution `x` of the system `A * x = b`.
+    pub solution: OMatrix<T, R, C>,
+
+    /// The normwise backward error `‖b - A * x‖ / (‖A‖ * ‖x‖ + ‖b‖)` of the refined solution,
+    /// where `‖·‖` is the infinity norm. For a system with several right-hand sides, this is the
+    /// largest backward error among all the columns of `x`.
+    pub backward_error: T::RealField,
+
+    /// The number of refinement steps performed.
+    pub iterations: usize,
+}
+
+/// Solves `a * x = b` and refines the solution with the residuals computed in the precision of
+/// `U`, while the corrections are obtained with `solve`, which works in the precision of `T`.
+///
+/// The refinement stops when the backward error reaches the machine epsilon of `U`, when it fails
+/// to be at least halved by a refinement step, or after `max_iter` steps. Returns `None` if
+/// `solve` fails.
+pub(crate) fn solve_refined<T, U, D, R2, C2, S2, S3>(
+    a: &Matrix<U, D, D, S2>,
+    b: &Matrix<U, R2, C2, S3>,
+    max_iter: usize,
+    mut solve: impl FnMut(&mut OMatrix<T, R2, C2>) -> bool,
+) -> Option<RefinedSolution<U, R2, C2>>
+where
+    T: ComplexField,
+    U: ComplexField + SupersetOf<T>,
+    D: Dim,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<U, D, D>,
+    S3: Storage<U, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R2, D> + AreMultipliable<D, D, R2, C2>,
+    DefaultAllocator: Allocator<T, R2, C2> + Allocator<U, R2, C2>,
+{
+    let a_norm = a.apply_norm(&InducedInfinityNorm);
+    let eps = U::RealField::default_epsilon();
+
+    let mut x_low = b.map(|e| e.to_subset_unchecked());
+
+    if !solve(&mut x_low) {
+        return None;
+    }
+
+    let mut x = x_low.map(|e| U::from_subset(&e));
+    let mut last_backward_error: Option<U::RealField> = None;
+    let mut iterations = 0;
+
+    loop {
+        let mut residual = b.clone_owned();
+        residual.gemm(-U::one(), a, &x, U::one());
+
+        let backward_error = backward_error(&residual, a_norm.clone(), &x, b);
+        let stagnated = matches!(
+            &last_backward_error,
+            Some(last) if backward_error.clone() + backward_error.clone() > last.clone()
+        );
+
+        if iterations == max_iter || backward_error <= eps || stagnated {
+            return Some(RefinedSolution {
+                solution: x,
+                backward_error,
+                iterations,
+            });
+        }
+
+        let mut correction = residual.map(|e| e.to_subset_unchecked());
+
+        if !solve(&mut correction) {
+            return None;
+        }
+
+        x += correction.map(|e| U::from_subset(&e));
+        last_backward_error = Some(backward_error);
+        iterations += 1;
+    }
+}
+
+fn backward_error<U, R2, C2, S3>(
+    residual: &OMatrix<U, R2, C2>,
+    a_norm: U::RealField,
+    x: &OMatrix<U, R2, C2>,
+    b: &Matrix<U, R2, C2, S3>,
+) -> U::RealField
+where
+    U: ComplexField,
+    R2: Dim,
+    C2: Dim,
+    S3: Storage<U, R2, C2>,
+    DefaultAllocator: Allocator<U, R2, C2>,
+{
+    let mut res = U::RealField::zero();
+
+    for j in 0..x.ncols() {
+        let denom = a_norm.clone() * x.column(j).apply_norm(&UniformNorm)
+            + b.column(j).apply_norm(&UniformNorm);
+        let numer = residual.column(j).apply_norm(&UniformNorm);
+
+        if !denom.is_zero() {
+            res = res.max(numer / denom);
+        }
+    }
+
+    res
+}
@@ -0,0 +1,112 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, OMatrix};
+use crate::dimension::{Dim, DimMin, DimMinimum};
+use crate::storage::StorageMut;
+use simba::scalar::ComplexField;
+
+use crate::linalg::LQ;
+
+/// The RQ decomposition of a general matrix.
+///
+/// The decomposed matrix `M` with `nrows` rows and `ncols` columns is equal to `R * Q` where `Q`
+/// has orthonormal rows and `R` is upper-triangular. If `nrows > ncols`, `R` is nonzero only
+/// on and above its `(nrows - ncols)`-th subdiagonal. The diagonal of `R` is real and
+/// non-negative, which makes this decomposition suitable for splitting a camera matrix into its
+/// calibration and rotation parts.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, R, C> +
+                           Allocator<T, DimMinimum<R, C>>,
+         LQ<T, R, C>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, R, C> +
+                           Allocator<T, DimMinimum<R, C>>,
+         LQ<T, R, C>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct RQ<T: ComplexField, R: DimMin<C>, C: Dim>
+where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<T, DimMinimum<R, C>>,
+{
+    // The LQ decomposition of the decomposed matrix with its rows and columns in reverse order.
+    lq: LQ<T, R, C>,
+}
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim> Copy for RQ<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<T, DimMinimum<R, C>>,
+    LQ<T, R, C>: Copy,
+{
+}
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim> RQ<T, R, C>
+where
+    DefaultAllocator:
+        Allocator<T, R, C> + Allocator<T, R> + Allocator<T, C> + Allocator<T, DimMinimum<R, C>>,
+{
+    /// Computes the RQ decomposition using householder reflections.
+    pub fn new(mut matrix: OMatrix<T, R, C>) -> Self {
+        // If `J` denotes the exchange matrix, `J * M * J = L * Q` implies
+        // `M = (J * L * J) * (J * Q * J)`, where `J * L * J` is upper-triangular.
+        reverse_rows_and_columns(&mut matrix);
+        RQ {
+            lq: LQ::new(matrix),
+        }
+    }
+
+    /// Retrieves the upper trapezoidal submatrix `R` of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn r(&self) -> OMatrix<T, R, DimMinimum<R, C>>
+    where
+        DefaultAllocator: Allocator<T, R, DimMinimum<R, C>>,
+    {
+        let mut res = self.lq.l();
+        reverse_rows_and_columns(&mut res);
+        res
+    }
+
+    /// Computes the matrix `Q` with orthonormal rows of this decomposition.
+    #[must_use]
+    pub fn q(&self) -> OMatrix<T, DimMinimum<R, C>, C>
+    where
+        DefaultAllocator: Allocator<T, DimMinimum<R, C>, C>,
+    {
+        let mut res = self.lq.q();
+        reverse_rows_and_columns(&mut res);
+        res
+    }
+
+    /// Unpacks this decomposition into its two matrix factors `(R, Q)`.
+    pub fn unpack(
+        self,
+    ) -> (
+        OMatrix<T, R, DimMinimum<R, C>>,
+        OMatrix<T, DimMinimum<R, C>, C>,
+    )
+    where
+        DefaultAllocator: Allocator<T, R, DimMinimum<R, C>> + Allocator<T, DimMinimum<R, C>, C>,
+    {
+        (self.r(), self.q())
+    }
+}
+
+fn reverse_rows_and_columns<T: ComplexField, R: Dim, C: Dim, S: StorageMut<T, R, C>>(
+    m: &mut Matrix<T, R, C, S>,
+) {
+    let (nrows, ncols) = m.shape();
+
+    for i in 0..nrows / 2 {
+        m.swap_rows(i, nrows - 1 - i);
+    }
+
+    for j in 0..ncols / 2 {
+        m.swap_columns(j, ncols - 1 - j);
+    }
+}
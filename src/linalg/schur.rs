@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 use approx::AbsDiffEq;
+use num::Zero;
 use num_complex::Complex as NumComplex;
 use simba::scalar::{ComplexField, RealField};
 use std::cmp;
@@ -111,7 +112,9 @@ where
         }
 
         let amax_m = m.camax();
-        m.unscale_mut(amax_m.clone());
+        if !amax_m.is_zero() {
+            m.unscale_mut(amax_m.clone());
+        }
 
         let hess = Hessenberg::new_with_workspace(m, work);
         let mut q;
@@ -456,7 +459,7 @@ where
     Some((q, m))
 }
 
-fn compute_2x2_eigvals<T: ComplexField, S: Storage<T, U2, U2>>(
+pub(crate) fn compute_2x2_eigvals<T: ComplexField, S: Storage<T, U2, U2>>(
     m: &SquareMatrix<T, U2, S>,
 ) -> Option<(T, T)> {
     // Solve the 2x2 eigenvalue subproblem.
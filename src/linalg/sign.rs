@@ -0,0 +1,66 @@
+//! This module provides the sign function to square matrices.
+
+use approx::AbsDiffEq;
+
+use crate::{
+    base::{
+        allocator::Allocator,
+        dimension::{DimMin, DimMinimum},
+        DefaultAllocator,
+    },
+    convert, ComplexField, OMatrix,
+};
+
+/// Maximum number of iterations of the Newton iteration.
+const MAX_ITERATIONS: usize = 100;
+
+impl<T: ComplexField, D> OMatrix<T, D, D>
+where
+    D: DimMin<D, Output = D>,
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), DimMinimum<D, D>>,
+{
+    /// Computes the sign of this matrix.
+    ///
+    /// The matrix sign function is `sign(A) = A * (A²)^(-1/2)`. It squares to the identity and
+    /// maps each eigenvalue of `A` to `1` or `-1` depending on the sign of its real part. It is
+    /// computed with the scaled Newton iteration.
+    ///
+    /// Returns `None` if this matrix has an eigenvalue on the imaginary axis, in which case its
+    /// sign is not defined.
+    #[must_use]
+    pub fn sign(&self) -> Option<Self> {
+        let n = self.nrows();
+
+        if n == 0 {
+            return Some(self.clone());
+        }
+
+        let tol = T::RealField::default_epsilon() * convert(n as f64);
+        let exponent: T::RealField = convert(-1.0 / n as f64);
+        let half: T::RealField = convert(0.5);
+
+        let mut x = self.clone();
+        let mut last_iteration = false;
+
+        for _ in 0..MAX_ITERATIONS {
+            let x_inv = x.clone().try_inverse()?;
+
+            // Determinantal scaling speeds up the initial convergence. The determinant is
+            // computed in logarithmic scale because it easily overflows for large matrices.
+            let mu = (x.clone().lu().ln_abs_determinant() * exponent.clone()).exp();
+            let next = (x.scale(mu.clone()) + x_inv.unscale(mu)).scale(half.clone());
+
+            let err = (&next - &x).norm() / next.norm();
+            x = next;
+
+            if last_iteration || err <= tol {
+                return Some(x);
+            }
+
+            // The convergence is quadratic, so one more iteration is enough.
+            last_iteration = err <= tol.clone().sqrt();
+        }
+
+        None
+    }
+}
@@ -0,0 +1,73 @@
+//! This module provides the principal square root (sqrt) function to square matrices.
+
+use approx::AbsDiffEq;
+
+use crate::{
+    base::{
+        allocator::Allocator,
+        dimension::{DimMin, DimMinimum},
+        DefaultAllocator,
+    },
+    convert, ComplexField, OMatrix,
+};
+
+/// Maximum number of iterations of the Denman–Beavers iteration.
+const MAX_ITERATIONS: usize = 100;
+
+impl<T: ComplexField, D> OMatrix<T, D, D>
+where
+    D: DimMin<D, Output = D>,
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), DimMinimum<D, D>>,
+{
+    /// Computes the principal square root of this matrix.
+    ///
+    /// The principal square root is the unique square root whose eigenvalues all have a positive
+    /// real part. It is computed with the scaled product form of the Denman–Beavers iteration.
+    ///
+    /// Returns `None` if this matrix has an eigenvalue on the closed negative real axis, in which
+    /// case it has no principal square root. In particular, a real matrix with a real negative
+    /// eigenvalue of odd multiplicity has no real square root at all.
+    #[must_use]
+    pub fn sqrt(&self) -> Option<Self> {
+        let (nrows, ncols) = self.shape_generic();
+        let n = self.nrows();
+
+        if n == 0 {
+            return Some(self.clone());
+        }
+
+        let ident = Self::identity_generic(nrows, ncols);
+        let tol = T::RealField::default_epsilon() * convert(n as f64);
+        let exponent: T::RealField = convert(-0.5 / n as f64);
+        let half: T::RealField = convert(0.5);
+
+        // `M` converges to the identity and `Y` to the square root.
+        let mut m = self.clone();
+        let mut y = self.clone();
+        let mut last_iteration = false;
+
+        for _ in 0..MAX_ITERATIONS {
+            let m_inv = m.clone().try_inverse()?;
+
+            // Determinantal scaling speeds up the initial convergence. The determinant is
+            // computed in logarithmic scale because it easily overflows for large matrices.
+            let mu = (m.clone().lu().ln_abs_determinant() * exponent.clone()).exp();
+            let mu2 = mu.clone() * mu.clone();
+            let m_inv = m_inv.unscale(mu2.clone());
+
+            y = (&y * (&ident + &m_inv)).scale(mu * half.clone());
+            m = ((m.scale(mu2) + m_inv).scale(half.clone()) + &ident).scale(half.clone());
+
+            let err = (&m - &ident).norm();
+
+            if last_iteration || err <= tol {
+                return Some(y);
+            }
+
+            // The convergence is quadratic, so one more iteration is enough.
+            last_iteration = err <= tol.clone().sqrt();
+        }
+
+        None
+    }
+}
@@ -6,7 +6,7 @@ use approx::AbsDiffEq;
 use num::{One, Zero};
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Matrix, Matrix2x3, OMatrix, OVector, Vector2};
+use crate::base::{DefaultAllocator, Matrix, Matrix2x3, Norm, OMatrix, OVector, Vector2};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimDiff, DimMin, DimMinimum, DimSub, U1};
 use crate::storage::Storage;
@@ -56,6 +56,35 @@ where
     pub singular_values: OVector<T::RealField, DimMinimum<R, C>>,
 }
 
+/// The algorithm used to compute a Singular Value Decomposition.
+///
+/// All the algorithms start by reducing the matrix to a bidiagonal form, except the Jacobi
+/// method which operates on the matrix directly.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SVDAlgorithm {
+    /// Diagonalization of the bidiagonal form with implicitly-shifted QR iterations
+    /// (Golub-Kahan). This is the algorithm used by `SVD::new`.
+    GolubKahan,
+    /// One-sided (Hestenes) Jacobi rotations orthogonalizing the columns or rows of the matrix.
+    ///
+    /// This is slower than the other algorithms but it computes even the smallest singular
+    /// values with high relative accuracy.
+    Jacobi,
+    /// Diagonalization of the bidiagonal form by recursively splitting it in two halves and
+    /// merging their SVDs by solving a secular equation.
+    ///
+    /// This is the fastest algorithm for large matrices when singular vectors are requested.
+    /// It requires the `std` or `alloc` feature, without which `GolubKahan` is used instead.
+    DivideAndConquer,
+}
+
+impl Default for SVDAlgorithm {
+    #[inline]
+    fn default() -> Self {
+        SVDAlgorithm::GolubKahan
+    }
+}
+
 impl<T: ComplexField, R: DimMin<C>, C: Dim> Copy for SVD<T, R, C>
 where
     DefaultAllocator: Allocator<T, DimMinimum<R, C>, C>
@@ -722,6 +751,89 @@ where
         })
     }
 
+    /// Computes the Singular Value Decomposition of `matrix` using the given algorithm.
+    /// The singular values are guaranteed to be sorted in descending order.
+    pub fn new_with_algorithm(
+        matrix: OMatrix<T, R, C>,
+        compute_u: bool,
+        compute_v: bool,
+        algorithm: SVDAlgorithm,
+    ) -> Self
+    where
+        DefaultAllocator: Allocator<T, C, R>,
+    {
+        Self::try_new_with_algorithm(
+            matrix,
+            compute_u,
+            compute_v,
+            algorithm,
+            T::RealField::default_epsilon(),
+            0,
+        )
+        .unwrap()
+    }
+
+    /// Attempts to compute the Singular Value Decomposition of `matrix` using the given
+    /// algorithm.
+    /// The singular values are guaranteed to be sorted in descending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `compute_u` − set this to `true` to enable the computation of left-singular vectors.
+    /// * `compute_v` − set this to `true` to enable the computation of right-singular vectors.
+    /// * `algorithm` − the algorithm used to compute the decomposition.
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations (or of sweeps for the Jacobi
+    ///   algorithm) performed by the algorithm. If this number of iteration is exceeded, `None`
+    ///   is returned. If `niter == 0`, then the algorithm continues indefinitely until
+    ///   convergence. `None` is also returned if the divide-and-conquer algorithm fails to find
+    ///   the roots of one of its secular equations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{DMatrix, SVDAlgorithm, SVD};
+    /// let m = DMatrix::from_fn(40, 30, |i, j| 1.0 / (i + j + 1) as f64);
+    /// let gk = SVD::new(m.clone(), true, true);
+    /// let jacobi = SVD::new_with_algorithm(m.clone(), true, true, SVDAlgorithm::Jacobi);
+    /// let dc = SVD::new_with_algorithm(m.clone(), true, true, SVDAlgorithm::DivideAndConquer);
+    ///
+    /// assert!(gk.singular_values.relative_eq(&jacobi.singular_values, 1.0e-10, 1.0e-10));
+    /// assert!(gk.singular_values.relative_eq(&dc.singular_values, 1.0e-10, 1.0e-10));
+    /// assert!(m.relative_eq(&dc.recompose().unwrap(), 1.0e-10, 1.0e-10));
+    /// ```
+    pub fn try_new_with_algorithm(
+        matrix: OMatrix<T, R, C>,
+        compute_u: bool,
+        compute_v: bool,
+        algorithm: SVDAlgorithm,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self>
+    where
+        DefaultAllocator: Allocator<T, C, R>,
+    {
+        let mut svd = match algorithm {
+            SVDAlgorithm::GolubKahan => {
+                return Self::try_new(matrix, compute_u, compute_v, eps, max_niter)
+            }
+            SVDAlgorithm::Jacobi => {
+                Self::try_new_jacobi_unordered(matrix, compute_u, compute_v, eps, max_niter)?
+            }
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            SVDAlgorithm::DivideAndConquer => Self::try_new_divide_and_conquer_unordered(
+                matrix, compute_u, compute_v, eps, max_niter,
+            )?,
+            #[cfg(not(any(feature = "std", feature = "alloc")))]
+            SVDAlgorithm::DivideAndConquer => {
+                return Self::try_new(matrix, compute_u, compute_v, eps, max_niter)
+            }
+        };
+
+        svd.sort_by_singular_values();
+        Some(svd)
+    }
+
     /// Sort the estimated components of the SVD by its singular values in descending order.
     /// Such an ordering is often implicitly required when the decompositions are used for estimation or fitting purposes.
     /// Using this function is only required if `new_unordered` or `try_new_unorderd` were used and the specific sorting is required afterward.
@@ -820,6 +932,52 @@ where
     {
         SVD::new_unordered(self.clone_owned(), true, true).pseudo_inverse(eps)
     }
+
+    /// Computes the condition number of this matrix with respect to the given `norm`.
+    ///
+    /// This is `‖A‖ * ‖A⁺‖` where `A⁺` is the pseudo-inverse of this matrix, computed with a
+    /// singular value decomposition. The result is infinite if this matrix is rank-deficient. See
+    /// `LU::rcond`, `QR::rcond` and `Cholesky::rcond` for cheaper estimates of the reciprocal of
+    /// the 1-norm condition number of square matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, SpectralNorm};
+    /// let m = Matrix2::new(2.0f64, 0.0,
+    ///                      0.0, 0.5);
+    /// assert!((m.cond(&SpectralNorm) - 4.0).abs() < 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn cond<N: Norm<T> + 'static>(&self, norm: &N) -> T::RealField
+    where
+        DefaultAllocator: Allocator<T, C, R>,
+    {
+        // The spectral condition number is the ratio of the extreme singular values, so there is
+        // no need for the singular vectors nor for the two extra SVDs computing the norms.
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        let is_spectral = TypeId::of::<N>() == TypeId::of::<crate::base::SpectralNorm>();
+        #[cfg(not(any(feature = "std", feature = "alloc")))]
+        let is_spectral = false;
+
+        let svd = SVD::new_unordered(self.clone_owned(), !is_spectral, !is_spectral);
+
+        if svd.singular_values.iter().any(|val| val.is_zero()) {
+            return T::RealField::one() / T::RealField::zero();
+        }
+
+        if is_spectral {
+            let max = svd.singular_values.max();
+            let min = svd.singular_values.min();
+            return max / min;
+        }
+
+        let pinv = svd
+            .pseudo_inverse(T::RealField::zero())
+            .expect("The singular vectors have been computed.");
+
+        norm.norm(self) * norm.norm(&pinv)
+    }
 }
 
 impl<T: ComplexField, R: DimMin<C>, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S>
@@ -846,9 +1004,11 @@ where
     }
 }
 
-// Explicit formulae inspired from the paper "Computing the Singular Values of 2-by-2 Complex
-// Matrices", Sanzheng Qiao and Xiaohong Wang.
-// http://www.cas.mcmaster.ca/sqrl/papers/sqrl5.pdf
+// Computes the SVD `[m11 m12; 0 m22] = U * diag(s) * V^T` of an upper-triangular 2x2 matrix,
+// where `U` and `V` are Givens rotations. The singular values are signed, the largest first.
+//
+// This follows the algorithm of LAPACK's `dlasv2`, which computes the singular vectors to high
+// relative accuracy even when the singular values are nearly equal.
 fn compute_2x2_uptrig_svd<T: RealField>(
     m11: T,
     m12: T,
@@ -860,44 +1020,143 @@ fn compute_2x2_uptrig_svd<T: RealField>(
     Vector2<T>,
     Option<GivensRotation<T>>,
 ) {
-    let two: T::RealField = crate::convert(2.0f64);
-    let half: T::RealField = crate::convert(0.5f64);
-
-    let denom = (m11.clone() + m22.clone()).hypot(m12.clone())
-        + (m11.clone() - m22.clone()).hypot(m12.clone());
-
-    // NOTE: v1 is the singular value that is the closest to m22.
-    // This prevents cancellation issues when constructing the vector `csv` below. If we chose
-    // otherwise, we would have v1 ~= m11 when m12 is small. This would cause catastrophic
-    // cancellation on `v1 * v1 - m11 * m11` below.
-    let mut v1 = m11.clone() * m22.clone() * two / denom.clone();
-    let mut v2 = half * denom;
-
-    let mut u = None;
-    let mut v_t = None;
-
-    if compute_u || compute_v {
-        let (csv, sgn_v) = GivensRotation::new(
-            m11.clone() * m12.clone(),
-            v1.clone() * v1.clone() - m11.clone() * m11.clone(),
-        );
-        v1 *= sgn_v.clone();
-        v2 *= sgn_v;
-
-        if compute_v {
-            v_t = Some(csv.clone());
+    let one = T::one();
+    let two: T = crate::convert(2.0f64);
+    let four: T = crate::convert(4.0f64);
+    let half: T = crate::convert(0.5f64);
+    let with_sign = |value: T, sign: &T| {
+        if *sign >= T::zero() {
+            value.abs()
+        } else {
+            -value.abs()
         }
+    };
+
+    let mut ft = m11.clone();
+    let mut fa = ft.clone().abs();
+    let mut ht = m22.clone();
+    let mut ha = m22.clone().abs();
+
+    // `pmax` points to the entry of largest magnitude: 1 for `m11`, 2 for `m12`, 3 for `m22`.
+    let mut pmax = 1;
+    let swap = ha > fa;
 
-        if compute_u {
-            let cu = (m11.scale(csv.c()) + m12 * csv.s()) / v1.clone();
-            let su = (m22 * csv.s()) / v1.clone();
-            let (csu, sgn_u) = GivensRotation::new(cu, su);
+    if swap {
+        pmax = 3;
+        std::mem::swap(&mut ft, &mut ht);
+        std::mem::swap(&mut fa, &mut ha);
+    }
 
-            v1 *= sgn_u.clone();
-            v2 *= sgn_u;
-            u = Some(csu);
+    let gt = m12.clone();
+    let ga = gt.clone().abs();
+
+    let (mut ssmin, mut ssmax, clt, slt, crt, srt);
+
+    if ga.is_zero() {
+        // The matrix is diagonal.
+        ssmin = ha;
+        ssmax = fa;
+        clt = one.clone();
+        crt = one.clone();
+        slt = T::zero();
+        srt = T::zero();
+    } else if ga > fa && fa.clone() / ga.clone() < T::default_epsilon() {
+        // The off-diagonal entry is very large.
+        pmax = 2;
+        ssmax = ga.clone();
+        ssmin = if ha > one {
+            fa / (ga / ha)
+        } else {
+            (fa / ga) * ha
+        };
+        clt = one.clone();
+        slt = ht / gt.clone();
+        srt = one.clone();
+        crt = ft / gt;
+    } else {
+        if ga > fa {
+            pmax = 2;
         }
+
+        let d = fa.clone() - ha.clone();
+        let mut l = if d == fa {
+            // Copes with infinite `m11` or `m22`.
+            one.clone()
+        } else {
+            d.clone() / fa.clone()
+        };
+        let m = gt.clone() / ft.clone();
+        let mut t = two.clone() - l.clone();
+        let mm = m.clone() * m.clone();
+        let s = (t.clone() * t.clone() + mm.clone()).sqrt();
+        let r = if l.is_zero() {
+            m.clone().abs()
+        } else {
+            (l.clone() * l.clone() + mm.clone()).sqrt()
+        };
+        let a = half * (s.clone() + r.clone());
+
+        ssmin = ha / a.clone();
+        ssmax = fa * a.clone();
+
+        t = if mm.is_zero() {
+            // Here `m` is tiny.
+            if l.is_zero() {
+                with_sign(two.clone(), &ft) * with_sign(one.clone(), &gt)
+            } else {
+                gt.clone() / with_sign(d, &ft) + m.clone() / t
+            }
+        } else {
+            (m.clone() / (s + t) + m.clone() / (r + l.clone())) * (one.clone() + a.clone())
+        };
+
+        l = (t.clone() * t.clone() + four).sqrt();
+        crt = two / l.clone();
+        srt = t / l;
+        clt = (crt.clone() + srt.clone() * m) / a.clone();
+        slt = (ht / ft) * srt.clone() / a;
     }
 
-    (u, Vector2::new(v1, v2), v_t)
+    let (csl, snl, csr, snr) = if swap {
+        (srt, crt, slt, clt)
+    } else {
+        (clt, slt, crt, srt)
+    };
+
+    // Correct the signs of the singular values.
+    let tsign = match pmax {
+        1 => {
+            with_sign(one.clone(), &csr)
+                * with_sign(one.clone(), &csl)
+                * with_sign(one.clone(), &m11)
+        }
+        2 => {
+            with_sign(one.clone(), &snr)
+                * with_sign(one.clone(), &csl)
+                * with_sign(one.clone(), &m12)
+        }
+        _ => {
+            with_sign(one.clone(), &snr)
+                * with_sign(one.clone(), &snl)
+                * with_sign(one.clone(), &m22)
+        }
+    };
+    ssmax = with_sign(ssmax, &tsign);
+    ssmin = with_sign(
+        ssmin,
+        &(tsign * with_sign(one.clone(), &m11) * with_sign(one, &m22)),
+    );
+
+    let u = if compute_u {
+        Some(GivensRotation::new_unchecked(csl, snl))
+    } else {
+        None
+    };
+    let v_t = if compute_v {
+        Some(GivensRotation::new_unchecked(csr, snr))
+    } else {
+        None
+    };
+
+    (u, Vector2::new(ssmax, ssmin), v_t)
 }
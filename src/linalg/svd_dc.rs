@@ -0,0 +1,589 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use num::Zero;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::{DMatrix, DVector, DefaultAllocator, OMatrix, OVector};
+use crate::dimension::{Const, Dim, DimDiff, DimMin, DimMinimum, DimSub, U1};
+use crate::linalg::Bidiagonal;
+use crate::SVD;
+
+/// Bidiagonal matrices with a dimension smaller or equal to this are not split further: their
+/// SVD is computed with the implicit-shift QR algorithm.
+const LEAF_SIZE: usize = 16;
+
+/// Maximum number of iterations performed to compute each root of the secular equation.
+const MAX_SECULAR_ITERATIONS: usize = 200;
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim> SVD<T, R, C>
+where
+    DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
+    DefaultAllocator: Allocator<T, R, C>
+        + Allocator<T, C>
+        + Allocator<T, R>
+        + Allocator<T, DimDiff<DimMinimum<R, C>, U1>>
+        + Allocator<T, DimMinimum<R, C>, C>
+        + Allocator<T, R, DimMinimum<R, C>>
+        + Allocator<T, DimMinimum<R, C>>
+        + Allocator<T::RealField, DimMinimum<R, C>>
+        + Allocator<T::RealField, DimDiff<DimMinimum<R, C>, U1>>,
+{
+    /// Attempts to compute the Singular Value Decomposition of `matrix` by bidiagonalizing it
+    /// and diagonalizing the resulting bidiagonal matrix with the divide-and-conquer method.
+    ///
+    /// The singular values are not guaranteed to be sorted in any particular order. If neither
+    /// `U` nor `V^t` are requested, this falls back to the implicit-shift QR algorithm which is
+    /// faster when no singular vectors are needed.
+    ///
+    /// The tolerance `eps` and iteration limit `max_niter` are forwarded to the implicit-shift
+    /// QR algorithm used on the leaves of the recursion. `eps` is also used to determine when
+    /// a subproblem can be deflated.
+    pub(crate) fn try_new_divide_and_conquer_unordered(
+        mut matrix: OMatrix<T, R, C>,
+        compute_u: bool,
+        compute_v: bool,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        assert!(
+            !matrix.is_empty(),
+            "Cannot compute the SVD of an empty matrix."
+        );
+
+        if !compute_u && !compute_v {
+            return Self::try_new_unordered(matrix, false, false, eps, max_niter);
+        }
+
+        let (nrows, ncols) = matrix.shape_generic();
+        let min_nrows_ncols = nrows.min(ncols);
+        let dim = min_nrows_ncols.value();
+
+        let m_amax = matrix.camax();
+
+        if !m_amax.is_zero() {
+            matrix.unscale_mut(m_amax.clone());
+        }
+
+        let bi_matrix = Bidiagonal::new(matrix);
+        let diagonal: Vec<_> = bi_matrix.diagonal().iter().cloned().collect();
+        let off_diagonal: Vec<_> = bi_matrix.off_diagonal().iter().cloned().collect();
+        let (singular_values, left, right) =
+            bidiagonal_svd(&diagonal, &off_diagonal, eps, max_niter)?;
+
+        // A lower-bidiagonal matrix is the transpose of the upper-bidiagonal matrix we just
+        // decomposed, so the roles of its left- and right-singular vectors are swapped.
+        let (b_u, b_v) = if bi_matrix.is_upper_diagonal() {
+            (left, right)
+        } else {
+            (right, left)
+        };
+
+        let u = if compute_u {
+            let u = bi_matrix.u();
+            let u = DMatrix::from_fn(nrows.value(), dim, |i, j| u[(i, j)].clone())
+                * b_u.map(T::from_real);
+            Some(OMatrix::from_fn_generic(nrows, min_nrows_ncols, |i, j| {
+                u[(i, j)].clone()
+            }))
+        } else {
+            None
+        };
+
+        let v_t = if compute_v {
+            let v_t = bi_matrix.v_t();
+            let v_t = b_v.transpose().map(T::from_real)
+                * DMatrix::from_fn(dim, ncols.value(), |i, j| v_t[(i, j)].clone());
+            Some(OMatrix::from_fn_generic(min_nrows_ncols, ncols, |i, j| {
+                v_t[(i, j)].clone()
+            }))
+        } else {
+            None
+        };
+
+        let singular_values = OVector::from_fn_generic(min_nrows_ncols, Const::<1>, |i, _| {
+            singular_values[i].clone() * m_amax.clone()
+        });
+
+        Some(Self {
+            u,
+            v_t,
+            singular_values,
+        })
+    }
+}
+
+/// Computes the SVD `B = U * diag(s) * V^T` of the square upper-bidiagonal matrix `B` with the
+/// given diagonal and off-diagonal, using the divide-and-conquer method.
+///
+/// Returns `(s, U, V)`, or `None` if the QR iterations on one of the leaves, or the root finding
+/// of one of the secular equations, did not converge.
+fn bidiagonal_svd<T: RealField>(
+    diagonal: &[T],
+    off_diagonal: &[T],
+    eps: T,
+    max_niter: usize,
+) -> Option<(DVector<T>, DMatrix<T>, DMatrix<T>)> {
+    let n = diagonal.len();
+
+    if n <= LEAF_SIZE {
+        let b = DMatrix::from_fn(n, n, |i, j| {
+            if i == j {
+                diagonal[i].clone()
+            } else if j == i + 1 {
+                off_diagonal[i].clone()
+            } else {
+                T::zero()
+            }
+        });
+        let svd = SVD::try_new_unordered(b, true, true, eps, max_niter)?;
+        return Some((svd.singular_values, svd.u?, svd.v_t?.transpose()));
+    }
+
+    // Split `B` as:
+    //
+    // [ B1 ext     0  ]
+    // [ α * e_k  β * e_1 ]
+    // [ 0         B2  ]
+    //
+    // where the `k × (k + 1)` upper block `B1 ext` has one extra column with a single nonzero
+    // entry on its last row, and `B2` is square.
+    let k = n / 2;
+    let alpha = diagonal[k].clone();
+    let beta = off_diagonal[k].clone();
+
+    // Chase the extra column of `B1 ext` with column rotations: `B1 ext * G = [B1 0]`.
+    let mut d1 = diagonal[..k].to_vec();
+    let mut e1 = off_diagonal[..k - 1].to_vec();
+    let mut g = DMatrix::<T>::identity(k + 1, k + 1);
+    let mut f = off_diagonal[k - 1].clone();
+
+    for i in (0..k).rev() {
+        let r = d1[i].clone().hypot(f.clone());
+
+        if r.is_zero() {
+            break;
+        }
+
+        let c = d1[i].clone() / r.clone();
+        let s = f.clone() / r.clone();
+        d1[i] = r;
+
+        for row in 0..=k {
+            let gi = g[(row, i)].clone();
+            let gk = g[(row, k)].clone();
+            g[(row, i)] = c.clone() * gi.clone() + s.clone() * gk.clone();
+            g[(row, k)] = c.clone() * gk - s.clone() * gi;
+        }
+
+        if i > 0 {
+            f = -s * e1[i - 1].clone();
+            e1[i - 1] *= c;
+        }
+    }
+
+    let (s1, u1, v1) = bidiagonal_svd(&d1, &e1, eps.clone(), max_niter)?;
+    let (s2, u2, v2) = bidiagonal_svd(
+        &diagonal[k + 1..],
+        &off_diagonal[k + 1..],
+        eps.clone(),
+        max_niter,
+    )?;
+
+    // Right-singular vectors of `B1 ext`, with the one of its null space first.
+    let mut w1 = DMatrix::<T>::zeros(k + 1, k + 1);
+    w1.column_mut(0).copy_from(&g.column(k));
+    w1.columns_mut(1, k).copy_from(&(g.columns(0, k) * v1));
+
+    // The middle matrix is an upper arrowhead matrix with first row `z` and diagonal `p`,
+    // where the first diagonal entry is zero.
+    let n2 = n - k - 1;
+    let mut poles = Vec::with_capacity(n);
+    let mut z = Vec::with_capacity(n);
+    poles.push(T::zero());
+    z.push(alpha.clone() * w1[(k, 0)].clone());
+
+    for i in 0..k {
+        poles.push(s1[i].clone());
+        z.push(alpha.clone() * w1[(k, i + 1)].clone());
+    }
+
+    for i in 0..n2 {
+        poles.push(s2[i].clone());
+        z.push(beta.clone() * v2[(0, i)].clone());
+    }
+
+    let (singular_values, um, vm) = arrowhead_svd(&poles, &z, eps)?;
+
+    let mut u = DMatrix::zeros(n, n);
+    u.rows_mut(0, k).copy_from(&(u1 * um.rows(1, k)));
+    u.row_mut(k).copy_from(&um.row(0));
+    u.rows_mut(k + 1, n2).copy_from(&(u2 * um.rows(k + 1, n2)));
+
+    let mut v = DMatrix::zeros(n, n);
+    v.rows_mut(0, k + 1).copy_from(&(w1 * vm.rows(0, k + 1)));
+    v.rows_mut(k + 1, n2).copy_from(&(v2 * vm.rows(k + 1, n2)));
+
+    Some((singular_values, u, v))
+}
+
+/// Computes the SVD `M = U * diag(s) * V^T` of the upper arrowhead matrix `M` with first row
+/// `z` and diagonal `[z[0], poles[1], poles[2], ...]`.
+///
+/// The first pole is required to be zero and all the poles must be non-negative. Returns `None`
+/// if the root finding of the secular equation did not converge.
+fn arrowhead_svd<T: RealField>(
+    poles: &[T],
+    z: &[T],
+    eps: T,
+) -> Option<(DVector<T>, DMatrix<T>, DMatrix<T>)> {
+    let n = poles.len();
+
+    // Sort the poles in ascending order, keeping the zero pole first.
+    let mut order: Vec<usize> = (0..n).collect();
+    order[1..].sort_by(|a, b| poles[*a].partial_cmp(&poles[*b]).unwrap_or(Ordering::Equal));
+    let mut p: Vec<T> = order.iter().map(|i| poles[*i].clone()).collect();
+    let mut z: Vec<T> = order.iter().map(|i| z[*i].clone()).collect();
+
+    let mut max = p[n - 1].clone();
+    for zi in &z {
+        max = max.max(zi.clone().abs());
+    }
+    let tol = eps.clone() * crate::convert(8.0) * max;
+
+    // Deflation. The rotations are stored as `(a, b, c, s, rotate_u)`, representing the
+    // rotation `G` with `G[(a, a)] = G[(b, b)] = c` and `G[(a, b)] = -G[(b, a)] = s`, applied
+    // to the columns of `M` and, if `rotate_u` is `true`, to its rows too.
+    let mut rotations = Vec::new();
+    let mut deflated: Vec<bool> = (0..n).map(|_| false).collect();
+    let mut singular_values = DVector::zeros(n);
+
+    if z[0].clone().abs() <= tol {
+        z[0] = tol.clone();
+    }
+
+    for i in 1..n {
+        // A negligible pole: rotate its entry of `z` into `z[0]`, leaving a zero column.
+        if p[i] <= tol {
+            let r = z[0].clone().hypot(z[i].clone());
+
+            if !r.is_zero() {
+                let c = z[0].clone() / r.clone();
+                let s = z[i].clone() / r.clone();
+                rotations.push((0, i, c, -s, false));
+                z[0] = r;
+            }
+
+            p[i] = T::zero();
+            z[i] = T::zero();
+            deflated[i] = true;
+        } else if z[i].clone().abs() <= tol {
+            // A negligible entry of `z`: the pole is a singular value.
+            singular_values[i] = p[i].clone();
+            z[i] = T::zero();
+            deflated[i] = true;
+        }
+    }
+
+    // Two poles too close to each other: rotate the entry of `z` of the first into the second.
+    let mut prev: Option<usize> = None;
+    for i in 1..n {
+        if deflated[i] {
+            continue;
+        }
+
+        if let Some(j) = prev {
+            if p[i].clone() - p[j].clone() <= tol {
+                let r = z[j].clone().hypot(z[i].clone());
+                let c = z[i].clone() / r.clone();
+                let s = z[j].clone() / r.clone();
+                rotations.push((j, i, c, s, true));
+                singular_values[j] = p[j].clone();
+                z[i] = r;
+                z[j] = T::zero();
+                deflated[j] = true;
+            }
+        }
+
+        prev = Some(i);
+    }
+
+    // Solve the remaining non-deflated arrowhead problem.
+    let active: Vec<usize> = (0..n).filter(|i| !deflated[*i]).collect();
+    let p_active: Vec<T> = active.iter().map(|i| p[*i].clone()).collect();
+    let z_active: Vec<T> = active.iter().map(|i| z[*i].clone()).collect();
+    let (s_active, u_active, v_active) = deflated_arrowhead_svd(&p_active, &z_active, eps)?;
+
+    let mut u = DMatrix::identity(n, n);
+    let mut v = DMatrix::identity(n, n);
+
+    for (a, ia) in active.iter().enumerate() {
+        singular_values[*ia] = s_active[a].clone();
+
+        for (b, ib) in active.iter().enumerate() {
+            u[(*ia, *ib)] = u_active[(a, b)].clone();
+            v[(*ia, *ib)] = v_active[(a, b)].clone();
+        }
+    }
+
+    for (a, b, c, s, rotate_u) in rotations.into_iter().rev() {
+        rotate_rows(&mut v, a, b, c.clone(), s.clone());
+
+        if rotate_u {
+            rotate_rows(&mut u, a, b, c, s);
+        }
+    }
+
+    // Undo the sort.
+    let mut u_unsorted = DMatrix::zeros(n, n);
+    let mut v_unsorted = DMatrix::zeros(n, n);
+
+    for (a, i) in order.iter().enumerate() {
+        u_unsorted.row_mut(*i).copy_from(&u.row(a));
+        v_unsorted.row_mut(*i).copy_from(&v.row(a));
+    }
+
+    Some((singular_values, u_unsorted, v_unsorted))
+}
+
+/// Applies `m <- G * m` where `G[(a, a)] = G[(b, b)] = c` and `G[(a, b)] = -G[(b, a)] = s`.
+fn rotate_rows<T: RealField>(m: &mut DMatrix<T>, a: usize, b: usize, c: T, s: T) {
+    for j in 0..m.ncols() {
+        let ma = m[(a, j)].clone();
+        let mb = m[(b, j)].clone();
+        m[(a, j)] = c.clone() * ma.clone() + s.clone() * mb.clone();
+        m[(b, j)] = c.clone() * mb - s.clone() * ma;
+    }
+}
+
+/// Computes the SVD of an arrowhead matrix with strictly increasing poles starting at zero, and
+/// nonzero entries in `z`.
+///
+/// Its singular values are the roots of the secular equation
+/// `f(σ) = 1 + Σ z[i]² / (p[i]² - σ²) = 0`, and are interlaced with the poles. Returns `None` if
+/// one of these roots could not be found.
+fn deflated_arrowhead_svd<T: RealField>(
+    p: &[T],
+    z: &[T],
+    eps: T,
+) -> Option<(DVector<T>, DMatrix<T>, DMatrix<T>)> {
+    let m = p.len();
+
+    if m == 1 {
+        let sign = if z[0] < T::zero() {
+            -T::one()
+        } else {
+            T::one()
+        };
+        return Some((
+            DVector::from_element(1, z[0].clone().abs()),
+            DMatrix::identity(1, 1),
+            DMatrix::from_element(1, 1, sign),
+        ));
+    }
+
+    // Each root `σ[j]` is stored as `σ[j]² = p[origin[j]]² + tau[j]` with `origin[j]` the
+    // closest pole, so that the differences `p[i]² - σ[j]²` can be computed accurately.
+    let mut origins = Vec::with_capacity(m);
+    let mut taus = Vec::with_capacity(m);
+    let z_norm_squared = z
+        .iter()
+        .fold(T::zero(), |acc, zi| acc + zi.clone() * zi.clone());
+
+    for j in 0..m {
+        let (origin, tau) = solve_secular_equation(p, z, j, z_norm_squared.clone(), eps.clone())?;
+        origins.push(origin);
+        taus.push(tau);
+    }
+
+    // `delta(i, j) = p[i]² - σ[j]²`.
+    let delta = |i: usize, j: usize| {
+        let o = origins[j];
+        (p[i].clone() - p[o].clone()) * (p[i].clone() + p[o].clone()) - taus[j].clone()
+    };
+
+    // Recompute `z` with the Löwner formula so that the computed singular vectors are
+    // numerically orthogonal.
+    let z_hat: Vec<T> = (0..m)
+        .map(|i| {
+            let mut prod = -delta(i, m - 1);
+
+            for j in 0..i {
+                prod *=
+                    -delta(i, j) / ((p[j].clone() - p[i].clone()) * (p[j].clone() + p[i].clone()));
+            }
+
+            for j in i..m - 1 {
+                prod *= -delta(i, j)
+                    / ((p[j + 1].clone() - p[i].clone()) * (p[j + 1].clone() + p[i].clone()));
+            }
+
+            let zi = prod.abs().sqrt();
+            if z[i] < T::zero() {
+                -zi
+            } else {
+                zi
+            }
+        })
+        .collect();
+
+    let mut singular_values = DVector::zeros(m);
+    let mut u = DMatrix::zeros(m, m);
+    let mut v = DMatrix::zeros(m, m);
+
+    for j in 0..m {
+        let o = origins[j];
+        singular_values[j] = (p[o].clone() * p[o].clone() + taus[j].clone()).sqrt();
+
+        u[(0, j)] = -T::one();
+        for i in 0..m {
+            let vi = z_hat[i].clone() / delta(i, j);
+            if i > 0 {
+                u[(i, j)] = p[i].clone() * vi.clone();
+            }
+            v[(i, j)] = vi;
+        }
+
+        let u_norm = u.column(j).norm();
+        let v_norm = v.column(j).norm();
+        u.column_mut(j).unscale_mut(u_norm);
+        v.column_mut(j).unscale_mut(v_norm);
+    }
+
+    Some((singular_values, u, v))
+}
+
+/// Computes the `j`-th root (in ascending order) of the secular equation of an arrowhead matrix
+/// with strictly increasing poles `p` starting at zero.
+///
+/// Returns the index `o` of the pole closest to the root, and `tau` such that the squared root
+/// is `p[o]² + tau`, or `None` if the root was not found within `MAX_SECULAR_ITERATIONS`
+/// iterations.
+fn solve_secular_equation<T: RealField>(
+    p: &[T],
+    z: &[T],
+    j: usize,
+    z_norm_squared: T,
+    eps: T,
+) -> Option<(usize, T)> {
+    let m = p.len();
+    let two: T = crate::convert(2.0);
+    let four: T = crate::convert(4.0);
+    let last = j + 1 == m;
+
+    // Evaluates the secular function with the squared poles shifted by `p[origin]²`.
+    let shifted_poles = |origin: usize| -> Vec<T> {
+        p.iter()
+            .map(|pi| (pi.clone() - p[origin].clone()) * (pi.clone() + p[origin].clone()))
+            .collect()
+    };
+    let secular = |d: &[T], tau: T| {
+        d.iter().zip(z.iter()).fold(T::one(), |acc, (di, zi)| {
+            acc + zi.clone() * zi.clone() / (di.clone() - tau.clone())
+        })
+    };
+
+    // The root lies between the poles `j` and `j + 1`, or between the last pole and
+    // `sqrt(p[m - 1]² + |z|²)`. Pick the closest pole as the origin.
+    let (origin, mut lo, mut hi) = if last {
+        (j, T::zero(), z_norm_squared)
+    } else {
+        let half_gap =
+            (p[j + 1].clone() - p[j].clone()) * (p[j + 1].clone() + p[j].clone()) / two.clone();
+
+        if secular(&shifted_poles(j), half_gap.clone()) >= T::zero() {
+            (j, T::zero(), half_gap)
+        } else {
+            (j + 1, -half_gap, T::zero())
+        }
+    };
+    let d = shifted_poles(origin);
+
+    // The two poles used by the rational model of the secular function, each one accounting
+    // for the terms of the poles on its side of the root (or, for the last root, for the last
+    // pole and all the others).
+    let (left, right) = if last { (j, j - 1) } else { (j, j + 1) };
+    let in_left_group = |i: usize| if last { i == j } else { i <= j };
+
+    let mut tau = (lo.clone() + hi.clone()) / two.clone();
+
+    for _ in 0..MAX_SECULAR_ITERATIONS {
+        let mut psi = T::zero();
+        let mut dpsi = T::zero();
+        let mut phi = T::zero();
+        let mut dphi = T::zero();
+        let mut abs_sum = T::zero();
+
+        for i in 0..m {
+            let delta = d[i].clone() - tau.clone();
+            let term = z[i].clone() * z[i].clone() / delta.clone();
+            let dterm = term.clone() / delta;
+            abs_sum += term.clone().abs();
+
+            if in_left_group(i) {
+                psi += term;
+                dpsi += dterm;
+            } else {
+                phi += term;
+                dphi += dterm;
+            }
+        }
+
+        let f = T::one() + psi + phi;
+
+        if f.is_zero() {
+            return Some((origin, tau));
+        } else if f < T::zero() {
+            lo = tau.clone();
+        } else {
+            hi = tau.clone();
+        }
+
+        if f.clone().abs() <= eps.clone() * crate::convert(m as f64) * (T::one() + abs_sum) {
+            return Some((origin, tau));
+        }
+
+        // Approximate the secular function by `c + sl / (dl - u) + sr / (dr - u)` around `tau`
+        // and solve for the step `u`, falling back to bisection if it leaves the bracket.
+        let dl = d[left].clone() - tau.clone();
+        let dr = d[right].clone() - tau.clone();
+        let sl = dpsi.clone() * dl.clone() * dl.clone();
+        let sr = dphi.clone() * dr.clone() * dr.clone();
+        let c = f - dpsi * dl.clone() - dphi * dr.clone();
+        let b = c.clone() * (dl.clone() + dr.clone()) + sl.clone() + sr.clone();
+        let cc = c.clone() * dl.clone() * dr.clone() + sl * dr + sr * dl;
+        let disc = b.clone() * b.clone() - four.clone() * c.clone() * cc.clone();
+
+        let in_bracket = |t: &T| *t > lo && *t < hi;
+        let mut new_tau = None;
+
+        if disc >= T::zero() {
+            let sqrt_disc = disc.sqrt();
+            let q = if b >= T::zero() {
+                (b + sqrt_disc) / two.clone()
+            } else {
+                (b - sqrt_disc) / two.clone()
+            };
+
+            if !q.is_zero() {
+                new_tau = Some(tau.clone() + cc / q.clone()).filter(in_bracket);
+            }
+
+            if new_tau.is_none() && !c.is_zero() {
+                new_tau = Some(tau.clone() + q / c).filter(in_bracket);
+            }
+        }
+
+        let new_tau = new_tau.unwrap_or_else(|| (lo.clone() + hi.clone()) / two.clone());
+
+        if new_tau == tau {
+            return Some((origin, tau));
+        }
+
+        tau = new_tau;
+    }
+
+    None
+}
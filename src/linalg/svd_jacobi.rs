@@ -0,0 +1,267 @@
+use num::{One, Zero};
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector};
+use crate::dimension::{Const, Dim, DimMin, DimMinimum};
+use crate::storage::StorageMut;
+use crate::SVD;
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim> SVD<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C>
+        + Allocator<T, C, R>
+        + Allocator<T, C>
+        + Allocator<T, R>
+        + Allocator<T, DimMinimum<R, C>, C>
+        + Allocator<T, R, DimMinimum<R, C>>
+        + Allocator<T::RealField, DimMinimum<R, C>>,
+{
+    /// Attempts to compute the Singular Value Decomposition of `matrix` using the one-sided
+    /// (Hestenes) Jacobi method.
+    ///
+    /// The singular values are not guaranteed to be sorted in any particular order. Each sweep
+    /// orthogonalizes all the pairs of columns (or rows if `matrix` has more columns than rows)
+    /// of `matrix`. The method is slower than the implicit-shift QR algorithm but it computes
+    /// even the small singular values with high relative accuracy.
+    ///
+    /// Returns `None` if the algorithm did not converge after `max_niter` sweeps. If
+    /// `max_niter == 0`, the algorithm continues indefinitely until convergence.
+    pub(crate) fn try_new_jacobi_unordered(
+        mut matrix: OMatrix<T, R, C>,
+        compute_u: bool,
+        compute_v: bool,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        assert!(
+            !matrix.is_empty(),
+            "Cannot compute the SVD of an empty matrix."
+        );
+        let (nrows, ncols) = matrix.shape_generic();
+        let min_nrows_ncols = nrows.min(ncols);
+
+        let m_amax = matrix.camax();
+
+        if !m_amax.is_zero() {
+            matrix.unscale_mut(m_amax.clone());
+        }
+
+        let (u, v_t, mut singular_values) = if nrows.value() >= ncols.value() {
+            // Orthogonalize the columns of `matrix`: `matrix * V = U * Σ`.
+            let mut v_t = if compute_v {
+                Some(OMatrix::identity_generic(min_nrows_ncols, ncols))
+            } else {
+                None
+            };
+
+            let converged = jacobi_sweeps(&mut matrix, eps, max_niter, |p, q, c, s, e| {
+                if let Some(ref mut v_t) = v_t {
+                    for j in 0..v_t.ncols() {
+                        let rp = v_t[(p, j)].clone();
+                        let rq = v_t[(q, j)].clone();
+                        v_t[(p, j)] =
+                            rp.clone().scale(c.clone()) - rq.clone() * e.clone().scale(s.clone());
+                        v_t[(q, j)] =
+                            rp * e.clone().conjugate().scale(s.clone()) + rq.scale(c.clone());
+                    }
+                }
+            });
+
+            if !converged {
+                return None;
+            }
+
+            let singular_values = OVector::from_fn_generic(min_nrows_ncols, Const::<1>, |i, _| {
+                matrix.column(i).norm()
+            });
+            let u = if compute_u {
+                normalize_columns(&mut matrix, &singular_values);
+                Some(OMatrix::from_fn_generic(nrows, min_nrows_ncols, |i, j| {
+                    matrix[(i, j)].clone()
+                }))
+            } else {
+                None
+            };
+
+            (u, v_t, singular_values)
+        } else {
+            // Orthogonalize the rows of `matrix`, i.e., the columns of its adjoint:
+            // `matrix^* * U = V * Σ`.
+            let mut adjoint = matrix.adjoint();
+            let mut u = if compute_u {
+                Some(OMatrix::identity_generic(nrows, min_nrows_ncols))
+            } else {
+                None
+            };
+
+            let converged = jacobi_sweeps(&mut adjoint, eps, max_niter, |p, q, c, s, e| {
+                if let Some(ref mut u) = u {
+                    rotate_columns(u, p, q, c, s, e);
+                }
+            });
+
+            if !converged {
+                return None;
+            }
+
+            let singular_values = OVector::from_fn_generic(min_nrows_ncols, Const::<1>, |i, _| {
+                adjoint.column(i).norm()
+            });
+            let v_t = if compute_v {
+                normalize_columns(&mut adjoint, &singular_values);
+                Some(OMatrix::from_fn_generic(min_nrows_ncols, ncols, |i, j| {
+                    adjoint[(j, i)].clone().conjugate()
+                }))
+            } else {
+                None
+            };
+
+            (u, v_t, singular_values)
+        };
+
+        singular_values *= m_amax;
+
+        Some(Self {
+            u,
+            v_t,
+            singular_values,
+        })
+    }
+}
+
+/// Applies the rotation `[a_p, a_q] <- [a_p, a_q] * [c, s * e; -s * conj(e), c]` to the columns
+/// `p` and `q` of `m`.
+fn rotate_columns<T: ComplexField, R: Dim, C: Dim, S: StorageMut<T, R, C>>(
+    m: &mut Matrix<T, R, C, S>,
+    p: usize,
+    q: usize,
+    c: T::RealField,
+    s: T::RealField,
+    e: T,
+) {
+    let e_s = e.scale(s);
+    let conj_e_s = e_s.clone().conjugate();
+
+    for i in 0..m.nrows() {
+        let ap = m[(i, p)].clone();
+        let aq = m[(i, q)].clone();
+        m[(i, p)] = ap.clone().scale(c.clone()) - aq.clone() * conj_e_s.clone();
+        m[(i, q)] = ap * e_s.clone() + aq.scale(c.clone());
+    }
+}
+
+/// Performs one-sided Jacobi sweeps on the columns of `m` until they are all mutually
+/// orthogonal up to `eps` (relative to their norms).
+///
+/// Each rotation applied to the columns `p` and `q` of `m` is also reported to `rotate`
+/// with the parameters `(p, q, c, s, e)` used by `rotate_columns`. Returns `false` if
+/// convergence was not reached after `max_niter` sweeps.
+fn jacobi_sweeps<T: ComplexField, R: Dim, C: Dim, S: StorageMut<T, R, C>>(
+    m: &mut Matrix<T, R, C, S>,
+    eps: T::RealField,
+    max_niter: usize,
+    mut rotate: impl FnMut(usize, usize, T::RealField, T::RealField, T),
+) -> bool {
+    let ncols = m.ncols();
+    let tol = eps * crate::convert(m.nrows() as f64);
+    let two: T::RealField = crate::convert(2.0);
+    let mut niter = 0;
+
+    loop {
+        let mut rotated = false;
+
+        for p in 0..ncols {
+            for q in p + 1..ncols {
+                let alpha = m.column(p).norm_squared();
+                let beta = m.column(q).norm_squared();
+
+                if alpha.is_zero() || beta.is_zero() {
+                    continue;
+                }
+
+                let gamma = m.column(p).dotc(&m.column(q));
+                let abs_gamma = gamma.clone().modulus();
+
+                if abs_gamma <= tol.clone() * alpha.clone().sqrt() * beta.clone().sqrt() {
+                    continue;
+                }
+
+                rotated = true;
+
+                // Phase of the off-diagonal entry of the 2x2 Gram matrix, and the classical
+                // real Jacobi rotation annihilating its modulus.
+                let e = gamma.unscale(abs_gamma.clone());
+                let zeta = (beta - alpha) / (two.clone() * abs_gamma);
+                let t = if zeta >= T::RealField::zero() {
+                    T::RealField::one() / (zeta.clone() + T::RealField::one().hypot(zeta.clone()))
+                } else {
+                    -T::RealField::one() / (-zeta.clone() + T::RealField::one().hypot(zeta.clone()))
+                };
+                let c = T::RealField::one() / T::RealField::one().hypot(t.clone());
+                let s = c.clone() * t;
+
+                rotate_columns(m, p, q, c.clone(), s.clone(), e.clone());
+                rotate(p, q, c, s, e);
+            }
+        }
+
+        if !rotated {
+            return true;
+        }
+
+        niter += 1;
+        if niter == max_niter {
+            return false;
+        }
+    }
+}
+
+/// Normalizes the first `norms.len()` columns of `m`, given their norms.
+///
+/// Columns with a zero norm are replaced by unit vectors orthogonal to all the other columns.
+fn normalize_columns<T: ComplexField, R: Dim, C: Dim, S: StorageMut<T, R, C>, D: Dim>(
+    m: &mut Matrix<T, R, C, S>,
+    norms: &OVector<T::RealField, D>,
+) where
+    DefaultAllocator: Allocator<T, R> + Allocator<T::RealField, D>,
+{
+    // At least one canonical basis vector has a component of norm `>= 1 / sqrt(nrows)` in the
+    // orthogonal complement of the other columns.
+    let threshold = crate::convert::<_, T::RealField>(0.5)
+        / crate::convert::<_, T::RealField>(m.nrows() as f64).sqrt();
+
+    for j in 0..norms.len() {
+        if !norms[j].is_zero() {
+            m.column_mut(j).unscale_mut(norms[j].clone());
+        }
+    }
+
+    for j in 0..norms.len() {
+        if !norms[j].is_zero() {
+            continue;
+        }
+
+        // Gram-Schmidt on the canonical basis vectors against the columns that are already
+        // normalized (the null columns with an index greater than `j` are still zero).
+        for k in 0..m.nrows() {
+            let mut candidate = OVector::zeros_generic(m.shape_generic().0, Const::<1>);
+            candidate[k] = T::one();
+
+            for _ in 0..2 {
+                for l in 0..norms.len() {
+                    if l != j {
+                        let proj = m.column(l).dotc(&candidate);
+                        candidate.axpy(-proj, &m.column(l), T::one());
+                    }
+                }
+            }
+
+            let norm = candidate.norm();
+            if norm > threshold {
+                m.column_mut(j).copy_from(&candidate.unscale(norm));
+                break;
+            }
+        }
+    }
+}
@@ -199,10 +199,21 @@ where
                     diag[start + 1].clone(),
                 );
                 let eigvals = m.eigenvalues().unwrap();
-                let basis = Vector2::new(
-                    eigvals.x.clone() - diag[start + 1].clone(),
-                    off_diag[start].clone(),
-                );
+
+                // Both `(λ - d₁, o)` and `(o, λ - d₀)` are eigenvectors associated to the
+                // eigenvalue `λ`. The one with the largest norm is the least affected by the
+                // cancellation in `λ - dᵢ`.
+                let basis = {
+                    let (d0, d1) = (diag[start].clone(), diag[start + 1].clone());
+                    let first = Vector2::new(eigvals.x.clone() - d1, off_diag[start].clone());
+                    let second = Vector2::new(off_diag[start].clone(), eigvals.x.clone() - d0);
+
+                    if first.norm_squared() >= second.norm_squared() {
+                        first
+                    } else {
+                        second
+                    }
+                };
 
                 diag[start] = eigvals[0].clone();
                 diag[start + 1] = eigvals[1].clone();
@@ -284,7 +295,9 @@ where
 
     /// Rebuild the original matrix.
     ///
-    /// This is useful if some of the eigenvalues have been manually modified.
+    /// This is useful if some of the eigenvalues have been manually modified. If only some of the
+    /// eigenpairs are stored, e.g., as computed by a partial eigensolver for dynamically-sized
+    /// matrices, this returns the corresponding low-rank approximation of the original matrix.
     #[must_use]
     pub fn recompose(&self) -> OMatrix<T, D, D> {
         let mut u_t = self.eigenvectors.clone();
@@ -292,8 +305,7 @@ where
             let val = self.eigenvalues[i].clone();
             u_t.column_mut(i).scale_mut(val);
         }
-        u_t.adjoint_mut();
-        &self.eigenvectors * u_t
+        &self.eigenvectors * u_t.adjoint()
     }
 }
 
@@ -340,6 +352,20 @@ where
         .unwrap()
         .0
     }
+
+    /// Applies the real function `f` to this symmetric matrix.
+    ///
+    /// This computes `Q * f(Λ) * Qᴴ` where `Q * Λ * Qᴴ` is the eigendecomposition of this
+    /// matrix. Only the lower-triangular part of the matrix is read.
+    #[must_use]
+    pub fn map_symmetric<F: FnMut(T::RealField) -> T::RealField>(
+        &self,
+        mut f: F,
+    ) -> OMatrix<T, D, D> {
+        let mut eig = SymmetricEigen::new(self.clone_owned());
+        eig.eigenvalues.apply(|e| *e = f(e.clone()));
+        eig.recompose()
+    }
 }
 
 #[cfg(test)]
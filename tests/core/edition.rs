@@ -1,6 +1,6 @@
 use na::{
-    DMatrix, Matrix, Matrix3, Matrix3x4, Matrix3x5, Matrix4, Matrix4x3, Matrix4x5, Matrix5,
-    Matrix5x3, Matrix5x4,
+    DMatrix, DVector, Matrix, Matrix2x4, Matrix3, Matrix3x4, Matrix3x5, Matrix4, Matrix4x3,
+    Matrix4x5, Matrix5, Matrix5x3, Matrix5x4, RowVector4, Vector1, Vector3,
 };
 use na::{Dynamic, U3, U5};
 
@@ -700,3 +700,103 @@ fn resize_empty_matrix() {
     assert_eq!(m1, m6.resize(0, 0, 42));
     assert_eq!(m1, m7.resize(0, 0, 42));
 }
+
+#[test]
+#[rustfmt::skip]
+fn select_where() {
+    let m = Matrix3x4::new(11, -12, 13, -14,
+                           -21, 22, -23, 24,
+                           31, -32, 33, -34);
+
+    assert_eq!(m.select_where(|e| *e > 0), DVector::from_vec(vec![11, 31, 22, 13, 33, 24]));
+    assert_eq!(m.select_where(|e| *e > 100), DVector::<i32>::zeros(0));
+    assert_eq!(
+        m.index(&m.map(|e| e < 0)),
+        DVector::from_vec(vec![-21, -12, -32, -23, -14, -34])
+    );
+}
+
+#[test]
+#[rustfmt::skip]
+fn gather_elements() {
+    let m = Matrix3x4::new(11, 12, 13, 14,
+                           21, 22, 23, 24,
+                           31, 32, 33, 34);
+
+    let fixed: Vector3<i32> = m.index([(2, 3), (0, 0), (2, 3)]);
+    assert_eq!(fixed, Vector3::new(34, 11, 34));
+
+    let indices = [(1, 2), (0, 3)];
+    assert_eq!(m.index(&indices[..]), DVector::from_vec(vec![23, 14]));
+    assert_eq!(m.index(&[][..]), DVector::<i32>::zeros(0));
+
+    assert_eq!(m.get([(3, 0)]), None);
+    assert_eq!(m.get(&[(0, 0), (0, 4)][..]), None);
+
+    let dynamic = DMatrix::from_fn(3, 4, |i, j| m[(i, j)]);
+    let wrong_mask = DMatrix::from_element(4, 3, true);
+    assert_eq!(dynamic.get(&wrong_mask), None);
+    assert_eq!(dynamic.index([(1, 1)]), Vector1::new(22));
+}
+
+#[test]
+#[should_panic]
+fn gather_elements_out_of_bounds() {
+    let m = Matrix3x4::<f32>::zeros();
+    let _ = m.index([(0, 0), (0, 4)]);
+}
+
+#[test]
+#[rustfmt::skip]
+fn scatter_elements() {
+    let mut m = Matrix3x4::<i32>::zeros();
+
+    m.scatter([(0, 1), (2, 3), (0, 1)], &Vector3::new(1, 2, 3));
+    assert_eq!(m, Matrix3x4::new(0, 3, 0, 0,
+                                 0, 0, 0, 0,
+                                 0, 0, 0, 2));
+
+    m.scatter(&[(1, 0), (1, 3)][..], &DVector::from_vec(vec![4, 5]));
+    assert_eq!(m, Matrix3x4::new(0, 3, 0, 0,
+                                 4, 0, 0, 5,
+                                 0, 0, 0, 2));
+
+    let mask = m.map(|e| e == 0);
+    m.scatter(&mask, &DVector::from_fn(8, |k, _| -(k as i32)));
+    assert_eq!(m, Matrix3x4::new(0, 3, -4, -7,
+                                 4, -2, -5, 5,
+                                 -1, -3, -6, 2));
+}
+
+#[test]
+#[should_panic]
+fn scatter_elements_mismatched_length() {
+    let mut m = Matrix3x4::<f32>::zeros();
+    let mask = Matrix3x4::from_fn(|i, _| i == 0);
+    m.scatter(&mask, &Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+#[rustfmt::skip]
+fn set_rows_and_columns() {
+    let mut m = Matrix3x4::<i32>::zeros();
+
+    m.set_rows(&[2, 0], &Matrix2x4::new(1, 2, 3, 4,
+                                        5, 6, 7, 8));
+    assert_eq!(m, Matrix3x4::new(5, 6, 7, 8,
+                                 0, 0, 0, 0,
+                                 1, 2, 3, 4));
+    assert_eq!(m.select_rows(&[2, 0]), Matrix2x4::new(1, 2, 3, 4, 5, 6, 7, 8));
+
+    m.set_columns(&[3, 1, 3], &DMatrix::from_fn(3, 3, |i, j| (10 * j + i) as i32));
+    assert_eq!(m, Matrix3x4::new(5, 10, 7, 20,
+                                 0, 11, 0, 21,
+                                 1, 12, 3, 22));
+}
+
+#[test]
+#[should_panic]
+fn set_rows_out_of_bounds() {
+    let mut m = Matrix3x4::<f32>::zeros();
+    m.set_rows(&[3], &RowVector4::zeros());
+}
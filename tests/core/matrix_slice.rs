@@ -2,9 +2,10 @@
 
 use na::{
     DMatrix, DMatrixSlice, DMatrixSliceMut, Matrix2, Matrix2x3, Matrix2x4, Matrix2x6, Matrix3,
-    Matrix3x2, Matrix3x4, Matrix4x2, Matrix6x2, MatrixSlice2, MatrixSlice2x3, MatrixSlice2xX,
-    MatrixSlice3, MatrixSlice3x2, MatrixSliceMut2, MatrixSliceMut2x3, MatrixSliceMut2xX,
-    MatrixSliceMut3, MatrixSliceMut3x2, MatrixSliceMutXx3, MatrixSliceXx3, RowVector4, Vector3,
+    Matrix3x2, Matrix3x4, Matrix4x2, Matrix4x3, Matrix6x2, MatrixSlice2, MatrixSlice2x3,
+    MatrixSlice2xX, MatrixSlice3, MatrixSlice3x2, MatrixSliceMut2, MatrixSliceMut2x3,
+    MatrixSliceMut2xX, MatrixSliceMut3, MatrixSliceMut3x2, MatrixSliceMutXx3, MatrixSliceXx3,
+    RowVector4, SliceError, Vector3,
 };
 
 #[test]
@@ -335,3 +336,104 @@ fn slice_with_steps_out_of_bounds() {
     let a = Matrix3x4::<f32>::zeros();
     a.slice_with_steps((1, 2), (2, 2), (0, 1));
 }
+
+#[test]
+#[rustfmt::skip]
+fn from_row_major_slice() {
+    let data = [ 1.0, 2.0, 3.0, 4.0,
+                 5.0, 6.0, 7.0, 8.0,
+                 9.0, 10.0, 11.0, 12.0 ];
+    let expected = Matrix3x4::new(1.0, 2.0, 3.0, 4.0,
+                                  5.0, 6.0, 7.0, 8.0,
+                                  9.0, 10.0, 11.0, 12.0);
+
+    let dynamic = DMatrixSlice::from_row_major_slice(&data, 3, 4).unwrap();
+    let fixed = MatrixSlice3x2::from_row_major_slice(&data[..6]).unwrap();
+    let row_dynamic = MatrixSlice2xX::from_row_major_slice(&data, 3).unwrap();
+
+    assert_eq!(dynamic, expected);
+    assert_eq!(fixed, Matrix3x2::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+    assert_eq!(row_dynamic, Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+    assert_eq!(dynamic.transpose(), DMatrixSlice::from_slice(&data, 4, 3));
+
+    let mut data = data;
+    let mut slice = DMatrixSliceMut::from_row_major_slice(&mut data, 2, 6).unwrap();
+    slice[(1, 0)] = 0.0;
+    assert_eq!(data[6], 0.0);
+
+    assert_eq!(
+        DMatrixSlice::from_row_major_slice(&data, 4, 4),
+        Err(SliceError::BufferTooSmall { required: 16, len: 12 })
+    );
+    assert!(DMatrixSlice::from_row_major_slice(&data[..0], 0, 3).is_ok());
+}
+
+#[test]
+#[rustfmt::skip]
+fn from_strided_slice() {
+    let data: Vec<f32> = (0..20).map(|i| i as f32).collect();
+
+    // Every other column of a 4x5 row-major buffer.
+    let slice = DMatrixSlice::from_strided_slice(&data, 4, 3, 5, 2).unwrap();
+    let expected = Matrix4x3::new(0.0, 2.0, 4.0,
+                                  5.0, 7.0, 9.0,
+                                  10.0, 12.0, 14.0,
+                                  15.0, 17.0, 19.0);
+    assert_eq!(slice, expected);
+    assert_eq!(
+        slice,
+        DMatrixSlice::from_slice_with_strides(&data, 4, 3, 5, 2)
+    );
+
+    // Broadcasting through a zero stride is allowed for immutable slices.
+    let broadcast = MatrixSliceXx3::from_strided_slice(&data, 2, 0, 1).unwrap();
+    for (i, j) in [(0, 0), (1, 0), (0, 2), (1, 2)] {
+        assert_eq!(broadcast[(i, j)], j as f32);
+    }
+
+    assert_eq!(
+        DMatrixSlice::from_strided_slice(&data, 4, 3, 5, 3),
+        Err(SliceError::BufferTooSmall { required: 22, len: 20 })
+    );
+    assert_eq!(
+        DMatrixSlice::from_strided_slice(&data, 2, 2, usize::MAX, 1),
+        Err(SliceError::BufferTooSmall { required: usize::MAX, len: 20 })
+    );
+}
+
+#[test]
+fn from_strided_slice_mut() {
+    let mut data: Vec<f32> = (0..20).map(|i| i as f32).collect();
+
+    let mut slice = DMatrixSliceMut::from_strided_slice(&mut data, 4, 3, 5, 2).unwrap();
+    slice.fill(-1.0);
+    for (i, e) in data.iter().enumerate() {
+        assert_eq!(*e == -1.0, i % 5 % 2 == 0, "{}", i);
+    }
+
+    let mut slice = MatrixSliceMutXx3::from_strided_slice(&mut data, 2, 3, 1).unwrap();
+    slice.fill(0.0);
+    assert_eq!(&data[..6], &[0.0; 6]);
+
+    assert_eq!(
+        DMatrixSliceMut::from_strided_slice(&mut data, 2, 3, 0, 1),
+        Err(SliceError::AliasedIndices)
+    );
+    assert_eq!(
+        DMatrixSliceMut::from_strided_slice(&mut data, 4, 3, 2, 3),
+        Err(SliceError::AliasedIndices)
+    );
+    assert_eq!(
+        DMatrixSliceMut::from_strided_slice(&mut data, usize::MAX, 2, 0, 1),
+        Err(SliceError::AliasedIndices)
+    );
+    assert_eq!(
+        DMatrixSliceMut::from_strided_slice(&mut data, 5, 3, 5, 2),
+        Err(SliceError::BufferTooSmall {
+            required: 25,
+            len: 20
+        })
+    );
+    assert!(DMatrixSliceMut::from_strided_slice(&mut data, 1, 3, 0, 1).is_ok());
+    assert!(DMatrixSliceMut::from_strided_slice(&mut data, 3, 4, 2, 3).is_ok());
+}
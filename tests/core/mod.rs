@@ -9,7 +9,11 @@ mod matrix;
 mod matrix_slice;
 #[cfg(feature = "mint")]
 mod mint;
+#[cfg(feature = "rayon")]
+mod par_iter;
 mod serde;
+mod stack;
+mod statistics;
 
 #[cfg(feature = "compare")]
 mod matrixcompare;
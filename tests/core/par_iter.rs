@@ -0,0 +1,151 @@
+use na::{DMatrix, Matrix3x4};
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+fn test_matrix(nrows: usize, ncols: usize) -> DMatrix<f64> {
+    DMatrix::from_fn(nrows, ncols, |i, j| ((i * ncols + j) as f64).sin() + 0.5)
+}
+
+/// Evaluates `f` in a thread pool with the given number of threads.
+fn with_threads<R: Send>(num_threads: usize, f: impl FnOnce() -> R + Send) -> R {
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .unwrap()
+        .install(f)
+}
+
+#[test]
+fn par_column_iter() {
+    let m = test_matrix(13, 50);
+    let slice = m.slice_with_steps((1, 2), (5, 16), (1, 2));
+
+    let columns: Vec<_> = slice.par_column_iter().collect();
+    assert_eq!(columns.len(), slice.ncols());
+    for (column, expected) in columns.iter().zip(slice.column_iter()) {
+        assert_eq!(*column, expected);
+    }
+
+    let reversed: Vec<_> = slice.par_column_iter().rev().collect();
+    for (j, column) in reversed.iter().rev().enumerate() {
+        assert_eq!(*column, slice.column(j));
+    }
+
+    let empty = DMatrix::<f64>::zeros(0, 5);
+    assert_eq!(empty.par_column_iter().count(), 5);
+    assert_eq!(DMatrix::<f64>::zeros(5, 0).par_column_iter().count(), 0);
+}
+
+#[test]
+fn par_column_iter_mut() {
+    let mut m = test_matrix(13, 50);
+    let mut expected = m.clone();
+
+    for (j, mut column) in expected
+        .slice_with_steps_mut((1, 2), (5, 16), (1, 2))
+        .column_iter_mut()
+        .enumerate()
+    {
+        column *= j as f64;
+    }
+
+    m.slice_with_steps_mut((1, 2), (5, 16), (1, 2))
+        .par_column_iter_mut()
+        .enumerate()
+        .for_each(|(j, mut column)| column *= j as f64);
+    assert_eq!(m, expected);
+
+    let mut a = Matrix3x4::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+    a.par_column_iter_mut()
+        .for_each(|mut column| column.neg_mut());
+    assert_eq!(a, -Matrix3x4::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12));
+}
+
+#[test]
+fn par_map_zip_map_and_apply() {
+    let a = test_matrix(31, 57);
+    let b = test_matrix(57, 31).transpose();
+
+    assert_eq!(a.par_map(|e| e.exp()), a.map(|e| e.exp()));
+    assert_eq!(
+        a.par_zip_map(&b, |x, y| x * y - 1.0),
+        a.zip_map(&b, |x, y| x * y - 1.0)
+    );
+
+    let slice = a.slice((2, 3), (10, 20));
+    assert_eq!(slice.par_map(|e| e as f32), slice.map(|e| e as f32));
+
+    let mut c = a.clone();
+    c.par_apply(|e| *e = 2.0 * *e + 1.0);
+    assert_eq!(c, a.map(|e| 2.0 * e + 1.0));
+
+    let empty = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(empty.par_map(|e| e + 1.0), empty);
+}
+
+#[test]
+#[should_panic]
+fn par_zip_map_dimension_mismatch() {
+    let a = test_matrix(3, 4);
+    let b = test_matrix(4, 3);
+    let _ = a.par_zip_map(&b, |x, y| x + y);
+}
+
+#[test]
+fn par_statistics() {
+    // Enough columns to be split into several chunks by the column reductions.
+    let m = test_matrix(11, 1500);
+
+    assert_eq!(m.par_row_sum(), m.row_sum());
+    assert_eq!(m.par_row_sum_tr(), m.row_sum_tr());
+    assert_eq!(m.par_row_product(), m.row_product());
+    assert_eq!(m.par_row_product_tr(), m.row_product_tr());
+    assert_eq!(m.par_row_mean(), m.row_mean());
+    assert_eq!(m.par_row_mean_tr(), m.row_mean_tr());
+    assert_eq!(m.par_row_variance(), m.row_variance());
+    assert_eq!(m.par_row_variance_tr(), m.row_variance_tr());
+
+    assert_relative_eq!(m.par_sum(), m.sum(), epsilon = 1.0e-9);
+    assert_relative_eq!(m.par_mean(), m.mean(), epsilon = 1.0e-12);
+    assert_relative_eq!(m.par_variance(), m.variance(), epsilon = 1.0e-12);
+    assert_relative_eq!(m.par_column_sum(), m.column_sum(), epsilon = 1.0e-9);
+    assert_relative_eq!(m.par_column_mean(), m.column_mean(), epsilon = 1.0e-12);
+    assert_relative_eq!(
+        m.par_column_variance(),
+        m.column_variance(),
+        epsilon = 1.0e-12
+    );
+
+    let small = m.slice((0, 0), (4, 20));
+    assert_relative_eq!(small.par_product(), small.product(), max_relative = 1.0e-12);
+    assert_relative_eq!(
+        small.par_column_product(),
+        small.column_product(),
+        max_relative = 1.0e-12
+    );
+
+    let empty = DMatrix::<f64>::zeros(3, 0);
+    assert_eq!(empty.par_sum(), 0.0);
+    assert_eq!(empty.par_mean(), 0.0);
+    assert_eq!(empty.par_variance(), 0.0);
+    assert_eq!(empty.par_column_sum(), empty.column_sum());
+}
+
+#[test]
+fn par_statistics_are_deterministic() {
+    let m = test_matrix(7, 3000);
+    let reductions = || {
+        (
+            m.par_sum(),
+            m.par_variance(),
+            m.par_column_sum(),
+            m.par_column_mean(),
+            m.par_column_variance(),
+        )
+    };
+
+    let sequential = with_threads(1, reductions);
+    for num_threads in 2..6 {
+        assert_eq!(with_threads(num_threads, reductions), sequential);
+    }
+}
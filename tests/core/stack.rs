@@ -0,0 +1,55 @@
+use na::{hstack, vstack, DMatrix, Dynamic, Matrix2, Matrix2x3, OMatrix, RowVector3, U2, U3};
+
+#[test]
+#[rustfmt::skip]
+fn hstack_matrices() {
+    let a = Matrix2::new(1, 2,
+                         3, 4);
+    let b = Matrix2::new(5, 6,
+                         7, 8);
+
+    let m: OMatrix<i32, U2, Dynamic> = hstack(&[a, b, a]);
+    assert_eq!(m, DMatrix::from_row_slice(2, 6, &[1, 2, 5, 6, 1, 2,
+                                                  3, 4, 7, 8, 3, 4]));
+
+    let c = DMatrix::from_row_slice(2, 1, &[9, 10]);
+    let d = DMatrix::from_row_slice(2, 0, &[]);
+    assert_eq!(hstack(&[&c, &d, &c]), DMatrix::from_row_slice(2, 2, &[9, 9, 10, 10]));
+    assert_eq!(hstack(&[a.columns(0, 1), b.columns(1, 1)]), Matrix2::new(1, 6, 3, 8));
+
+    let empty: [Matrix2<i32>; 0] = [];
+    assert_eq!(hstack(&empty).shape(), (2, 0));
+    assert_eq!(hstack::<i32, _, _, _, DMatrix<i32>>(&[]).shape(), (0, 0));
+}
+
+#[test]
+#[rustfmt::skip]
+fn vstack_matrices() {
+    let a = Matrix2x3::new(1, 2, 3,
+                           4, 5, 6);
+    let b = RowVector3::new(7, 8, 9);
+
+    let m: OMatrix<i32, Dynamic, U3> = vstack(&[b, a.row(1).into_owned(), b]);
+    assert_eq!(m, DMatrix::from_row_slice(3, 3, &[7, 8, 9,
+                                                  4, 5, 6,
+                                                  7, 8, 9]));
+
+    let m = vstack(&[DMatrix::from_row_slice(2, 3, a.transpose().as_slice()), DMatrix::zeros(1, 3)]);
+    assert_eq!(m, DMatrix::from_row_slice(3, 3, &[1, 2, 3,
+                                                  4, 5, 6,
+                                                  0, 0, 0]));
+
+    assert_eq!(vstack(&[a, a]).transpose(), hstack(&[a.transpose(), a.transpose()]));
+}
+
+#[test]
+#[should_panic]
+fn hstack_mismatched_rows() {
+    let _ = hstack(&[DMatrix::<f64>::zeros(2, 2), DMatrix::zeros(3, 2)]);
+}
+
+#[test]
+#[should_panic]
+fn vstack_mismatched_columns() {
+    let _ = vstack(&[DMatrix::<f64>::zeros(2, 2), DMatrix::zeros(2, 3)]);
+}
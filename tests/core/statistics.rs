@@ -0,0 +1,232 @@
+use na::{DMatrix, DVector, Matrix3x4, RowDVector, Vector3, Vector4};
+
+fn test_matrix(nrows: usize, ncols: usize) -> DMatrix<f64> {
+    DMatrix::from_fn(nrows, ncols, |i, j| ((i * ncols + j) as f64).sin() * 10.0)
+}
+
+#[test]
+fn cumulative_statistics() {
+    let m = test_matrix(5, 7);
+
+    let row_cumsum = m.row_cumsum();
+    let column_cumsum = m.column_cumsum();
+    for i in 0..m.nrows() {
+        for j in 0..m.ncols() {
+            assert_relative_eq!(row_cumsum[(i, j)], m.rows(0, i + 1).column(j).sum());
+            assert_relative_eq!(column_cumsum[(i, j)], m.columns(0, j + 1).row(i).sum());
+        }
+    }
+    assert_relative_eq!(
+        row_cumsum.row(4).clone_owned(),
+        m.row_sum(),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        column_cumsum.column(6).clone_owned(),
+        m.column_sum(),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        m.row_cumprod().row(4).clone_owned(),
+        m.row_product(),
+        epsilon = 1.0e-9
+    );
+    assert_relative_eq!(
+        m.column_cumprod().column(6).clone_owned(),
+        m.column_product(),
+        epsilon = 1.0e-9
+    );
+
+    let a = Matrix3x4::from_fn(|i, j| (i + j + 1) as i32);
+    assert_eq!(a.row_cumsum().row(2), a.row_sum());
+    assert_eq!(a.column_cumprod().column(3), a.column_product());
+
+    let empty = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(empty.row_cumsum(), empty);
+    assert_eq!(empty.column_cumprod(), empty);
+}
+
+#[test]
+fn quantiles() {
+    let v = DVector::from_vec(vec![7.0, 1.0, 3.0, 5.0, 9.0]);
+    assert_eq!(v.median(), 5.0);
+    assert_eq!(v.quantile(0.0), 1.0);
+    assert_eq!(v.quantile(0.1), 1.8);
+    assert_eq!(v.quantile(0.75), 7.0);
+    assert_eq!(v.quantile(1.0), 9.0);
+    assert_eq!(DVector::from_element(1, 4.0).quantile(0.3), 4.0);
+
+    let m = test_matrix(6, 5);
+    for (j, median) in m.row_median().iter().enumerate() {
+        assert_eq!(*median, m.column(j).median());
+    }
+    for (i, quantile) in m.column_quantile(0.3).iter().enumerate() {
+        assert_eq!(*quantile, m.row(i).quantile(0.3));
+    }
+    assert_eq!(m.row_quantile_tr(0.9), m.row_quantile(0.9).transpose());
+    assert_eq!(m.row_median_tr(), m.row_median().transpose());
+    assert_eq!(m.column_median(), m.column_quantile(0.5));
+}
+
+#[test]
+#[should_panic(expected = "Cannot compute the quantile of a matrix containing NaN.")]
+fn quantile_with_nan() {
+    let _ = DVector::from_vec(vec![7.0, f64::NAN, 3.0]).quantile(0.5);
+}
+
+#[test]
+#[should_panic]
+fn quantile_out_of_range() {
+    let _ = Vector3::new(1.0, 2.0, 3.0).quantile(1.5);
+}
+
+#[test]
+#[should_panic]
+fn median_of_empty_matrix() {
+    let _ = DMatrix::<f64>::zeros(0, 0).median();
+}
+
+#[test]
+fn weighted_statistics() {
+    let m = test_matrix(4, 6);
+
+    // Unit weights give the unweighted statistics.
+    let ones = DMatrix::from_element(4, 6, 1.0);
+    assert_relative_eq!(m.weighted_mean(&ones), m.mean(), epsilon = 1.0e-12);
+    assert_relative_eq!(m.weighted_variance(&ones), m.variance(), epsilon = 1.0e-12);
+    let row_ones = DVector::from_element(4, 1.0);
+    assert_relative_eq!(
+        m.row_weighted_mean(&row_ones),
+        m.row_mean(),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        m.row_weighted_variance(&row_ones),
+        m.row_variance(),
+        epsilon = 1.0e-12
+    );
+    let column_ones = DVector::from_element(6, 2.0);
+    assert_relative_eq!(
+        m.column_weighted_mean(&column_ones),
+        m.column_mean(),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        m.column_weighted_variance(&column_ones),
+        m.column_variance(),
+        epsilon = 1.0e-12
+    );
+
+    // Integer weights are equivalent to repeating the weighted elements.
+    let a = Vector4::new(1.0, 2.0, 4.0, 8.0);
+    let w = Vector4::new(2.0, 0.0, 1.0, 1.0);
+    let repeated = Vector4::new(1.0, 1.0, 4.0, 8.0);
+    assert_relative_eq!(a.weighted_mean(&w), repeated.mean());
+    assert_relative_eq!(a.weighted_variance(&w), repeated.variance());
+
+    let weights = DVector::from_fn(6, |j, _| j as f64 + 1.0);
+    let weighted = m.column_weighted_variance(&weights);
+    for i in 0..m.nrows() {
+        let row = m.row(i).transpose();
+        assert_relative_eq!(
+            weighted[i],
+            row.weighted_variance(&weights),
+            epsilon = 1.0e-12
+        );
+    }
+}
+
+#[test]
+fn covariance_and_correlation() {
+    let observations = test_matrix(9, 4);
+    let cov = observations.row_covariance();
+    let corr = observations.row_correlation();
+
+    assert_eq!(cov.shape(), (4, 4));
+    assert_relative_eq!(cov, cov.transpose(), epsilon = 1.0e-12);
+    assert_relative_eq!(
+        cov.diagonal(),
+        observations.row_variance_tr(),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        observations.transpose().column_covariance(),
+        cov,
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        observations.transpose().column_correlation(),
+        corr,
+        epsilon = 1.0e-12
+    );
+
+    for i in 0..4 {
+        assert_relative_eq!(corr[(i, i)], 1.0, epsilon = 1.0e-12);
+        for j in 0..4 {
+            assert!(corr[(i, j)].abs() <= 1.0 + 1.0e-12);
+            let xi = observations.column(i) - DVector::repeat(9, observations.column(i).mean());
+            let xj = observations.column(j) - DVector::repeat(9, observations.column(j).mean());
+            assert_relative_eq!(cov[(i, j)], xi.dot(&xj) / 9.0, epsilon = 1.0e-12);
+        }
+    }
+
+    let empty = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(empty.row_covariance(), DMatrix::zeros(3, 3));
+    assert_eq!(empty.transpose().column_covariance(), DMatrix::zeros(3, 3));
+}
+
+#[test]
+fn moving_window_statistics() {
+    let m = test_matrix(7, 5);
+
+    for window in 1..9 {
+        let row_mean = m.row_moving_mean(window);
+        let row_std = m.row_moving_std(window);
+        let column_mean = m.column_moving_mean(window);
+        let column_std = m.column_moving_std(window);
+
+        for i in 0..m.nrows() {
+            for j in 0..m.ncols() {
+                let start = (i + 1).saturating_sub(window);
+                let column = m.slice((start, j), (i + 1 - start, 1));
+                assert_relative_eq!(row_mean[(i, j)], column.mean(), epsilon = 1.0e-12);
+                assert_relative_eq!(row_std[(i, j)], column.variance().sqrt(), epsilon = 1.0e-12);
+
+                let start = (j + 1).saturating_sub(window);
+                let row = m.slice((i, start), (1, j + 1 - start));
+                assert_relative_eq!(column_mean[(i, j)], row.mean(), epsilon = 1.0e-12);
+                assert_relative_eq!(column_std[(i, j)], row.variance().sqrt(), epsilon = 1.0e-12);
+            }
+        }
+    }
+
+    // A window of one element is the identity, and a window covering everything is the
+    // cumulative mean.
+    assert_eq!(m.row_moving_mean(1), m);
+    assert_eq!(m.column_moving_std(1), DMatrix::zeros(7, 5));
+    let v = RowDVector::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(
+        v.column_moving_mean(10),
+        RowDVector::from_vec(vec![1.0, 1.5, 2.0, 2.5])
+    );
+}
+
+#[test]
+fn moving_std_of_constant_windows() {
+    for i in 0..2000 {
+        let value = 0.01 * i as f64 + 0.005;
+        let v = DVector::from_element(10, value);
+        assert_eq!(v.row_moving_std(3), DVector::zeros(10));
+        assert_eq!(v.transpose().column_moving_std(3), RowDVector::zeros(10));
+    }
+
+    let m = DMatrix::from_element(4, 6, 123.456);
+    assert_eq!(m.row_moving_std(3), DMatrix::zeros(4, 6));
+    assert_eq!(m.column_moving_std(4), DMatrix::zeros(4, 6));
+}
+
+#[test]
+#[should_panic]
+fn moving_mean_empty_window() {
+    let _ = Vector3::new(1.0, 2.0, 3.0).row_moving_mean(0);
+}
@@ -18,6 +18,8 @@ extern crate nalgebra as na;
 extern crate num_traits as num;
 #[cfg(feature = "rand")]
 extern crate rand_package as rand;
+#[cfg(feature = "rayon")]
+extern crate rayon_package as rayon;
 
 #[cfg(all(feature = "debug", feature = "compare", feature = "rand"))]
 mod core;
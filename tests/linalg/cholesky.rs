@@ -16,7 +16,7 @@ macro_rules! gen_tests(
         mod $module {
             use na::debug::RandomSDP;
             use na::dimension::{Const, Dynamic};
-            use na::{DMatrix, DVector, Matrix4x3, Vector4};
+            use na::{DMatrix, DVector, InducedOneNorm, Matrix4x3, Vector4};
             use rand::random;
             use simba::scalar::ComplexField;
             #[allow(unused_imports)]
@@ -165,6 +165,29 @@ macro_rules! gen_tests(
 
                     prop_assert!(relative_eq!(m_updated, m_chol_updated, epsilon = 1.0e-7));
                 }
+
+                #[test]
+                fn cholesky_solve_refined(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let b = DMatrix::<$scalar>::new_random(n, nb).map(|e| e.0);
+                    let refined = m.clone().cholesky().unwrap().solve_refined(&m, &b, 10);
+
+                    prop_assert!(refined.iterations <= 10);
+                    prop_assert!(refined.backward_error <= 1.0e-12);
+                    prop_assert!(relative_eq!(&m * refined.solution, b, epsilon = 1.0e-7));
+                }
+
+                #[test]
+                fn cholesky_rcond(n in PROPTEST_MATRIX_DIM) {
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let chol = m.clone().cholesky().unwrap();
+                    let rcond = chol.rcond();
+                    let exact = 1.0 / (m.apply_norm(&InducedOneNorm) * chol.inverse().apply_norm(&InducedOneNorm));
+
+                    // The norms are underestimated, but by a small factor only.
+                    prop_assert!(rcond >= exact * (1.0 - 1.0e-7));
+                    prop_assert!(rcond <= exact * 10.0);
+                }
             }
         }
     }
@@ -172,3 +195,25 @@ macro_rules! gen_tests(
 
 gen_tests!(complex, RandComplex<f64>);
 gen_tests!(f64, RandScalar<f64>);
+
+mod mixed_precision {
+    use na::debug::RandomSDP;
+    use na::dimension::Const;
+    use na::Vector4;
+
+    use crate::proptest::*;
+    use proptest::{prop_assert, proptest};
+    use rand::random;
+
+    proptest! {
+        #[test]
+        fn cholesky_solve_refined_mixed_precision(_n in PROPTEST_MATRIX_DIM) {
+            let m = RandomSDP::new(Const::<4>, random::<f64>).unwrap();
+            let b = Vector4::<f64>::new_random();
+            let refined = m.cast::<f32>().cholesky().unwrap().solve_refined(&m, &b, 10);
+
+            prop_assert!(refined.backward_error <= 1.0e-14);
+            prop_assert!(relative_eq!(m * refined.solution, b, epsilon = 1.0e-10));
+        }
+    }
+}
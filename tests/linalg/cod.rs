@@ -0,0 +1,97 @@
+use na::{Matrix3, Matrix3x4};
+
+#[test]
+fn cod_rank_deficient() {
+    let m = Matrix3x4::new(1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.0, 1.0, 0.0, 1.0, 0.0);
+    let cod = m.cod(1.0e-10);
+    assert_eq!(cod.rank(), 2);
+
+    let (q, t, z) = cod.unpack();
+    assert_relative_eq!(m, q * t * z, epsilon = 1.0e-10);
+    assert!(q.is_orthogonal(1.0e-10));
+    assert!((z * z.transpose()).is_identity(1.0e-10));
+    assert_eq!(t.lower_triangle(), t);
+    assert_eq!(t.row(2).norm(), 0.0);
+
+    let cod = Matrix3::<f64>::zeros().cod(1.0e-10);
+    assert_eq!(cod.rank(), 0);
+    assert_eq!(cod.t(), Matrix3::zeros());
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::DMatrix;
+                use num::Zero;
+                use std::cmp;
+
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, prop_assert_eq, proptest};
+
+                proptest! {
+                    #[test]
+                    fn cod(m in dmatrix_($scalar)) {
+                        let (q, t, z) = m.clone().cod(0.0).unpack();
+
+                        prop_assert!(relative_eq!(m, &q * &t * &z, epsilon = 1.0e-7));
+                        prop_assert!(q.is_orthogonal(1.0e-7));
+                        prop_assert!((&z * z.adjoint()).is_identity(1.0e-7));
+                        prop_assert!(t.lower_triangle() == t);
+                    }
+
+                    #[test]
+                    fn cod_rank_deficient(nrows in PROPTEST_MATRIX_DIM, ncols in PROPTEST_MATRIX_DIM, rank in PROPTEST_MATRIX_DIM) {
+                        let rank = cmp::min(rank, cmp::min(nrows, ncols));
+                        let a = DMatrix::<$scalar_type>::new_random(nrows, rank).map(|e| e.0).qr().q();
+                        let c = DMatrix::<$scalar_type>::new_random(rank, ncols).map(|e| e.0).lq().q();
+                        let m = &a * &c;
+
+                        let cod = m.clone().cod(1.0e-9);
+                        let (q, t, z) = cod.clone().unpack();
+
+                        prop_assert_eq!(cod.rank(), rank);
+                        prop_assert!(relative_eq!(m, &q * &t * &z, epsilon = 1.0e-7));
+                        prop_assert!(q.is_orthogonal(1.0e-7));
+                        prop_assert!((&z * z.adjoint()).is_identity(1.0e-7));
+                        prop_assert!(t.rows(rank, t.nrows() - rank).iter().all(|e| e.is_zero()));
+                        prop_assert!(t.columns(rank, t.ncols() - rank).iter().all(|e| e.is_zero()));
+                    }
+
+                    #[test]
+                    fn cod_static_5_3(m in matrix5x3_($scalar)) {
+                        let (q, t, z) = m.cod(1.0e-9).unpack();
+
+                        prop_assert!(relative_eq!(m, q * t * z, epsilon = 1.0e-7));
+                        prop_assert!(q.is_orthogonal(1.0e-7));
+                        prop_assert!(z.is_orthogonal(1.0e-7));
+                    }
+
+                    #[test]
+                    fn cod_static_3_5(m in matrix3x5_($scalar)) {
+                        let (q, t, z) = m.cod(1.0e-9).unpack();
+
+                        prop_assert!(relative_eq!(m, q * t * z, epsilon = 1.0e-7));
+                        prop_assert!(q.is_orthogonal(1.0e-7));
+                        prop_assert!((z * z.adjoint()).is_identity(1.0e-7));
+                    }
+
+                    #[test]
+                    fn cod_static_square(m in matrix4_($scalar)) {
+                        let (q, t, z) = m.cod(1.0e-9).unpack();
+
+                        prop_assert!(relative_eq!(m, q * t * z, epsilon = 1.0e-7));
+                        prop_assert!(q.is_orthogonal(1.0e-7));
+                        prop_assert!(z.is_orthogonal(1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
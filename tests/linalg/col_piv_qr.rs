@@ -1,6 +1,6 @@
 #[cfg_attr(rustfmt, rustfmt_skip)]
 
-use na::Matrix4;
+use na::{Matrix1x2, Matrix3x2, Matrix4, Vector1, Vector2, Vector3};
 
 #[test]
 fn col_piv_qr() {
@@ -22,6 +22,38 @@ fn col_piv_qr() {
     assert!(relative_eq!(m, qr, epsilon = 1.0e-7));
 }
 
+#[test]
+fn col_piv_qr_min_norm() {
+    // Underdetermined system.
+    let m = Matrix1x2::new(1.0, 1.0);
+    let (x, residual) = m.col_piv_qr().solve_min_norm(&Vector1::new(2.0), 1.0e-10);
+    assert_relative_eq!(x, Vector2::new(1.0, 1.0), epsilon = 1.0e-10);
+    assert_relative_eq!(residual, 0.0, epsilon = 1.0e-10);
+
+    // Rank-deficient overdetermined system.
+    let m = Matrix3x2::new(1.0, 2.0, 2.0, 4.0, 3.0, 6.0);
+    let col_piv_qr = m.col_piv_qr();
+    assert_eq!(col_piv_qr.rank(1.0e-10), 1);
+
+    let (x, residual) = col_piv_qr.solve_min_norm(&Vector3::new(1.0, 2.0, 3.0), 1.0e-10);
+    assert_relative_eq!(x, Vector2::new(0.2, 0.4), epsilon = 1.0e-10);
+    assert_relative_eq!(residual, 0.0, epsilon = 1.0e-10);
+
+    let (x, residual) = m.lstsq(&Vector3::new(1.0, 0.0, 0.0), 1.0e-10);
+    assert_relative_eq!(
+        m.transpose() * (m * x - Vector3::new(1.0, 0.0, 0.0)),
+        Vector2::zeros(),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(x, Vector2::new(1.0, 2.0) / 70.0, epsilon = 1.0e-10);
+    assert_relative_eq!(residual, (13.0f64 / 14.0).sqrt(), epsilon = 1.0e-10);
+
+    // Zero matrix.
+    let (x, residual) = Matrix3x2::zeros().lstsq(&Vector3::new(1.0, 2.0, 2.0), 1.0e-10);
+    assert_eq!(x, Vector2::zeros());
+    assert_relative_eq!(residual, 3.0, epsilon = 1.0e-10);
+}
+
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
     macro_rules! gen_tests(
@@ -116,6 +148,43 @@ mod proptest_tests {
                          }
                     }
 
+                    #[test]
+                    fn col_piv_qr_least_squares(m in dmatrix_($scalar), nb in PROPTEST_MATRIX_DIM) {
+                        let m = if m.nrows() < m.ncols() { m.transpose() } else { m };
+                        let b = DMatrix::<$scalar_type>::new_random(m.nrows(), nb).map(|e| e.0);
+
+                        if let Some((x, residual)) = m.clone().col_piv_qr().solve_least_squares(&b) {
+                            let r = &m * &x - &b;
+
+                            prop_assert!(relative_eq!(m.adjoint() * &r, DMatrix::zeros(m.ncols(), nb), epsilon = 1.0e-6));
+                            prop_assert!(relative_eq!(residual, r.norm(), epsilon = 1.0e-7));
+                        }
+                    }
+
+                    #[test]
+                    fn col_piv_qr_min_norm(nrows in PROPTEST_MATRIX_DIM, ncols in PROPTEST_MATRIX_DIM, rank in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        // Keep `c` wide so that it is well-conditioned.
+                        let rank = cmp::min(rank, cmp::min(nrows, ncols.saturating_sub(1)));
+                        let a = DMatrix::<$scalar_type>::new_random(nrows, rank).map(|e| e.0).qr().q();
+                        let c = DMatrix::<$scalar_type>::new_random(rank, ncols).map(|e| e.0);
+                        let m = &a * &c;
+                        let b = DMatrix::<$scalar_type>::new_random(nrows, nb).map(|e| e.0);
+
+                        let col_piv_qr = m.clone().col_piv_qr();
+                        let (x, residual) = col_piv_qr.solve_min_norm(&b, 1.0e-9);
+                        let r = &m * &x - &b;
+
+                        // The minimum-norm least-squares solution is given by the pseudo-inverse
+                        // `(a * c)⁺ = c⁺ * aᴴ`, since `a` has orthonormal columns.
+                        let cct = (&c * c.adjoint()).try_inverse().unwrap();
+                        let expected = c.adjoint() * cct * a.adjoint() * &b;
+
+                        prop_assert!(col_piv_qr.rank(1.0e-9) <= rank);
+                        prop_assert!(relative_eq!(x, expected, epsilon = 1.0e-6));
+                        prop_assert!(relative_eq!(residual, r.norm(), epsilon = 1.0e-6));
+                        prop_assert!(relative_eq!(m.lstsq(&b, 1.0e-9).0, x, epsilon = 1.0e-6));
+                    }
+
                     #[test]
                     fn col_piv_qr_inverse(n in PROPTEST_MATRIX_DIM) {
                         let n = cmp::max(1, cmp::min(n, 15)); // To avoid slowing down the test too much.
@@ -0,0 +1,91 @@
+use na::{
+    DMatrix, EuclideanNorm, InducedInfinityNorm, InducedOneNorm, Matrix2x3, Matrix3, SpectralNorm,
+};
+
+#[test]
+#[rustfmt::skip]
+fn induced_norms() {
+    let m = Matrix2x3::new(1.0, -2.0, 3.0,
+                           -4.0, 5.0, 0.5);
+
+    assert_eq!(m.apply_norm(&InducedOneNorm), 7.0);
+    assert_eq!(m.apply_norm(&InducedInfinityNorm), 9.5);
+    assert_relative_eq!(m.apply_norm(&SpectralNorm), m.singular_values()[0], epsilon = 1.0e-12);
+
+    let m2 = Matrix2x3::zeros();
+    assert_eq!(m.apply_metric_distance(&m2, &InducedOneNorm), 7.0);
+    assert_eq!(m.apply_metric_distance(&m2, &InducedInfinityNorm), 9.5);
+    assert_relative_eq!(
+        m.apply_metric_distance(&m2, &SpectralNorm),
+        m.apply_norm(&SpectralNorm),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+#[rustfmt::skip]
+fn cond_singular() {
+    let m = Matrix3::new(1.0, 2.0, 3.0,
+                         4.0, 5.0, 6.0,
+                         0.0, 0.0, 0.0);
+
+    assert_eq!(m.cond(&SpectralNorm), f64::INFINITY);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::{DMatrix, EuclideanNorm, InducedInfinityNorm, InducedOneNorm, SpectralNorm};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn induced_norms(m in dmatrix_($scalar)) {
+                        let one_norm = m.apply_norm(&InducedOneNorm);
+                        let inf_norm = m.apply_norm(&InducedInfinityNorm);
+                        let spectral_norm = m.apply_norm(&SpectralNorm);
+
+                        prop_assert!(relative_eq!(one_norm, m.adjoint().apply_norm(&InducedInfinityNorm), epsilon = 1.0e-7));
+                        prop_assert!(spectral_norm <= m.norm() * (1.0 + 1.0e-7));
+                        prop_assert!(spectral_norm * spectral_norm <= one_norm * inf_norm * (1.0 + 1.0e-7));
+                    }
+
+                    #[test]
+                    fn cond(n in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let m1 = m.clone().try_inverse().unwrap();
+                        let sv = m.singular_values();
+
+                        prop_assert!(relative_eq!(m.cond(&SpectralNorm), sv[0] / sv[n - 1], max_relative = 1.0e-7));
+                        prop_assert!(relative_eq!(
+                            m.cond(&InducedOneNorm),
+                            m.apply_norm(&InducedOneNorm) * m1.apply_norm(&InducedOneNorm),
+                            max_relative = 1.0e-7
+                        ));
+                        prop_assert!(relative_eq!(
+                            m.cond(&EuclideanNorm),
+                            m.norm() * m1.norm(),
+                            max_relative = 1.0e-7
+                        ));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
+
+#[test]
+fn cond_identity() {
+    let m = DMatrix::<f64>::identity(4, 4);
+
+    assert_relative_eq!(m.cond(&SpectralNorm), 1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(m.cond(&EuclideanNorm), 4.0, epsilon = 1.0e-12);
+}
@@ -1,4 +1,4 @@
-use na::DMatrix;
+use na::{Complex, DMatrix, DVector, Matrix3};
 
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
@@ -116,88 +116,174 @@ fn symmetric_eigen_singular_24x24() {
     );
 }
 
-//  #[cfg(feature = "arbitrary")]
-//  quickcheck! {
-// TODO: full eigendecomposition is not implemented yet because of its complexity when some
-// eigenvalues have multiplicity > 1.
-//
-//    /*
-//     * NOTE: for the following tests, we use only upper-triangular matrices.
-//     * Thes ensures the schur decomposition will work, and allows use to test the eigenvector
-//     * computation.
-//     */
-//    fn eigen(n: usize) -> bool {
-//        let n = cmp::max(1, cmp::min(n, 10));
-//        let m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_with_adjascent_duplicate_diagonals(n: usize) -> bool {
-//        let n = cmp::max(1, cmp::min(n, 10));
-//        let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        // Suplicate some adjascent diagonal elements.
-//        for i in 0 .. n / 2 {
-//            m[(i * 2 + 1, i * 2 + 1)] = m[(i * 2, i * 2)];
-//        }
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_with_nonadjascent_duplicate_diagonals(n: usize) -> bool {
-//        let n = cmp::max(3, cmp::min(n, 10));
-//        let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        // Suplicate some diagonal elements.
-//        for i in n / 2 .. n {
-//            m[(i, i)] = m[(i - n / 2, i - n / 2)];
-//        }
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_4x4(m: Matrix4<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_3x3(m: Matrix3<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_2x2(m: Matrix2<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        println!("{}", m);
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//  }
-//
-// fn verify_eigenvectors<D: Dim>(m: OMatrix<f64, D>, mut eig: RealEigen<f64, D>) -> bool
-//     where DefaultAllocator: Allocator<f64, D, D>   +
-//                             Allocator<f64, D>      +
-//                             Allocator<usize, D, D> +
-//                             Allocator<usize, D>,
-//           OMatrix<f64, D>: Display,
-//           OVector<f64, D>: Display {
-//     let mv = &m * &eig.eigenvectors;
-//
-//     println!("eigenvalues: {}eigenvectors: {}", eig.eigenvalues, eig.eigenvectors);
-//
-//     let dim = m.nrows();
-//     for i in 0 .. dim {
-//         let mut col = eig.eigenvectors.column_mut(i);
-//         col *= eig.eigenvalues[i];
-//     }
-//
-//     println!("{}{:.5}{:.5}", m, mv, eig.eigenvectors);
-//
-//     relative_eq!(eig.eigenvectors, mv, epsilon = 1.0e-5)
-// }
+#[test]
+#[rustfmt::skip]
+fn symmetric_eigen_nearly_equal_eigenvalues_3x3() {
+    // The last 2x2 subproblem has an eigenvector that used to be computed with a catastrophic
+    // cancellation.
+    let m = DMatrix::from_row_slice(3, 3, &[
+        -0.0011188556447897166, 0.1124092634082775,   0.0,
+         0.1124092634082775,   -8.74538732621953,     0.02026745927734967,
+         0.0,                   0.02026745927734967,  0.00016120293363677016,
+    ]);
+
+    let eig = m.clone().symmetric_eigen();
+    let x = &eig.eigenvectors;
+
+    assert_relative_eq!(x.transpose() * x, DMatrix::identity(3, 3), epsilon = 1.0e-12);
+    assert_relative_eq!(&m * x, x * DMatrix::from_diagonal(&eig.eigenvalues), epsilon = 1.0e-12);
+}
+
+#[cfg(feature = "proptest-support")]
+mod eigen_proptest_tests {
+    use na::{Complex, DMatrix};
+
+    use crate::proptest::*;
+    use proptest::{prop_assert, proptest};
+
+    use super::{verify_left_eigenvectors, verify_right_eigenvectors};
+
+    proptest! {
+        #[test]
+        fn eigen(n in PROPTEST_MATRIX_DIM) {
+            let n   = n.clamp(1, 10);
+            let m   = DMatrix::<f64>::new_random(n, n);
+            let eig = m.clone().eigen();
+
+            prop_assert!(verify_right_eigenvectors(&m, &eig.eigenvalues, &eig.eigenvectors))
+        }
+
+        #[test]
+        fn eigen_left(m in dmatrix()) {
+            let n   = m.nrows();
+            let m   = m.resize(n, n, 0.0);
+            let eig = na::Eigen::new_with_left_eigenvectors(m.clone());
+            let left = eig.left_eigenvectors.unwrap();
+
+            prop_assert!(verify_right_eigenvectors(&m, &eig.eigenvalues, &eig.eigenvectors));
+            prop_assert!(verify_left_eigenvectors(&m, &eig.eigenvalues, &left))
+        }
+
+        #[test]
+        fn eigen_complex_eigenvalues_match(m in dmatrix()) {
+            let n   = m.nrows();
+            let m   = m.resize(n, n, 0.0);
+            let eig = m.clone().eigen();
+            let expected = m.complex_eigenvalues();
+
+            prop_assert!(relative_eq!(eig.eigenvalues, expected, epsilon = 1.0e-7))
+        }
+
+        #[test]
+        fn eigen_static_square_4x4(m in matrix4()) {
+            let eig = m.eigen();
+            let m   = m.map(|e| Complex::new(e, 0.0));
+            let vals = eig.eigenvalues;
+            let vecs = eig.eigenvectors;
+
+            prop_assert!(relative_eq!(m * vecs, vecs * na::Matrix4::from_diagonal(&vals), epsilon = 1.0e-7))
+        }
+
+        #[test]
+        fn eigen_static_square_3x3(m in matrix3()) {
+            let eig = m.eigen();
+            let m   = m.map(|e| Complex::new(e, 0.0));
+            let vals = eig.eigenvalues;
+            let vecs = eig.eigenvectors;
+
+            prop_assert!(relative_eq!(m * vecs, vecs * na::Matrix3::from_diagonal(&vals), epsilon = 1.0e-7))
+        }
+
+        #[test]
+        fn eigen_static_square_2x2(m in matrix2()) {
+            let eig = m.eigen();
+            let m   = m.map(|e| Complex::new(e, 0.0));
+            let vals = eig.eigenvalues;
+            let vecs = eig.eigenvectors;
+
+            prop_assert!(relative_eq!(m * vecs, vecs * na::Matrix2::from_diagonal(&vals), epsilon = 1.0e-7))
+        }
+    }
+}
+
+fn verify_right_eigenvectors(
+    m: &DMatrix<f64>,
+    vals: &DVector<Complex<f64>>,
+    vecs: &DMatrix<Complex<f64>>,
+) -> bool {
+    let m = m.map(|e| Complex::new(e, 0.0));
+    let scale = m.camax().max(1.0);
+    relative_eq!(
+        &m * vecs,
+        vecs * DMatrix::from_diagonal(vals),
+        epsilon = 1.0e-7 * scale
+    )
+}
+
+fn verify_left_eigenvectors(
+    m: &DMatrix<f64>,
+    vals: &DVector<Complex<f64>>,
+    vecs: &DMatrix<Complex<f64>>,
+) -> bool {
+    let m = m.map(|e| Complex::new(e, 0.0));
+    let scale = m.camax().max(1.0);
+    relative_eq!(
+        vecs.adjoint() * &m,
+        DMatrix::from_diagonal(vals) * vecs.adjoint(),
+        epsilon = 1.0e-7 * scale
+    )
+}
+
+#[test]
+fn eigen_rotation_has_complex_conjugate_eigenvalues() {
+    let m = Matrix3::new(0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 2.0);
+    let eig = m.eigen();
+
+    let mut vals: Vec<_> = eig.eigenvalues.iter().cloned().collect();
+    vals.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+    assert_relative_eq!(vals[0], Complex::new(0.0, -1.0), epsilon = 1.0e-10);
+    assert_relative_eq!(vals[1], Complex::new(2.0, 0.0), epsilon = 1.0e-10);
+    assert_relative_eq!(vals[2], Complex::new(0.0, 1.0), epsilon = 1.0e-10);
+
+    let mc = m.map(|e| Complex::new(e, 0.0));
+    assert_relative_eq!(
+        mc * eig.eigenvectors,
+        eig.eigenvectors * Matrix3::from_diagonal(&eig.eigenvalues),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn eigen_with_repeated_eigenvalues() {
+    let m = DMatrix::from_row_slice(
+        4,
+        4,
+        &[
+            2.0, 0.0, 0.0, 0.0, //
+            0.0, 2.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, -3.0, //
+            0.0, 0.0, 3.0, 1.0,
+        ],
+    );
+    let eig = na::Eigen::new_with_left_eigenvectors(m.clone());
+
+    assert!(verify_right_eigenvectors(
+        &m,
+        &eig.eigenvalues,
+        &eig.eigenvectors
+    ));
+    assert!(verify_left_eigenvectors(
+        &m,
+        &eig.eigenvalues,
+        eig.left_eigenvectors.as_ref().unwrap()
+    ));
+}
+
+#[test]
+fn eigen_empty() {
+    let m = DMatrix::<f64>::zeros(0, 0);
+    let eig = m.eigen();
+
+    assert!(eig.eigenvalues.is_empty());
+    assert!(eig.eigenvectors.is_empty());
+}
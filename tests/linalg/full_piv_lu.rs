@@ -126,6 +126,18 @@ mod proptest_tests {
                          prop_assert!(sol2.is_none() || relative_eq!(&m * sol2.unwrap(), b2, epsilon = 1.0e-6));
                     }
 
+                    #[test]
+                    fn full_piv_lu_solve_refined(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let b = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+
+                        if let Some(refined) = m.clone().full_piv_lu().solve_refined(&m, &b, 10) {
+                            prop_assert!(refined.iterations <= 10);
+                            prop_assert!(refined.backward_error <= 1.0e-12);
+                            prop_assert!(relative_eq!(&m * refined.solution, b, epsilon = 1.0e-6));
+                        }
+                    }
+
                     #[test]
                     fn full_piv_lu_inverse(n in PROPTEST_MATRIX_DIM) {
                         let n = cmp::max(1, cmp::min(n, 15)); // To avoid slowing down the test too much.
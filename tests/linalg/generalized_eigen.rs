@@ -0,0 +1,60 @@
+use na::{Complex, Matrix3};
+
+#[test]
+#[rustfmt::skip]
+fn generalized_eigen_infinite_eigenvalue() {
+    let a = Matrix3::new(1.0, 2.0, 3.0,
+                         4.0, 5.0, 6.0,
+                         7.0, 8.0, 10.0);
+    let b = Matrix3::new(1.0, 0.0, 0.0,
+                         0.0, 1.0, 0.0,
+                         0.0, 0.0, 0.0);
+
+    let eig = a.generalized_eigen(b);
+    assert_eq!(eig.betas.iter().filter(|beta| **beta <= 1.0e-10).count(), 1);
+
+    let (a, b) = (a.map(Complex::from), b.map(Complex::from));
+    for i in 0..3 {
+        let x = eig.eigenvectors.column(i);
+        let alpha = eig.alphas[i];
+        let beta = Complex::from(eig.betas[i]);
+        assert_relative_eq!(a * x * beta, b * x * alpha, epsilon = 1.0e-7);
+    }
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use crate::proptest::*;
+    use na::{Complex, DMatrix, Matrix4};
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn generalized_eigen(n in PROPTEST_MATRIX_DIM) {
+            let a = DMatrix::<f64>::new_random(n, n);
+            let b = DMatrix::<f64>::new_random(n, n);
+            let eig = a.clone().generalized_eigen(b.clone());
+            let (a, b) = (a.map(Complex::from), b.map(Complex::from));
+
+            for i in 0..n {
+                let x = eig.eigenvectors.column(i);
+                let alpha = eig.alphas[i];
+                let beta = Complex::from(eig.betas[i]);
+                prop_assert!(relative_eq!(&a * x * beta, &b * x * alpha, epsilon = 1.0e-7));
+            }
+        }
+
+        #[test]
+        fn generalized_eigen_static_mat4(a in matrix4(), b in matrix4()) {
+            let eig = a.generalized_eigen(b);
+            let (a, b): (Matrix4<Complex<f64>>, _) = (a.map(Complex::from), b.map(Complex::from));
+
+            for i in 0..4 {
+                let x = eig.eigenvectors.column(i);
+                let alpha = eig.alphas[i];
+                let beta = Complex::from(eig.betas[i]);
+                prop_assert!(relative_eq!(a * x * beta, b * x * alpha, epsilon = 1.0e-7));
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use na::Matrix3;
+
+#[test]
+#[rustfmt::skip]
+fn generalized_schur_singular_b() {
+    let a = Matrix3::new(1.0, 2.0, 3.0,
+                         4.0, 5.0, 6.0,
+                         7.0, 8.0, 10.0);
+    let b = Matrix3::new(1.0, 0.0, 0.0,
+                         0.0, 1.0, 0.0,
+                         0.0, 0.0, 0.0);
+
+    let schur = a.generalized_schur(b);
+    let (_, betas) = schur.complex_eigenvalues();
+    let (q, s, t, z) = schur.unpack();
+
+    assert!(relative_eq!(q * s * z.transpose(), a, epsilon = 1.0e-7));
+    assert!(relative_eq!(q * t * z.transpose(), b, epsilon = 1.0e-7));
+    assert_eq!(betas.iter().filter(|beta| **beta <= 1.0e-10).count(), 1);
+}
+
+#[test]
+#[rustfmt::skip]
+fn generalized_schur_complex_eigenvalues() {
+    // The eigenvalues of this pair are `±i` and `2 / 3`.
+    let a = Matrix3::new(0.0, -1.0, 0.0,
+                         1.0,  0.0, 0.0,
+                         0.0,  0.0, 2.0);
+    let b = Matrix3::new(1.0, 0.0, 0.0,
+                         0.0, 1.0, 0.0,
+                         0.0, 0.0, 3.0);
+
+    let (alphas, betas) = a.generalized_schur(b).complex_eigenvalues();
+    let mut lambdas: Vec<_> = alphas.iter().zip(betas.iter()).map(|(a, b)| a / *b).collect();
+    lambdas.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+
+    assert_relative_eq!(lambdas[0], na::Complex::new(0.0, -1.0), epsilon = 1.0e-10);
+    assert_relative_eq!(lambdas[1], na::Complex::new(2.0 / 3.0, 0.0), epsilon = 1.0e-10);
+    assert_relative_eq!(lambdas[2], na::Complex::new(0.0, 1.0), epsilon = 1.0e-10);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::DMatrix;
+                use num::Zero;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn generalized_schur(n in PROPTEST_MATRIX_DIM) {
+                        let a = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let b = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let (q, s, t, z) = a.clone().generalized_schur(b.clone()).unpack();
+
+                        prop_assert!(relative_eq!(&q * &s * z.adjoint(), a, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(&q * &t * z.adjoint(), b, epsilon = 1.0e-7));
+                        prop_assert!(q.is_orthogonal(1.0e-7));
+                        prop_assert!(z.is_orthogonal(1.0e-7));
+                        prop_assert!(t.lower_triangle() == DMatrix::from_diagonal(&t.diagonal()));
+
+                        for i in 2..n {
+                            for j in 0..i - 1 {
+                                prop_assert!(s[(i, j)].is_zero());
+                            }
+                        }
+                    }
+
+                    #[test]
+                    fn generalized_schur_static_mat4(a in matrix4_($scalar), b in matrix4_($scalar)) {
+                        let (q, s, t, z) = a.clone().generalized_schur(b.clone()).unpack();
+
+                        prop_assert!(relative_eq!(q * s * z.adjoint(), a, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(q * t * z.adjoint(), b, epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
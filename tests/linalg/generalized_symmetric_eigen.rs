@@ -0,0 +1,76 @@
+use na::{DMatrix, Matrix3};
+
+#[test]
+#[rustfmt::skip]
+fn generalized_symmetric_eigen_mat3() {
+    let a = Matrix3::new(2.0, 1.0, 0.0,
+                         1.0, 3.0, 1.0,
+                         0.0, 1.0, 4.0);
+    let b = Matrix3::new(4.0, 1.0, 0.0,
+                         1.0, 3.0, 0.0,
+                         0.0, 0.0, 2.0);
+
+    let eig = a.generalized_symmetric_eigen(b).unwrap();
+    let x = &eig.eigenvectors;
+
+    assert_relative_eq!(a * x, b * x * Matrix3::from_diagonal(&eig.eigenvalues), epsilon = 1.0e-7);
+    assert_relative_eq!(x.transpose() * b * x, Matrix3::identity(), epsilon = 1.0e-7);
+    assert_relative_eq!(eig.recompose(&b), a, epsilon = 1.0e-7);
+}
+
+#[test]
+fn generalized_symmetric_eigen_not_positive_definite() {
+    let a = DMatrix::<f64>::identity(3, 3);
+    let b = -DMatrix::<f64>::identity(3, 3);
+
+    assert!(a.generalized_symmetric_eigen(b).is_none());
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::{DMatrix, Matrix4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    // `B` is shifted by its norm to be well-conditioned.
+                    #[test]
+                    fn generalized_symmetric_eigen(n in PROPTEST_MATRIX_DIM) {
+                        let a = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let b = &m * m.adjoint() + DMatrix::from_diagonal_element(n, n, na::convert(m.norm_squared() + 1.0));
+
+                        let eig = a.clone().generalized_symmetric_eigen(b.clone()).unwrap();
+                        let x = &eig.eigenvectors;
+                        let lambda = DMatrix::from_diagonal(&eig.eigenvalues.map(|e| e.into()));
+
+                        prop_assert!(relative_eq!(&a * x, &b * x * lambda, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(x.adjoint() * &b * x, DMatrix::identity(n, n), epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(eig.recompose(&b), a, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn generalized_symmetric_eigen_static_mat4(a in matrix4_($scalar), m in matrix4_($scalar)) {
+                        let a = a.hermitian_part();
+                        let b = m * m.adjoint() + Matrix4::from_diagonal_element(na::convert(m.norm_squared() + 1.0));
+
+                        let eig = a.generalized_symmetric_eigen(b).unwrap();
+                        let x = eig.eigenvectors;
+                        let lambda = Matrix4::from_diagonal(&eig.eigenvalues.map(|e| e.into()));
+
+                        prop_assert!(relative_eq!(a * x, b * x * lambda, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(x.adjoint() * b * x, Matrix4::identity(), epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
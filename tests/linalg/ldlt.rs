@@ -0,0 +1,179 @@
+use na::{Matrix2, Matrix3, Vector3};
+
+#[test]
+#[rustfmt::skip]
+fn ldlt_simple_indefinite() {
+    // The diagonal is zero, so that a 2x2 pivot is required.
+    let m = Matrix3::new(
+        0.0, 1.0, 2.0,
+        1.0, 0.0, 3.0,
+        2.0, 3.0, 0.0);
+
+    let ldlt = m.ldlt();
+    assert_relative_eq!(ldlt.determinant(), 12.0, epsilon = 1.0e-7);
+    assert_eq!(ldlt.inertia(), (1, 2, 0));
+    assert!(ldlt.is_invertible());
+
+    let b = Vector3::new(1.0, 2.0, 3.0);
+    let x = ldlt.solve(&b).unwrap();
+    assert_relative_eq!(m * x, b, epsilon = 1.0e-7);
+
+    let (p, l, d) = ldlt.unpack();
+    let mut ldlt = l * d * l.transpose();
+    p.inv_permute_rows(&mut ldlt);
+    p.inv_permute_columns(&mut ldlt);
+
+    assert_relative_eq!(m, ldlt, epsilon = 1.0e-7);
+}
+
+#[test]
+#[rustfmt::skip]
+fn ldlt_reads_lower_triangle_only() {
+    let m = Matrix3::new(
+        4.0, 100.0, 100.0,
+        2.0,  -3.0, 100.0,
+        1.0,   0.5,   2.0);
+    let symmetric = m.lower_triangle() + m.lower_triangle().transpose() - Matrix3::from_diagonal(&m.diagonal());
+
+    let (p, l, d) = m.ldlt().unpack();
+    let mut ldlt = l * d * l.transpose();
+    p.inv_permute_rows(&mut ldlt);
+    p.inv_permute_columns(&mut ldlt);
+
+    assert_relative_eq!(symmetric, ldlt, epsilon = 1.0e-7);
+}
+
+#[test]
+#[rustfmt::skip]
+fn ldlt_singular() {
+    let m = Matrix2::new(
+        1.0, 1.0,
+        1.0, 1.0);
+
+    let ldlt = m.ldlt();
+    assert_eq!(ldlt.inertia(), (1, 0, 1));
+    assert_eq!(ldlt.determinant(), 0.0);
+    assert!(!ldlt.is_invertible());
+    assert!(ldlt.solve(&na::Vector2::new(1.0, 2.0)).is_none());
+    assert!(ldlt.try_inverse().is_none());
+
+    let zero = Matrix3::<f64>::zeros().ldlt();
+    assert_eq!(zero.inertia(), (0, 0, 3));
+    assert_eq!(zero.l(), Matrix3::identity());
+    assert_eq!(zero.d(), Matrix3::zeros());
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::{DMatrix, DVector, Matrix4, Matrix4x3, Vector4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, prop_assert_eq, proptest};
+                use simba::scalar::ComplexField;
+
+                proptest! {
+                    #[test]
+                    fn ldlt(m in dmatrix_($scalar)) {
+                        let n = m.nrows().min(m.ncols());
+                        let m = m.slice_range(..n, ..n).hermitian_part();
+                        let (p, l, d) = m.clone().ldlt().unpack();
+                        let mut ldlt = &l * d * l.adjoint();
+                        p.inv_permute_rows(&mut ldlt);
+                        p.inv_permute_columns(&mut ldlt);
+
+                        prop_assert!(relative_eq!(m, ldlt, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn ldlt_static(m in matrix4_($scalar)) {
+                        let m = m.hermitian_part();
+                        let (p, l, d) = m.ldlt().unpack();
+                        let mut ldlt = l * d * l.adjoint();
+                        p.inv_permute_rows(&mut ldlt);
+                        p.inv_permute_columns(&mut ldlt);
+
+                        prop_assert!(relative_eq!(m, ldlt, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn ldlt_solve(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let m = &m + m.adjoint();
+
+                        let ldlt = m.clone().ldlt();
+                        let b1 = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+                        let b2 = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+
+                        let sol1 = ldlt.solve(&b1);
+                        let sol2 = ldlt.solve(&b2);
+
+                        prop_assert!(sol1.is_none() || relative_eq!(&m * sol1.unwrap(), b1, epsilon = 1.0e-6));
+                        prop_assert!(sol2.is_none() || relative_eq!(&m * sol2.unwrap(), b2, epsilon = 1.0e-6));
+                    }
+
+                    #[test]
+                    fn ldlt_solve_static(m in matrix4_($scalar)) {
+                        let m = m.hermitian_part();
+                        let ldlt = m.ldlt();
+                        let b1 = Vector4::<$scalar_type>::new_random().map(|e| e.0);
+                        let b2 = Matrix4x3::<$scalar_type>::new_random().map(|e| e.0);
+
+                        let sol1 = ldlt.solve(&b1);
+                        let sol2 = ldlt.solve(&b2);
+
+                        prop_assert!(sol1.is_none() || relative_eq!(m * sol1.unwrap(), b1, epsilon = 1.0e-6));
+                        prop_assert!(sol2.is_none() || relative_eq!(m * sol2.unwrap(), b2, epsilon = 1.0e-6));
+                    }
+
+                    #[test]
+                    fn ldlt_inverse(n in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let mut l = m.lower_triangle();
+                        l.fill_diagonal(na::one());
+
+                        // A well-conditioned indefinite matrix.
+                        let mut d = DMatrix::identity(n, n) * l[(0, 0)];
+                        for i in (1..n).step_by(2) {
+                            d[(i, i)] = -d[(i, i)];
+                        }
+                        let m = &l * d * l.adjoint();
+
+                        let m1 = m.clone().ldlt().try_inverse().unwrap();
+                        let id1 = &m * &m1;
+                        let id2 = &m1 * &m;
+
+                        prop_assert!(id1.is_identity(1.0e-5));
+                        prop_assert!(id2.is_identity(1.0e-5));
+                    }
+
+                    #[test]
+                    fn ldlt_determinant(m in dmatrix_($scalar)) {
+                        let n = m.nrows().min(m.ncols());
+                        let m = m.slice_range(..n, ..n).hermitian_part();
+                        let lu_det = m.clone().lu().determinant();
+                        let ldlt_det = m.ldlt().determinant();
+
+                        prop_assert!(relative_eq!(lu_det.real(), ldlt_det, epsilon = 1.0e-7, max_relative = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn ldlt_inertia(m in matrix4_($scalar)) {
+                        let m: Matrix4<_> = m.hermitian_part();
+                        let eigenvalues = m.symmetric_eigenvalues();
+                        let positive = eigenvalues.iter().filter(|&&e| e > 0.0).count();
+                        let negative = eigenvalues.iter().filter(|&&e| e < 0.0).count();
+
+                        prop_assert_eq!(m.ldlt().inertia(), (positive, negative, 0));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
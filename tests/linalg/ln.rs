@@ -0,0 +1,109 @@
+use na::{DMatrix, Matrix2, Matrix3, Rotation3, Vector3};
+
+#[test]
+fn ln_rotation() {
+    let axisangle = Vector3::new(0.3, -0.7, 1.1);
+    let rot = Rotation3::new(axisangle).into_inner();
+
+    assert_relative_eq!(
+        rot.ln().unwrap(),
+        axisangle.cross_matrix(),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn ln_identity() {
+    assert_relative_eq!(
+        Matrix3::<f64>::identity().ln().unwrap(),
+        Matrix3::zeros(),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn ln_negative_eigenvalue() {
+    let m = Matrix2::new(-1.0, 0.0, 3.0, 2.0);
+    assert!(m.ln().is_none());
+    assert!(m.powf(0.5).is_none());
+}
+
+#[test]
+fn powf_integer() {
+    let m = Matrix2::new(4.0, 1.0, 2.0, 3.0);
+
+    assert_relative_eq!(m.powf(3.0).unwrap(), m.pow(3), epsilon = 1.0e-9);
+    assert_relative_eq!(
+        m.powf(-1.0).unwrap(),
+        m.try_inverse().unwrap(),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn ln_large_dimension() {
+    // The determinant of this matrix overflows.
+    let m = DMatrix::from_diagonal_element(128, 128, 1.0e3);
+    assert_relative_eq!(
+        m.ln().unwrap(),
+        DMatrix::from_diagonal_element(128, 128, f64::ln(1.0e3)),
+        epsilon = 1.0e-10
+    );
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::{DMatrix, Matrix4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use std::cmp;
+
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn ln_exp(n in PROPTEST_MATRIX_DIM) {
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let m = m.unscale(m.norm());
+
+                        prop_assert!(relative_eq!(m.exp().ln().unwrap(), m, epsilon = 1.0e-7));
+                    }
+
+                    // The matrices are shifted so that their eigenvalues have a positive real part.
+                    #[test]
+                    fn ln_static_square_4x4(m in matrix4_($scalar)) {
+                        let m = m.unscale(m.norm() + 1.0) + Matrix4::from_diagonal_element(na::convert(2.0));
+
+                        prop_assert!(relative_eq!(m.ln().unwrap().exp(), m, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn powf_static_square_4x4(m in matrix4_($scalar), p in -2.0..2.0f64) {
+                        let m = m.unscale(m.norm() + 1.0) + Matrix4::from_diagonal_element(na::convert(2.0));
+                        let powf = m.powf(p).unwrap();
+
+                        prop_assert!(relative_eq!(powf * m, m * powf, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(powf * m.powf(-p).unwrap(), Matrix4::identity(), epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(m.powf(0.5).unwrap(), m.sqrt().unwrap(), epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn ln_map_symmetric(m in matrix4_($scalar)) {
+                        let m = m.unscale(m.norm() + 1.0);
+                        let m = m * m.adjoint() + Matrix4::identity();
+
+                        prop_assert!(relative_eq!(m.ln().unwrap(), m.map_symmetric(|e| e.ln()), epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
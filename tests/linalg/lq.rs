@@ -0,0 +1,55 @@
+#![cfg(feature = "proptest-support")]
+
+macro_rules! gen_tests(
+    ($module: ident, $scalar: expr) => {
+        mod $module {
+            #[allow(unused_imports)]
+            use crate::core::helper::{RandScalar, RandComplex};
+            use crate::proptest::*;
+            use proptest::{prop_assert, proptest};
+
+            proptest! {
+                #[test]
+                fn lq(m in dmatrix_($scalar)) {
+                    let lq = m.clone().lq();
+                    let l  = lq.l();
+                    let q  = lq.q();
+
+                    prop_assert!(relative_eq!(m, &l * &q, epsilon = 1.0e-7));
+                    prop_assert!((&q * q.adjoint()).is_identity(1.0e-7));
+                    prop_assert!(l.lower_triangle() == l);
+                }
+
+                #[test]
+                fn lq_static_5_3(m in matrix5x3_($scalar)) {
+                    let (l, q) = m.lq().unpack();
+
+                    prop_assert!(relative_eq!(m, l * q, epsilon = 1.0e-7));
+                    prop_assert!(q.is_orthogonal(1.0e-7));
+                    prop_assert!(l.lower_triangle() == l);
+                }
+
+                #[test]
+                fn lq_static_3_5(m in matrix3x5_($scalar)) {
+                    let (l, q) = m.lq().unpack();
+
+                    prop_assert!(relative_eq!(m, l * q, epsilon = 1.0e-7));
+                    prop_assert!((q * q.adjoint()).is_identity(1.0e-7));
+                    prop_assert!(l.lower_triangle() == l);
+                }
+
+                #[test]
+                fn lq_static_square(m in matrix4_($scalar)) {
+                    let (l, q) = m.lq().unpack();
+
+                    prop_assert!(relative_eq!(m, l * q, epsilon = 1.0e-7));
+                    prop_assert!(q.is_orthogonal(1.0e-7));
+                    prop_assert!(l.lower_triangle() == l);
+                }
+            }
+        }
+    }
+);
+
+gen_tests!(complex, complex_f64());
+gen_tests!(f64, PROPTEST_F64);
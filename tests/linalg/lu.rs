@@ -19,6 +19,13 @@ fn lu_simple() {
     assert!(relative_eq!(m, lu, epsilon = 1.0e-7));
 }
 
+#[test]
+fn lu_rcond_singular() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    assert_eq!(m.lu().rcond(), 0.0);
+    assert_eq!(Matrix3::<f64>::identity().lu().rcond(), 1.0);
+}
+
 #[test]
 #[rustfmt::skip]
 fn lu_simple_with_pivot() {
@@ -43,7 +50,7 @@ mod proptest_tests {
     macro_rules! gen_tests(
         ($module: ident, $scalar: expr, $scalar_type: ty) => {
             mod $module {
-                use na::{DMatrix, Matrix4x3, DVector, Vector4};
+                use na::{DMatrix, Matrix4x3, DVector, InducedOneNorm, Vector4};
                 #[allow(unused_imports)]
                 use crate::core::helper::{RandScalar, RandComplex};
                 use crate::proptest::*;
@@ -117,6 +124,18 @@ mod proptest_tests {
                          prop_assert!(sol2.is_none() || relative_eq!(&m * sol2.unwrap(), b2, epsilon = 1.0e-6));
                     }
 
+                    #[test]
+                    fn lu_solve_refined(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let b = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+
+                        if let Some(refined) = m.clone().lu().solve_refined(&m, &b, 10) {
+                            prop_assert!(refined.iterations <= 10);
+                            prop_assert!(refined.backward_error <= 1.0e-12);
+                            prop_assert!(relative_eq!(&m * refined.solution, b, epsilon = 1.0e-6));
+                        }
+                    }
+
                     #[test]
                     fn lu_inverse(n in PROPTEST_MATRIX_DIM) {
                         let m  = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
@@ -148,6 +167,20 @@ mod proptest_tests {
                             prop_assert!(id2.is_identity(1.0e-5));
                         }
                     }
+
+                    #[test]
+                    fn lu_rcond(n in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let rcond = m.clone().lu().rcond();
+
+                        if let Some(m1) = m.clone().try_inverse() {
+                            let exact = 1.0 / (m.apply_norm(&InducedOneNorm) * m1.apply_norm(&InducedOneNorm));
+
+                            // The norms are underestimated, but by a small factor only.
+                            prop_assert!(rcond >= exact * (1.0 - 1.0e-7));
+                            prop_assert!(rcond <= exact * 10.0);
+                        }
+                    }
                 }
             }
         }
@@ -155,4 +188,21 @@ mod proptest_tests {
 
     gen_tests!(complex, complex_f64(), RandComplex<f64>);
     gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+
+    use crate::proptest::*;
+    use na::{InducedInfinityNorm, Matrix4};
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn lu_solve_refined_mixed_precision(m in matrix4(), b in vector4()) {
+            // Make the matrix diagonally dominant so that it is well-conditioned.
+            let m = m + Matrix4::identity() * (m.apply_norm(&InducedInfinityNorm) + 1.0);
+            let refined = m.cast::<f32>().lu().solve_refined(&m, &b, 10).unwrap();
+
+            prop_assert!(refined.iterations > 0);
+            prop_assert!(refined.backward_error <= 1.0e-14);
+            prop_assert!(relative_eq!(m * refined.solution, b, epsilon = 1.0e-10));
+        }
+    }
 }
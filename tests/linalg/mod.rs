@@ -1,18 +1,29 @@
 mod balancing;
 mod bidiagonal;
 mod cholesky;
+mod cod;
 mod col_piv_qr;
+mod cond;
 mod convolution;
 mod eigen;
 mod exp;
 mod full_piv_lu;
+mod generalized_eigen;
+mod generalized_schur;
+mod generalized_symmetric_eigen;
 mod hessenberg;
 mod inverse;
+mod ldlt;
+mod ln;
+mod lq;
 mod lu;
 mod pow;
 mod qr;
+mod rq;
 mod schur;
+mod sign;
 mod solve;
+mod sqrt;
 mod svd;
 mod tridiagonal;
 mod udu;
@@ -3,7 +3,7 @@
 macro_rules! gen_tests(
     ($module: ident, $scalar: expr, $scalar_type: ty) => {
         mod $module {
-            use na::{DMatrix, DVector, Matrix4x3, Vector4};
+            use na::{DMatrix, DVector, InducedOneNorm, Matrix4x3, RowDVector, RowVector4, Vector3, Vector4, Vector5};
             use std::cmp;
             #[allow(unused_imports)]
             use crate::core::helper::{RandScalar, RandComplex};
@@ -83,6 +83,139 @@ macro_rules! gen_tests(
                      }
                 }
 
+                #[test]
+                fn qr_least_squares(m in dmatrix_($scalar), nb in PROPTEST_MATRIX_DIM) {
+                    let m = if m.nrows() < m.ncols() { m.transpose() } else { m };
+                    let b = DMatrix::<$scalar_type>::new_random(m.nrows(), nb).map(|e| e.0);
+
+                    if let Some((x, residual)) = m.clone().qr().solve_least_squares(&b) {
+                        let r = &m * &x - &b;
+
+                        // The residual is orthogonal to the range of `m`.
+                        prop_assert!(relative_eq!(m.adjoint() * &r, DMatrix::zeros(m.ncols(), nb), epsilon = 1.0e-6));
+                        prop_assert!(relative_eq!(residual, r.norm(), epsilon = 1.0e-7));
+                    }
+                }
+
+                #[test]
+                fn qr_least_squares_static(m in matrix5x3_($scalar)) {
+                    let b = Vector5::<$scalar_type>::new_random().map(|e| e.0);
+
+                    if let Some((x, residual)) = m.qr().solve_least_squares(&b) {
+                        let r = m * x - b;
+
+                        prop_assert!(relative_eq!(m.adjoint() * r, Vector3::zeros(), epsilon = 1.0e-6));
+                        prop_assert!(relative_eq!(residual, r.norm(), epsilon = 1.0e-7));
+                    }
+                }
+
+                #[test]
+                fn qr_rank_one_update(m in dmatrix_($scalar)) {
+                    let u = DVector::<$scalar_type>::new_random(m.nrows()).map(|e| e.0);
+                    let v = DVector::<$scalar_type>::new_random(m.ncols()).map(|e| e.0);
+                    let m_updated = &m + &u * v.adjoint();
+
+                    let mut qr = m.qr();
+                    qr.rank_one_update(&u, &v);
+                    let (q, r) = (qr.q(), qr.r());
+
+                    prop_assert!(relative_eq!(m_updated, &q * &r, epsilon = 1.0e-7));
+                    prop_assert!(q.is_orthogonal(1.0e-7));
+                    prop_assert!(r.upper_triangle() == r);
+
+                    // Subsequent updates start from the explicit factors.
+                    let u2 = DVector::<$scalar_type>::new_random(m_updated.nrows()).map(|e| e.0);
+                    let v2 = DVector::<$scalar_type>::new_random(m_updated.ncols()).map(|e| e.0);
+                    qr.rank_one_update(&u2, &v2);
+
+                    prop_assert!(relative_eq!(m_updated + &u2 * v2.adjoint(), qr.q() * qr.r(), epsilon = 1.0e-7));
+                }
+
+                #[test]
+                fn qr_rank_one_update_static(m in matrix5x3_($scalar)) {
+                    let u = Vector5::<$scalar_type>::new_random().map(|e| e.0);
+                    let v = Vector3::<$scalar_type>::new_random().map(|e| e.0);
+
+                    let mut qr = m.qr();
+                    qr.rank_one_update(&u, &v);
+
+                    prop_assert!(relative_eq!(m + u * v.adjoint(), qr.q() * qr.r(), epsilon = 1.0e-7));
+                }
+
+                #[test]
+                fn qr_insert_column(m in dmatrix_($scalar), j in 0usize..=20) {
+                    let j = j % (m.ncols() + 1);
+                    let col = DVector::<$scalar_type>::new_random(m.nrows()).map(|e| e.0);
+                    let mut m_updated = m.clone().insert_column(j, na::zero());
+                    m_updated.set_column(j, &col);
+
+                    let qr = m.qr().insert_column(j, col);
+                    let (q, r) = (qr.q(), qr.r());
+
+                    prop_assert!(relative_eq!(m_updated, &q * &r, epsilon = 1.0e-7));
+                    prop_assert!(q.is_orthogonal(1.0e-7));
+                    prop_assert!(r.upper_triangle() == r);
+                }
+
+                #[test]
+                fn qr_remove_column(m in dmatrix_($scalar), j in 0usize..20) {
+                    let j = j % m.ncols();
+                    let qr = m.clone().qr().remove_column(j);
+                    let (q, r) = (qr.q(), qr.r());
+
+                    prop_assert!(relative_eq!(m.remove_column(j), &q * &r, epsilon = 1.0e-7));
+                    prop_assert!(q.is_orthogonal(1.0e-7));
+                    prop_assert!(r.upper_triangle() == r);
+                }
+
+                #[test]
+                fn qr_insert_row(m in dmatrix_($scalar), i in 0usize..=20) {
+                    let i = i % (m.nrows() + 1);
+                    let row = RowDVector::<$scalar_type>::new_random(m.ncols()).map(|e| e.0);
+                    let mut m_updated = m.clone().insert_row(i, na::zero());
+                    m_updated.set_row(i, &row);
+
+                    let qr = m.qr().insert_row(i, row);
+                    let (q, r) = (qr.q(), qr.r());
+
+                    prop_assert!(relative_eq!(m_updated, &q * &r, epsilon = 1.0e-7));
+                    prop_assert!(q.is_orthogonal(1.0e-7));
+                    prop_assert!(r.upper_triangle() == r);
+                }
+
+                #[test]
+                fn qr_remove_row(m in dmatrix_($scalar), i in 0usize..20) {
+                    let i = i % m.nrows();
+                    let qr = m.clone().qr().remove_row(i);
+                    let (q, r) = (qr.q(), qr.r());
+
+                    prop_assert!(relative_eq!(m.remove_row(i), &q * &r, epsilon = 1.0e-7));
+                    prop_assert!(q.is_orthogonal(1.0e-7));
+                    prop_assert!(r.upper_triangle() == r);
+                }
+
+                #[test]
+                fn qr_updates_static(m in matrix4_($scalar)) {
+                    let col = Vector4::<$scalar_type>::new_random().map(|e| e.0);
+                    let row = RowVector4::<$scalar_type>::new_random().map(|e| e.0);
+
+                    // Replace the second column, then the last row.
+                    let qr = m.qr().remove_column(1).insert_column(1, col);
+                    let qr = qr.remove_row(3).insert_row(3, row);
+
+                    let mut m_updated = m;
+                    m_updated.set_column(1, &col);
+                    m_updated.set_row(3, &row);
+
+                    prop_assert!(relative_eq!(m_updated, qr.q() * qr.r(), epsilon = 1.0e-7));
+
+                    if qr.is_invertible() {
+                        let b = Vector4::<$scalar_type>::new_random().map(|e| e.0);
+                        let sol = qr.solve(&b).unwrap();
+                        prop_assert!(relative_eq!(m_updated * sol, b, epsilon = 1.0e-6));
+                    }
+                }
+
                 #[test]
                 fn qr_inverse(n in PROPTEST_MATRIX_DIM) {
                     let n = cmp::max(1, cmp::min(n, 15)); // To avoid slowing down the test too much.
@@ -109,6 +242,20 @@ macro_rules! gen_tests(
                         prop_assert!(id2.is_identity(1.0e-5));
                     }
                 }
+
+                #[test]
+                fn qr_rcond(n in PROPTEST_MATRIX_DIM) {
+                    let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                    let rcond = m.clone().qr().rcond();
+
+                    if let Some(m1) = m.clone().try_inverse() {
+                        let exact = 1.0 / (m.apply_norm(&InducedOneNorm) * m1.apply_norm(&InducedOneNorm));
+
+                        // The norms are underestimated, but by a small factor only.
+                        prop_assert!(rcond >= exact * (1.0 - 1.0e-7));
+                        prop_assert!(rcond <= exact * 10.0);
+                    }
+                }
             }
         }
     }
@@ -116,3 +263,53 @@ macro_rules! gen_tests(
 
 gen_tests!(complex, complex_f64(), RandComplex<f64>);
 gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+
+#[test]
+fn qr_update_with_zero_diagonal() {
+    // The first column is zero, so `R` has a zero on its diagonal after each update.
+    let m = na::Matrix3::new(0.0, 1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 5.0, -6.0);
+    let col = na::Vector3::new(1.0, -2.0, 3.0);
+
+    let qr = m.qr().remove_column(2);
+    assert!(relative_eq!(
+        m.remove_column(2),
+        qr.q() * qr.r(),
+        epsilon = 1.0e-12
+    ));
+
+    let qr = qr.insert_column(2, col);
+    let mut m_updated = m;
+    m_updated.set_column(2, &col);
+    assert!(relative_eq!(m_updated, qr.q() * qr.r(), epsilon = 1.0e-12));
+    assert!(qr.q().is_orthogonal(1.0e-12));
+}
+
+#[test]
+fn qr_update_with_zero_diagonal_complex() {
+    let c = |re, im| na::Complex::new(re, im);
+    let m = na::Matrix3::new(
+        c(0.0, 0.0),
+        c(1.0, 2.0),
+        c(2.0, -1.0),
+        c(0.0, 0.0),
+        c(3.0, -1.0),
+        c(4.0, 0.5),
+        c(0.0, 0.0),
+        c(-5.0, 1.0),
+        c(-6.0, 2.0),
+    );
+    let col = na::Vector3::new(c(1.0, 1.0), c(-2.0, 0.5), c(3.0, -3.0));
+
+    let qr = m.qr().remove_column(2);
+    assert!(relative_eq!(
+        m.remove_column(2),
+        qr.q() * qr.r(),
+        epsilon = 1.0e-12
+    ));
+
+    let qr = qr.insert_column(2, col);
+    let mut m_updated = m;
+    m_updated.set_column(2, &col);
+    assert!(relative_eq!(m_updated, qr.q() * qr.r(), epsilon = 1.0e-12));
+    assert!(qr.q().is_orthogonal(1.0e-12));
+}
@@ -0,0 +1,88 @@
+use na::{Matrix3, Matrix3x4, Vector3};
+
+#[test]
+fn rq_camera_matrix() {
+    let k = Matrix3::new(800.0, 0.5, 320.0, 0.0, 780.0, 240.0, 0.0, 0.0, 1.0);
+    let rot = na::Rotation3::from_euler_angles(0.1, -0.4, 1.2);
+    let t = Vector3::new(1.0, 2.0, 3.0);
+    let p = k * Matrix3x4::from_columns(&[
+        rot.matrix().column(0).into_owned(),
+        rot.matrix().column(1).into_owned(),
+        rot.matrix().column(2).into_owned(),
+        t,
+    ]);
+
+    let (r, q) = p.fixed_columns::<3>(0).into_owned().rq().unpack();
+
+    assert_relative_eq!(r, k, epsilon = 1.0e-7);
+    assert_relative_eq!(q, *rot.matrix(), epsilon = 1.0e-7);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr) => {
+            mod $module {
+                use num::Zero;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn rq(m in dmatrix_($scalar)) {
+                        let rq = m.clone().rq();
+                        let r  = rq.r();
+                        let q  = rq.q();
+                        let offset = m.nrows().saturating_sub(m.ncols());
+
+                        prop_assert!(relative_eq!(m, &r * &q, epsilon = 1.0e-7));
+                        prop_assert!((&q * q.adjoint()).is_identity(1.0e-7));
+
+                        // `r` is zero below its `offset`-th subdiagonal.
+                        for i in 0..r.nrows() {
+                            for j in 0..r.ncols() {
+                                if i > j + offset {
+                                    prop_assert!(r[(i, j)].is_zero());
+                                }
+                            }
+                        }
+                    }
+
+                    #[test]
+                    fn rq_static_5_3(m in matrix5x3_($scalar)) {
+                        let (r, q) = m.rq().unpack();
+
+                        prop_assert!(relative_eq!(m, r * q, epsilon = 1.0e-7));
+                        prop_assert!(q.is_orthogonal(1.0e-7));
+                        // The last three rows of `r` are upper-triangular.
+                        let r_bottom = r.fixed_rows::<3>(2);
+                        prop_assert!(r_bottom.upper_triangle() == r_bottom);
+                    }
+
+                    #[test]
+                    fn rq_static_3_5(m in matrix3x5_($scalar)) {
+                        let (r, q) = m.rq().unpack();
+
+                        prop_assert!(relative_eq!(m, r * q, epsilon = 1.0e-7));
+                        prop_assert!((q * q.adjoint()).is_identity(1.0e-7));
+                        prop_assert!(r.upper_triangle() == r);
+                    }
+
+                    #[test]
+                    fn rq_static_square(m in matrix4_($scalar)) {
+                        let (r, q) = m.rq().unpack();
+
+                        prop_assert!(relative_eq!(m, r * q, epsilon = 1.0e-7));
+                        prop_assert!(q.is_orthogonal(1.0e-7));
+                        prop_assert!(r.upper_triangle() == r);
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64());
+    gen_tests!(f64, PROPTEST_F64);
+}
@@ -13,6 +13,15 @@ fn schur_simpl_mat3() {
     assert!(relative_eq!(vecs * vals * vecs.transpose(), m, epsilon = 1.0e-7));
 }
 
+#[test]
+fn schur_zero_matrix() {
+    let m = DMatrix::<f64>::zeros(4, 4);
+    let (vecs, vals) = m.clone().schur().unpack();
+
+    assert_eq!(vals, m);
+    assert!(relative_eq!(vecs * vals, m));
+}
+
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
     macro_rules! gen_tests(
@@ -0,0 +1,75 @@
+use na::{DMatrix, Matrix2, Matrix3};
+
+#[test]
+#[rustfmt::skip]
+fn sign_triangular() {
+    let m = Matrix3::new(2.0, 1.0,  4.0,
+                         0.0, -3.0, 1.0,
+                         0.0, 0.0,  5.0);
+    let sign = m.sign().unwrap();
+
+    assert_relative_eq!(sign.diagonal(), na::Vector3::new(1.0, -1.0, 1.0), epsilon = 1.0e-10);
+    assert_relative_eq!(sign * sign, Matrix3::identity(), epsilon = 1.0e-10);
+    assert_relative_eq!(m * sign, sign * m, epsilon = 1.0e-10);
+}
+
+#[test]
+fn sign_imaginary_eigenvalues() {
+    let m = Matrix2::new(0.0, -1.0, 1.0, 0.0);
+    assert!(m.sign().is_none());
+}
+
+#[test]
+fn sign_large_dimension() {
+    // The determinant of this matrix overflows.
+    let mut m = DMatrix::from_diagonal_element(128, 128, 1.0e3);
+    m[(0, 0)] = -1.0e3;
+
+    let mut expected = DMatrix::identity(128, 128);
+    expected[(0, 0)] = -1.0;
+    assert_relative_eq!(m.sign().unwrap(), expected, epsilon = 1.0e-10);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::{DMatrix, Matrix4, Vector4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    // The matrices are shifted so that none of their eigenvalues is too close
+                    // to the imaginary axis.
+                    #[test]
+                    fn sign(n in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let m = m.unscale(m.norm()) + DMatrix::from_diagonal_element(n, n, na::convert(2.0));
+                        let sign = m.sign().unwrap();
+
+                        prop_assert!(relative_eq!(sign, DMatrix::identity(n, n), epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!((-m).sign().unwrap(), -DMatrix::identity(n, n), epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn sign_static_square_4x4(m in matrix4_($scalar)) {
+                        let q = m.qr().q();
+                        let d = Vector4::new(1.0, -2.0, 3.0, -0.5).map(na::convert);
+                        let m = q * Matrix4::from_diagonal(&d) * q.adjoint();
+                        let sign = m.sign().unwrap();
+
+                        prop_assert!(relative_eq!(sign * sign, Matrix4::identity(), epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(sign, m.map_symmetric(|e| e.signum()), epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
@@ -0,0 +1,86 @@
+use na::{DMatrix, Matrix2, Rotation2};
+
+#[test]
+fn sqrt_rotation() {
+    let rot = Rotation2::new(1.2);
+    let half_rot = Rotation2::new(0.6);
+
+    assert_relative_eq!(
+        rot.matrix().clone_owned().sqrt().unwrap(),
+        half_rot.matrix().clone_owned(),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn sqrt_negative_eigenvalue() {
+    let m = Matrix2::new(-1.0, 0.0, 3.0, 2.0);
+    assert!(m.sqrt().is_none());
+}
+
+#[test]
+fn sqrt_singular() {
+    let m = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+    assert!(m.sqrt().is_none());
+}
+
+#[test]
+fn sqrt_large_dimension() {
+    // The determinant of these matrices overflows or underflows.
+    for value in [1.0e3, 1.0e-3] {
+        let m = DMatrix::from_diagonal_element(128, 128, value);
+        assert_relative_eq!(
+            m.sqrt().unwrap(),
+            DMatrix::from_diagonal_element(128, 128, f64::sqrt(value)),
+            epsilon = 1.0e-10
+        );
+    }
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::{DMatrix, Matrix4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    // The matrices are shifted so that their eigenvalues have a positive real part.
+                    #[test]
+                    fn sqrt(n in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let m = m.unscale(m.norm()) + DMatrix::from_diagonal_element(n, n, na::convert(2.0));
+                        let sqrt = m.sqrt().unwrap();
+
+                        prop_assert!(relative_eq!(&sqrt * &sqrt, m, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn sqrt_static_square_4x4(m in matrix4_($scalar)) {
+                        let m = m.unscale(m.norm() + 1.0) + Matrix4::from_diagonal_element(na::convert(2.0));
+                        let sqrt = m.sqrt().unwrap();
+
+                        prop_assert!(relative_eq!(sqrt * sqrt, m, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn sqrt_map_symmetric(m in matrix4_($scalar)) {
+                        let m = m.unscale(m.norm() + 1.0);
+                        let m = m * m.adjoint() + Matrix4::identity();
+                        let sqrt = m.sqrt().unwrap();
+
+                        prop_assert!(relative_eq!(sqrt, m.map_symmetric(|e| e.sqrt()), epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
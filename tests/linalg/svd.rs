@@ -1,5 +1,5 @@
 use crate::utils::is_sorted_descending;
-use na::{DMatrix, Matrix6};
+use na::{DMatrix, Matrix3, Matrix6, SVDAlgorithm, SVD};
 
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
@@ -8,7 +8,7 @@ mod proptest_tests {
             mod $module {
                 use na::{
                     DMatrix, DVector, Matrix2, Matrix3, Matrix4,
-                    ComplexField
+                    ComplexField, SVDAlgorithm, SVD
                 };
                 use std::cmp;
                 #[allow(unused_imports)]
@@ -119,6 +119,59 @@ mod proptest_tests {
                         prop_assert!(is_sorted_descending(s.as_slice()));
                     }
 
+                    #[test]
+                    fn svd_jacobi(m in dmatrix_($scalar)) {
+                        let svd = SVD::new_with_algorithm(m.clone(), true, true, SVDAlgorithm::Jacobi);
+                        let reference = m.singular_values();
+                        let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+                        let ds = DMatrix::from_diagonal(&s.map(|e| ComplexField::from_real(e)));
+
+                        prop_assert!(s.iter().all(|e| *e >= 0.0));
+                        prop_assert!(is_sorted_descending(s.as_slice()));
+                        prop_assert!(relative_eq!(s, reference, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(m, &u * ds * &v_t, epsilon = 1.0e-7));
+                        prop_assert!((u.ad_mul(&u)).is_identity(1.0e-7));
+                        prop_assert!((&v_t * v_t.adjoint()).is_identity(1.0e-7));
+                    }
+
+                    #[test]
+                    fn svd_jacobi_static_5_3(m in matrix5x3_($scalar)) {
+                        let svd = SVD::new_with_algorithm(m, true, true, SVDAlgorithm::Jacobi);
+                        let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+                        let ds = Matrix3::from_diagonal(&s.map(|e| ComplexField::from_real(e)));
+
+                        prop_assert!(is_sorted_descending(s.as_slice()));
+                        prop_assert!(relative_eq!(m, &u * ds * &v_t, epsilon = 1.0e-7));
+                        prop_assert!(u.is_orthogonal(1.0e-7));
+                        prop_assert!(v_t.is_orthogonal(1.0e-7));
+                    }
+
+                    #[test]
+                    fn svd_jacobi_static_2_5(m in matrix2x5_($scalar)) {
+                        let svd = SVD::new_with_algorithm(m, true, true, SVDAlgorithm::Jacobi);
+                        let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+                        let ds = Matrix2::from_diagonal(&s.map(|e| ComplexField::from_real(e)));
+
+                        prop_assert!(is_sorted_descending(s.as_slice()));
+                        prop_assert!(relative_eq!(m, u * ds * v_t, epsilon = 1.0e-7));
+                        prop_assert!(u.is_orthogonal(1.0e-7));
+                    }
+
+                    #[test]
+                    fn svd_divide_and_conquer(m in matrix($scalar, 1..=60, 1..=60)) {
+                        let svd = SVD::new_with_algorithm(m.clone(), true, true, SVDAlgorithm::DivideAndConquer);
+                        let reference = m.singular_values();
+                        let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+                        let ds = DMatrix::from_diagonal(&s.map(|e| ComplexField::from_real(e)));
+
+                        prop_assert!(s.iter().all(|e| *e >= 0.0));
+                        prop_assert!(is_sorted_descending(s.as_slice()));
+                        prop_assert!(relative_eq!(s, reference, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(m, &u * ds * &v_t, epsilon = 1.0e-7));
+                        prop_assert!((u.ad_mul(&u)).is_identity(1.0e-7));
+                        prop_assert!((&v_t * v_t.adjoint()).is_identity(1.0e-7));
+                    }
+
                     #[test]
                     fn svd_pseudo_inverse(m in dmatrix_($scalar)) {
                         let svd = m.clone().svd(true, true);
@@ -220,6 +273,16 @@ fn svd_singular() {
     assert!(u.is_orthogonal(1.0e-5));
     assert!(v_t.is_orthogonal(1.0e-5));
     assert_relative_eq!(m, &u * ds * &v_t, epsilon = 1.0e-5);
+
+    for algorithm in [SVDAlgorithm::Jacobi, SVDAlgorithm::DivideAndConquer] {
+        let svd = SVD::new_with_algorithm(m.clone(), true, true, algorithm);
+        let (u, v_t) = (svd.u.clone().unwrap(), svd.v_t.clone().unwrap());
+
+        assert_relative_eq!(svd.singular_values, s, epsilon = 1.0e-10);
+        assert!(u.is_orthogonal(1.0e-5));
+        assert!(v_t.is_orthogonal(1.0e-5));
+        assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-5);
+    }
 }
 
 // Same as the previous test but with one additional row.
@@ -405,6 +468,18 @@ fn svd3_fail() {
     assert_relative_eq!(m, recomp, epsilon = 1.0e-5);
 }
 
+#[test]
+fn svd2_nearly_equal_singular_values() {
+    // The singular values of this matrix are nearly equal, which made the previous closed-form
+    // 2x2 kernel lose about five digits on the singular vectors.
+    let m = DMatrix::from_column_slice(2, 2, &[-1.3557, 1.6758, 2.6682, 2.1585]);
+    let svd = m.clone().svd(true, true);
+
+    assert!(svd.u.as_ref().unwrap().is_orthogonal(1.0e-14));
+    assert!(svd.v_t.as_ref().unwrap().is_orthogonal(1.0e-14));
+    assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-12);
+}
+
 #[test]
 fn svd_err() {
     let m = DMatrix::from_element(10, 10, 0.0);
@@ -460,3 +535,100 @@ fn svd_sorted() {
         epsilon = 1.0e-5
     );
 }
+
+#[test]
+fn svd_jacobi_high_relative_accuracy() {
+    // A matrix with orthogonal columns of widely different norms: its singular values are
+    // exactly the column norms.
+    let q = na::Rotation3::from_euler_angles(0.3, -1.2, 2.1).into_inner();
+    let d = Matrix3::from_diagonal(&na::Vector3::new(1.0, 1.0e-8, 1.0e-16));
+    let m = q * d;
+
+    let svd = SVD::new_with_algorithm(m, true, true, SVDAlgorithm::Jacobi);
+
+    assert_relative_eq!(svd.singular_values[0], 1.0, max_relative = 1.0e-12);
+    assert_relative_eq!(svd.singular_values[1], 1.0e-8, max_relative = 1.0e-12);
+    assert_relative_eq!(svd.singular_values[2], 1.0e-16, max_relative = 1.0e-12);
+    assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-14);
+}
+
+#[test]
+fn svd_jacobi_rank_deficient() {
+    let a = DMatrix::<f64>::new_random(12, 3);
+    let b = DMatrix::<f64>::new_random(3, 8);
+
+    for m in [&a * &b, (&a * &b).transpose()] {
+        let svd = SVD::new_with_algorithm(m.clone(), true, true, SVDAlgorithm::Jacobi);
+        let (u, v_t) = (svd.u.clone().unwrap(), svd.v_t.clone().unwrap());
+
+        assert_eq!(svd.rank(1.0e-10), 3);
+        assert!((u.transpose() * &u).is_identity(1.0e-10));
+        assert!((&v_t * v_t.transpose()).is_identity(1.0e-10));
+        assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn svd_zeros_with_algorithm() {
+    for algorithm in [SVDAlgorithm::Jacobi, SVDAlgorithm::DivideAndConquer] {
+        for (nrows, ncols) in [(10, 10), (40, 30), (30, 40)] {
+            let m = DMatrix::from_element(nrows, ncols, 0.0);
+            let svd = SVD::new_with_algorithm(m.clone(), true, true, algorithm);
+            let (u, v_t) = (svd.u.clone().unwrap(), svd.v_t.clone().unwrap());
+
+            assert!((u.transpose() * &u).is_identity(1.0e-10));
+            assert!((&v_t * v_t.transpose()).is_identity(1.0e-10));
+            assert_eq!(Ok(m), svd.recompose());
+        }
+    }
+}
+
+#[test]
+fn svd_divide_and_conquer_large() {
+    for (nrows, ncols) in [(150, 120), (120, 150), (200, 200)] {
+        let m = DMatrix::<f64>::new_random(nrows, ncols);
+        let svd = m
+            .clone()
+            .svd_with_algorithm(true, true, SVDAlgorithm::DivideAndConquer);
+        let (u, v_t) = (svd.u.clone().unwrap(), svd.v_t.clone().unwrap());
+
+        assert!(is_sorted_descending(svd.singular_values.as_slice()));
+        assert_relative_eq!(svd.singular_values, m.singular_values(), epsilon = 1.0e-10);
+        assert!((u.transpose() * &u).is_identity(1.0e-10));
+        assert!((&v_t * v_t.transpose()).is_identity(1.0e-10));
+        assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn svd_divide_and_conquer_clustered() {
+    // Repeated and vanishing singular values exercise the deflation of the merge steps.
+    let n = 70;
+    let q1 = DMatrix::<f64>::new_random(n, n).qr().q();
+    let q2 = DMatrix::<f64>::new_random(n, n).qr().q();
+    let expected = na::DVector::from_fn(n, |i, _| match i % 4 {
+        0 => 2.0,
+        1 => 1.0,
+        2 => 1.0 + 1.0e-15,
+        _ => 0.0,
+    });
+    let m = &q1 * DMatrix::from_diagonal(&expected) * &q2;
+
+    let svd = SVD::new_with_algorithm(m.clone(), true, true, SVDAlgorithm::DivideAndConquer);
+    let (u, v_t) = (svd.u.clone().unwrap(), svd.v_t.clone().unwrap());
+    let mut expected = expected.as_slice().to_vec();
+    expected.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+    assert_relative_eq!(
+        svd.singular_values,
+        na::DVector::from_vec(expected),
+        epsilon = 1.0e-10
+    );
+    assert!((u.transpose() * &u).is_identity(1.0e-10));
+    assert!((&v_t * v_t.transpose()).is_identity(1.0e-10));
+    assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-10);
+
+    let identity = DMatrix::<f64>::identity(50, 50);
+    let svd = SVD::new_with_algorithm(identity.clone(), true, true, SVDAlgorithm::DivideAndConquer);
+    assert_relative_eq!(identity, svd.recompose().unwrap(), epsilon = 1.0e-12);
+}